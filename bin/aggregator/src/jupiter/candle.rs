@@ -3,6 +3,8 @@
 
 use crate::log_ms;
 use crate::time::sleep_ms;
+use base::model::ChangeKind;
+use base::repo::TokenPairChangeRepo;
 use common::model::Partition;
 use log::trace;
 use solana::jupiter::repo::CandleRepo;
@@ -12,6 +14,7 @@ use tokio::task::JoinHandle;
 pub struct RefreshCandles {
     pool: PgPool,
     repo: CandleRepo,
+    change_repo: TokenPairChangeRepo,
 }
 
 impl RefreshCandles {
@@ -19,12 +22,14 @@ impl RefreshCandles {
         Self {
             pool,
             repo: CandleRepo::new(),
+            change_repo: TokenPairChangeRepo::new(),
         }
     }
 
     pub async fn refresh(&self) -> Vec<JoinHandle<()>> {
         let mut result = Vec::new();
         let repo = self.repo.clone();
+        let change_repo = self.change_repo.clone();
         let pool = self.pool.clone();
 
         for partition in Partition::enumerate() {
@@ -47,33 +52,41 @@ impl RefreshCandles {
             }));
 
             let repo_candle = repo.clone();
+            let change_repo = change_repo.clone();
             let pool_candle = pool.clone();
             result.push(tokio::spawn(async move {
                 loop {
                     if let Ok(Some(mut tx)) = pool_candle.try_begin().await {
-                        log_ms!("calculate_1m", partition, async {
-                            repo_candle.calculate_1m(&mut tx, partition).await.unwrap();
-                        });
+                        let mut changed = Vec::new();
 
-                        log_ms!("calculate_5m", partition, async {
-                            repo_candle.calculate_5m(&mut tx, partition).await.unwrap();
-                        });
+                        changed.extend(log_ms!("calculate_1m", partition, async {
+                            repo_candle.calculate_1m(&mut tx, partition).await.unwrap()
+                        }));
 
-                        log_ms!("calculate_15m", partition, async {
-                            repo_candle.calculate_15m(&mut tx, partition).await.unwrap();
-                        });
+                        changed.extend(log_ms!("calculate_5m", partition, async {
+                            repo_candle.calculate_5m(&mut tx, partition).await.unwrap()
+                        }));
 
-                        log_ms!("calculate_1h", partition, async {
-                            repo_candle.calculate_1h(&mut tx, partition).await.unwrap();
-                        });
+                        changed.extend(log_ms!("calculate_15m", partition, async {
+                            repo_candle.calculate_15m(&mut tx, partition).await.unwrap()
+                        }));
 
-                        log_ms!("calculate_6h", partition, async {
-                            repo_candle.calculate_6h(&mut tx, partition).await.unwrap();
-                        });
+                        changed.extend(log_ms!("calculate_1h", partition, async {
+                            repo_candle.calculate_1h(&mut tx, partition).await.unwrap()
+                        }));
 
-                        log_ms!("calculate_1d", partition, async {
-                            repo_candle.calculate_1d(&mut tx, partition).await.unwrap();
-                        });
+                        changed.extend(log_ms!("calculate_6h", partition, async {
+                            repo_candle.calculate_6h(&mut tx, partition).await.unwrap()
+                        }));
+
+                        changed.extend(log_ms!("calculate_1d", partition, async {
+                            repo_candle.calculate_1d(&mut tx, partition).await.unwrap()
+                        }));
+
+                        change_repo
+                            .insert(&mut tx, ChangeKind::Candle, changed)
+                            .await
+                            .unwrap();
 
                         let _ = tx.commit().await;
                         sleep_ms(500, 1000).await;
@@ -16,6 +16,7 @@ use tracing_subscriber::EnvFilter;
 mod config;
 mod jupiter;
 mod pumpfun;
+mod raydium;
 mod solana;
 mod time;
 
@@ -47,6 +48,10 @@ fn main() {
             pumpfun::RefreshSummaries::new(setup_pool(&config.postgres).await);
         let pumpfun_refresh_twaps = pumpfun::RefreshTwaps::new(setup_pool(&config.postgres).await);
 
+        let raydium_refresh_candles =
+            raydium::RefreshCandles::new(setup_pool(&config.postgres).await);
+        let raydium_refresh_twaps = raydium::RefreshTwaps::new(setup_pool(&config.postgres).await);
+
         let solana_refresh_sol = solana::RefreshSol::new(setup_pool(&config.postgres).await);
 
         let handles: Vec<JoinHandle<()>> = vec![
@@ -56,6 +61,8 @@ fn main() {
             pumpfun_refresh_candles.refresh().await,
             pumpfun_refresh_twaps.refresh().await,
             pumpfun_refresh_summaries.refresh().await,
+            raydium_refresh_candles.refresh().await,
+            raydium_refresh_twaps.refresh().await,
         ]
         .into_iter()
         .flatten()
@@ -3,6 +3,8 @@
 
 use crate::log_ms;
 use crate::time::sleep_ms;
+use base::model::ChangeKind;
+use base::repo::TokenPairChangeRepo;
 use common::model::Partition;
 use log::trace;
 use solana::pumpfun::repo::SummaryRepo;
@@ -12,6 +14,7 @@ use tokio::task::JoinHandle;
 pub struct RefreshSummaries {
     pool: PgPool,
     repo: SummaryRepo,
+    change_repo: TokenPairChangeRepo,
 }
 
 impl RefreshSummaries {
@@ -19,42 +22,52 @@ impl RefreshSummaries {
         Self {
             pool,
             repo: SummaryRepo::new(),
+            change_repo: TokenPairChangeRepo::new(),
         }
     }
 
     pub async fn refresh(&self) -> Vec<JoinHandle<()>> {
         let mut result = Vec::new();
         let repo = self.repo.clone();
+        let change_repo = self.change_repo.clone();
         let pool = self.pool.clone();
         for partition in Partition::enumerate() {
             let repo = repo.clone();
+            let change_repo = change_repo.clone();
             let pool = pool.clone();
             result.push(tokio::spawn(async move {
                 loop {
                     if let Ok(Some(mut tx)) = pool.try_begin().await {
-                        log_ms!("calculate_1m", partition, async {
+                        let mut changed = Vec::new();
+
+                        changed.extend(log_ms!("calculate_1m", partition, async {
                             repo.calculate_1m(&mut tx, partition).await.unwrap()
-                        });
+                        }));
 
-                        log_ms!("calculate_5m", partition, async {
+                        changed.extend(log_ms!("calculate_5m", partition, async {
                             repo.calculate_5m(&mut tx, partition).await.unwrap()
-                        });
+                        }));
 
-                        log_ms!("calculate_15m", partition, async {
+                        changed.extend(log_ms!("calculate_15m", partition, async {
                             repo.calculate_15m(&mut tx, partition).await.unwrap()
-                        });
+                        }));
 
-                        log_ms!("calculate_1h", partition, async {
+                        changed.extend(log_ms!("calculate_1h", partition, async {
                             repo.calculate_1h(&mut tx, partition).await.unwrap()
-                        });
+                        }));
 
-                        log_ms!("calculate_6h", partition, async {
+                        changed.extend(log_ms!("calculate_6h", partition, async {
                             repo.calculate_6h(&mut tx, partition).await.unwrap()
-                        });
+                        }));
 
-                        log_ms!("calculate_1d", partition, async {
+                        changed.extend(log_ms!("calculate_1d", partition, async {
                             repo.calculate_1d(&mut tx, partition).await.unwrap()
-                        });
+                        }));
+
+                        change_repo
+                            .insert(&mut tx, ChangeKind::Summary, changed)
+                            .await
+                            .unwrap();
 
                         let _ = tx.commit().await;
                         sleep_ms(500, 1000).await;
@@ -0,0 +1,8 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+pub use candle::RefreshCandles;
+pub use twap::RefreshTwaps;
+
+mod candle;
+mod twap;
@@ -15,6 +15,7 @@ use common::ConfigValue;
 pub struct Config {
     pub server: ServerConfig,
     pub postgres: PostgresConfig,
+    pub rpc: RpcConfig,
     pub telegram: TelegramConfig,
     pub wallet: WalletConfig,
 }
@@ -24,6 +25,11 @@ pub struct WalletConfig {
     pub secret: ConfigValue,
 }
 
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct RpcConfig {
+    pub url: ConfigValue,
+}
+
 #[derive(Clone, Debug, Default, Deserialize)]
 pub struct ServerConfig {
     pub port: ConfigValue,
@@ -4,9 +4,13 @@
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
+use common::model::ValidationError;
 use common::service::ServiceError;
+use log::error;
 use serde::{Deserialize, Deserializer};
 use serde_json::json;
+use solana::raydium;
+use solana::rpc::RpcClientError;
 
 #[derive(Debug, Deserialize, PartialEq)]
 pub enum HttpError {
@@ -16,6 +20,7 @@ pub enum HttpError {
     InternalServer(String),
     NotFound(String),
     Unprocessable(String),
+    Validation(Vec<ValidationError>),
 }
 
 impl HttpError {
@@ -42,6 +47,18 @@ impl HttpError {
     pub fn unprocessable(message: impl Into<String>) -> Self {
         Self::Unprocessable(message.into())
     }
+
+    pub fn message(&self) -> &str {
+        match self {
+            HttpError::BadRequest(message)
+            | HttpError::Conflict(message)
+            | HttpError::Forbidden(message)
+            | HttpError::InternalServer(message)
+            | HttpError::NotFound(message)
+            | HttpError::Unprocessable(message) => message,
+            HttpError::Validation(_) => VALIDATION_MESSAGE,
+        }
+    }
 }
 
 impl From<ServiceError> for HttpError {
@@ -51,14 +68,39 @@ impl From<ServiceError> for HttpError {
             ServiceError::Internal(_) => {
                 HttpError::InternalServer("Internal server error".to_string())
             }
+            ServiceError::Invalid(message) => HttpError::unprocessable(message),
             ServiceError::NotFound(message) => HttpError::not_found(message),
+            ServiceError::Validation(errors) => HttpError::Validation(errors),
+        }
+    }
+}
+
+impl From<raydium::Error> for HttpError {
+    fn from(value: raydium::Error) -> Self {
+        match value {
+            raydium::Error::InputIsOutputTokenError => HttpError::unprocessable(value.to_string()),
+            raydium::Error::MarketNotFoundError | raydium::Error::PoolKeysNotFoundError { .. } => {
+                HttpError::not_found("Market not found")
+            }
+            _ => HttpError::internal_server("Internal server error"),
         }
     }
 }
 
+impl From<RpcClientError> for HttpError {
+    fn from(value: RpcClientError) -> Self {
+        error!("{value}");
+        HttpError::internal_server("Internal server error")
+    }
+}
+
+const VALIDATION_MESSAGE: &str = "Validation failed";
+
 pub struct HttpErrorResponse {
     pub code: StatusCode,
     pub message: String,
+    /// Every problem of the request, only present if it failed validation
+    pub errors: Vec<ValidationError>,
 }
 
 impl<'de> Deserialize<'de> for HttpErrorResponse {
@@ -74,12 +116,15 @@ impl<'de> Deserialize<'de> for HttpErrorResponse {
         struct Error {
             code: u16,
             message: String,
+            #[serde(default)]
+            errors: Vec<ValidationError>,
         }
 
         let response: ErrorResponse = Deserialize::deserialize(deserializer)?;
         Ok(HttpErrorResponse {
             code: StatusCode::from_u16(response.error.code).unwrap(),
             message: response.error.message,
+            errors: response.error.errors,
         })
     }
 }
@@ -90,26 +135,37 @@ impl From<HttpError> for HttpErrorResponse {
             HttpError::BadRequest(message) => HttpErrorResponse {
                 code: StatusCode::BAD_REQUEST,
                 message,
+                errors: vec![],
             },
             HttpError::Conflict(message) => HttpErrorResponse {
                 code: StatusCode::CONFLICT,
                 message,
+                errors: vec![],
             },
             HttpError::Forbidden(message) => HttpErrorResponse {
                 code: StatusCode::FORBIDDEN,
                 message,
+                errors: vec![],
             },
             HttpError::InternalServer(message) => HttpErrorResponse {
                 code: StatusCode::INTERNAL_SERVER_ERROR,
                 message,
+                errors: vec![],
             },
             HttpError::NotFound(message) => HttpErrorResponse {
                 code: StatusCode::NOT_FOUND,
                 message,
+                errors: vec![],
             },
             HttpError::Unprocessable(message) => HttpErrorResponse {
                 code: StatusCode::UNPROCESSABLE_ENTITY,
                 message,
+                errors: vec![],
+            },
+            HttpError::Validation(errors) => HttpErrorResponse {
+                code: StatusCode::UNPROCESSABLE_ENTITY,
+                message: VALIDATION_MESSAGE.to_string(),
+                errors,
             },
         }
     }
@@ -118,12 +174,22 @@ impl From<HttpError> for HttpErrorResponse {
 impl IntoResponse for HttpErrorResponse {
     fn into_response(self) -> Response {
         let status = self.code;
-        let body = Json(json!({
-            "error": {
-                "code": status.as_u16(),
-                "message": self.message
-            }
-        }));
+        let body = if self.errors.is_empty() {
+            Json(json!({
+                "error": {
+                    "code": status.as_u16(),
+                    "message": self.message
+                }
+            }))
+        } else {
+            Json(json!({
+                "error": {
+                    "code": status.as_u16(),
+                    "message": self.message,
+                    "errors": self.errors
+                }
+            }))
+        };
         (status, body).into_response()
     }
 }
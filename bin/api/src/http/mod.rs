@@ -3,12 +3,12 @@
 
 pub use server::Server;
 
+pub mod error;
 pub mod middleware;
 pub mod model;
 pub mod state;
 pub mod v1;
 
-mod error;
 mod json;
 mod server;
 mod testing;
@@ -1,8 +1,12 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use base::model::{RuleId, RuleName, RuleStatus, Sequence};
+use base::model::{
+    RuleId, RuleLimit, RuleName, RuleStatus, Sequence, TokenPairId, ValidationError, Venue,
+};
+use common::model::Timestamp;
 use serde::{Deserialize, Serialize};
+use solana::backtest::BacktestMatch;
 
 #[derive(Deserialize, Debug)]
 pub struct HttpRuleCreateRequest {
@@ -24,6 +28,7 @@ pub struct HttpRuleUpdateRequest {
     pub name: Option<RuleName>,
     pub sequence: Option<Sequence>,
     pub status: Option<RuleStatus>,
+    pub limit: Option<RuleLimit>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -33,6 +38,7 @@ pub struct HttpRuleUpdateResponse {
     pub name: RuleName,
     pub status: RuleStatus,
     pub sequence: Sequence,
+    pub limit: RuleLimit,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -56,4 +62,33 @@ pub struct HttpRulGetResponse {
     pub name: RuleName,
     pub status: RuleStatus,
     pub sequence: Sequence,
+    pub limit: RuleLimit,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRuleBacktestRequest {
+    pub venue: Venue,
+    pub sequence: Sequence,
+    pub from: Timestamp,
+    pub to: Timestamp,
+    pub token_pairs: Vec<TokenPairId>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRuleBacktestResponse {
+    pub matches: Box<[BacktestMatch]>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct HttpRuleValidateRequest {
+    pub sequence: Sequence,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRuleValidateResponse {
+    pub valid: bool,
+    pub errors: Box<[ValidationError]>,
 }
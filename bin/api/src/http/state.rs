@@ -2,8 +2,13 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::config::Config;
+use crate::ws::Hub;
 use base::service::UserService;
-use base::service::{AuthService, RuleService};
+use base::service::{AuthService, RuleService, TransferService};
+use solana::backtest::BacktestService;
+use solana::pumpfun;
+use solana::raydium::Raydium;
+use solana::rpc::RpcClient;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -12,9 +17,24 @@ pub struct AppState(pub Arc<AppStateInner>);
 
 impl AppState {
     pub fn auth_service(&self) -> AuthService{ self.service.auth.clone()}
+    pub fn backtest_service(&self) -> BacktestService {
+        self.service.backtest.clone()
+    }
+    pub fn pumpfun_token_service(&self) -> pumpfun::service::TokenService {
+        self.service.pumpfun_token.clone()
+    }
+    pub fn raydium(&self) -> Arc<Raydium> {
+        self.service.raydium.clone()
+    }
+    pub fn rpc(&self) -> RpcClient {
+        self.service.rpc.clone()
+    }
     pub fn rule_service(&self) -> RuleService {
         self.service.rule.clone()
     }
+    pub fn transfer_service(&self) -> TransferService {
+        self.service.transfer.clone()
+    }
     pub fn user_service(&self) -> UserService {
         self.service.user.clone()
     }
@@ -30,12 +50,18 @@ impl Deref for AppState {
 #[derive(Clone)]
 pub struct AppStateInner {
     pub config: Config,
+    pub hub: Hub,
     pub service: Service,
 }
 
 #[derive(Clone)]
 pub struct Service {
     pub auth: AuthService,
+    pub backtest: BacktestService,
+    pub pumpfun_token: pumpfun::service::TokenService,
+    pub raydium: Arc<Raydium>,
+    pub rpc: RpcClient,
     pub rule: RuleService,
+    pub transfer: TransferService,
     pub user: UserService,
 }
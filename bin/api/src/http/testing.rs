@@ -2,7 +2,7 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 #[cfg(test)]
-use crate::config::{Config, TelegramConfig, WalletConfig};
+use crate::config::{Config, RpcConfig, TelegramConfig, WalletConfig};
 #[cfg(test)]
 use crate::http::error::HttpErrorResponse;
 #[cfg(test)]
@@ -10,6 +10,8 @@ use crate::http::state::{AppState, AppStateInner, Service};
 #[cfg(test)]
 use crate::router;
 #[cfg(test)]
+use crate::ws::Hub;
+#[cfg(test)]
 use axum::body::{to_bytes, Body};
 #[cfg(test)]
 use axum::response::Response;
@@ -18,7 +20,7 @@ use axum::{http, Router};
 #[cfg(test)]
 use base::service::AuthService;
 #[cfg(test)]
-use base::service::{RuleService, UserService};
+use base::service::{RuleService, TransferService, UserService};
 #[cfg(test)]
 use common::crypt::SecretKey;
 #[cfg(test)]
@@ -28,6 +30,14 @@ use common::ConfigValue;
 #[cfg(test)]
 use serde::de::DeserializeOwned;
 #[cfg(test)]
+use solana::backtest::BacktestService;
+#[cfg(test)]
+use solana::pumpfun;
+#[cfg(test)]
+use solana::raydium::Raydium;
+#[cfg(test)]
+use solana::rpc::RpcClient;
+#[cfg(test)]
 use sqlx::PgPool;
 #[cfg(test)]
 use std::future::Future;
@@ -50,25 +60,33 @@ pub(crate) struct Test {
 impl Test {
     pub(crate) async fn new_empty_db() -> Self {
         let pool = get_test_pool().await;
-        Self::setup(pool)
+        Self::setup(pool, RpcClient::new("http://localhost:8899"))
     }
 
     pub(crate) async fn new() -> Self {
+        Self::new_with_rpc(RpcClient::new("http://localhost:8899")).await
+    }
+
+    /// Serves the chain from rpc, e.g. a testing::rpc::LocalRpc
+    pub(crate) async fn new_with_rpc(rpc: RpcClient) -> Self {
         let pool = get_test_pool().await;
 
         let mut tx = pool.begin().await.unwrap();
         initialise_database(&mut tx).await;
         tx.commit().await.unwrap();
 
-        Self::setup(pool)
+        Self::setup(pool, rpc)
     }
 
-    fn setup(pool: PgPool) -> Self {
+    fn setup(pool: PgPool, rpc: RpcClient) -> Self {
         Self {
             router: router::setup_v1(AppState(Arc::new(AppStateInner {
                 config: Config {
                     server: Default::default(),
                     postgres: Default::default(),
+                    rpc: RpcConfig {
+                        url: ConfigValue::Value("http://localhost:8899".to_string()),
+                    },
                     telegram: TelegramConfig {
                         token: ConfigValue::Value(
                             "7212584558:AAFyZo37lw4VPHPIdbynqKtMacHPwF0uMGE".to_string(),
@@ -81,13 +99,20 @@ impl Test {
                         ),
                     },
                 },
+                hub: Hub::new(),
                 service: Service {
                     auth: AuthService::testing(pool.clone()),
+                    backtest: BacktestService::new(pool.clone()),
+                    pumpfun_token: pumpfun::service::TokenService::testing(pool.clone()),
+                    raydium: Arc::new(Raydium::new(rpc.clone())),
+                    rpc,
                     rule: RuleService::testing(pool.clone()),
+                    transfer: TransferService::testing(pool.clone()),
                     user: UserService::new(
                         pool.clone(),
+                        // the secret testing::wallet encrypts the private keys of the test wallets with
                         SecretKey::from(
-                            "276b49cc192cc66ab939de3892eba683152edab76c2162b21049d8fb0d9e7e5f",
+                            "3d7948d31771b3924dbeec3de83d905580d988c84964a6afd4c9cedd06776e91",
                         ),
                     ),
                 },
@@ -0,0 +1,226 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::http::error::HttpError;
+use crate::http::json::JsonReq;
+use crate::http::model::rule::{HttpRuleBacktestRequest, HttpRuleBacktestResponse};
+use crate::http::state::AppState;
+use axum::extract::State;
+use axum::{Extension, Json};
+use base::model::AuthenticatedUser;
+use common::model::Timeframe;
+use log::debug;
+use solana::backtest::BacktestQuery;
+
+const MAX_TOKEN_PAIRS: usize = 10;
+
+pub async fn backtest(
+    State(state): State<AppState>,
+    Extension(_user): Extension<AuthenticatedUser>,
+    JsonReq(req): JsonReq<HttpRuleBacktestRequest>,
+) -> Result<Json<HttpRuleBacktestResponse>, HttpError> {
+    debug!("POST /v1/rules/backtest {:?}", req);
+
+    let from = req.from.to_epoch_seconds();
+    let to = req.to.to_epoch_seconds();
+
+    if to <= from {
+        return Err(HttpError::unprocessable("From must be before to"));
+    }
+
+    if to - from > Timeframe::D1.seconds() {
+        return Err(HttpError::unprocessable(
+            "Time range must not be longer than 1 day",
+        ));
+    }
+
+    if req.token_pairs.is_empty() {
+        return Err(HttpError::unprocessable("Token pairs must not be empty"));
+    }
+
+    if req.token_pairs.len() > MAX_TOKEN_PAIRS {
+        return Err(HttpError::unprocessable(format!(
+            "Token pairs must not be more than {MAX_TOKEN_PAIRS}"
+        )));
+    }
+
+    let matches = state
+        .backtest_service()
+        .backtest(BacktestQuery {
+            venue: req.venue,
+            sequence: req.sequence,
+            from: req.from,
+            to: req.to,
+            token_pairs: req.token_pairs,
+        })
+        .await?;
+
+    Ok(Json(HttpRuleBacktestResponse {
+        matches: matches.into_boxed_slice(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::model::rule::HttpRuleBacktestResponse;
+    use crate::http::testing::{extract, extract_error, Test};
+    use axum::http::StatusCode;
+    use base::model::TokenPairId;
+    use common::model::{PriceQuote, Timeframe};
+    use sqlx::Executor;
+
+    fn request(from: &str, to: &str) -> String {
+        request_with_token_pairs(from, to, "[1]")
+    }
+
+    fn request_with_token_pairs(from: &str, to: &str, token_pairs: &str) -> String {
+        format!(
+            r#"{{"venue":"PumpFun","from":"{from}","to":"{to}","tokenPairs":{token_pairs},"sequence":{{"condition":{{"type":"COMPARE","field":"PRICE","operator":"INCREASED_BY_MORE_THAN","value":{{"type":"PERCENT","value":30.0}},"timeframe":"M1"}},"action":{{"type":"BUY"}}}}}}"#
+        )
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn ok() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            tx.execute(r#"
+            insert into pumpfun.candle_1m (token_pair_id, timestamp, open, high, low, close, avg, amount_base_buy, amount_quote_buy, amount_base_sell, amount_quote_sell, swap_buy, swap_sell, volume_buy, volume_sell) values
+                (1, '2025-03-20 10:00:00+00', 10, 10, 10, 10, 10, 0, 0, 0, 0, 1, 0, 1, 0),
+                (1, '2025-03-20 10:01:00+00', 20, 20, 20, 20, 20, 0, 0, 0, 0, 1, 0, 1, 0),
+                (1, '2025-03-20 10:02:00+00', 25, 25, 25, 25, 25, 0, 0, 0, 0, 1, 0, 1, 0);
+            "#)
+            .await
+            .unwrap();
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .post_json_as_test_user(
+                "/v1/rules/backtest",
+                &request("2025-03-20T10:00:00Z", "2025-03-20T11:00:00Z"),
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = extract::<HttpRuleBacktestResponse>(response).await.unwrap();
+        assert_eq!(response.matches.len(), 1);
+
+        let result = response.matches.first().unwrap();
+        assert_eq!(result.token_pair, TokenPairId(1));
+        assert_eq!(result.timestamp.to_epoch_seconds(), 1_742_464_860);
+        assert_eq!(result.price, PriceQuote::from(20i64));
+        assert_eq!(result.price_path[0].after, Timeframe::M1);
+        assert_eq!(result.price_path[0].price, PriceQuote::from(25i64));
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn no_matches() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/rules/backtest",
+                &request("2025-03-20T10:00:00Z", "2025-03-20T11:00:00Z"),
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = extract::<HttpRuleBacktestResponse>(response).await.unwrap();
+        assert!(response.matches.is_empty());
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn from_after_to() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/rules/backtest",
+                &request("2025-03-20T11:00:00Z", "2025-03-20T10:00:00Z"),
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.code, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.message, "From must be before to");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn time_range_too_long() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/rules/backtest",
+                &request("2025-03-20T10:00:00Z", "2025-03-21T10:00:01Z"),
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.code, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.message, "Time range must not be longer than 1 day");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn missing_token_pairs() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/rules/backtest",
+                r#"{"venue":"PumpFun","from":"2025-03-20T10:00:00Z","to":"2025-03-20T11:00:00Z","sequence":{"condition":{"type":"COMPARE","field":"PRICE","operator":"INCREASED_BY_MORE_THAN","value":{"type":"PERCENT","value":30.0},"timeframe":"M1"},"action":{"type":"BUY"}}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn empty_token_pairs() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/rules/backtest",
+                &request_with_token_pairs("2025-03-20T10:00:00Z", "2025-03-20T11:00:00Z", "[]"),
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Token pairs must not be empty");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn too_many_token_pairs() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/rules/backtest",
+                &request_with_token_pairs(
+                    "2025-03-20T10:00:00Z",
+                    "2025-03-20T11:00:00Z",
+                    "[1,2,3,4,5,6,7,8,9,10,11]",
+                ),
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Token pairs must not be more than 10");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn requires_authentication() {
+        let test = Test::new_empty_db().await;
+        let response = test
+            .post_unauthenticated_json(
+                "/v1/rules/backtest",
+                &request("2025-03-20T10:00:00Z", "2025-03-20T11:00:00Z"),
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.code, StatusCode::FORBIDDEN);
+        assert_eq!(error.message, "User not found");
+    }
+}
@@ -42,7 +42,7 @@ mod tests {
     use crate::http::model::rule::HttpRuleCreateResponse;
     use crate::http::testing::{extract, extract_error, Test};
     use axum::http::StatusCode;
-    use base::model::{Action, Condition, TelegramActionButtonConfig, Value};
+    use base::model::{Action, Condition, TelegramActionButtonConfig, ValidationError, Value};
 
     #[test_log::test(tokio::test)]
     async fn ok() {
@@ -146,4 +146,29 @@ mod tests {
 			"Failed to deserialize the JSON body into the target type: missing field `name` at line 1 column 2"
 		);
     }
+
+    #[test_log::test(tokio::test)]
+    async fn invalid_sequence() {
+        let test = Test::new().await;
+        let response = test
+			.post_json_as_test_user(
+				"/v1/rules",
+				r#"{"name":"test","sequence":{"condition":{"type":"AND","conditions":[
+					{"type":"COMPARE","field":"PRICE","operator":"MORE_THAN","value":{"type":"COUNT","value":1}}]},
+				"action":{"type":"BUY"}}}"#,
+			)
+			.await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.code, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.message, "Validation failed");
+        assert_eq!(
+            error.errors,
+            vec![ValidationError::new(
+                "$.condition.conditions[0].value",
+                "field PRICE does not accept value type COUNT with operator MORE_THAN"
+            )]
+        );
+    }
 }
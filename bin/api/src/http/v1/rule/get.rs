@@ -23,6 +23,7 @@ pub async fn get(
         name: r.name,
         status: r.status,
         sequence: r.sequence,
+        limit: r.limit,
     }))
 }
 
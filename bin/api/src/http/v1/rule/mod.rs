@@ -1,12 +1,16 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+pub use backtest::backtest;
 pub use create::create;
 pub use get::get;
 pub use list::list;
 pub use update::update;
+pub use validate::validate;
 
+mod backtest;
 mod create;
 mod get;
 mod list;
 mod update;
+mod validate;
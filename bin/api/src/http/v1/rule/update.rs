@@ -27,6 +27,7 @@ pub async fn update(
                 name: req.name,
                 sequence: req.sequence,
                 status: req.status,
+                limit: req.limit,
             },
             user,
         )
@@ -37,6 +38,7 @@ pub async fn update(
         name: result.name,
         status: result.status,
         sequence: result.sequence,
+        limit: result.limit,
     }))
 }
 
@@ -47,10 +49,15 @@ mod tests {
     use axum::http::StatusCode;
     use base::model::Field::PriceAvg;
     use base::model::Operator::MoreThan;
-    use base::model::RuleStatus::Archived;
-    use base::model::{Action, Condition, TelegramActionButtonConfig, Value};
+    use base::model::RuleStatus::{Active, ActiveExhausted, Archived, Inactive};
+    use base::model::{
+        Action, Condition, Mint, RuleLimit, RuleRearm, TelegramActionButtonConfig, ValidationError,
+        Value,
+    };
+    use base::repo::{InvocationCreateCmd, InvocationRepo};
     use common::model::Timeframe::M15;
     use testing::rule::create_inactive_rule_for_test_user;
+    use testing::token_pair::get_or_create_token_pair;
     use Condition::Compare;
 
     #[test_log::test(tokio::test)]
@@ -164,6 +171,293 @@ mod tests {
         );
     }
 
+    #[test_log::test(tokio::test)]
+    async fn limit() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .patch_json_as_test_user(
+                "/v1/rules/4",
+                r#"{"limit":{"maxInvocations":5,"cooldownSeconds":60,"rearm":"EDGE","maxInvocationsPerTokenPair":3,"startAt":"2025-03-01T00:00:00Z","endAt":"2025-04-01T00:00:00Z"}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = extract::<HttpRuleUpdateResponse>(response).await.unwrap();
+        assert_eq!(response.status, Inactive);
+        assert_eq!(response.limit.max_invocations, Some(5));
+        assert_eq!(response.limit.cooldown_seconds, Some(60));
+        assert_eq!(response.limit.rearm, RuleRearm::Edge);
+        assert_eq!(response.limit.max_invocations_per_token_pair, Some(3));
+        assert_eq!(
+            response.limit.start_at.unwrap().to_string(),
+            "2025-03-01T00:00:00Z"
+        );
+        assert_eq!(
+            response.limit.end_at.unwrap().to_string(),
+            "2025-04-01T00:00:00Z"
+        );
+
+        // name and sequence stay untouched
+        assert_eq!(response.name, "MoneyMaker");
+
+        let response = test
+            .patch_json_as_test_user("/v1/rules/4", r#"{"limit":{}}"#)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = extract::<HttpRuleUpdateResponse>(response).await.unwrap();
+        assert_eq!(response.limit, RuleLimit::default());
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn limit_exhausts_rule() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            let rule = create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+            let token_pair = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+            InvocationRepo::new()
+                .create(
+                    &mut tx,
+                    InvocationCreateCmd {
+                        user: rule.user,
+                        rule: rule.id,
+                        token_pair: token_pair.id,
+                        stage: 0,
+                        next: None,
+                        deadline: None,
+                    },
+                )
+                .await
+                .unwrap();
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .patch_json_as_test_user(
+                "/v1/rules/4",
+                r#"{"status":"ACTIVE","limit":{"maxInvocations":1}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = extract::<HttpRuleUpdateResponse>(response).await.unwrap();
+        assert_eq!(response.status, ActiveExhausted);
+
+        let response = test
+            .patch_json_as_test_user("/v1/rules/4", r#"{"limit":{"maxInvocations":2}}"#)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = extract::<HttpRuleUpdateResponse>(response).await.unwrap();
+        assert_eq!(response.status, Active);
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn limit_max_invocations_must_be_positive() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .patch_json_as_test_user("/v1/rules/4", r#"{"limit":{"maxInvocations":0}}"#)
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Validation failed");
+        assert_eq!(
+            error.errors,
+            vec![ValidationError::new(
+                "$.maxInvocations",
+                "must be greater than 0"
+            )]
+        );
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn limit_max_invocations_per_token_pair_must_be_positive() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .patch_json_as_test_user(
+                "/v1/rules/4",
+                r#"{"limit":{"rearm":"ALWAYS","maxInvocationsPerTokenPair":0}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Validation failed");
+        assert_eq!(
+            error.errors,
+            vec![ValidationError::new(
+                "$.maxInvocationsPerTokenPair",
+                "must be greater than 0"
+            )]
+        );
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn limit_cooldown_must_not_be_negative() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .patch_json_as_test_user(
+                "/v1/rules/4",
+                r#"{"limit":{"rearm":"ALWAYS","cooldownSeconds":-1}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Validation failed");
+        assert_eq!(
+            error.errors,
+            vec![ValidationError::new(
+                "$.cooldownSeconds",
+                "must not be negative"
+            )]
+        );
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn limit_cooldown_requires_rearm() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .patch_json_as_test_user(
+                "/v1/rules/4",
+                r#"{"limit":{"rearm":"NEVER","cooldownSeconds":60}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Validation failed");
+        assert_eq!(
+            error.errors,
+            vec![ValidationError::new(
+                "$.cooldownSeconds",
+                "requires the rule to rearm"
+            )]
+        );
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn limit_max_invocations_per_token_pair_requires_rearm() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .patch_json_as_test_user(
+                "/v1/rules/4",
+                r#"{"limit":{"maxInvocationsPerTokenPair":2}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Validation failed");
+        assert_eq!(
+            error.errors,
+            vec![ValidationError::new(
+                "$.maxInvocationsPerTokenPair",
+                "requires the rule to rearm"
+            )]
+        );
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn limit_end_before_start() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .patch_json_as_test_user(
+                "/v1/rules/4",
+                r#"{"limit":{"startAt":"2025-04-01T00:00:00Z","endAt":"2025-03-01T00:00:00Z"}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Validation failed");
+        assert_eq!(
+            error.errors,
+            vec![ValidationError::new("$.endAt", "must be after startAt")]
+        );
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn invalid_sequence() {
+        let test = Test::new().await;
+
+        test.tx(|mut tx| async move {
+            create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+            tx.commit().await.unwrap()
+        })
+        .await;
+
+        let response = test
+            .patch_json_as_test_user(
+                "/v1/rules/4",
+                r#"{"sequence":{"condition":{"type":"COMPARE","field":"PRICE_CLOSE","operator":"MORE_THAN","value":{"type":"QUOTE","value":"1"}},"action":{"type":"BUY"}}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Validation failed");
+        assert_eq!(
+            error.errors,
+            vec![ValidationError::new(
+                "$.condition.timeframe",
+                "timeframe required"
+            )]
+        );
+    }
+
     #[test_log::test(tokio::test)]
     async fn not_found() {
         let test = Test::new().await;
@@ -0,0 +1,94 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::http::error::HttpError;
+use crate::http::json::JsonReq;
+use crate::http::model::rule::{HttpRuleValidateRequest, HttpRuleValidateResponse};
+use axum::{Extension, Json};
+use base::model::AuthenticatedUser;
+use log::debug;
+
+pub async fn validate(
+    Extension(_user): Extension<AuthenticatedUser>,
+    JsonReq(req): JsonReq<HttpRuleValidateRequest>,
+) -> Result<Json<HttpRuleValidateResponse>, HttpError> {
+    debug!("POST /v1/rules/validate {:?}", req);
+
+    let errors = req.sequence.validate();
+
+    Ok(Json(HttpRuleValidateResponse {
+        valid: errors.is_empty(),
+        errors: errors.into_boxed_slice(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::model::rule::HttpRuleValidateResponse;
+    use crate::http::testing::{extract, extract_error, Test};
+    use axum::http::StatusCode;
+    use base::model::ValidationError;
+
+    #[test_log::test(tokio::test)]
+    async fn ok() {
+        let test = Test::new().await;
+        let response = test
+			.post_json_as_test_user(
+				"/v1/rules/validate",
+				r#"{"sequence":{"condition":{"type":"AND","conditions":[
+					{"type":"COMPARE","field":"PRICE","operator":"MORE_THAN","value":{"type":"QUOTE","value":"1"}},
+					{"type":"COMPARE","field":"PRICE","operator":"INCREASED_BY_MORE_THAN","value":{"type":"PERCENT","value":10.0},"timeframe":"M5"}]},
+				"action":{"type":"BUY"}}}"#,
+			)
+			.await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = extract::<HttpRuleValidateResponse>(response).await.unwrap();
+        assert!(response.valid);
+        assert!(response.errors.is_empty());
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn invalid() {
+        let test = Test::new().await;
+        let response = test
+			.post_json_as_test_user(
+				"/v1/rules/validate",
+				r#"{"sequence":{"condition":{"type":"AND","conditions":[
+					{"type":"COMPARE","field":"PRICE","operator":"MORE_THAN","value":{"type":"COUNT","value":1}},
+					{"type":"COMPARE","field":"PRICE","operator":"INCREASED_BY_MORE_THAN","value":{"type":"PERCENT","value":10.0}}]},
+				"action":{"type":"BUY"}}}"#,
+			)
+			.await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = extract::<HttpRuleValidateResponse>(response).await.unwrap();
+        assert!(!response.valid);
+        assert_eq!(
+            response.errors.as_ref(),
+            [
+                ValidationError::new(
+                    "$.condition.conditions[0].value",
+                    "field PRICE does not accept value type COUNT with operator MORE_THAN"
+                ),
+                ValidationError::new("$.condition.conditions[1].timeframe", "timeframe required"),
+            ]
+        );
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn requires_authentication() {
+        let test = Test::new_empty_db().await;
+        let response = test
+            .post_unauthenticated_json(
+                "/v1/rules/validate",
+                r#"{"sequence":{"condition":{"type":"AND","conditions":[]},"action":{"type":"BUY"}}}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.code, StatusCode::FORBIDDEN);
+        assert_eq!(error.message, "User not found");
+    }
+}
@@ -7,8 +7,72 @@ pub use quote::quote;
 pub use send::send;
 pub use swap::swap;
 
+use crate::http::error::HttpError;
+use base::model::PublicKey;
+use serde::{Deserialize, Serialize};
+use solana::raydium::{RaydiumSwap, SwapExecutionMode};
+use std::str::FromStr;
+
 mod create;
 mod get;
 mod quote;
 mod send;
 mod swap;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SwapMode {
+    /// amount is the exact input, the output varies
+    #[default]
+    ExactIn,
+    /// amount is the exact output, the input varies
+    ExactOut,
+}
+
+impl From<SwapMode> for SwapExecutionMode {
+    fn from(value: SwapMode) -> Self {
+        match value {
+            SwapMode::ExactIn => SwapExecutionMode::ExactIn,
+            SwapMode::ExactOut => SwapExecutionMode::ExactOut,
+        }
+    }
+}
+
+/// Validates the parameters shared by quote and swap requests
+fn raydium_swap(
+    input_mint: &str,
+    output_mint: &str,
+    amount: u64,
+    mode: SwapMode,
+    slippage_bps: u16,
+) -> Result<RaydiumSwap, HttpError> {
+    let input_mint = PublicKey::from_str(input_mint)
+        .map_err(|_| HttpError::unprocessable("Invalid input mint"))?;
+    let output_mint = PublicKey::from_str(output_mint)
+        .map_err(|_| HttpError::unprocessable("Invalid output mint"))?;
+
+    if input_mint == output_mint {
+        return Err(HttpError::unprocessable(
+            "Input and output mint must be different",
+        ));
+    }
+
+    if amount == 0 {
+        return Err(HttpError::unprocessable("Amount must be greater than 0"));
+    }
+
+    if slippage_bps > 10_000 {
+        return Err(HttpError::unprocessable(
+            "Slippage must not exceed 10000 basis points",
+        ));
+    }
+
+    Ok(RaydiumSwap {
+        input_token_mint: input_mint,
+        output_token_mint: output_mint,
+        slippage_bps,
+        amount,
+        mode: mode.into(),
+        market: None,
+    })
+}
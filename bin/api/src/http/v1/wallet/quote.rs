@@ -1,61 +1,58 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use std::str::FromStr;
-
 use crate::http::error::HttpError;
 use crate::http::json::JsonReq;
 use crate::http::state::AppState;
+use crate::http::v1::wallet::{raydium_swap, SwapMode};
 use axum::extract::{Path, State};
 use axum::{Extension, Json};
-use base::model::AuthenticatedUser;
-use base::model::PublicKey;
+use base::model::{AuthenticatedUser, WalletId};
 use log::debug;
 use serde::{Deserialize, Serialize};
-use solana::raydium::{Raydium, RaydiumSwap, SwapExecutionMode};
 
 #[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct QuoteRequest {
-    // from: String,
-    // to: String,
-    // amount: u64,
-    // slippage: u16,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub amount: u64,
+    #[serde(default)]
+    pub mode: SwapMode,
+    pub slippage_bps: u16,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct QuoteResponse {
-    from: String,
-    to: String,
-    amount: u64,
-    amount_with_slippage: u64,
+    pub from: String,
+    pub to: String,
+    pub amount: u64,
+    pub amount_with_slippage: u64,
 }
 
 pub async fn quote(
-    Path(id): Path<String>,
+    Path(id): Path<WalletId>,
     Extension(user): Extension<AuthenticatedUser>,
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     JsonReq(req): JsonReq<QuoteRequest>,
 ) -> Result<Json<QuoteResponse>, HttpError> {
-    debug!("POST /v1/wallets/{}/quote {:?}", id, req);
-
-    println!("{:#?}", user);
+    debug!("POST /v1/wallets/{}/quote {:?}", id.0, req);
 
-    let sol = PublicKey::from_str("So11111111111111111111111111111111111111112").unwrap();
-    let usdc = PublicKey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+    let swap = raydium_swap(
+        &req.input_mint,
+        &req.output_mint,
+        req.amount,
+        req.mode,
+        req.slippage_bps,
+    )?;
 
-    let swap_input = RaydiumSwap {
-        input_token_mint: sol,
-        output_token_mint: usdc,
-        slippage_bps: 1000, // 10%
-        amount: 1_000_000,  // 0.001 SOL
-        mode: SwapExecutionMode::ExactIn,
-        market: None,
-    };
+    let wallet = state.user_service().get_wallet(user.id).await?;
+    if wallet.id != id {
+        return Err(HttpError::not_found("Wallet not found"));
+    }
 
-    let raydium = Raydium::default();
-
-    let quote = raydium.quote(swap_input).await.unwrap();
-    println!("Quote: {:#?}", quote);
+    let quote = state.raydium().quote(swap).await?;
 
     Ok(Json(QuoteResponse {
         from: quote.input_mint.to_string(),
@@ -64,3 +61,84 @@ pub async fn quote(
         amount_with_slippage: quote.output_amount_with_slippage,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::http::testing::{extract_error, Test};
+    use axum::http::StatusCode;
+
+    #[test_log::test(tokio::test)]
+    async fn invalid_mint() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/1/quote",
+                r#"{"inputMint":"NotAMint","outputMint":"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v","amount":1000000,"slippageBps":100}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Invalid input mint");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn same_mint() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/1/quote",
+                r#"{"inputMint":"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v","outputMint":"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v","amount":1000000,"slippageBps":100}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Input and output mint must be different");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn zero_amount() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/1/quote",
+                r#"{"inputMint":"So11111111111111111111111111111111111111112","outputMint":"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v","amount":0,"slippageBps":100}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Amount must be greater than 0");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn slippage_too_high() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/1/quote",
+                r#"{"inputMint":"So11111111111111111111111111111111111111112","outputMint":"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v","amount":1000000,"mode":"EXACT_OUT","slippageBps":10001}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Slippage must not exceed 10000 basis points");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn wallet_of_another_user() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/2/quote",
+                r#"{"inputMint":"So11111111111111111111111111111111111111112","outputMint":"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v","amount":1000000,"slippageBps":100}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Wallet not found");
+    }
+}
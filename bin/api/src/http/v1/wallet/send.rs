@@ -6,25 +6,399 @@ use crate::http::json::JsonReq;
 use crate::http::state::AppState;
 use axum::extract::{Path, State};
 use axum::{Extension, Json};
-use base::model::AuthenticatedUser;
-use log::debug;
+use base::model::{
+    AuthenticatedUser, DecimalAmount, KeyPair, Mint, PublicKey, TransferId, TransferStatus,
+    WalletId,
+};
+use base::repo::{TransferCreateCmd, TransferUpdateCmd};
+use log::{debug, error};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+const SOL_DECIMALS: u8 = 9;
 
 #[derive(Deserialize, Debug)]
-pub struct SendRequest {}
+#[serde(rename_all = "camelCase")]
+pub struct SendRequest {
+    /// mint of the spl token to send, native SOL if absent
+    pub mint: Option<String>,
+    pub recipient: String,
+    /// raw amount in the smallest unit of the token, lamports for SOL
+    pub amount: u64,
+}
 
 #[derive(Debug, Deserialize, Serialize)]
-pub struct SendResponse {}
+#[serde(rename_all = "camelCase")]
+pub struct SendResponse {
+    pub id: i64,
+    pub mint: Option<String>,
+    pub recipient: String,
+    pub amount: DecimalAmount,
+    pub signature: String,
+}
 
 pub async fn send(
-    Path(id): Path<String>,
+    Path(id): Path<WalletId>,
     Extension(user): Extension<AuthenticatedUser>,
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     JsonReq(req): JsonReq<SendRequest>,
 ) -> Result<Json<SendResponse>, HttpError> {
-    debug!("POST /v1/wallets/{}/send {:?}", id, req);
+    debug!("POST /v1/wallets/{}/send {:?}", id.0, req);
+
+    let recipient = PublicKey::from_str(&req.recipient)
+        .map_err(|_| HttpError::unprocessable("Invalid recipient"))?;
+
+    let mint = match &req.mint {
+        None => None,
+        Some(mint) => Some(Mint::from(
+            PublicKey::from_str(mint)
+                .map_err(|_| HttpError::unprocessable("Invalid mint"))?
+                .0,
+        )),
+    };
+
+    if req.amount == 0 {
+        return Err(HttpError::unprocessable("Amount must be greater than 0"));
+    }
+
+    let wallet = state.user_service().get_wallet_unsafe(id, user.id).await?;
+
+    if wallet.public_key == recipient {
+        return Err(HttpError::unprocessable(
+            "Recipient must be different from wallet",
+        ));
+    }
+
+    let rpc = state.rpc();
+    let transfer_service = state.transfer_service();
+
+    let (token, balance) = match &mint {
+        None => (None, None),
+        Some(mint) => {
+            let balance = transfer_service
+                .get_balance(wallet.public_key.clone(), Some(mint.clone()))
+                .await?;
+            (balance.token, Some(balance.balance))
+        }
+    };
+
+    let decimals = token
+        .as_ref()
+        .map(|token| token.decimals.0 as u8)
+        .unwrap_or(SOL_DECIMALS);
+
+    let amount = DecimalAmount::new(req.amount, decimals);
+
+    // the pending transfer makes concurrent sends of the wallet fail, until its outcome is recorded
+    let pending = transfer_service
+        .create(TransferCreateCmd {
+            user: user.id,
+            wallet: wallet.id,
+            token: token.map(|token| token.id),
+            recipient: recipient.clone(),
+            amount: amount.clone(),
+            status: TransferStatus::Pending,
+            signature: None,
+            error: None,
+        })
+        .await?;
+
+    let checked = async {
+        // the indexed balance might lag behind, therefore SOL is checked against the chain directly
+        let lamports = rpc.sol_balance(wallet.public_key.clone()).await?;
+
+        match &mint {
+            None => {
+                let reserve = rpc.transfer_sol_reserve();
+                if req.amount.saturating_add(reserve) > lamports {
+                    return Err(HttpError::unprocessable("Insufficient balance"));
+                }
+            }
+            Some(mint) => {
+                if balance.as_ref().is_none_or(|balance| amount.0 > balance.0) {
+                    return Err(HttpError::unprocessable("Insufficient balance"));
+                }
+
+                let reserve = rpc
+                    .transfer_token_reserve(recipient.clone(), mint.clone())
+                    .await?;
+                if reserve > lamports {
+                    return Err(HttpError::unprocessable(
+                        "Insufficient SOL to pay for the transfer",
+                    ));
+                }
+            }
+        }
+        Ok::<(), HttpError>(())
+    }
+    .await;
+
+    if let Err(err) = checked {
+        fail(&state, pending.id, err.message().to_string()).await?;
+        return Err(err);
+    }
+
+    let payer = KeyPair {
+        public: wallet.public_key.clone(),
+        private: wallet.private_key.clone(),
+    };
+
+    let sent = match &mint {
+        None => {
+            rpc.transfer_sol(&payer, recipient.clone(), req.amount)
+                .await
+        }
+        Some(mint) => {
+            rpc.transfer_token(
+                &payer,
+                recipient.clone(),
+                mint.clone(),
+                req.amount,
+                decimals,
+            )
+            .await
+        }
+    };
+
+    let signature = match sent {
+        Ok(signature) => signature,
+        Err(err) => {
+            fail(&state, pending.id, err.to_string()).await?;
+            return Err(err.into());
+        }
+    };
+
+    // the transaction was sent already, failing the request would make the caller retry and send twice
+    if let Err(err) = transfer_service
+        .update(TransferUpdateCmd {
+            id: pending.id,
+            status: TransferStatus::Submitted,
+            signature: Some(signature.clone()),
+            error: None,
+        })
+        .await
+    {
+        error!(
+            "unable to record transfer {} submitted with {signature} - {err}",
+            pending.id.0
+        )
+    }
+
+    Ok(Json(SendResponse {
+        id: pending.id.0,
+        mint: mint.map(|mint| mint.to_string()),
+        recipient: pending.recipient.0,
+        amount: pending.amount,
+        signature: signature.0,
+    }))
+}
+
+async fn fail(state: &AppState, id: TransferId, error: String) -> Result<(), HttpError> {
+    state
+        .transfer_service()
+        .update(TransferUpdateCmd {
+            id,
+            status: TransferStatus::Failed,
+            signature: None,
+            error: Some(error),
+        })
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::testing::{extract, extract_error, Test};
+    use crate::http::v1::wallet::send::SendResponse;
+    use axum::http::StatusCode;
+    use base::model::{PublicKey, TransferStatus};
+    use sqlx::{Executor, Row};
+    use testing::rpc::LocalRpc;
+
+    const SEND: &str =
+        r#"{"recipient":"CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM","amount":1000000}"#;
+
+    /// Funds wallet 1 of the test user with lamports
+    async fn test_with_sol(lamports: u64) -> (Test, LocalRpc) {
+        let rpc = LocalRpc::new();
+        let test = Test::new_with_rpc(rpc.client()).await;
+
+        let local = rpc.clone();
+        test.tx(move |mut tx| async move {
+            let public_key = tx
+                .fetch_one("select public_key from solana.wallet where id = 1")
+                .await
+                .unwrap()
+                .get::<String, _>("public_key");
+            local.add_sol(PublicKey::from(public_key).into(), lamports);
+        })
+        .await;
+
+        (test, rpc)
+    }
+
+    async fn assert_transfer_status(test: &Test, expected: Vec<TransferStatus>) {
+        test.tx(|mut tx| async move {
+            let statuses = tx
+                .fetch_all("select status from solana.transfer order by id")
+                .await
+                .unwrap()
+                .iter()
+                .map(|row| row.get::<TransferStatus, _>("status"))
+                .collect::<Vec<_>>();
+            assert_eq!(statuses, expected);
+        })
+        .await;
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn ok() {
+        let (test, rpc) = test_with_sol(1_000_000_000).await;
+
+        let response = test
+            .post_json_as_test_user("/v1/wallets/1/send", SEND)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = extract::<SendResponse>(response).await.unwrap();
+        assert_eq!(
+            response.recipient,
+            "CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"
+        );
+        assert_eq!(response.mint, None);
+
+        let transactions = rpc.sent_transactions();
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(
+            response.signature,
+            transactions[0].signatures[0].to_string()
+        );
+
+        assert_transfer_status(&test, vec![TransferStatus::Submitted]).await;
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn insufficient_balance() {
+        let (test, rpc) = test_with_sol(1_000_000).await;
+
+        let response = test
+            .post_json_as_test_user("/v1/wallets/1/send", SEND)
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Insufficient balance");
+        assert!(rpc.sent_transactions().is_empty());
+
+        // the pending transfer must not block the next send of the wallet
+        assert_transfer_status(&test, vec![TransferStatus::Failed]).await;
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn another_transfer_pending() {
+        let (test, rpc) = test_with_sol(1_000_000_000).await;
+        test.tx(|mut tx| async move {
+            tx.execute(
+                r#"insert into solana.transfer (user_id, wallet_id, recipient, amount, status)
+                values (1, 1, 'CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM', 1, 3)"#,
+            )
+            .await
+            .unwrap();
+            tx.commit().await.unwrap();
+        })
+        .await;
+
+        let response = test
+            .post_json_as_test_user("/v1/wallets/1/send", SEND)
+            .await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let error = extract_error(response).await;
+        assert_eq!(
+            error.message,
+            "Another transfer of the wallet is in progress"
+        );
+        assert!(rpc.sent_transactions().is_empty());
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn missing_recipient() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user("/v1/wallets/1/send", r#"{"amount":1000000}"#)
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn invalid_recipient() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/1/send",
+                r#"{"recipient":"NotAnAddress","amount":1000000}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Invalid recipient");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn invalid_mint() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/1/send",
+                r#"{"mint":"NotAMint","recipient":"CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM","amount":1000000}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Invalid mint");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn zero_amount() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/1/send",
+                r#"{"recipient":"CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM","amount":0}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Amount must be greater than 0");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn wallet_of_another_user() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/2/send",
+                r#"{"recipient":"CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM","amount":1000000}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
 
-    println!("{:#?}", user);
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Wallet not found");
+    }
 
-    Ok(Json(SendResponse {}))
+    #[test_log::test(tokio::test)]
+    async fn wallet_does_not_exist() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/404/send",
+                r#"{"recipient":"CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM","amount":1000000}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -1,79 +1,70 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use std::str::FromStr;
-
 use crate::http::error::HttpError;
 use crate::http::json::JsonReq;
 use crate::http::state::AppState;
+use crate::http::v1::wallet::{raydium_swap, SwapMode};
 use axum::extract::{Path, State};
 use axum::{Extension, Json};
-use base::model::AuthenticatedUser;
-use base::model::PublicKey;
+use base::model::{AuthenticatedUser, WalletId};
 use log::debug;
 use serde::{Deserialize, Serialize};
-use solana::raydium::{ComputeUnitLimits, PriorityFeeConfig, Raydium, RaydiumSwap, SwapConfigOverrides, SwapExecutionMode};
+use solana::raydium::{PriorityFeeConfig, SwapConfigOverrides};
 
 #[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct SwapRequest {
-    // from: String,
-    // to: String,
-    // amount: u64,
-    // slippage: u16,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub amount: u64,
+    #[serde(default)]
+    pub mode: SwapMode,
+    pub slippage_bps: u16,
+    /// compute unit price in micro lamports
+    pub priority_fee: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SwapResponse {
-    from: String,
-    to: String,
-    amount: u64,
-    amount_with_slippage: u64,
-    signature: String,
+    pub from: String,
+    pub to: String,
+    pub amount: u64,
+    pub amount_with_slippage: u64,
+    pub signature: String,
 }
 
 pub async fn swap(
-    Path(id): Path<String>,
-    Extension(_user): Extension<AuthenticatedUser>,
-    State(_state): State<AppState>,
+    Path(id): Path<WalletId>,
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState>,
     JsonReq(req): JsonReq<SwapRequest>,
 ) -> Result<Json<SwapResponse>, HttpError> {
-    debug!("POST /v1/wallets/{}/swap {:?}", id, req);
-
-    let sol = PublicKey::from_str("So11111111111111111111111111111111111111112").unwrap();
-    let usdc = PublicKey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
-
-    let swap_input = RaydiumSwap {
-        input_token_mint: sol,
-        output_token_mint: usdc,
-        slippage_bps: 1000, // 10%
-        amount: 1_000_000,  // 0.001 SOL
-        mode: SwapExecutionMode::ExactIn,
-        market: None,
-    };
+    debug!("POST /v1/wallets/{}/swap {:?}", id.0, req);
+
+    let swap = raydium_swap(
+        &req.input_mint,
+        &req.output_mint,
+        req.amount,
+        req.mode,
+        req.slippage_bps,
+    )?;
 
-    let raydium = Raydium::default();
+    let wallet = state.user_service().get_wallet_unsafe(id, user.id).await?;
 
-    let quote = raydium.quote(swap_input).await.unwrap();
-    println!("Quote: {:#?}", quote);
+    let raydium = state.raydium();
+    let quote = raydium.quote(swap).await?;
 
     let overrides = SwapConfigOverrides {
-        // priority_fee: Some(PriorityFeeConfig::DynamicMultiplier(1)),
-        priority_fee: Some(PriorityFeeConfig::FixedCuPrice(200_000)),
-        cu_limits: Some(ComputeUnitLimits::Fixed(60_000)),
-        wrap_and_unwrap_sol: None,
-        destination_token_account: None,
-        as_legacy_transaction: None,
+        priority_fee: req.priority_fee.map(PriorityFeeConfig::FixedCuPrice),
+        ..Default::default()
     };
 
     let signature = raydium
-        .swap(
-            "3ZSqgN5FTNTto4vqZgEzFrCwqcuq3b6hXFeAajE31Tp6pwi85y6izHG9TpRGF1p5S5AasuPD9b7kDkEqCGxeoySb".to_string(),
-            quote.clone(),
-            Some(overrides),
-        )
-        .await
-        .unwrap();
-    // println!("{}", signature)
+        .swap(wallet.private_key.0, quote.clone(), Some(overrides))
+        .await?;
+
     Ok(Json(SwapResponse {
         from: quote.input_mint.to_string(),
         to: quote.output_mint.to_string(),
@@ -82,3 +73,63 @@ pub async fn swap(
         signature: signature.0,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::http::testing::{extract_error, Test};
+    use axum::http::StatusCode;
+
+    #[test_log::test(tokio::test)]
+    async fn missing_amount() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/1/swap",
+                r#"{"inputMint":"So11111111111111111111111111111111111111112","outputMint":"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v","slippageBps":100}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn invalid_output_mint() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/1/swap",
+                r#"{"inputMint":"So11111111111111111111111111111111111111112","outputMint":"NotAMint","amount":1000000,"slippageBps":100,"priorityFee":200000}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Invalid output mint");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn wallet_of_another_user() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/2/swap",
+                r#"{"inputMint":"So11111111111111111111111111111111111111112","outputMint":"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v","amount":1000000,"slippageBps":100}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let error = extract_error(response).await;
+        assert_eq!(error.message, "Wallet not found");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn wallet_does_not_exist() {
+        let test = Test::new().await;
+        let response = test
+            .post_json_as_test_user(
+                "/v1/wallets/404/swap",
+                r#"{"inputMint":"So11111111111111111111111111111111111111112","outputMint":"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v","amount":1000000,"slippageBps":100}"#,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
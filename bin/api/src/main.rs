@@ -10,14 +10,19 @@ use std::sync::Arc;
 
 use crate::config::Config;
 use crate::http::state::{AppState, AppStateInner, Service};
-use base::repo::{AuthRepo, RuleRepo};
+use base::repo::{AuthRepo, InvocationRepo, RuleRepo, TokenPairRepo, TokenRepo, TransferRepo};
 use base::service::UserService;
-use base::service::{AuthService, RuleService};
+use base::service::{AuthService, RuleService, TransferService};
 use common::crypt::SecretKey;
 use common::repo::pool::setup_pool;
 use common::ResolveOr;
 use log::info;
 use signal::unix::{signal, SignalKind};
+use solana::backtest::BacktestService;
+use solana::pumpfun;
+use solana::pumpfun::repo::{CurrentRepo, SummaryRepo};
+use solana::raydium::Raydium;
+use solana::rpc::RpcClient;
 use tokio::runtime::Builder;
 use tokio::sync::broadcast;
 use tokio::{join, signal};
@@ -61,14 +66,33 @@ fn main() {
 
         let secret = SecretKey::from(config.wallet.secret.resolve());
 
-        let router = router::setup_v1(AppState(Arc::new(AppStateInner {
+        let rpc_client = RpcClient::new(config.rpc.url.resolve());
+
+        let state = AppState(Arc::new(AppStateInner {
             config,
+            hub: ws::Hub::new(),
             service: Service {
                 auth: AuthService::new(pool.clone(), AuthRepo::new()),
-                rule: RuleService::new(pool.clone(), RuleRepo::new()),
+                backtest: BacktestService::new(pool.clone()),
+                pumpfun_token: pumpfun::service::TokenService::new(
+                    pool.clone(),
+                    TokenPairRepo::new(TokenRepo::new_read_only()),
+                    CurrentRepo::new(),
+                    SummaryRepo::new(),
+                ),
+                raydium: Arc::new(Raydium::new(rpc_client.clone())),
+                rpc: rpc_client,
+                rule: RuleService::new(pool.clone(), RuleRepo::new(), InvocationRepo::new()),
+                transfer: TransferService::new(
+                    pool.clone(),
+                    TokenRepo::new_read_only(),
+                    TransferRepo::new(),
+                ),
                 user: UserService::new(pool.clone(), secret),
             },
-        })));
+        }));
+
+        let router = router::setup_v1(state.clone());
 
         tokio::spawn(async move {
             let mut sigterm = signal(SignalKind::terminate()).unwrap();
@@ -80,7 +104,10 @@ fn main() {
             }
         });
 
-        let _ = join!(http::Server::start(bind_address, exit.resubscribe(), router).await,);
+        let _ = join!(
+            http::Server::start(bind_address, exit.resubscribe(), router).await,
+            ws::publish(state, exit.resubscribe())
+        );
     })
 
     // // build our application with some routes
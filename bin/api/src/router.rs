@@ -30,6 +30,8 @@ pub fn setup_v1(app_state: AppState) -> Router {
                 .route("/rules/{id}", get(v1::rule::get))
                 .route("/rules/{id}", patch(v1::rule::update))
                 .route("/rules", post(v1::rule::create))
+                .route("/rules/backtest", post(v1::rule::backtest))
+                .route("/rules/validate", post(v1::rule::validate))
                 .route("/wallets", post(v1::wallet::create))
                 .route("/wallets/{id}", get(v1::wallet::get))
                 .route("/wallets/{id}/quote", post(v1::wallet::quote))
@@ -0,0 +1,209 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::ws::model::WsServerMessage;
+use base::model::{TokenPairId, UserId};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+
+const USER_CHANNEL_CAPACITY: usize = 256;
+
+/// Something happened which is of interest either to everyone subscribed to a token pair or to a single user
+#[derive(Clone, Debug)]
+pub enum WsEvent {
+    TokenPair(TokenPairId, WsServerMessage),
+    User(UserId, WsServerMessage),
+}
+
+/// Keeps track of what connected clients are interested in and fans out events to their sockets
+#[derive(Clone)]
+pub struct Hub(pub Arc<HubInner>);
+
+impl Deref for Hub {
+    type Target = HubInner;
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+pub struct HubInner {
+    sender: broadcast::Sender<(TokenPairId, WsServerMessage)>,
+    token_pairs: Mutex<HashMap<TokenPairId, usize>>,
+    users: Mutex<HashMap<UserId, UserChannel>>,
+}
+
+/// Events of a user get their own channel, so that a flood of token pair events can not make
+/// the sockets of the user lag behind and skip them
+struct UserChannel {
+    sockets: usize,
+    sender: broadcast::Sender<WsServerMessage>,
+}
+
+impl Default for Hub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hub {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(1024);
+        Self(Arc::new(HubInner {
+            sender,
+            token_pairs: Mutex::new(HashMap::new()),
+            users: Mutex::new(HashMap::new()),
+        }))
+    }
+
+    /// Token pair events, every socket decides on its own whether it is subscribed to the token pair
+    pub fn receiver(&self) -> broadcast::Receiver<(TokenPairId, WsServerMessage)> {
+        self.sender.subscribe()
+    }
+
+    /// Hands token pair events to all connected sockets and user events to the sockets of the user
+    pub fn publish(&self, event: WsEvent) {
+        // sending fails only if no socket is connected
+        match event {
+            WsEvent::TokenPair(token_pair, message) => {
+                let _ = self.sender.send((token_pair, message));
+            }
+            WsEvent::User(user, message) => {
+                if let Some(channel) = self.users.lock().unwrap().get(&user) {
+                    let _ = channel.sender.send(message);
+                }
+            }
+        }
+    }
+
+    /// Registers a socket of the user, the receiver gets the events of the user
+    pub fn join(&self, user: UserId) -> broadcast::Receiver<WsServerMessage> {
+        let mut users = self.users.lock().unwrap();
+        let channel = users.entry(user).or_insert_with(|| UserChannel {
+            sockets: 0,
+            sender: broadcast::channel(USER_CHANNEL_CAPACITY).0,
+        });
+        channel.sockets += 1;
+        channel.sender.subscribe()
+    }
+
+    pub fn leave(&self, user: UserId) {
+        let mut users = self.users.lock().unwrap();
+        if let Some(channel) = users.get_mut(&user) {
+            channel.sockets -= 1;
+            if channel.sockets == 0 {
+                users.remove(&user);
+            }
+        }
+    }
+
+    pub fn watch(&self, token_pairs: impl IntoIterator<Item = TokenPairId>) {
+        acquire(&self.token_pairs, token_pairs);
+    }
+
+    pub fn unwatch(&self, token_pairs: impl IntoIterator<Item = TokenPairId>) {
+        release(&self.token_pairs, token_pairs);
+    }
+
+    /// Token pairs at least one connected socket is subscribed to
+    pub fn token_pairs(&self) -> Vec<TokenPairId> {
+        self.token_pairs.lock().unwrap().keys().copied().collect()
+    }
+
+    /// Users with at least one connected socket
+    pub fn users(&self) -> HashSet<UserId> {
+        self.users.lock().unwrap().keys().copied().collect()
+    }
+}
+
+fn acquire<T: Eq + Hash>(counts: &Mutex<HashMap<T, usize>>, keys: impl IntoIterator<Item = T>) {
+    let mut counts = counts.lock().unwrap();
+    for key in keys {
+        *counts.entry(key).or_default() += 1;
+    }
+}
+
+fn release<T: Eq + Hash>(counts: &Mutex<HashMap<T, usize>>, keys: impl IntoIterator<Item = T>) {
+    let mut counts = counts.lock().unwrap();
+    for key in keys {
+        if let Some(count) = counts.get_mut(&key) {
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ws::hub::{Hub, WsEvent};
+    use crate::ws::model::WsServerMessage;
+    use tokio::sync::broadcast::error::TryRecvError;
+
+    #[test]
+    fn test_watch() {
+        let test_instance = Hub::new();
+        test_instance.watch([1.into(), 2.into()]);
+        test_instance.watch([2.into()]);
+
+        let mut result = test_instance.token_pairs();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+
+        test_instance.unwatch([1.into(), 2.into()]);
+        assert_eq!(test_instance.token_pairs(), vec![2]);
+
+        test_instance.unwatch([2.into()]);
+        assert!(test_instance.token_pairs().is_empty());
+    }
+
+    #[test]
+    fn test_join() {
+        let test_instance = Hub::new();
+        let _first = test_instance.join(1.into());
+        let _second = test_instance.join(1.into());
+        test_instance.leave(1.into());
+        assert!(test_instance.users().contains(&1.into()));
+
+        test_instance.leave(1.into());
+        assert!(test_instance.users().is_empty());
+    }
+
+    #[test]
+    fn test_user_events_do_not_lag_behind_token_pair_events() {
+        let test_instance = Hub::new();
+        let mut token_pairs = test_instance.receiver();
+        let mut user = test_instance.join(1.into());
+        let mut other_user = test_instance.join(2.into());
+
+        for _ in 0..2000 {
+            test_instance.publish(WsEvent::TokenPair(
+                1.into(),
+                WsServerMessage::error("token pair"),
+            ));
+        }
+        test_instance.publish(WsEvent::User(1.into(), WsServerMessage::error("user")));
+
+        assert!(matches!(
+            token_pairs.try_recv(),
+            Err(TryRecvError::Lagged(_))
+        ));
+        assert!(matches!(
+            user.try_recv(),
+            Ok(WsServerMessage::Error { message }) if message == "user"
+        ));
+        assert!(matches!(other_user.try_recv(), Err(TryRecvError::Empty)));
+    }
+
+    #[test]
+    fn test_user_events_without_socket_get_dropped() {
+        let test_instance = Hub::new();
+        test_instance.publish(WsEvent::User(1.into(), WsServerMessage::error("user")));
+
+        let mut user = test_instance.join(1.into());
+        assert!(matches!(user.try_recv(), Err(TryRecvError::Empty)));
+    }
+}
@@ -1,31 +1,115 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use std::borrow::Cow;
-use std::collections::HashMap;
-
-use axum::extract::Request;
-use axum::http::StatusCode;
+use crate::http::error::HttpError;
+use crate::http::state::AppState;
+use axum::extract::{Request, State};
 use axum::middleware::Next;
 use axum::response::Response;
 use base::model::AuthenticatedUser;
+use base::service::AuthService;
+use common::service::ServiceError;
 
-pub async fn auth(mut req: Request, next: Next) -> Result<Response, StatusCode> {
-    if let Some(query) = req.uri().query() {
-        let query_params: HashMap<_, _> = url::form_urlencoded::parse(query.as_bytes()).collect();
-        let user = authenticate_user(query_params).await.ok_or(StatusCode::NOT_FOUND)?;
-        req.extensions_mut().insert(user);
-        return Ok(next.run(req).await);
-    }
+/// Browsers can not set headers on websocket requests, therefore the token is passed as access_token query parameter
+pub async fn auth(
+    State(state): State<AppState>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, HttpError> {
+    let user = authenticate_user(state.auth_service(), req.uri().query()).await?;
+    req.extensions_mut().insert(user);
+    Ok(next.run(req).await)
+}
 
-    todo!()
+async fn authenticate_user(
+    auth_service: AuthService,
+    query: Option<&str>,
+) -> Result<AuthenticatedUser, HttpError> {
+    let token = query
+        .and_then(|query| {
+            url::form_urlencoded::parse(query.as_bytes())
+                .find(|(key, _)| key == "access_token")
+                .map(|(_, value)| value.to_string())
+        })
+        .ok_or(HttpError::forbidden("User not found"))?;
+
+    match auth_service.get_by_token(token).await {
+        Ok(user) => Ok(user),
+        Err(err) => match err {
+            ServiceError::NotFound(_) => Err(HttpError::forbidden("User not found")),
+            _ => Err(err.into()),
+        },
+    }
 }
 
-async fn authenticate_user(query: HashMap<Cow<'_, str>, Cow<'_, str>>) -> Option<AuthenticatedUser> {
-    if let Some(access_token) = query.get("access_token") {
-        if access_token == "valid-token" {
-            return Some(AuthenticatedUser { id: 1.into() });
-        }
+#[cfg(test)]
+mod tests {
+    use crate::http::error::HttpError;
+    use crate::ws::middleware::auth::authenticate_user;
+    use base::repo::AuthRepo;
+    use base::service::AuthService;
+    use testing::auth::create_auth;
+    use testing::run_test_with_pool_on_empty_db;
+    use testing::user::get_or_create_test_user;
+
+    #[test_log::test(tokio::test)]
+    async fn test_ok() {
+        run_test_with_pool_on_empty_db(|pool| async move {
+            let service = AuthService::new(pool.clone(), AuthRepo::new());
+
+            let mut tx = pool.begin().await.unwrap();
+            get_or_create_test_user(&mut tx).await;
+            let _ = create_auth(&mut tx, 1, "token").await;
+            let _ = tx.commit().await;
+
+            let result = authenticate_user(service, Some("access_token=token"))
+                .await
+                .unwrap();
+            assert_eq!(result.id, 1);
+        })
+        .await;
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_token_does_not_exists() {
+        run_test_with_pool_on_empty_db(|pool| async move {
+            let service = AuthService::new(pool.clone(), AuthRepo::new());
+
+            let mut tx = pool.begin().await.unwrap();
+            get_or_create_test_user(&mut tx).await;
+            let _ = tx.commit().await;
+
+            let result = authenticate_user(service, Some("access_token=token")).await;
+            assert_eq!(
+                result.err().unwrap(),
+                HttpError::forbidden("User not found")
+            );
+        })
+        .await;
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_missing_access_token() {
+        run_test_with_pool_on_empty_db(|pool| async move {
+            let service = AuthService::new(pool.clone(), AuthRepo::new());
+
+            let mut tx = pool.begin().await.unwrap();
+            get_or_create_test_user(&mut tx).await;
+            let _ = create_auth(&mut tx, 1, "token").await;
+            let _ = tx.commit().await;
+
+            let result = authenticate_user(service.clone(), Some("token=token")).await;
+            assert_eq!(
+                result.err().unwrap(),
+                HttpError::forbidden("User not found")
+            );
+
+            let result = authenticate_user(service, None).await;
+            assert_eq!(
+                result.err().unwrap(),
+                HttpError::forbidden("User not found")
+            );
+        })
+        .await;
     }
-    None
 }
@@ -1,224 +1,159 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use std::net::SocketAddr;
-use std::ops::ControlFlow;
+use std::collections::HashSet;
 
-//allows to extract the IP of connecting user
-use axum::extract::connect_info::ConnectInfo;
+use crate::http::state::AppState;
+use crate::ws::model::{WsClientMessage, WsServerMessage};
 use axum::extract::ws::{Message, WebSocket};
-use axum::extract::WebSocketUpgrade;
-use axum::{body::Bytes, response::IntoResponse};
-use axum_extra::{headers, TypedHeader};
-//allows to split the websocket stream into separate TX and RX branches
+use axum::extract::{State, WebSocketUpgrade};
+use axum::response::IntoResponse;
+use axum::Extension;
+use base::model::{AuthenticatedUser, TokenPairId};
+use futures::stream::SplitSink;
 use futures::{sink::SinkExt, stream::StreamExt};
+use log::{debug, warn};
+use tokio::select;
+use tokio::sync::broadcast::error::RecvError;
 
+pub use hub::Hub;
+pub use publish::publish;
+
+mod hub;
 pub mod middleware;
+mod model;
+mod publish;
+
+/// Maximum number of token pairs a single socket can be subscribed to
+const MAX_SUBSCRIPTIONS: usize = 100;
 
-// #[tokio::main]
-// async fn main() {
-//     tracing_subscriber::registry()
-//         .with(
-//             tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-//                 format!("{}=debug,tower_http=debug", env!("CARGO_CRATE_NAME")).into()
-//             }),
-//         )
-//         .with(tracing_subscriber::fmt::layer())
-//         .init();
-//
-//     let assets_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets");
-//
-//     // build our application with some routes
-//     let app = Router::new()
-//         // .fallback_service(ServeDir::new(assets_dir).append_index_html_on_directories(true))
-//         .route("/ws", any(ws_handler))
-//         // logging so we can see whats going on
-//         .layer(
-//             TraceLayer::new_for_http()
-//                 .make_span_with(DefaultMakeSpan::default().include_headers(true)),
-//         );
-//
-//     // run it with hyper
-//     let listener = tokio::net::TcpListener::bind("127.0.0.1:3000")
-//         .await
-//         .unwrap();
-//     info!("listening on {}", listener.local_addr().unwrap());
-//     axum::serve(
-//         listener,
-//         app.into_make_service_with_connect_info::<SocketAddr>(),
-//     )
-//         .await
-//         .unwrap();
-// }
-
-/// The handler for the HTTP request (this gets called when the HTTP request lands at the start
-/// of websocket negotiation). After this completes, the actual switching from HTTP to
-/// websocket protocol will occur.
-/// This is the last point where we can extract TCP/IP metadata such as IP address of the client
-/// as well as things from HTTP headers such as user-agent of the browser etc.
 pub async fn handler(
     ws: WebSocketUpgrade,
-    user_agent: Option<TypedHeader<headers::UserAgent>>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
 ) -> impl IntoResponse {
-    let user_agent = if let Some(TypedHeader(user_agent)) = user_agent {
-        user_agent.to_string()
-    } else {
-        String::from("Unknown browser")
-    };
-    println!("`{user_agent}` at {addr} connected.");
-    // finalize the upgrade process by returning upgrade callback.
-    // we can customize the callback by sending additional info such as address.
-    ws.on_upgrade(move |socket| handle_socket(socket, addr))
+    ws.on_upgrade(move |socket| handle_socket(state, socket, user))
 }
 
-/// Actual websocket statemachine (one will be spawned per connection)
-async fn handle_socket(mut socket: WebSocket, who: SocketAddr) {
-    tokio::spawn(async move {
-        // send a ping (unsupported by some browsers) just to kick things off and get a response
-        if socket
-            .send(Message::Ping(Bytes::from_static(&[1, 2, 3])))
-            .await
-            .is_ok()
-        {
-            println!("Pinged {who}...");
-        } else {
-            println!("Could not send ping {who}!");
-            // no Error here since the only thing we can do is to close the connection.
-            // If we can not send messages, there is no way to salvage the statemachine anyway.
-            return;
-        }
-
-        // receive single message from a client (we can either receive or send with socket).
-        // this will likely be the Pong for our Ping or a hello message from client.
-        // waiting for message from a client will block this task, but will not block other client's
-        // connections.
-        if let Some(msg) = socket.recv().await {
-            if let Ok(msg) = msg {
-                if process_message(msg, who).is_break() {
-                    return;
-                }
-            } else {
-                println!("client {who} abruptly disconnected");
-                return;
-            }
-        }
-
-        // Since each client gets individual statemachine, we can pause handling
-        // when necessary to wait for some external event (in this case illustrated by sleeping).
-        // Waiting for this client to finish getting its greetings does not prevent other clients from
-        // connecting to server and receiving their greetings.
-        for i in 1..5 {
-            if socket
-                .send(Message::Text(format!("Hi {i} times!").into()))
-                .await
-                .is_err()
-            {
-                println!("client {who} abruptly disconnected");
-                return;
-            }
-            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-        }
-
-        // By splitting socket we can send and receive at the same time. In this example we will send
-        // unsolicited messages to client based on some sort of server's internal event (i.e .timer).
-        let (mut sender, mut receiver) = socket.split();
-
-        // Spawn a task that will push several messages to the client (does not matter what client does)
-        let mut send_task = tokio::spawn(async move {
-            let n_msg = 20;
-            for i in 0..n_msg {
-                // In case of any websocket error, we exit.
-                if sender
-                    .send(Message::Text(format!("Server message {i} ...").into()))
-                    .await
-                    .is_err()
-                {
-                    return i;
+/// Pushes pump.fun updates of subscribed token pairs and rule matches of the user until the client disconnects
+async fn handle_socket(state: AppState, socket: WebSocket, user: AuthenticatedUser) {
+    let (mut sender, mut receiver) = socket.split();
+    let mut events = state.hub.receiver();
+    let mut user_events = state.hub.join(user.id);
+    let mut subscriptions: HashSet<TokenPairId> = HashSet::new();
+
+    debug!("user {} connected", user.id.0);
+
+    loop {
+        select! {
+            message = receiver.next() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    // ping and pong are answered by axum, binary messages are not part of the protocol
+                    Some(Ok(Message::Ping(_))) | Some(Ok(Message::Pong(_))) | Some(Ok(Message::Binary(_))) => continue,
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                };
+
+                let replies = match serde_json::from_str::<WsClientMessage>(text.as_str()) {
+                    Ok(message) => handle_message(&state, &mut subscriptions, message).await,
+                    Err(err) => vec![WsServerMessage::error(err.to_string())],
+                };
+
+                if send_all(&mut sender, replies).await.is_err() {
+                    break;
                 }
-
-                tokio::time::sleep(std::time::Duration::from_millis(300)).await;
             }
-
-            // println!("Sending close to {who}...");
-            // if let Err(e) = sender
-            //     .send(Message::Close(Some(CloseFrame {
-            //         code: axum::extract::ws::close_code::NORMAL,
-            //         reason: Utf8Bytes::from_static("Goodbye"),
-            //     })))
-            //     .await
-            // {
-            //     println!("Could not send Close due to {e}, probably it is ok?");
-            // }
-            n_msg
-        });
-
-        // This second task will receive messages from client and print them on server console
-        let mut recv_task = tokio::spawn(async move {
-            let mut cnt = 0;
-            while let Some(Ok(msg)) = receiver.next().await {
-                cnt += 1;
-                // print message and break if instructed to do so
-                if process_message(msg, who).is_break() {
+            event = events.recv() => {
+                let message = match event {
+                    Ok((token_pair, message)) if subscriptions.contains(&token_pair) => message,
+                    Ok(_) => continue,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("user {} missed {skipped} websocket events", user.id.0);
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                if send_all(&mut sender, vec![message]).await.is_err() {
                     break;
                 }
             }
-            cnt
-        });
-
-        // If any one of the tasks exit, abort the other.
-        tokio::select! {
-            rv_a = (&mut send_task) => {
-                match rv_a {
-                    Ok(a) => println!("{a} messages sent to {who}"),
-                    Err(a) => println!("Error sending messages {a:?}")
-                }
-                recv_task.abort();
-            },
-            rv_b = (&mut recv_task) => {
-                match rv_b {
-                    Ok(b) => println!("Received {b} messages"),
-                    Err(b) => println!("Error receiving messages {b:?}")
+            event = user_events.recv() => {
+                let message = match event {
+                    Ok(message) => message,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("user {} missed {skipped} of their websocket events", user.id.0);
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                if send_all(&mut sender, vec![message]).await.is_err() {
+                    break;
                 }
-                send_task.abort();
             }
         }
+    }
 
-        // returning from the handler closes the websocket connection
-        println!("Websocket context {who} destroyed");
-    });
+    state.hub.leave(user.id);
+    state.hub.unwatch(subscriptions);
+    debug!("user {} disconnected", user.id.0);
 }
 
-/// helper to print contents of messages to stdout. Has special treatment for Close.
-fn process_message(msg: Message, who: SocketAddr) -> ControlFlow<(), ()> {
-    match msg {
-        Message::Text(t) => {
-            println!(">>> {who} sent str: {t:?}");
-        }
-        Message::Binary(d) => {
-            println!(">>> {} sent {} bytes: {:?}", who, d.len(), d);
-        }
-        Message::Close(c) => {
-            if let Some(cf) = c {
-                println!(
-                    ">>> {} sent close with code {} and reason `{}`",
-                    who, cf.code, cf.reason
-                );
-            } else {
-                println!(">>> {who} somehow sent close message without CloseFrame");
+async fn handle_message(
+    state: &AppState,
+    subscriptions: &mut HashSet<TokenPairId>,
+    message: WsClientMessage,
+) -> Vec<WsServerMessage> {
+    match message {
+        WsClientMessage::Subscribe { token_pairs } => {
+            let added = token_pairs
+                .iter()
+                .copied()
+                .filter(|token_pair| !subscriptions.contains(token_pair))
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            if subscriptions.len() + added.len() > MAX_SUBSCRIPTIONS {
+                return vec![WsServerMessage::error(format!(
+                    "Unable to subscribe to more than {MAX_SUBSCRIPTIONS} token pairs"
+                ))];
             }
-            return ControlFlow::Break(());
-        }
 
-        Message::Pong(v) => {
-            println!(">>> {who} sent pong with {v:?}");
+            subscriptions.extend(added.iter().copied());
+            state.hub.watch(added.iter().copied());
+
+            // the client gets the latest state right away, afterwards only what changed
+            let mut result = vec![WsServerMessage::Subscribed { token_pairs }];
+            result.extend(
+                publish::load_pumpfun(state, &added)
+                    .await
+                    .into_iter()
+                    .map(|(_, message)| message),
+            );
+            result
         }
-        // You should never need to manually handle Message::Ping, as axum's websocket library
-        // will do so for you automagically by replying with Pong and copying the v according to
-        // spec. But if you need the contents of the pings you can see them here.
-        Message::Ping(v) => {
-            println!(">>> {who} sent ping with {v:?}");
+        WsClientMessage::Unsubscribe { token_pairs } => {
+            let removed = token_pairs
+                .iter()
+                .copied()
+                .filter(|token_pair| subscriptions.remove(token_pair))
+                .collect::<Vec<_>>();
+
+            state.hub.unwatch(removed);
+            vec![WsServerMessage::Unsubscribed { token_pairs }]
         }
     }
-    ControlFlow::Continue(())
+}
+
+async fn send_all(
+    sender: &mut SplitSink<WebSocket, Message>,
+    messages: Vec<WsServerMessage>,
+) -> Result<(), axum::Error> {
+    for message in messages {
+        let json = serde_json::to_string(&message).unwrap();
+        sender.send(Message::Text(json.into())).await?;
+    }
+    Ok(())
 }
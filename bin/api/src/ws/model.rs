@@ -0,0 +1,141 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::solana::Slot;
+use base::model::{Invocation, InvocationId, RuleId, TokenPairId};
+use common::model::{MarketCapQuote, MarketCapUsd, Percent, PriceQuote, PriceUsd, Timeframe};
+use serde::{Deserialize, Serialize};
+use solana::pumpfun::model::summary::TimeframeSummary;
+use solana::pumpfun::model::Current;
+
+/// Messages a client sends over the websocket
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WsClientMessage {
+    #[serde(rename_all = "camelCase")]
+    Subscribe { token_pairs: Vec<TokenPairId> },
+    #[serde(rename_all = "camelCase")]
+    Unsubscribe { token_pairs: Vec<TokenPairId> },
+}
+
+/// Messages the server pushes to a client
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WsServerMessage {
+    #[serde(rename_all = "camelCase")]
+    Subscribed {
+        token_pairs: Vec<TokenPairId>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Unsubscribed {
+        token_pairs: Vec<TokenPairId>,
+    },
+    PumpfunCurrent(WsPumpfunCurrent),
+    PumpfunSummary(WsPumpfunSummary),
+    RuleMatched(WsRuleMatched),
+    Error {
+        message: String,
+    },
+}
+
+impl WsServerMessage {
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::Error {
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsPumpfunCurrent {
+    pub token_pair: TokenPairId,
+    pub slot: Slot,
+    pub progress: Percent,
+    pub price: PriceQuote,
+    pub price_usd: Option<PriceUsd>,
+    pub market_cap: Option<MarketCapQuote>,
+    pub market_cap_usd: Option<MarketCapUsd>,
+    pub complete: bool,
+}
+
+impl From<Current> for WsPumpfunCurrent {
+    fn from(value: Current) -> Self {
+        Self {
+            token_pair: value.id,
+            slot: value.slot,
+            progress: value.progress,
+            price: value.price,
+            price_usd: value.price_usd,
+            market_cap: value.market_cap,
+            market_cap_usd: value.market_cap_usd,
+            complete: value.complete,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsPumpfunSummary {
+    pub token_pair: TokenPairId,
+    pub timeframe: Timeframe,
+    pub summary: TimeframeSummary,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsRuleMatched {
+    pub invocation: InvocationId,
+    pub rule: RuleId,
+    pub token_pair: TokenPairId,
+    pub stage: i16,
+}
+
+impl From<Invocation> for WsRuleMatched {
+    fn from(value: Invocation) -> Self {
+        Self {
+            invocation: value.id,
+            rule: value.rule,
+            token_pair: value.token_pair,
+            stage: value.stage,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ws::model::{WsClientMessage, WsRuleMatched, WsServerMessage};
+
+    #[test]
+    fn test_subscribe() {
+        let result: WsClientMessage =
+            serde_json::from_str(r#"{"type":"subscribe","tokenPairs":[1,2]}"#).unwrap();
+        assert_eq!(
+            result,
+            WsClientMessage::Subscribe {
+                token_pairs: vec![1.into(), 2.into()]
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_type() {
+        let result = serde_json::from_str::<WsClientMessage>(r#"{"type":"echo"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rule_matched() {
+        let result = serde_json::to_string(&WsServerMessage::RuleMatched(WsRuleMatched {
+            invocation: 3.into(),
+            rule: 2.into(),
+            token_pair: 1.into(),
+            stage: 0,
+        }))
+        .unwrap();
+        assert_eq!(
+            result,
+            r#"{"type":"ruleMatched","invocation":3,"rule":2,"tokenPair":1,"stage":0}"#
+        );
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::http::state::AppState;
+use crate::ws::hub::WsEvent;
+use crate::ws::model::{WsPumpfunSummary, WsServerMessage};
+use base::model::{InvocationId, TokenPairId};
+use common::model::Timeframe;
+use log::{info, warn};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::select;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+const TIMEFRAMES: [Timeframe; 6] = [
+    Timeframe::M1,
+    Timeframe::M5,
+    Timeframe::M15,
+    Timeframe::H1,
+    Timeframe::H6,
+    Timeframe::D1,
+];
+
+/// Identifies a pushed pump.fun message of a token pair, the current state has no timeframe
+type PumpfunKey = (TokenPairId, Option<Timeframe>);
+
+/// Periodically loads what changed for connected clients and publishes it to the hub,
+/// so that the database is queried once for all sockets instead of once per socket
+pub fn publish(state: AppState, mut exit: broadcast::Receiver<()>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_millis(500));
+        let mut sent: HashMap<PumpfunKey, String> = HashMap::new();
+        let mut last_invocation = state
+            .rule_service()
+            .latest_invocation()
+            .await
+            .unwrap_or_default()
+            .unwrap_or(InvocationId(0));
+
+        loop {
+            select! {
+                _ = exit.recv() => {
+                    info!("Stops publishing to websockets");
+                    break;
+                }
+                _ = interval.tick() => {
+                    publish_pumpfun(&state, &mut sent).await;
+                    last_invocation = publish_rule_matched(&state, last_invocation).await;
+                }
+            }
+        }
+    })
+}
+
+/// Current state and summaries of the given token pairs
+pub async fn load_pumpfun(
+    state: &AppState,
+    token_pairs: &[TokenPairId],
+) -> Vec<(PumpfunKey, WsServerMessage)> {
+    let mut result = vec![];
+    if token_pairs.is_empty() {
+        return result;
+    }
+
+    let service = state.pumpfun_token_service();
+    match service.list_current(token_pairs.to_vec()).await {
+        Ok(currents) => {
+            for current in currents {
+                result.push((
+                    (current.id, None),
+                    WsServerMessage::PumpfunCurrent(current.into()),
+                ))
+            }
+        }
+        Err(err) => warn!("failed to load pumpfun current: {err}"),
+    }
+
+    for timeframe in TIMEFRAMES {
+        match service
+            .list_summaries(token_pairs.to_vec(), timeframe)
+            .await
+        {
+            Ok(summaries) => {
+                for (token_pair, summary) in summaries {
+                    result.push((
+                        (token_pair, Some(timeframe)),
+                        WsServerMessage::PumpfunSummary(WsPumpfunSummary {
+                            token_pair,
+                            timeframe,
+                            summary,
+                        }),
+                    ))
+                }
+            }
+            Err(err) => warn!("failed to load pumpfun summary {timeframe:?}: {err}"),
+        }
+    }
+
+    result
+}
+
+/// Publishes the pump.fun messages of subscribed token pairs which differ from what was published before
+async fn publish_pumpfun(state: &AppState, sent: &mut HashMap<PumpfunKey, String>) {
+    let token_pairs = state.hub.token_pairs();
+    sent.retain(|(token_pair, _), _| token_pairs.contains(token_pair));
+
+    for (key, message) in load_pumpfun(state, &token_pairs).await {
+        let json = serde_json::to_string(&message).unwrap();
+        if sent.get(&key) == Some(&json) {
+            continue;
+        }
+        sent.insert(key, json);
+        state.hub.publish(WsEvent::TokenPair(key.0, message));
+    }
+}
+
+/// Publishes rules which matched since the last invocation to their connected users,
+/// returns the last invocation seen
+async fn publish_rule_matched(state: &AppState, last: InvocationId) -> InvocationId {
+    let invocations = match state
+        .rule_service()
+        .list_invocations_after(last, 1000)
+        .await
+    {
+        Ok(invocations) => invocations,
+        Err(err) => {
+            warn!("failed to list invocations: {err}");
+            return last;
+        }
+    };
+
+    let Some(result) = invocations.last().map(|invocation| invocation.id) else {
+        return last;
+    };
+
+    let users = state.hub.users();
+    for invocation in invocations {
+        if users.contains(&invocation.user) {
+            state.hub.publish(WsEvent::User(
+                invocation.user,
+                WsServerMessage::RuleMatched(invocation.into()),
+            ));
+        }
+    }
+
+    result
+}
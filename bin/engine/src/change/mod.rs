@@ -0,0 +1,28 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::TokenPairChange;
+use base::repo::TokenPairChangeRepo;
+use common::model::Limit;
+use common::service::ServiceResult;
+use sqlx::PgPool;
+
+#[derive(Clone)]
+pub struct ChangeService {
+    pool: PgPool,
+    repo: TokenPairChangeRepo,
+}
+
+impl ChangeService {
+    pub fn new(pool: PgPool, repo: TokenPairChangeRepo) -> Self {
+        Self { pool, repo }
+    }
+
+    /// Takes the oldest token pair changes reported by the indexer and aggregator
+    pub async fn pop(&self, limit: impl Into<Limit>) -> ServiceResult<Box<[TokenPairChange]>> {
+        let mut tx = self.pool.begin().await?;
+        let result = self.repo.pop(&mut tx, limit).await?;
+        tx.commit().await?;
+        Ok(result)
+    }
+}
@@ -9,10 +9,45 @@ use log::info;
 use serde::Deserialize;
 
 use common::repo::pool::PostgresConfig;
+use common::ConfigValue;
 
 #[derive(Debug, Deserialize)]
 pub struct Config {
+    pub engine: EngineConfig,
     pub postgres: PostgresConfig,
+    pub rpc: RpcConfig,
+    pub trade: TradeConfig,
+    pub wallet: WalletConfig,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EngineConfig {
+    // max number of token pair changes processed at once
+    pub change_limit: ConfigValue,
+    // interval in which active rules get reloaded
+    pub rule_refresh_ms: ConfigValue,
+    // interval in which rules with time dependent facts get tested against all token pairs
+    pub sweep_ms: ConfigValue,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct RpcConfig {
+    pub url: ConfigValue,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TradeConfig {
+    // amount of lamports spent by Action::Buy
+    pub buy_lamports: ConfigValue,
+    // percentage of the token balance sold by Action::Sell
+    pub sell_percent: ConfigValue,
+    // slippage tolerance in basis points, must not exceed 10000
+    pub slippage_bps: ConfigValue,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WalletConfig {
+    pub secret: ConfigValue,
 }
 
 impl Config {
@@ -0,0 +1,305 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{Invocation, RuleId, TokenPairId};
+use common::model::Timestamp;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// How often the initial stage of a rule was performed for a token pair
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Invoked {
+    pub(crate) count: usize,
+    pub(crate) last: Timestamp,
+    /// whether the condition stopped matching since the last invocation
+    pub(crate) armed: bool,
+}
+
+/// Invocations of a single rule, as known to the database
+#[derive(Default)]
+pub(crate) struct RuleInvocations {
+    /// token pairs for which the initial stage was already performed
+    pub(crate) invoked: HashMap<TokenPairId, Invoked>,
+    /// invocations which wait for the condition of their next stage
+    pub(crate) pending: HashMap<TokenPairId, Invocation>,
+}
+
+/// Keeps track of invocations per rule, so that repeated matches of the same token pair
+/// do not hit the database
+#[derive(Clone, Default)]
+pub(crate) struct InvocationCache(Arc<RwLock<HashMap<RuleId, RuleInvocations>>>);
+
+impl InvocationCache {
+    pub(crate) async fn is_loaded(&self, rule: RuleId) -> bool {
+        self.0.read().await.contains_key(&rule)
+    }
+
+    pub(crate) async fn load(&self, rule: RuleId, invocations: RuleInvocations) {
+        self.0.write().await.insert(rule, invocations);
+    }
+
+    pub(crate) async fn invoked(&self, rule: RuleId, token_pair: TokenPairId) -> Option<Invoked> {
+        self.0
+            .read()
+            .await
+            .get(&rule)
+            .and_then(|invocations| invocations.invoked.get(&token_pair))
+            .copied()
+    }
+
+    /// Number of token pairs for which the initial stage of the rule was performed
+    pub(crate) async fn invoked_count(&self, rule: RuleId) -> usize {
+        self.0
+            .read()
+            .await
+            .get(&rule)
+            .map(|invocations| invocations.invoked.len())
+            .unwrap_or(0)
+    }
+
+    pub(crate) async fn pending(
+        &self,
+        rule: RuleId,
+        token_pair: TokenPairId,
+    ) -> Option<Invocation> {
+        self.0
+            .read()
+            .await
+            .get(&rule)
+            .and_then(|invocations| invocations.pending.get(&token_pair))
+            .cloned()
+    }
+
+    /// Records that the initial stage of the rule was performed for the token pair
+    pub(crate) async fn record(&self, rule: RuleId, token_pair: TokenPairId, timestamp: Timestamp) {
+        self.0
+            .write()
+            .await
+            .entry(rule)
+            .or_default()
+            .invoked
+            .entry(token_pair)
+            .and_modify(|invoked| {
+                invoked.count += 1;
+                invoked.last = timestamp;
+                invoked.armed = false;
+            })
+            .or_insert(Invoked {
+                count: 1,
+                last: timestamp,
+                armed: false,
+            });
+    }
+
+    /// Records that the condition of the rule did not match the token pair
+    pub(crate) async fn arm(&self, rule: RuleId, token_pair: TokenPairId) {
+        if let Some(invoked) = self
+            .0
+            .write()
+            .await
+            .get_mut(&rule)
+            .and_then(|invocations| invocations.invoked.get_mut(&token_pair))
+        {
+            invoked.armed = true;
+        }
+    }
+
+    /// Replaces the pending invocation of the token pair, if the invocation waits for
+    /// another stage, or removes it otherwise
+    pub(crate) async fn set_pending(
+        &self,
+        rule: RuleId,
+        token_pair: TokenPairId,
+        invocation: Option<Invocation>,
+    ) {
+        let mut guard = self.0.write().await;
+        let invocations = guard.entry(rule).or_default();
+        match invocation.filter(|invocation| invocation.next.is_some()) {
+            Some(invocation) => {
+                invocations.pending.insert(token_pair, invocation);
+            }
+            None => {
+                invocations.pending.remove(&token_pair);
+            }
+        }
+    }
+
+    /// Drops the invocations of the rule, so that they get loaded again
+    pub(crate) async fn invalidate(&self, rule: RuleId) {
+        self.0.write().await.remove(&rule);
+    }
+
+    /// Drops the invocations of all rules which are not retained
+    pub(crate) async fn retain(&self, rules: &HashSet<RuleId>) {
+        self.0.write().await.retain(|rule, _| rules.contains(rule));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base::model::{Action, Condition, Sequence};
+    use common::model::CreatedAt;
+
+    fn invocation(next: Option<Sequence>) -> Invocation {
+        Invocation {
+            id: 1.into(),
+            rule: RuleId(1),
+            token_pair: TokenPairId(2),
+            user: 1.into(),
+            stage: 0,
+            next,
+            deadline: None,
+            created_at: CreatedAt::now(),
+        }
+    }
+
+    fn sequence() -> Sequence {
+        Sequence {
+            condition: Condition::And { conditions: vec![] },
+            action: Action::Sell,
+        }
+    }
+
+    fn at(epoch_second: i64) -> Timestamp {
+        Timestamp::from_epoch_second(epoch_second).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_invoked() {
+        let test_instance = InvocationCache::default();
+        assert!(!test_instance.is_loaded(RuleId(1)).await);
+        assert!(test_instance
+            .invoked(RuleId(1), TokenPairId(2))
+            .await
+            .is_none());
+
+        test_instance
+            .record(RuleId(1), TokenPairId(2), at(100))
+            .await;
+        assert!(test_instance.is_loaded(RuleId(1)).await);
+        assert_eq!(
+            test_instance.invoked(RuleId(1), TokenPairId(2)).await,
+            Some(Invoked {
+                count: 1,
+                last: at(100),
+                armed: false
+            })
+        );
+        assert!(test_instance
+            .invoked(RuleId(1), TokenPairId(3))
+            .await
+            .is_none());
+        assert!(test_instance
+            .invoked(RuleId(2), TokenPairId(2))
+            .await
+            .is_none());
+
+        test_instance
+            .record(RuleId(1), TokenPairId(3), at(100))
+            .await;
+        test_instance
+            .record(RuleId(1), TokenPairId(3), at(200))
+            .await;
+        assert_eq!(test_instance.invoked_count(RuleId(1)).await, 2);
+        assert_eq!(test_instance.invoked_count(RuleId(2)).await, 0);
+
+        let invoked = test_instance
+            .invoked(RuleId(1), TokenPairId(3))
+            .await
+            .unwrap();
+        assert_eq!(invoked.count, 2);
+        assert_eq!(invoked.last, at(200));
+    }
+
+    #[tokio::test]
+    async fn test_arm() {
+        let test_instance = InvocationCache::default();
+
+        test_instance.arm(RuleId(1), TokenPairId(2)).await;
+        assert!(test_instance
+            .invoked(RuleId(1), TokenPairId(2))
+            .await
+            .is_none());
+
+        test_instance
+            .record(RuleId(1), TokenPairId(2), at(100))
+            .await;
+        test_instance.arm(RuleId(1), TokenPairId(2)).await;
+        let invoked = test_instance
+            .invoked(RuleId(1), TokenPairId(2))
+            .await
+            .unwrap();
+        assert!(invoked.armed);
+
+        test_instance
+            .record(RuleId(1), TokenPairId(2), at(200))
+            .await;
+        let invoked = test_instance
+            .invoked(RuleId(1), TokenPairId(2))
+            .await
+            .unwrap();
+        assert!(!invoked.armed);
+    }
+
+    #[tokio::test]
+    async fn test_pending() {
+        let test_instance = InvocationCache::default();
+
+        test_instance
+            .set_pending(
+                RuleId(1),
+                TokenPairId(2),
+                Some(invocation(Some(sequence()))),
+            )
+            .await;
+        let pending = test_instance
+            .pending(RuleId(1), TokenPairId(2))
+            .await
+            .unwrap();
+        assert_eq!(pending.next, Some(sequence()));
+
+        test_instance
+            .set_pending(RuleId(1), TokenPairId(2), Some(invocation(None)))
+            .await;
+        assert!(test_instance
+            .pending(RuleId(1), TokenPairId(2))
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_retain() {
+        let test_instance = InvocationCache::default();
+        test_instance
+            .record(RuleId(1), TokenPairId(2), at(100))
+            .await;
+        test_instance
+            .record(RuleId(2), TokenPairId(2), at(100))
+            .await;
+
+        test_instance.retain(&HashSet::from([RuleId(2)])).await;
+        assert!(!test_instance.is_loaded(RuleId(1)).await);
+        assert!(test_instance.is_loaded(RuleId(2)).await);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate() {
+        let test_instance = InvocationCache::default();
+        test_instance
+            .record(RuleId(1), TokenPairId(2), at(100))
+            .await;
+        test_instance
+            .record(RuleId(2), TokenPairId(2), at(100))
+            .await;
+
+        test_instance.invalidate(RuleId(1)).await;
+        assert!(!test_instance.is_loaded(RuleId(1)).await);
+        assert!(test_instance
+            .invoked(RuleId(1), TokenPairId(2))
+            .await
+            .is_none());
+        assert!(test_instance.is_loaded(RuleId(2)).await);
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+mod cache;
+
+use crate::invocation::cache::{InvocationCache, Invoked, RuleInvocations};
+use crate::trade::TradeService;
+use base::model::{Action, Invocation, Rule, RuleId, RuleRearm, TokenPairId, TradeSide, Venue};
+use base::repo::{InvocationCreateCmd, InvocationRepo};
+use base::service::{NotificationRuleMatched, NotificationService};
+use common::model::{Count, Timestamp};
+use common::repo::error::RepoError;
+use common::service::ServiceResult;
+use log::error;
+use sqlx::PgPool;
+use std::collections::HashSet;
+
+#[derive(Clone)]
+pub struct InvocationService {
+    pool: PgPool,
+    repo: InvocationRepo,
+    notification: NotificationService,
+    trade: TradeService,
+    cache: InvocationCache,
+}
+
+impl InvocationService {
+    pub fn new(
+        pool: PgPool,
+        repo: InvocationRepo,
+        notification: NotificationService,
+        trade: TradeService,
+    ) -> Self {
+        Self {
+            pool,
+            repo,
+            notification,
+            trade,
+            cache: InvocationCache::default(),
+        }
+    }
+
+    /// Forgets the cached invocations of the rule, e.g. after it was updated
+    pub async fn invalidate(&self, rule: RuleId) {
+        self.cache.invalidate(rule).await
+    }
+
+    /// Forgets the cached invocations of all rules which are no longer active
+    pub async fn retain(&self, rules: &HashSet<RuleId>) {
+        self.cache.retain(rules).await
+    }
+
+    /// The invocation of the rule for the token pair which waits for the condition of its next stage
+    pub async fn pending(
+        &self,
+        rule: &Rule,
+        token_pair: TokenPairId,
+    ) -> ServiceResult<Option<Invocation>> {
+        self.load(rule).await?;
+        self.unexpired_pending(rule, token_pair).await
+    }
+
+    /// Performs the initial stage of a rule for a token pair.
+    /// Returns None if the rule is outside of its schedule, was invoked for as many token pairs as allowed
+    /// or is not rearmed for a token pair it was already invoked for.
+    /// A token pair, whose invocation still waits for its next stage, does not get invoked again
+    pub async fn invoke(
+        &self,
+        rule: &Rule,
+        token_pair: TokenPairId,
+        venue: Venue,
+    ) -> ServiceResult<Option<Invocation>> {
+        let now = Timestamp::now();
+        if !rule.limit.scheduled(now) {
+            return Ok(None);
+        }
+
+        self.load(rule).await?;
+        match self.cache.invoked(rule.id, token_pair).await {
+            Some(invoked) => {
+                if self.unexpired_pending(rule, token_pair).await?.is_some() {
+                    return Ok(None);
+                }
+
+                if rule.limit.rearm == RuleRearm::Edge && !invoked.armed {
+                    return Ok(None);
+                }
+
+                if !rule
+                    .limit
+                    .rearmed(Count(invoked.count as i64), invoked.last, now)
+                {
+                    return Ok(None);
+                }
+            }
+            None => {
+                let invoked = self.cache.invoked_count(rule.id).await;
+                if rule.limit.exhausted(Count(invoked as i64)) {
+                    return Ok(None);
+                }
+            }
+        }
+
+        let result = self
+            .perform(rule, token_pair, venue, None, &rule.sequence.action)
+            .await?;
+
+        if let Some(invocation) = &result {
+            self.cache
+                .record(rule.id, token_pair, invocation.created_at.0)
+                .await;
+            self.cache
+                .set_pending(rule.id, token_pair, Some(invocation.clone()))
+                .await;
+        }
+
+        Ok(result)
+    }
+
+    /// Records that the condition of an edge triggered rule did not match the token pair,
+    /// so that the rule gets invoked again once the condition matches again
+    pub async fn unmatched(&self, rule: &Rule, token_pair: TokenPairId) -> ServiceResult<()> {
+        if rule.limit.rearm != RuleRearm::Edge {
+            return Ok(());
+        }
+
+        self.load(rule).await?;
+        self.cache.arm(rule.id, token_pair).await;
+        Ok(())
+    }
+
+    /// Performs the pending next stage of an invocation, after its condition matched.
+    /// Returns None if the stage was already performed
+    pub async fn advance(
+        &self,
+        rule: &Rule,
+        previous: &Invocation,
+        venue: Venue,
+    ) -> ServiceResult<Option<Invocation>> {
+        let Some(next) = &previous.next else {
+            return Ok(None);
+        };
+
+        let result = self
+            .perform(
+                rule,
+                previous.token_pair,
+                venue,
+                Some(previous),
+                &next.action,
+            )
+            .await?;
+
+        self.cache
+            .set_pending(rule.id, previous.token_pair, result.clone())
+            .await;
+
+        Ok(result)
+    }
+
+    /// The cached pending invocation of the token pair, unless its deadline passed.
+    /// An expired invocation gets completed without performing its next stage
+    async fn unexpired_pending(
+        &self,
+        rule: &Rule,
+        token_pair: TokenPairId,
+    ) -> ServiceResult<Option<Invocation>> {
+        let Some(pending) = self.cache.pending(rule.id, token_pair).await else {
+            return Ok(None);
+        };
+
+        if !pending.expired(Timestamp::now()) {
+            return Ok(Some(pending));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        match self.repo.complete(&mut tx, pending.id).await {
+            Ok(_) | Err(RepoError::NotFound) => {}
+            Err(err) => return Err(err.into()),
+        }
+        tx.commit().await?;
+
+        self.cache.set_pending(rule.id, token_pair, None).await;
+        Ok(None)
+    }
+
+    /// Loads the invocations of the rule into the cache, unless they are already cached
+    async fn load(&self, rule: &Rule) -> ServiceResult<()> {
+        if self.cache.is_loaded(rule.id).await {
+            return Ok(());
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let invoked = self.repo.list_invoked(&mut tx, rule.id).await?;
+        let pending = self.repo.list_pending(&mut tx, rule.id).await?;
+        tx.commit().await?;
+
+        self.cache
+            .load(
+                rule.id,
+                RuleInvocations {
+                    // the condition might have stopped matching while the rule was not cached
+                    invoked: invoked
+                        .into_iter()
+                        .map(|invoked| {
+                            (
+                                invoked.token_pair,
+                                Invoked {
+                                    count: invoked.count.0 as usize,
+                                    last: invoked.last.0,
+                                    armed: false,
+                                },
+                            )
+                        })
+                        .collect(),
+                    pending: pending
+                        .into_iter()
+                        .map(|invocation| (invocation.token_pair, invocation))
+                        .collect(),
+                },
+            )
+            .await;
+
+        Ok(())
+    }
+
+    /// Performs an action for a token pair whose facts were provided by venue
+    async fn perform(
+        &self,
+        rule: &Rule,
+        token_pair: TokenPairId,
+        venue: Venue,
+        previous: Option<&Invocation>,
+        action: &Action,
+    ) -> ServiceResult<Option<Invocation>> {
+        let deadline = action.window_seconds().map(|seconds| {
+            Timestamp::from_epoch_micros(Timestamp::now().to_epoch_micros() + seconds * 1_000_000)
+        });
+        let (action, next) = action.split();
+
+        let mut tx = self.pool.begin().await?;
+
+        if let Some(previous) = previous {
+            match self.repo.complete(&mut tx, previous.id).await {
+                Ok(_) => {}
+                Err(RepoError::NotFound) => {
+                    tx.rollback().await?;
+                    return Ok(None);
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        let invocation = match self
+            .repo
+            .create(
+                &mut tx,
+                InvocationCreateCmd {
+                    user: rule.user,
+                    rule: rule.id,
+                    token_pair,
+                    stage: previous.map(|p| p.stage + 1).unwrap_or(0),
+                    next,
+                    deadline,
+                },
+            )
+            .await
+        {
+            Ok(invocation) => invocation,
+            Err(RepoError::AlreadyExists) => {
+                tx.rollback().await?;
+                return Ok(None);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut trade = None;
+        match action {
+            Action::AndThen { .. } => unreachable!("split never returns an AndThen action"),
+            Action::Buy => trade = Some(TradeSide::Buy),
+            Action::NotifyTelegram { buttons } => {
+                let _ = self
+                    .notification
+                    .create_rule_matched_tx(
+                        &mut tx,
+                        NotificationRuleMatched::Telegram {
+                            user: rule.user,
+                            rule: rule.id,
+                            venue,
+                            token_pair,
+                            buttons: buttons.clone(),
+                        },
+                    )
+                    .await;
+            }
+            Action::Sell => trade = Some(TradeSide::Sell),
+        }
+
+        tx.commit().await?;
+
+        // sending a transaction takes several rpc round trips, which must not hold up the evaluation of other rules
+        if let Some(side) = trade {
+            let trade = self.trade.clone();
+            let invocation = invocation.clone();
+            tokio::spawn(async move {
+                if let Err(err) = trade.execute(&invocation, side).await {
+                    error!(
+                        "unable to record trade of invocation {} - {err}",
+                        invocation.id.0
+                    );
+                }
+            });
+        }
+
+        Ok(Some(invocation))
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+mod summary;
+
+use crate::jupiter::fact::summary::add_summary_to_facts;
+use base::model::Fact::{MarketCapQuote, MarketCapUsd, VenueJupiter};
+use base::model::{add_social_to_facts, Fact, Facts, Mint, TokenPairId, Value};
+use base::repo::TokenPairRepo;
+use common::model::{Limit, TimeUnit, Timeframe};
+use solana::jupiter::repo::{LiquidityRepo, SummaryQuery, SummaryRepo};
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone)]
+pub struct FactService {
+    pool: PgPool,
+    pair: TokenPairRepo,
+    summary: SummaryRepo,
+    liquidity: LiquidityRepo,
+}
+
+impl FactService {
+    pub fn new(
+        pool: PgPool,
+        pair: TokenPairRepo,
+        summary: SummaryRepo,
+        liquidity: LiquidityRepo,
+    ) -> Self {
+        Self {
+            pool,
+            pair,
+            summary,
+            liquidity,
+        }
+    }
+
+    /// Facts of all token pairs traded through Jupiter
+    pub async fn jupiter_facts(&self) -> HashMap<TokenPairId, Facts> {
+        self.load_jupiter_facts(None).await
+    }
+
+    /// Facts of the given token pairs, as far as they are traded through Jupiter
+    pub async fn jupiter_facts_of(
+        &self,
+        token_pairs: &[TokenPairId],
+    ) -> HashMap<TokenPairId, Facts> {
+        if token_pairs.is_empty() {
+            return HashMap::new();
+        }
+        self.load_jupiter_facts(Some(token_pairs)).await
+    }
+
+    async fn load_jupiter_facts(
+        &self,
+        token_pairs: Option<&[TokenPairId]>,
+    ) -> HashMap<TokenPairId, Facts> {
+        let mut tx = self.pool.begin().await.unwrap();
+
+        let mut summaries = Vec::new();
+        for timeframe in [
+            Timeframe::M1,
+            Timeframe::M5,
+            Timeframe::M15,
+            Timeframe::H1,
+            Timeframe::H6,
+            Timeframe::D1,
+        ] {
+            let summary = match token_pairs {
+                None => self
+                    .summary
+                    .list(
+                        &mut tx,
+                        SummaryQuery {
+                            limit: Limit::unlimited(),
+                            timeframe,
+                        },
+                    )
+                    .await
+                    .unwrap(),
+                Some(token_pairs) => self
+                    .summary
+                    .list_by_token_pairs(&mut tx, token_pairs.iter().copied(), timeframe)
+                    .await
+                    .unwrap(),
+            };
+            summaries.push((timeframe, summary));
+        }
+
+        let ids = summaries
+            .iter()
+            .flat_map(|(_, summary)| summary.iter().map(|(token_pair_id, _)| *token_pair_id))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let pairs = self.pair.list_by_ids(&mut tx, ids.clone()).await.unwrap();
+        let liquidity = self
+            .liquidity
+            .list_by_token_pairs(&mut tx, ids)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+
+        let quoted_in_sol = pairs
+            .iter()
+            .filter(|tp| tp.quote.mint == Mint::wsol())
+            .map(|tp| tp.id)
+            .collect::<HashSet<_>>();
+
+        let mut result: HashMap<TokenPairId, Facts> = HashMap::new();
+
+        for (timeframe, summary) in summaries {
+            for (token_pair_id, summary) in summary {
+                let facts = result.entry(token_pair_id).or_default();
+
+                // the most recent 1m candle is the current state of the token pair
+                if timeframe == Timeframe::M1 {
+                    facts.set_value(Fact::PriceQuote, Value::quote(summary.price.0.clone()));
+                    if let Some(usd) = &summary.price_usd {
+                        facts.set_value(Fact::PriceUsd, Value::usd(usd.0.clone()));
+                    }
+
+                    if let Some(quote) = &summary.market_cap {
+                        facts.set_value(MarketCapQuote, Value::quote(quote.0.clone()));
+                    }
+
+                    if let Some(usd) = &summary.market_cap_usd {
+                        facts.set_value(MarketCapUsd, Value::usd(usd.0.clone()));
+                    }
+                }
+
+                add_summary_to_facts(
+                    facts,
+                    summary,
+                    timeframe,
+                    quoted_in_sol.contains(&token_pair_id),
+                );
+                facts.set_value(VenueJupiter, Value::boolean(true));
+            }
+        }
+
+        for (token_pair_id, amms) in liquidity {
+            if let Some(facts) = result.get_mut(&token_pair_id) {
+                for amm in amms {
+                    facts.set_value(amm.fact(), Value::boolean(true));
+                }
+            }
+        }
+
+        for tp in pairs {
+            let Some(facts) = result.get_mut(&tp.id) else {
+                continue;
+            };
+
+            if let Some(age) = tp.base.age() {
+                facts.set_value(
+                    Fact::AgeBaseDuration,
+                    Value::duration(age.0, TimeUnit::Second),
+                )
+            }
+
+            if let Some(age) = tp.quote.age() {
+                facts.set_value(
+                    Fact::AgeQuoteDuration,
+                    Value::duration(age.0, TimeUnit::Second),
+                )
+            }
+
+            add_social_to_facts(facts, &tp.base);
+        }
+
+        result
+    }
+}
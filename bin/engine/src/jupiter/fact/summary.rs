@@ -0,0 +1,86 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{Fact, Facts, Value};
+use common::model::Timeframe;
+use solana::jupiter::model::Summary;
+use Fact::{
+    MarketCapQuoteAggregate, MarketCapSolAggregate, MarketCapUsdAggregate, PriceAvgQuoteAggregate,
+    PriceAvgUsdAggregate, SwapAllChangeAggregate, SwapAllCountAggregate, SwapAllPercentAggregate,
+    SwapBuyChangeAggregate, SwapBuyCountAggregate, SwapBuyPercentAggregate,
+    SwapSellChangeAggregate, SwapSellCountAggregate, SwapSellPercentAggregate,
+    VolumeChangeQuoteAggregate,
+};
+
+/// The market cap is only a SOL value, if the token pair is quoted in WSOL
+pub(crate) fn add_summary_to_facts(
+    facts: &mut Facts,
+    summary: Summary,
+    timeframe: Timeframe,
+    quoted_in_sol: bool,
+) {
+    facts.set_timeframe_value(
+        PriceAvgQuoteAggregate,
+        Value::quote(summary.price_avg.0),
+        timeframe,
+    );
+
+    if let Some(usd) = summary.price_avg_usd {
+        facts.set_timeframe_value(PriceAvgUsdAggregate, Value::usd(usd.0), timeframe);
+    }
+
+    if let Some(quote) = summary.market_cap {
+        facts.set_timeframe_value(
+            MarketCapQuoteAggregate,
+            Value::quote(quote.0.clone()),
+            timeframe,
+        );
+
+        if quoted_in_sol {
+            facts.set_timeframe_value(MarketCapSolAggregate, Value::sol(quote.0), timeframe);
+        }
+    }
+
+    if let Some(usd) = summary.market_cap_usd {
+        facts.set_timeframe_value(MarketCapUsdAggregate, Value::usd(usd.0), timeframe);
+    }
+
+    for (swap, count, change, percent) in [
+        (
+            summary.swap,
+            SwapAllCountAggregate,
+            SwapAllChangeAggregate,
+            SwapAllPercentAggregate,
+        ),
+        (
+            summary.swap_buy,
+            SwapBuyCountAggregate,
+            SwapBuyChangeAggregate,
+            SwapBuyPercentAggregate,
+        ),
+        (
+            summary.swap_sell,
+            SwapSellCountAggregate,
+            SwapSellChangeAggregate,
+            SwapSellPercentAggregate,
+        ),
+    ] {
+        facts.set_timeframe_value(count, swap.count, timeframe);
+
+        if let Some(value) = swap.change {
+            facts.set_timeframe_value(change, value, timeframe);
+        }
+
+        if let Some(value) = swap.percent {
+            facts.set_timeframe_value(percent, Value::percent(value.0), timeframe);
+        }
+    }
+
+    if let Some(change) = summary.volume_change {
+        facts.set_timeframe_value(
+            VolumeChangeQuoteAggregate,
+            Value::quote(change.0),
+            timeframe,
+        );
+    }
+}
@@ -3,16 +3,26 @@
 
 #![cfg_attr(not(debug_assertions), deny(warnings))]
 
+use crate::change::ChangeService;
 use crate::config::Config;
-use crate::pumpfun::FactService;
+use crate::invocation::InvocationService;
+use crate::rule::RuleIndex;
 use crate::state::{AppState, AppStateInner, Service};
-use base::model::{Action, Venue};
+use crate::trade::{TradeConfig, TradeService};
+use base::model::{Fact, Facts, Rule, RuleId, TokenPairId, Value, Venue};
 use base::repo::{
-    InvocationCreateCmd, InvocationRepo, NotificationRepo, RuleRepo, TokenPairRepo, TokenRepo,
+    InvocationRepo, NotificationRepo, RuleRepo, TokenPairChangeRepo, TokenPairRepo, TokenRepo,
+    TradeRepo, WalletRepo,
 };
-use base::service::{NotificationRuleMatched, NotificationService, RuleService};
+use base::service::{NotificationService, RuleService};
+use common::crypt::SecretKey;
 use common::repo::pool::setup_pool;
+use common::ResolveOr;
+use log::error;
 use solana::pumpfun::repo::{CurrentRepo, SummaryRepo};
+use solana::raydium::Raydium;
+use solana::rpc::RpcClient;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::runtime::Builder;
@@ -20,11 +30,15 @@ use tokio::time::{sleep, Instant};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
-use Venue::PumpFun;
 
+mod change;
 mod config;
+mod invocation;
+mod jupiter;
 mod pumpfun;
+mod rule;
 mod state;
+mod trade;
 
 fn main() {
     tracing_subscriber::registry()
@@ -47,89 +61,294 @@ fn main() {
         let pool = setup_pool(&config.postgres).await;
 
         let token_repo = TokenRepo::new_read_only();
+        let rpc_client = RpcClient::new(config.rpc.url.resolve());
+
+        let slippage_bps = u16::try_from(config.trade.slippage_bps.resolve_or(500usize))
+            .ok()
+            .filter(|bps| *bps <= 10_000)
+            .expect("trade.slippage_bps must not exceed 10000");
+
+        let trade = TradeService::new(
+            pool.clone(),
+            rpc_client.clone(),
+            Raydium::new(rpc_client),
+            TokenPairRepo::new(token_repo.clone()),
+            WalletRepo {
+                secret: SecretKey::from(config.wallet.secret.resolve()),
+            },
+            TradeRepo::new(),
+            TradeConfig {
+                buy_lamports: config.trade.buy_lamports.resolve_or(10_000_000usize) as u64,
+                sell_percent: config.trade.sell_percent.resolve_or(100usize) as u64,
+                slippage_bps,
+            },
+        );
 
         let state = AppState(Arc::new(AppStateInner {
             service: Service {
-                fact: FactService::new(
+                change: ChangeService::new(pool.clone(), TokenPairChangeRepo::new()),
+                jupiter_fact: jupiter::FactService::new(
+                    pool.clone(),
+                    TokenPairRepo::new(token_repo.clone()),
+                    solana::jupiter::repo::SummaryRepo::new(),
+                    solana::jupiter::repo::LiquidityRepo::new(),
+                ),
+                pumpfun_fact: pumpfun::FactService::new(
                     pool.clone(),
                     TokenPairRepo::new(token_repo.clone()),
                     SummaryRepo::new(),
                     CurrentRepo::new(),
                 ),
-                notification: NotificationService::new(pool.clone(), NotificationRepo::new()),
-                rule: RuleService::new(pool.clone(), RuleRepo::new()),
+                invocation: InvocationService::new(
+                    pool.clone(),
+                    InvocationRepo::new(),
+                    NotificationService::new(pool.clone(), NotificationRepo::new()),
+                    trade,
+                ),
+                rule: RuleService::new(pool.clone(), RuleRepo::new(), InvocationRepo::new()),
             },
         }));
 
+        let change_limit = config.engine.change_limit.resolve_or(1000usize);
+        let rule_refresh =
+            Duration::from_millis(config.engine.rule_refresh_ms.resolve_or(5_000usize) as u64);
+        let sweep = Duration::from_millis(config.engine.sweep_ms.resolve_or(60_000usize) as u64);
+
+        let mut rules = RuleIndex::default();
+        let mut last_rule_refresh: Option<Instant> = None;
+        let mut last_sweep = Instant::now();
+
         loop {
-            let rules = state.service.rule.list_active().await.unwrap();
-
-            let start = Instant::now();
-            let pumpfun_facts = state.service.fact.pumpfun_facts().await;
-            println!(
-                "{} pumpfun facts - took {}",
-                pumpfun_facts.len(),
-                (Instant::now().duration_since(start)).as_millis()
-            );
-
-            for rule in &rules {
-                if !rule.applicable() {
-                    // FIXME filter them out before hitting this loop
-                    continue;
-                }
-                println!("test rule - {}", rule.id.0);
-                for (token_pair_id, facts) in &pumpfun_facts {
-                    if rule.sequence.condition.test(facts) {
-                        let mut tx = pool.begin().await.unwrap();
-
-                        match InvocationRepo::new()
-                            .create(
-                                &mut tx,
-                                InvocationCreateCmd {
-                                    user: rule.user,
-                                    rule: rule.id,
-                                    token_pair: *token_pair_id,
-                                    next: None,
-                                },
-                            )
-                            .await
-                        {
-                            Ok(_) => {
-                                println!("met - {token_pair_id}");
-
-                                match &rule.sequence.action {
-                                    Action::AndThen { .. } => {}
-                                    Action::Buy => {}
-                                    Action::NotifyTelegram { buttons } => {
-                                        let _ = state
-                                            .service
-                                            .notification
-                                            .create_rule_matched_tx(
-                                                &mut tx,
-                                                NotificationRuleMatched::Telegram {
-                                                    user: rule.user,
-                                                    rule: rule.id,
-                                                    venue: PumpFun,
-                                                    token_pair: *token_pair_id,
-                                                    buttons: buttons.clone(),
-                                                },
-                                            )
-                                            .await;
-                                    }
-                                    Action::Sell => {}
-                                }
+            if last_rule_refresh.is_none_or(|last| last.elapsed() >= rule_refresh) {
+                match state.service.rule.list_active().await {
+                    Ok(active) => {
+                        let refreshed = RuleIndex::new(active.into_vec());
+                        let changed = refreshed.changed_since(&rules);
+                        rules = refreshed;
 
-                                tx.commit().await.unwrap();
-                            }
-                            Err(_) => {
-                                // FIXME cache already invoked strategies - otherwise this might be heavy on the database
-                                tx.rollback().await.unwrap();
+                        state
+                            .service
+                            .invocation
+                            .retain(&rules.ids().collect())
+                            .await;
+
+                        replenish_exhausted(&state).await;
+
+                        // the cached invocations of an updated rule might be stale, e.g. after its invocations were removed
+                        for id in &changed {
+                            state.service.invocation.invalidate(*id).await;
+                        }
+
+                        // new and updated rules have never seen the token pairs which did not change since
+                        if !changed.is_empty() {
+                            let all_facts = load_facts(&state, None).await;
+                            for id in changed {
+                                let Some(rule) = rules.rule(id) else {
+                                    continue;
+                                };
+                                for (token_pair, facts) in &all_facts {
+                                    test_rule(&state, rule, *token_pair, facts).await;
+                                }
                             }
                         }
                     }
+                    Err(err) => error!("unable to list active rules - {err}"),
                 }
+                last_rule_refresh = Some(Instant::now());
+            }
+
+            let changes = match state.service.change.pop(change_limit as i64).await {
+                Ok(changes) => changes,
+                Err(err) => {
+                    error!("unable to pop token pair changes - {err}");
+                    Box::default()
+                }
+            };
+
+            if !changes.is_empty() {
+                let start = Instant::now();
+
+                let mut candidates: HashMap<TokenPairId, HashSet<RuleId>> = HashMap::new();
+                for change in &changes {
+                    let affected = rules.affected_by(change.kind.facts());
+                    candidates
+                        .entry(change.token_pair)
+                        .or_default()
+                        .extend(affected);
+                }
+                candidates.retain(|_, affected| !affected.is_empty());
+
+                let token_pairs = candidates.keys().copied().collect::<Vec<_>>();
+                let changed_facts = load_facts(&state, Some(&token_pairs)).await;
+
+                for (token_pair, affected) in &candidates {
+                    let Some(facts) = changed_facts.get(token_pair) else {
+                        continue;
+                    };
+                    for id in affected {
+                        if let Some(rule) = rules.rule(*id) {
+                            test_rule(&state, rule, *token_pair, facts).await;
+                        }
+                    }
+                }
+
+                println!(
+                    "{} changes of {} token pairs - took {}",
+                    changes.len(),
+                    candidates.len(),
+                    Instant::now().duration_since(start).as_millis()
+                );
+            }
+
+            // facts like the age of a token change without the token pair being changed
+            if last_sweep.elapsed() >= sweep {
+                if rules.time_dependent().next().is_some() {
+                    let all_facts = load_facts(&state, None).await;
+                    for rule in rules.time_dependent() {
+                        for (token_pair, facts) in &all_facts {
+                            test_rule(&state, rule, *token_pair, facts).await;
+                        }
+                    }
+                }
+                last_sweep = Instant::now();
+            }
+
+            if changes.len() < change_limit {
+                sleep(Duration::from_millis(250)).await;
             }
-            sleep(Duration::from_millis(1000)).await;
         }
     })
 }
+
+/// Facts of all token pairs or only of the given ones, across all venues.
+/// A token pair traded on pump.fun and through Jupiter is described by the pump.fun bonding curve
+/// until the curve completed, afterwards the Jupiter candles take precedence
+async fn load_facts(
+    state: &AppState,
+    token_pairs: Option<&[TokenPairId]>,
+) -> HashMap<TokenPairId, Facts> {
+    let (mut result, jupiter_facts) = match token_pairs {
+        None => (
+            state.service.pumpfun_fact.pumpfun_facts().await,
+            state.service.jupiter_fact.jupiter_facts().await,
+        ),
+        Some(token_pairs) => (
+            state
+                .service
+                .pumpfun_fact
+                .pumpfun_facts_of(token_pairs)
+                .await,
+            state
+                .service
+                .jupiter_fact
+                .jupiter_facts_of(token_pairs)
+                .await,
+        ),
+    };
+
+    for (token_pair, mut jupiter) in jupiter_facts {
+        match result.remove(&token_pair) {
+            None => {
+                result.insert(token_pair, jupiter);
+            }
+            Some(mut pumpfun) => {
+                if curve_completed(&pumpfun) {
+                    jupiter.merge(pumpfun);
+                    result.insert(token_pair, jupiter);
+                } else {
+                    pumpfun.merge(jupiter);
+                    result.insert(token_pair, pumpfun);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// The pump.fun bonding curve completed, once it graduated or its progress reached 100%
+fn curve_completed(facts: &Facts) -> bool {
+    matches!(
+        facts.get(&Fact::CurveProgressPercent),
+        Some(Value::Percent { value }) if *value >= 100.0
+    ) || matches!(
+        facts.get(&Fact::Graduated),
+        Some(Value::Boolean { value: true })
+    )
+}
+
+/// The venue whose facts took precedence when loading the facts of a token pair
+fn venue(facts: &Facts) -> Venue {
+    let is_set = |fact: Fact| matches!(facts.get(&fact), Some(Value::Boolean { value: true }));
+    if is_set(Fact::VenueJupiter) && (!is_set(Fact::VenuePumpfun) || curve_completed(facts)) {
+        Venue::Jupiter
+    } else {
+        Venue::PumpFun
+    }
+}
+
+/// Tests the pending stage and the initial condition of a rule against the facts of a token pair
+async fn test_rule(state: &AppState, rule: &Rule, token_pair: TokenPairId, facts: &Facts) {
+    let venue = venue(facts);
+
+    match state.service.invocation.pending(rule, token_pair).await {
+        Ok(Some(invocation)) => {
+            if let Some(next) = &invocation.next {
+                if next.condition.test(facts) {
+                    match state
+                        .service
+                        .invocation
+                        .advance(rule, &invocation, venue)
+                        .await
+                    {
+                        Ok(Some(advanced)) => {
+                            println!("met stage {} - {}", advanced.stage, advanced.token_pair)
+                        }
+                        Ok(None) => {}
+                        Err(err) => {
+                            error!("unable to advance invocation {} - {err}", invocation.id.0)
+                        }
+                    }
+                }
+            }
+        }
+        Ok(None) => {}
+        Err(err) => error!("unable to load pending invocations - {err}"),
+    }
+
+    if rule.sequence.condition.test(facts) {
+        match state
+            .service
+            .invocation
+            .invoke(rule, token_pair, venue)
+            .await
+        {
+            Ok(Some(_)) => {
+                println!("met - {token_pair}");
+                if rule.limit.max_invocations.is_some() {
+                    if let Err(err) = state.service.rule.update_exhaustion(rule.id).await {
+                        error!("unable to update exhaustion of rule {} - {err}", rule.id.0);
+                    }
+                }
+            }
+            Ok(None) => {}
+            Err(err) => error!("unable to invoke rule {} - {err}", rule.id.0),
+        }
+    } else if let Err(err) = state.service.invocation.unmatched(rule, token_pair).await {
+        error!("unable to rearm rule {} - {err}", rule.id.0);
+    }
+}
+
+/// Activates exhausted rules again, once they can be invoked again - e.g. because their invocations were removed
+async fn replenish_exhausted(state: &AppState) {
+    match state.service.rule.list_active_exhausted().await {
+        Ok(exhausted) => {
+            for rule in exhausted {
+                if let Err(err) = state.service.rule.update_exhaustion(rule.id).await {
+                    error!("unable to update exhaustion of rule {} - {err}", rule.id.0);
+                }
+            }
+        }
+        Err(err) => error!("unable to list exhausted rules - {err}"),
+    }
+}
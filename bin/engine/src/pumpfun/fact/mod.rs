@@ -1,13 +1,14 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-mod summary;
-
-use crate::pumpfun::fact::summary::add_summary_to_facts;
-use base::model::Fact::{CurveProgressAgeDuration, MarketCapQuote, MarketCapUsd, VenuePumpfun};
-use base::model::{Fact, Facts, TokenPairId, Value};
+use base::model::Fact::{
+    CurveProgressAgeDuration, Graduated, GraduationAgeDuration, MarketCapQuote, MarketCapUsd,
+    VenuePumpfun,
+};
+use base::model::{add_social_to_facts, Fact, Facts, TokenPairId, Value};
 use base::repo::TokenPairRepo;
 use common::model::{Limit, TimeUnit, Timeframe};
+use solana::pumpfun::fact::add_summary_to_facts;
 use solana::pumpfun::repo::{CurrentQuery, CurrentRepo, SummaryQuery, SummaryRepo};
 use sqlx::PgPool;
 use std::collections::HashMap;
@@ -37,15 +38,39 @@ impl FactService {
         }
     }
 
+    /// Facts of all pump.fun token pairs
     pub async fn pumpfun_facts(&self) -> HashMap<TokenPairId, Facts> {
+        self.load_pumpfun_facts(None).await
+    }
+
+    /// Facts of the given pump.fun token pairs only
+    pub async fn pumpfun_facts_of(
+        &self,
+        token_pairs: &[TokenPairId],
+    ) -> HashMap<TokenPairId, Facts> {
+        if token_pairs.is_empty() {
+            return HashMap::new();
+        }
+        self.load_pumpfun_facts(Some(token_pairs)).await
+    }
+
+    async fn load_pumpfun_facts(
+        &self,
+        token_pairs: Option<&[TokenPairId]>,
+    ) -> HashMap<TokenPairId, Facts> {
         let mut tx = self.pool.begin().await.unwrap();
 
         let start = Instant::now();
-        let mut result: HashMap<TokenPairId, Facts> = self
-            .pair
-            .list_all(&mut tx)
-            .await
-            .unwrap()
+        let pairs = match token_pairs {
+            None => self.pair.list_all(&mut tx).await.unwrap(),
+            Some(token_pairs) => self
+                .pair
+                .list_by_ids(&mut tx, token_pairs.iter().copied())
+                .await
+                .unwrap(),
+        };
+
+        let mut result: HashMap<TokenPairId, Facts> = pairs
             .into_iter()
             .map(|tp| {
                 let mut facts = Facts::new();
@@ -64,6 +89,8 @@ impl FactService {
                     )
                 }
 
+                add_social_to_facts(&mut facts, &tp.base);
+
                 (tp.id, facts)
             })
             .collect();
@@ -73,17 +100,25 @@ impl FactService {
             Instant::now().duration_since(start).as_millis()
         );
 
-        for current in self
-            .current
-            .list(
-                &mut tx,
-                CurrentQuery {
-                    limit: Limit::unlimited(),
-                },
-            )
-            .await
-            .unwrap()
-        {
+        let currents = match token_pairs {
+            None => self
+                .current
+                .list(
+                    &mut tx,
+                    CurrentQuery {
+                        limit: Limit::unlimited(),
+                    },
+                )
+                .await
+                .unwrap(),
+            Some(token_pairs) => self
+                .current
+                .list_by_token_pairs(&mut tx, token_pairs.iter().copied())
+                .await
+                .unwrap(),
+        };
+
+        for current in currents {
             if let Some(facts) = result.get_mut(&current.id) {
                 facts.set_value(CurveProgressPercent, Value::percent(current.progress.0));
                 facts.set_value(
@@ -92,6 +127,14 @@ impl FactService {
                 );
                 facts.set_value(VenuePumpfun, Value::boolean(true));
 
+                facts.set_value(Graduated, Value::boolean(current.graduated_at.is_some()));
+                if let Some(age) = current.graduation_age() {
+                    facts.set_value(
+                        GraduationAgeDuration,
+                        Value::duration(age.0, TimeUnit::Second),
+                    );
+                }
+
                 facts.set_value(Fact::PriceQuote, Value::quote(current.price.0));
                 if let Some(usd) = current.price_usd {
                     facts.set_value(Fact::PriceUsd, Value::usd(usd.0))
@@ -100,7 +143,7 @@ impl FactService {
                 if let Some(quote) = current.market_cap {
                     facts.set_value(MarketCapQuote, Value::quote(quote.0));
                 }
-                
+
                 if let Some(usd) = current.market_cap_usd {
                     facts.set_value(MarketCapUsd, Value::usd(usd.0))
                 }
@@ -117,17 +160,24 @@ impl FactService {
         ] {
             let start = Instant::now();
 
-            let summary = self
-                .summary
-                .list(
-                    &mut tx,
-                    SummaryQuery {
-                        limit: Limit::unlimited(),
-                        timeframe,
-                    },
-                )
-                .await
-                .unwrap();
+            let summary = match token_pairs {
+                None => self
+                    .summary
+                    .list(
+                        &mut tx,
+                        SummaryQuery {
+                            limit: Limit::unlimited(),
+                            timeframe,
+                        },
+                    )
+                    .await
+                    .unwrap(),
+                Some(token_pairs) => self
+                    .summary
+                    .list_by_token_pairs(&mut tx, token_pairs.iter().copied(), timeframe)
+                    .await
+                    .unwrap(),
+            };
 
             for (token_pair_id, summary) in summary {
                 let facts = result.entry(token_pair_id).or_insert(Facts::default());
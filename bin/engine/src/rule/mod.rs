@@ -0,0 +1,87 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{Fact, Rule, RuleId, RuleVersion};
+use log::debug;
+use std::collections::{HashMap, HashSet};
+
+/// Active rules, indexed by the facts their conditions reference
+#[derive(Default)]
+pub struct RuleIndex {
+    rules: HashMap<RuleId, Rule>,
+    by_fact: HashMap<Fact, HashSet<RuleId>>,
+    time_dependent: HashSet<RuleId>,
+}
+
+impl RuleIndex {
+    pub fn new(rules: impl IntoIterator<Item = Rule>) -> Self {
+        let mut result = Self::default();
+
+        for rule in rules {
+            if !rule.applicable() {
+                let errors = rule.sequence.validate();
+                if !errors.is_empty() {
+                    debug!(
+                        "skipping rule {}: {}",
+                        rule.id,
+                        errors
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    );
+                }
+                continue;
+            }
+
+            for fact in rule.sequence.facts() {
+                if fact.is_time_dependent() {
+                    result.time_dependent.insert(rule.id);
+                }
+                result.by_fact.entry(fact).or_default().insert(rule.id);
+            }
+
+            result.rules.insert(rule.id, rule);
+        }
+
+        result
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = RuleId> + '_ {
+        self.rules.keys().copied()
+    }
+
+    pub fn rule(&self, id: RuleId) -> Option<&Rule> {
+        self.rules.get(&id)
+    }
+
+    /// Rules which reference at least one of the facts
+    pub fn affected_by(&self, facts: impl IntoIterator<Item = Fact>) -> HashSet<RuleId> {
+        facts
+            .into_iter()
+            .filter_map(|fact| self.by_fact.get(&fact))
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    /// Rules which reference a fact whose value changes with the passage of time alone
+    pub fn time_dependent(&self) -> impl Iterator<Item = &Rule> {
+        self.time_dependent
+            .iter()
+            .filter_map(|id| self.rules.get(id))
+    }
+
+    /// Rules which are new or were updated compared to the previous index
+    pub fn changed_since(&self, previous: &RuleIndex) -> Vec<RuleId> {
+        self.rules
+            .values()
+            .filter(|rule| previous.version(rule.id) != Some(rule.version))
+            .map(|rule| rule.id)
+            .collect()
+    }
+
+    fn version(&self, id: RuleId) -> Option<RuleVersion> {
+        self.rules.get(&id).map(|rule| rule.version)
+    }
+}
@@ -1,8 +1,10 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use crate::pumpfun::FactService;
-use base::service::{NotificationService, RuleService};
+use crate::change::ChangeService;
+use crate::invocation::InvocationService;
+use crate::{jupiter, pumpfun};
+use base::service::RuleService;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -23,7 +25,9 @@ pub struct AppStateInner {
 
 #[derive(Clone)]
 pub struct Service {
-    pub fact: FactService,
-    pub notification: NotificationService,
+    pub change: ChangeService,
+    pub jupiter_fact: jupiter::FactService,
+    pub pumpfun_fact: pumpfun::FactService,
+    pub invocation: InvocationService,
     pub rule: RuleService,
 }
@@ -0,0 +1,553 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{
+    DecimalAmount, Invocation, KeyPair, Mint, PublicKey, TokenPair, Trade, TradeSide, TradeStatus,
+    Venue,
+};
+use base::repo::{TokenPairRepo, TradeCreateCmd, TradeRepo, TradeUpdateCmd, WalletRepo};
+use common::model::PriceQuote;
+use common::service::ServiceResult;
+use log::{error, info};
+use solana::pumpfun::model::SubmittedSwap;
+use solana::pumpfun::{Pumpfun, PumpfunError, Rpc};
+use solana::raydium::{Raydium, RaydiumSwap, SwapExecutionMode};
+use solana::rpc::RpcClient;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+#[derive(Clone, Debug)]
+pub struct TradeConfig {
+    pub buy_lamports: u64,
+    pub sell_percent: u64,
+    pub slippage_bps: u16,
+}
+
+#[derive(Clone)]
+pub struct TradeService {
+    pool: PgPool,
+    rpc: RpcClient,
+    pumpfun: Pumpfun,
+    pumpfun_rpc: Rpc,
+    raydium: Arc<Raydium>,
+    pair: TokenPairRepo,
+    wallet: WalletRepo,
+    trade: TradeRepo,
+    config: TradeConfig,
+}
+
+struct Executed {
+    venue: Venue,
+    swap: SubmittedSwap,
+}
+
+impl TradeService {
+    pub fn new(
+        pool: PgPool,
+        rpc: RpcClient,
+        raydium: Raydium,
+        pair: TokenPairRepo,
+        wallet: WalletRepo,
+        trade: TradeRepo,
+        config: TradeConfig,
+    ) -> Self {
+        Self {
+            pool,
+            pumpfun: Pumpfun::new(rpc.clone()),
+            pumpfun_rpc: Rpc::new(rpc.clone()),
+            raydium: Arc::new(raydium),
+            rpc,
+            pair,
+            wallet,
+            trade,
+            config,
+        }
+    }
+
+    /// Executes the trade of an invocation from the wallet of the rule owner and records its outcome.
+    /// The trade gets recorded as pending before its transaction is sent, so that no sent transaction goes unrecorded
+    pub async fn execute(&self, invocation: &Invocation, side: TradeSide) -> ServiceResult<Trade> {
+        let mut tx = self.pool.begin().await?;
+        let token_pair = self.pair.get_by_id(&mut tx, invocation.token_pair).await?;
+        tx.commit().await?;
+
+        let venue = self.venue(&token_pair).await;
+
+        let mut tx = self.pool.begin().await?;
+        let wallet = self.wallet.get_by_user_id(&mut tx, invocation.user).await?;
+        let private_key = self.wallet.get_private_key(&mut tx, wallet.id).await?;
+        let pending = self
+            .trade
+            .create(
+                &mut tx,
+                TradeCreateCmd {
+                    invocation: invocation.id,
+                    user: invocation.user,
+                    wallet: wallet.id,
+                    token_pair: token_pair.id,
+                    venue,
+                    side,
+                    status: TradeStatus::Pending,
+                    amount_base: None,
+                    amount_quote: None,
+                    price: None,
+                    signature: None,
+                    error: None,
+                },
+            )
+            .await?;
+        tx.commit().await?;
+
+        let key_pair = KeyPair {
+            public: wallet.public_key.clone(),
+            private: private_key,
+        };
+
+        let result = match side {
+            TradeSide::Buy => self.buy(key_pair, &token_pair, venue).await,
+            TradeSide::Sell => self.sell(key_pair, &token_pair, venue).await,
+        };
+
+        let cmd = match result {
+            Ok(executed) => {
+                info!(
+                    "{side} of {} submitted on {} - {}",
+                    token_pair.base.mint, executed.venue, executed.swap.signature
+                );
+                let amount_base =
+                    DecimalAmount::new(executed.swap.token_amount, token_pair.base.decimals);
+                let amount_quote =
+                    DecimalAmount::new(executed.swap.sol_amount, token_pair.quote.decimals);
+                let price = if executed.swap.token_amount > 0 {
+                    Some(PriceQuote(amount_quote.0.clone() / amount_base.0.clone()))
+                } else {
+                    None
+                };
+
+                TradeUpdateCmd {
+                    id: pending.id,
+                    venue: executed.venue,
+                    status: TradeStatus::Submitted,
+                    amount_base: Some(amount_base),
+                    amount_quote: Some(amount_quote),
+                    price,
+                    signature: Some(executed.swap.signature),
+                    error: None,
+                }
+            }
+            Err((venue, err)) => {
+                error!("{side} of {} failed - {err}", token_pair.base.mint);
+                TradeUpdateCmd {
+                    id: pending.id,
+                    venue,
+                    status: TradeStatus::Failed,
+                    amount_base: None,
+                    amount_quote: None,
+                    price: None,
+                    signature: None,
+                    error: Some(err),
+                }
+            }
+        };
+
+        let signature = cmd.signature.clone();
+        let result = self.record(cmd).await;
+        if let (Err(err), Some(signature)) = (&result, signature) {
+            error!(
+                "unable to record {side} of {} submitted with {signature} - {err}",
+                token_pair.base.mint
+            );
+        }
+        result
+    }
+
+    /// Tokens whose bonding curve completed migrated to raydium and can no longer be traded on pump.fun
+    async fn venue(&self, token_pair: &TokenPair) -> Venue {
+        match self
+            .pumpfun_rpc
+            .get_curve_info(PublicKey::from(token_pair.base.mint.to_string()))
+            .await
+        {
+            Some(curve) if curve.complete => Venue::Raydium,
+            _ => Venue::PumpFun,
+        }
+    }
+
+    async fn record(&self, cmd: TradeUpdateCmd) -> ServiceResult<Trade> {
+        let mut tx = self.pool.begin().await?;
+        let trade = self.trade.update(&mut tx, cmd).await?;
+        tx.commit().await?;
+        Ok(trade)
+    }
+
+    async fn buy(
+        &self,
+        key_pair: KeyPair,
+        token_pair: &TokenPair,
+        venue: Venue,
+    ) -> Result<Executed, (Venue, String)> {
+        let mint = token_pair.base.mint.clone();
+        if venue == Venue::Raydium {
+            return self
+                .raydium_swap(
+                    key_pair,
+                    Mint::wsol(),
+                    mint,
+                    self.config.buy_lamports,
+                    TradeSide::Buy,
+                )
+                .await;
+        }
+
+        match self
+            .pumpfun
+            .buy(
+                key_pair.clone(),
+                mint.clone(),
+                self.config.buy_lamports,
+                Some(u64::from(self.config.slippage_bps)),
+            )
+            .await
+        {
+            Ok(swap) => Ok(Executed {
+                venue: Venue::PumpFun,
+                swap,
+            }),
+            // bonding curve completed - the token migrated to raydium
+            Err(PumpfunError::CurveCompleted) => {
+                self.raydium_swap(
+                    key_pair,
+                    Mint::wsol(),
+                    mint,
+                    self.config.buy_lamports,
+                    TradeSide::Buy,
+                )
+                .await
+            }
+            Err(err) => Err((Venue::PumpFun, err.to_string())),
+        }
+    }
+
+    async fn sell(
+        &self,
+        key_pair: KeyPair,
+        token_pair: &TokenPair,
+        venue: Venue,
+    ) -> Result<Executed, (Venue, String)> {
+        let mint = token_pair.base.mint.clone();
+
+        let balance = self
+            .rpc
+            .token_balance(key_pair.public.clone(), mint.clone())
+            .await
+            .map_err(|err| (venue, err.to_string()))?;
+
+        let amount = (balance as u128 * self.config.sell_percent.min(100) as u128 / 100) as u64;
+        if amount == 0 {
+            return Err((venue, PumpfunError::NothingToSell.to_string()));
+        }
+
+        if venue == Venue::Raydium {
+            return self
+                .raydium_swap(key_pair, mint, Mint::wsol(), amount, TradeSide::Sell)
+                .await;
+        }
+
+        match self
+            .pumpfun
+            .sell(
+                key_pair.clone(),
+                mint.clone(),
+                Some(amount),
+                Some(u64::from(self.config.slippage_bps)),
+            )
+            .await
+        {
+            Ok(swap) => Ok(Executed {
+                venue: Venue::PumpFun,
+                swap,
+            }),
+            // bonding curve completed - the token migrated to raydium
+            Err(PumpfunError::CurveCompleted) => {
+                self.raydium_swap(key_pair, mint, Mint::wsol(), amount, TradeSide::Sell)
+                    .await
+            }
+            Err(err) => Err((Venue::PumpFun, err.to_string())),
+        }
+    }
+
+    async fn raydium_swap(
+        &self,
+        key_pair: KeyPair,
+        input: Mint,
+        output: Mint,
+        amount: u64,
+        side: TradeSide,
+    ) -> Result<Executed, (Venue, String)> {
+        let quote = self
+            .raydium
+            .quote(RaydiumSwap {
+                input_token_mint: input.0,
+                output_token_mint: output.0,
+                slippage_bps: self.config.slippage_bps,
+                amount,
+                mode: SwapExecutionMode::ExactIn,
+                market: None,
+            })
+            .await
+            .map_err(|err| (Venue::Raydium, err.to_string()))?;
+
+        let signature = self
+            .raydium
+            .swap(key_pair.private.0, quote.clone(), None)
+            .await
+            .map_err(|err| (Venue::Raydium, err.to_string()))?;
+
+        let (sol_amount, token_amount) = match side {
+            TradeSide::Buy => (quote.input_amount, quote.output_amount),
+            TradeSide::Sell => (quote.output_amount, quote.input_amount),
+        };
+
+        Ok(Executed {
+            venue: Venue::Raydium,
+            swap: SubmittedSwap {
+                signature,
+                sol_amount,
+                token_amount,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base::model::Wallet;
+    use base::repo::{InvocationCreateCmd, InvocationRepo, TokenRepo, TradeQueryAll};
+    use base::test::SuccessfulTokenInfoLoader;
+    use common::crypt::SecretKey;
+    use common::model::Limit;
+    use solana::raydium::HttpClient;
+    use solana_sdk::pubkey;
+    use solana_sdk::pubkey::Pubkey;
+    use testing::rpc::LocalRpc;
+    use testing::rule::create_inactive_rule_for_test_user;
+    use testing::run_test_with_pool;
+    use testing::token_pair::get_or_create_token_pair;
+
+    const MINT: Pubkey = pubkey!("CpV7zK77DkyVvbCgGWtWrsqvh2VonFrqPg8cecrTpump");
+    const FEE_RECIPIENT: Pubkey = pubkey!("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM");
+
+    fn local_rpc(complete: bool) -> LocalRpc {
+        let rpc = LocalRpc::new();
+        rpc.add_pumpfun_global(FEE_RECIPIENT, 100);
+        if complete {
+            rpc.add_pumpfun_curve(MINT, 0, 0, 0, 0, true);
+        } else {
+            rpc.add_pumpfun_curve(
+                MINT,
+                1_073_000_000_000_000,
+                30_000_000_000,
+                793_100_000_000_000,
+                0,
+                false,
+            );
+        }
+        rpc
+    }
+
+    fn trade_service(pool: PgPool, rpc: &LocalRpc) -> TradeService {
+        TradeService::new(
+            pool,
+            rpc.client(),
+            // nothing listens on this port, every request to raydium fails immediately
+            Raydium::new_with_http_client(rpc.client(), HttpClient::new("http://127.0.0.1:1")),
+            TokenPairRepo::new(TokenRepo::new(Box::new(
+                SuccessfulTokenInfoLoader::default(),
+            ))),
+            WalletRepo {
+                secret: SecretKey::from(
+                    "3d7948d31771b3924dbeec3de83d905580d988c84964a6afd4c9cedd06776e91",
+                ),
+            },
+            TradeRepo::new(),
+            TradeConfig {
+                buy_lamports: 100_000_000,
+                sell_percent: 50,
+                slippage_bps: 500,
+            },
+        )
+    }
+
+    async fn create_invocation(pool: &PgPool) -> (Invocation, Wallet) {
+        let mut tx = pool.begin().await.unwrap();
+        let rule = create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let token_pair = get_or_create_token_pair(&mut tx, MINT, Mint::wsol()).await;
+        let invocation = InvocationRepo::new()
+            .create(
+                &mut tx,
+                InvocationCreateCmd {
+                    user: rule.user,
+                    rule: rule.id,
+                    token_pair: token_pair.id,
+                    stage: 0,
+                    next: None,
+                    deadline: None,
+                },
+            )
+            .await
+            .unwrap();
+        let wallet = WalletRepo {
+            secret: SecretKey::from(
+                "3d7948d31771b3924dbeec3de83d905580d988c84964a6afd4c9cedd06776e91",
+            ),
+        }
+        .get_by_user_id(&mut tx, rule.user)
+        .await
+        .unwrap();
+        tx.commit().await.unwrap();
+        (invocation, wallet)
+    }
+
+    async fn list_trades(pool: &PgPool) -> Box<[Trade]> {
+        let mut tx = pool.begin().await.unwrap();
+        TradeRepo::new()
+            .list_all(
+                &mut tx,
+                TradeQueryAll {
+                    limit: Limit::max(),
+                },
+            )
+            .await
+            .unwrap()
+    }
+
+    #[test_log::test(sqlx::test)]
+    async fn test_buy() {
+        run_test_with_pool(|pool| async move {
+            let rpc = local_rpc(false);
+            let (invocation, _) = create_invocation(&pool).await;
+
+            let test_instance = trade_service(pool.clone(), &rpc);
+            let result = test_instance
+                .execute(&invocation, TradeSide::Buy)
+                .await
+                .unwrap();
+
+            assert_eq!(result.invocation, invocation.id);
+            assert_eq!(result.venue, Venue::PumpFun);
+            assert_eq!(result.side, TradeSide::Buy);
+            assert_eq!(result.status, TradeStatus::Submitted);
+            assert_eq!(result.error, None);
+
+            let transactions = rpc.sent_transactions();
+            assert_eq!(transactions.len(), 1);
+            assert_eq!(
+                result.signature.unwrap().0,
+                transactions[0].signatures[0].to_string()
+            );
+
+            let trades = list_trades(&pool).await;
+            assert_eq!(trades.len(), 1);
+            assert_eq!(trades[0].id, result.id);
+        })
+        .await
+    }
+
+    #[test_log::test(sqlx::test)]
+    async fn test_sell() {
+        run_test_with_pool(|pool| async move {
+            let rpc = local_rpc(false);
+            let (invocation, wallet) = create_invocation(&pool).await;
+            rpc.add_token_account(wallet.public_key.into(), MINT, 2_000_000);
+
+            let test_instance = trade_service(pool.clone(), &rpc);
+            let result = test_instance
+                .execute(&invocation, TradeSide::Sell)
+                .await
+                .unwrap();
+
+            assert_eq!(result.venue, Venue::PumpFun);
+            assert_eq!(result.side, TradeSide::Sell);
+            assert_eq!(result.status, TradeStatus::Submitted);
+            assert_eq!(
+                result.amount_base,
+                Some(DecimalAmount::new(1_000_000u64, 6))
+            );
+            assert_eq!(rpc.sent_transactions().len(), 1);
+        })
+        .await
+    }
+
+    #[test_log::test(sqlx::test)]
+    async fn test_buy_curve_completed_falls_back_to_raydium() {
+        run_test_with_pool(|pool| async move {
+            let rpc = local_rpc(true);
+            let (invocation, _) = create_invocation(&pool).await;
+
+            let test_instance = trade_service(pool.clone(), &rpc);
+            let result = test_instance
+                .execute(&invocation, TradeSide::Buy)
+                .await
+                .unwrap();
+
+            assert_eq!(result.venue, Venue::Raydium);
+            assert_eq!(result.status, TradeStatus::Failed);
+            assert_eq!(result.signature, None);
+            assert!(result.error.is_some());
+            assert!(rpc.sent_transactions().is_empty());
+
+            let trades = list_trades(&pool).await;
+            assert_eq!(trades.len(), 1);
+            assert_eq!(trades[0].venue, Venue::Raydium);
+            assert_eq!(trades[0].status, TradeStatus::Failed);
+        })
+        .await
+    }
+
+    #[test_log::test(sqlx::test)]
+    async fn test_sell_curve_completed_falls_back_to_raydium() {
+        run_test_with_pool(|pool| async move {
+            let rpc = local_rpc(true);
+            let (invocation, wallet) = create_invocation(&pool).await;
+            rpc.add_token_account(wallet.public_key.into(), MINT, 2_000_000);
+
+            let test_instance = trade_service(pool.clone(), &rpc);
+            let result = test_instance
+                .execute(&invocation, TradeSide::Sell)
+                .await
+                .unwrap();
+
+            assert_eq!(result.venue, Venue::Raydium);
+            assert_eq!(result.status, TradeStatus::Failed);
+            assert!(rpc.sent_transactions().is_empty());
+        })
+        .await
+    }
+
+    #[test_log::test(sqlx::test)]
+    async fn test_sell_nothing_to_sell() {
+        run_test_with_pool(|pool| async move {
+            let rpc = local_rpc(false);
+            let (invocation, _) = create_invocation(&pool).await;
+
+            let test_instance = trade_service(pool.clone(), &rpc);
+            let result = test_instance
+                .execute(&invocation, TradeSide::Sell)
+                .await
+                .unwrap();
+
+            assert_eq!(result.venue, Venue::PumpFun);
+            assert_eq!(result.status, TradeStatus::Failed);
+            assert_eq!(
+                result.error.unwrap(),
+                PumpfunError::NothingToSell.to_string()
+            );
+            assert!(rpc.sent_transactions().is_empty());
+
+            let trades = list_trades(&pool).await;
+            assert_eq!(trades.len(), 1);
+        })
+        .await
+    }
+}
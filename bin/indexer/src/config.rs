@@ -5,6 +5,7 @@ use std::env::args;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+use base::model::solana::Slot;
 use common::repo::pool::PostgresConfig;
 use common::ConfigValue;
 use serde::Deserialize;
@@ -16,6 +17,8 @@ pub struct Config {
     pub slotstream: SlotStreamConfig,
     pub postgres: PostgresConfig,
     pub rpc: RpcConfig,
+    #[serde(default)]
+    pub metadata: MetadataConfig,
     pub tokio: TokioConfig,
     pub rayon: RayonConfig,
 }
@@ -35,6 +38,12 @@ pub struct RpcConfig {
     pub url: ConfigValue,
 }
 
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct MetadataConfig {
+    // metadata json requests per second
+    pub rate: ConfigValue,
+}
+
 #[derive(Clone, Debug, Default, Deserialize)]
 pub struct SlotStreamConfig {
     pub url: ConfigValue,
@@ -50,7 +59,7 @@ impl Config {
     pub fn load() -> Self {
         let args: Vec<String> = args().collect();
 
-        let config_path = if args.len() == 2 {
+        let config_path = if args.len() >= 2 {
             PathBuf::from_str(args.get(1).unwrap()).unwrap()
         } else {
             let path = PathBuf::from_str(args.first().unwrap()).unwrap();
@@ -63,3 +72,67 @@ impl Config {
         toml::from_str(&config).expect("Unable to parse config")
     }
 }
+
+#[derive(Debug)]
+pub enum Mode {
+    // Follows the tip of the chain
+    Live,
+    // Indexes the missing slots between first and last (inclusive) and exits
+    Backfill {
+        first: Slot,
+        last: Slot,
+    },
+    // Downloads the blocks between first and last (inclusive) into an archive file and exits
+    Dump {
+        first: Slot,
+        last: Slot,
+        path: PathBuf,
+    },
+    // Indexes all blocks of an archive file and exits
+    Replay {
+        path: PathBuf,
+    },
+}
+
+impl Mode {
+    pub fn load() -> Self {
+        let args: Vec<String> = args().collect();
+
+        let slot = |idx: usize, usage: &str| {
+            args.get(idx)
+                .and_then(|arg| arg.parse::<i64>().ok())
+                .map(Slot)
+                .unwrap_or_else(|| panic!("Usage: {usage}"))
+        };
+
+        let path = |idx: usize, usage: &str| {
+            args.get(idx)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| panic!("Usage: {usage}"))
+        };
+
+        match args.get(2).map(String::as_str) {
+            None => Mode::Live,
+            Some("backfill") => {
+                let usage = "indexer <config> backfill <first slot> <last slot>";
+                let (first, last) = (slot(3, usage), slot(4, usage));
+                assert!(first <= last, "First slot must not be after the last slot");
+                Mode::Backfill { first, last }
+            }
+            Some("dump") => {
+                let usage = "indexer <config> dump <first slot> <last slot> <archive>";
+                let (first, last) = (slot(3, usage), slot(4, usage));
+                assert!(first <= last, "First slot must not be after the last slot");
+                Mode::Dump {
+                    first,
+                    last,
+                    path: path(5, usage),
+                }
+            }
+            Some("replay") => Mode::Replay {
+                path: path(3, "indexer <config> replay <archive>"),
+            },
+            Some(mode) => panic!("Unknown mode {mode}"),
+        }
+    }
+}
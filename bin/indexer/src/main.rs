@@ -3,8 +3,8 @@
 
 #![cfg_attr(not(debug_assertions), deny(warnings))]
 
-use crate::config::Config;
-use crate::solana::index_solana;
+use crate::config::{Config, Mode};
+use crate::solana::{backfill_solana, dump_solana, index_solana, replay_solana};
 use common::ResolveOr;
 use log::info;
 use tokio::runtime::Builder;
@@ -24,6 +24,7 @@ pub fn main() {
         .init();
 
     let config = Config::load();
+    let mode = Mode::load();
 
     let rayon_threads = config.rayon.threads.resolve_or(1);
     info!("rayon threads: {}", rayon_threads);
@@ -42,5 +43,10 @@ pub fn main() {
         .build()
         .unwrap();
 
-    index_solana(runtime, config)
+    match mode {
+        Mode::Live => index_solana(runtime, config),
+        Mode::Backfill { first, last } => backfill_solana(runtime, config, first, last),
+        Mode::Dump { first, last, path } => dump_solana(runtime, config, first, last, path),
+        Mode::Replay { path } => replay_solana(runtime, config, path),
+    }
 }
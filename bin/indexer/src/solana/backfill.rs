@@ -0,0 +1,76 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::config::Config;
+use crate::solana::block::{index_block, index_skipped};
+use crate::solana::finalize::finalize;
+use crate::solana::indexer::IndexerRepo;
+use crate::solana::setup_state;
+use base::model::solana::{Slot, SlotGap};
+use common::repo::pool::setup_pool;
+use common::ResolveOr;
+use solana::rpc::RpcClient;
+use tokio::runtime::Runtime;
+use tracing::{error, info, warn};
+
+/// Downloads and indexes every slot between first and last (inclusive) which was neither indexed nor
+/// recorded as skipped yet. Slots which fail to download remain gaps and can be backfilled again.
+pub fn backfill_solana(runtime: Runtime, config: Config, first: Slot, last: Slot) {
+    runtime.block_on(async move {
+        let pool = setup_pool(&config.postgres).await;
+        let state = setup_state(pool.clone(), &config);
+        let indexer_repo = IndexerRepo::default();
+
+        let mut tx = pool.begin().await.unwrap();
+        let gaps = indexer_repo.list_gaps(&mut tx, first, last).await.unwrap();
+        tx.commit().await.unwrap();
+
+        let slots = gaps.iter().flat_map(SlotGap::slots).collect::<Vec<_>>();
+        info!("backfills {} slots between {first} and {last}", slots.len());
+
+        let rpc_client = RpcClient::new(
+            config
+                .blockstream
+                .url
+                .resolve_or("http://api.mainnet-beta.solana.com".to_string()),
+        );
+        let concurrency = config.blockstream.concurrency.resolve_or(1usize).max(1);
+
+        for chunk in slots.chunks(concurrency) {
+            let downloads = chunk
+                .iter()
+                .map(|slot| {
+                    let rpc_client = rpc_client.clone();
+                    let slot = *slot;
+                    tokio::spawn(async move { (slot, rpc_client.get_block(slot).await) })
+                })
+                .collect::<Vec<_>>();
+
+            let mut skipped = vec![];
+            for download in downloads {
+                match download.await.unwrap() {
+                    (_, Ok(Some(block))) => index_block(state.clone(), block).await,
+                    (slot, Ok(None)) => skipped.push(slot),
+                    (slot, Err(err)) => error!("failed to fetch block for slot: {slot} - {err}"),
+                }
+            }
+            index_skipped(state.clone(), skipped).await;
+        }
+
+        // backfilled slots are usually old enough to be finalized right away
+        finalize(state.clone(), &rpc_client).await;
+
+        let mut tx = pool.begin().await.unwrap();
+        let watermark = indexer_repo.update_watermark(&mut tx).await.unwrap();
+        let remaining = indexer_repo.list_gaps(&mut tx, first, last).await.unwrap();
+        tx.commit().await.unwrap();
+
+        if let Some(watermark) = watermark {
+            info!("watermark {watermark}");
+        }
+
+        for gap in remaining {
+            warn!("slots {} to {} are still missing", gap.first, gap.last);
+        }
+    });
+}
@@ -2,7 +2,7 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::solana::state::State;
-use base::repo::TokenBalanceToInsert;
+use base::repo::{SolBalanceToInsert, TokenBalanceToInsert};
 use common::repo::Tx;
 
 pub(crate) async fn index_token_balance<'a>(
@@ -16,3 +16,15 @@ pub(crate) async fn index_token_balance<'a>(
         .await
         .unwrap();
 }
+
+pub(crate) async fn index_sol_balance<'a>(
+    tx: &mut Tx<'a>,
+    state: State,
+    balances: Vec<SolBalanceToInsert>,
+) {
+    state
+        .token_balance_repo
+        .insert_sol_balances(tx, balances)
+        .await
+        .unwrap();
+}
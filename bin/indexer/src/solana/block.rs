@@ -1,16 +1,17 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use crate::solana::balance::index_token_balance;
-use crate::solana::indexer::IndexerRepo;
+use crate::solana::balance::{index_sol_balance, index_token_balance};
+use crate::solana::indexer::{IndexerRepo, SlotToInsert};
 use crate::solana::state::State;
-use crate::solana::{jupiter, pumpfun};
-use base::model::solana::{Block, TransactionStatus};
+use crate::solana::{jupiter, pumpfun, raydium};
+use base::model::solana::{Block, Slot, TransactionStatus};
 use base::model::{AddressId, DecimalAmount, Decimals, Mint, PublicKey, TokenId};
-use base::repo::{TokenBalanceToInsert, TokenToInsert};
+use base::repo::{SolBalanceToInsert, TokenBalanceToInsert, TokenToInsert};
 use solana::jupiter::parse::JupiterParser;
 use solana::parse::Parser;
 use solana::pumpfun::PumpFunParser;
+use solana::raydium::parse::RaydiumParser;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use std::time::Instant;
@@ -21,6 +22,7 @@ pub async fn index_block(state: State, block: Block) {
 
     let jupiter_parser = JupiterParser::new();
     let pumpfun_parser = PumpFunParser::new();
+    let raydium_parser = RaydiumParser::new();
 
     let indexer_repo = IndexerRepo::default();
 
@@ -30,6 +32,9 @@ pub async fn index_block(state: State, block: Block) {
     let jupiter_account =
         PublicKey::from_str("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4").unwrap();
 
+    let raydium_account =
+        PublicKey::from_str("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8").unwrap();
+
     // FIXME it would be interesting to see what the time difference is between indexing a block and the actual block time
 
     let mut jupiter_slot_swaps = solana::jupiter::repo::SlotSwaps {
@@ -38,6 +43,12 @@ pub async fn index_block(state: State, block: Block) {
         swaps: vec![],
     };
 
+    let mut raydium_slot_swaps = solana::raydium::repo::SlotSwaps {
+        slot: block.slot,
+        timestamp: block.timestamp,
+        swaps: vec![],
+    };
+
     let mut pumpfun_slot_swaps = solana::pumpfun::repo::SlotSwaps {
         slot: block.slot,
         timestamp: block.timestamp,
@@ -45,6 +56,7 @@ pub async fn index_block(state: State, block: Block) {
     };
 
     let mut pumpfun_token_mints = vec![];
+    let mut pumpfun_graduated_mints = vec![];
 
     let tx_parsing_start = Instant::now();
 
@@ -81,6 +93,8 @@ pub async fn index_block(state: State, block: Block) {
                                     description: None,
                                     image: None,
                                     website: None,
+                                    twitter: None,
+                                    telegram: None,
                                     creator: Some(creator.id),
                                     block: Some(block.slot.into()),
                                     block_time: Some(block.timestamp),
@@ -110,6 +124,14 @@ pub async fn index_block(state: State, block: Block) {
                                         signature: transaction.signature.clone(),
                                     });
                             }
+
+                            solana::pumpfun::model::Instruction::Complete { mint, .. }
+                            | solana::pumpfun::model::Instruction::Withdraw { mint }
+                            | solana::pumpfun::model::Instruction::Migrate { mint } => {
+                                if !pumpfun_graduated_mints.contains(&mint) {
+                                    pumpfun_graduated_mints.push(mint);
+                                }
+                            }
                         }
                     }
                 }
@@ -119,10 +141,42 @@ pub async fn index_block(state: State, block: Block) {
                 if let Ok(instructions) = jupiter_parser.parse(&transaction) {
                     for instruction in instructions {
                         match instruction {
-                            solana::jupiter::model::Instruction::Swap { swaps, signer } => {
+                            solana::jupiter::model::Instruction::Swap { route, signer } => {
+                                jupiter_slot_swaps
+                                    .swaps
+                                    .push(solana::jupiter::repo::SlotSwap {
+                                        input_mint: route.input_mint,
+                                        input_amount: route.input_amount,
+                                        output_mint: route.output_mint,
+                                        output_amount: route.output_amount,
+                                        wallet: signer,
+                                        signature: transaction.signature.clone(),
+                                        legs: route
+                                            .legs
+                                            .into_iter()
+                                            .map(|leg| solana::jupiter::repo::SlotSwapLeg {
+                                                amm: leg.amm,
+                                                input_mint: leg.input_mint,
+                                                input_amount: leg.input_amount,
+                                                output_mint: leg.output_mint,
+                                                output_amount: leg.output_amount,
+                                            })
+                                            .collect(),
+                                    });
+                            }
+                        }
+                    }
+                }
+            }
+
+            if transaction.keys.contains(&raydium_account) {
+                if let Ok(instructions) = raydium_parser.parse(&transaction) {
+                    for instruction in instructions {
+                        match instruction {
+                            solana::raydium::model::Instruction::Swap { swaps, signer } => {
                                 for swap in &swaps {
-                                    jupiter_slot_swaps.swaps.push(
-                                        solana::jupiter::repo::SlotSwap {
+                                    raydium_slot_swaps.swaps.push(
+                                        solana::raydium::repo::SlotSwap {
                                             input_mint: swap.input_mint.clone(),
                                             input_amount: swap.input_amount.clone(),
                                             output_mint: swap.output_mint.clone(),
@@ -185,7 +239,7 @@ pub async fn index_block(state: State, block: Block) {
 
     let tokens: HashMap<Mint, TokenId> = tokens.into_iter().map(|m| (m.mint, m.id)).collect();
 
-    // let mut sol_balances: Vec<SolBalanceToInsert> = vec![];
+    let mut sol_balances: Vec<SolBalanceToInsert> = vec![];
     let mut token_balances: Vec<TokenBalanceToInsert> = vec![];
     for transaction in block.transactions {
         if transaction.status == TransactionStatus::Success {
@@ -200,15 +254,15 @@ pub async fn index_block(state: State, block: Block) {
                 })
             }
 
-            // for sol in transaction.balance.sol {
-            //     sol_balances.push(SolBalanceToInsert {
-            //         slot: block.slot,
-            //         timestamp: block.timestamp.0,
-            //         address: addresses[&sol.address],
-            //         pre: sol.pre,
-            //         post: sol.post,
-            //     })
-            // }
+            for sol in transaction.balance.sol {
+                sol_balances.push(SolBalanceToInsert {
+                    slot: block.slot,
+                    timestamp: block.timestamp.0,
+                    address: addresses[&sol.address],
+                    pre: sol.pre,
+                    post: sol.post,
+                })
+            }
         }
     }
 
@@ -224,9 +278,18 @@ pub async fn index_block(state: State, block: Block) {
     let indexing_start = Instant::now();
     pumpfun::index_tokens(&mut tx, state.clone(), pumpfun_token_mints).await;
     pumpfun::index_swap(&mut tx, state.clone(), pumpfun_slot_swaps).await;
+    pumpfun::index_graduation(
+        &mut tx,
+        state.clone(),
+        pumpfun_graduated_mints,
+        slot,
+        block.timestamp.0,
+    )
+    .await;
     jupiter::index_swap(&mut tx, state.clone(), jupiter_slot_swaps).await;
+    raydium::index_swap(&mut tx, state.clone(), raydium_slot_swaps).await;
     index_token_balance(&mut tx, state.clone(), token_balances).await;
-    // index_sol_balance(&mut tx, state.clone(), sol_balances).await;
+    index_sol_balance(&mut tx, state.clone(), sol_balances).await;
     let indexing_done = Instant::now();
 
     debug!(
@@ -235,5 +298,28 @@ pub async fn index_block(state: State, block: Block) {
     );
 
     indexer_repo.set(&mut tx, slot).await.unwrap();
+    indexer_repo
+        .insert_slots(&mut tx, [SlotToInsert::indexed(slot, block.timestamp)])
+        .await
+        .unwrap();
+    indexer_repo.update_watermark(&mut tx).await.unwrap();
+    tx.commit().await.unwrap();
+}
+
+/// Records slots for which the leader did not produce a block, so that they do not show up as gaps
+pub async fn index_skipped(state: State, slots: Vec<Slot>) {
+    if slots.is_empty() {
+        return;
+    }
+    debug!("skipped {} slots", slots.len());
+
+    let indexer_repo = IndexerRepo::default();
+
+    let mut tx = state.pool.begin().await.unwrap();
+    indexer_repo
+        .insert_slots(&mut tx, slots.into_iter().map(SlotToInsert::skipped))
+        .await
+        .unwrap();
+    indexer_repo.update_watermark(&mut tx).await.unwrap();
     tx.commit().await.unwrap();
 }
@@ -0,0 +1,58 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::config::Config;
+use base::model::solana::Slot;
+use common::ResolveOr;
+use solana::rpc::RpcClient;
+use solana::stream::ArchiveWriter;
+use std::path::PathBuf;
+use tokio::runtime::Runtime;
+use tracing::{error, info};
+
+/// Downloads the blocks between first and last (inclusive) into an archive file, which can be replayed offline.
+/// Stops at the first slot which fails to download, so that every slot missing in the archive was skipped.
+pub fn dump_solana(runtime: Runtime, config: Config, first: Slot, last: Slot, path: PathBuf) {
+    runtime.block_on(async move {
+        let mut writer = ArchiveWriter::create(path.clone()).expect("Unable to create archive");
+
+        let rpc_client = RpcClient::new(
+            config
+                .blockstream
+                .url
+                .resolve_or("http://api.mainnet-beta.solana.com".to_string()),
+        );
+        let concurrency = config.blockstream.concurrency.resolve_or(1usize).max(1);
+
+        let slots = (first.0..=last.0).map(Slot).collect::<Vec<_>>();
+        let mut count = 0usize;
+
+        'dump: for chunk in slots.chunks(concurrency) {
+            let downloads = chunk
+                .iter()
+                .map(|slot| {
+                    let rpc_client = rpc_client.clone();
+                    let slot = *slot;
+                    tokio::spawn(async move { (slot, rpc_client.get_block(slot).await) })
+                })
+                .collect::<Vec<_>>();
+
+            for download in downloads {
+                match download.await.unwrap() {
+                    (_, Ok(Some(block))) => {
+                        writer.append(&block).expect("Unable to write block");
+                        count += 1;
+                    }
+                    (_, Ok(None)) => {}
+                    (slot, Err(err)) => {
+                        error!("failed to fetch block for slot: {slot} - {err}");
+                        break 'dump;
+                    }
+                }
+            }
+        }
+
+        writer.flush().expect("Unable to write archive");
+        info!("dumped {count} blocks into {}", path.to_string_lossy());
+    });
+}
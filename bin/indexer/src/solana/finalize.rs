@@ -0,0 +1,157 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::solana::block::index_block;
+use crate::solana::indexer::IndexerRepo;
+use crate::solana::state::State;
+use base::model::solana::{IndexedSlot, Slot, SlotStatus};
+use base::model::ChangeKind;
+use common::repo::Tx;
+use solana::rpc::RpcClient;
+use std::collections::HashSet;
+use tracing::{error, info, warn};
+
+/// Compares the recorded slots up to the finalized slot with the finalized fork.
+/// Blocks of a dropped fork get rolled back and their slots count as skipped, blocks which were missed because the
+/// confirmed fork skipped their slot get indexed. Only finalized slots move the watermark, so that candles and
+/// rules never see swaps which did not land. Token pairs touched by a dropped fork get a change, so that rules
+/// evaluate their restored state.
+pub async fn finalize(state: State, rpc_client: &RpcClient) {
+    let indexer_repo = IndexerRepo::default();
+
+    let finalized = match rpc_client.finalized_slot().await {
+        Ok(slot) => slot,
+        Err(err) => {
+            error!("unable to get finalized slot - {err}");
+            return;
+        }
+    };
+
+    let mut tx = state.pool.begin().await.unwrap();
+    let unfinalized = indexer_repo
+        .list_unfinalized(&mut tx, finalized)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    let (Some(first), Some(last)) = (unfinalized.first(), unfinalized.last()) else {
+        return;
+    };
+
+    let blocks = match rpc_client.finalized_blocks(first.slot, last.slot).await {
+        Ok(blocks) => blocks.into_iter().collect::<HashSet<_>>(),
+        Err(err) => {
+            error!(
+                "unable to get finalized blocks between {} and {} - {err}",
+                first.slot, last.slot
+            );
+            return;
+        }
+    };
+
+    let mut indexed = vec![];
+    let mut skipped = vec![];
+    let mut dropped = vec![];
+    let mut missed = vec![];
+
+    for slot in unfinalized {
+        match (slot.status, blocks.contains(&slot.slot)) {
+            (SlotStatus::Indexed, true) => indexed.push(slot.slot),
+            (SlotStatus::Skipped, false) => skipped.push(slot.slot),
+            (SlotStatus::Indexed, false) => dropped.push(slot),
+            (SlotStatus::Skipped, true) => missed.push(slot.slot),
+        }
+    }
+
+    let mut tx = state.pool.begin().await.unwrap();
+    if !dropped.is_empty() {
+        warn!("rolls back {} slots of a dropped fork", dropped.len());
+        rollback(&mut tx, &state, &dropped).await;
+    }
+
+    indexer_repo
+        .finalize_slots(&mut tx, indexed, SlotStatus::Indexed)
+        .await
+        .unwrap();
+    indexer_repo
+        .finalize_slots(
+            &mut tx,
+            skipped
+                .into_iter()
+                .chain(dropped.iter().map(|slot| slot.slot)),
+            SlotStatus::Skipped,
+        )
+        .await
+        .unwrap();
+    if let Some(watermark) = indexer_repo.update_watermark(&mut tx).await.unwrap() {
+        state
+            .token_balance_repo
+            .finalize(&mut tx, watermark)
+            .await
+            .unwrap();
+    }
+    tx.commit().await.unwrap();
+
+    for slot in missed {
+        info!("index missed block {slot}");
+        match rpc_client.get_block(slot).await {
+            Ok(Some(block)) => index_block(state.clone(), block).await,
+            Ok(None) => warn!("finalized block {slot} is not available"),
+            Err(err) => error!("failed to fetch block for slot: {slot} - {err}"),
+        }
+    }
+}
+
+async fn rollback<'a>(tx: &mut Tx<'a>, state: &State, dropped: &[IndexedSlot]) {
+    let slots = dropped.iter().map(|slot| slot.slot).collect::<Vec<Slot>>();
+
+    let timestamps = dropped.iter().filter_map(|slot| slot.timestamp);
+    let (Some(from), Some(to)) = (timestamps.clone().min(), timestamps.max()) else {
+        return;
+    };
+
+    let swapped = state
+        .pumpfun_swap_repo
+        .rollback(tx, &slots, from.0, to.0)
+        .await
+        .unwrap();
+    let ungraduated = state
+        .pumpfun_current_repo
+        .rollback(tx, &slots)
+        .await
+        .unwrap();
+    state
+        .token_pair_change_repo
+        .insert(tx, ChangeKind::Swap, swapped)
+        .await
+        .unwrap();
+    state
+        .token_pair_change_repo
+        .insert(tx, ChangeKind::Graduation, ungraduated)
+        .await
+        .unwrap();
+    let mut restored = state
+        .jupiter_swap_repo
+        .rollback(tx, &slots, from.0, to.0)
+        .await
+        .unwrap();
+    restored.extend(
+        state
+            .raydium_swap_repo
+            .rollback(tx, &slots, from.0, to.0)
+            .await
+            .unwrap(),
+    );
+    restored.sort();
+    restored.dedup();
+    state
+        .token_pair_change_repo
+        .insert(tx, ChangeKind::Candle, restored)
+        .await
+        .unwrap();
+    state
+        .token_balance_repo
+        .rollback(tx, &slots, from.0, to.0)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,38 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::solana::indexer::IndexerRepo;
+use base::model::solana::{Commitment, Slot, SlotStatus};
+use common::repo::{RepoResult, Tx};
+use sqlx::query;
+
+impl IndexerRepo {
+    /// Marks the slots as finalized with the status they have on the finalized fork
+    pub async fn finalize_slots<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        slots: impl IntoIterator<Item = Slot> + Send,
+        status: SlotStatus,
+    ) -> RepoResult<()> {
+        let slots = slots.into_iter().collect::<Vec<_>>();
+        if slots.is_empty() {
+            return Ok(());
+        }
+
+        query(
+            r#"
+update solana.indexed_slot set
+    status = $2,
+    commitment = $3,
+    timestamp = case when $2 = 2 then null else timestamp end
+where slot = any($1::int8[]);
+"#,
+        )
+        .bind(slots)
+        .bind(status)
+        .bind(Commitment::Finalized)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+}
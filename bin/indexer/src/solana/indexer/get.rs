@@ -14,6 +14,7 @@ impl IndexerRepo {
             .await
             .map(|r| Indexer {
                 slot: r.get::<Slot, _>("slot"),
+                watermark: r.get::<Option<Slot>, _>("watermark"),
                 updated_at: r.get::<UpdatedAt, _>("updated_at"),
             })?)
     }
@@ -0,0 +1,74 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::solana::indexer::IndexerRepo;
+use base::model::solana::{Commitment, Slot, SlotStatus};
+use common::model::BlockTimestamp;
+use common::repo::{RepoResult, Tx};
+use sqlx::query;
+
+pub struct SlotToInsert {
+    pub slot: Slot,
+    pub status: SlotStatus,
+    pub timestamp: Option<BlockTimestamp>,
+}
+
+impl SlotToInsert {
+    pub fn indexed(slot: Slot, timestamp: BlockTimestamp) -> Self {
+        Self {
+            slot,
+            status: SlotStatus::Indexed,
+            timestamp: Some(timestamp),
+        }
+    }
+
+    pub fn skipped(slot: Slot) -> Self {
+        Self {
+            slot,
+            status: SlotStatus::Skipped,
+            timestamp: None,
+        }
+    }
+}
+
+impl IndexerRepo {
+    /// Records the slots as handled, an already recorded slot takes the new status.
+    /// Blocks are downloaded with confirmed commitment, so slots start out as confirmed until they got finalized
+    pub async fn insert_slots<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        slots: impl IntoIterator<Item = SlotToInsert> + Send,
+    ) -> RepoResult<()> {
+        let mut to_insert = vec![];
+        let mut statuses = vec![];
+        let mut timestamps = vec![];
+
+        for slot in slots {
+            to_insert.push(slot.slot);
+            statuses.push(slot.status);
+            timestamps.push(slot.timestamp);
+        }
+
+        if to_insert.is_empty() {
+            return Ok(());
+        }
+
+        query(
+            r#"
+insert into solana.indexed_slot (slot, status, commitment, timestamp)
+select unnest($1::int8[]), unnest($2::int2[]), $3, unnest($4::timestamptz[])
+on conflict (slot) do update set
+    status = excluded.status,
+    commitment = excluded.commitment,
+    timestamp = excluded.timestamp;
+"#,
+        )
+        .bind(to_insert)
+        .bind(statuses)
+        .bind(Commitment::Confirmed)
+        .bind(timestamps)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+}
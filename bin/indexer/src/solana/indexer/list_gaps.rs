@@ -0,0 +1,47 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::solana::indexer::IndexerRepo;
+use base::model::solana::{Slot, SlotGap};
+use common::repo::{RepoResult, Tx};
+use sqlx::{query, Row};
+
+impl IndexerRepo {
+    /// Lists the slots between first and last (inclusive) which were neither indexed nor recorded as skipped
+    pub async fn list_gaps<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        first: impl Into<Slot> + Send,
+        last: impl Into<Slot> + Send,
+    ) -> RepoResult<Vec<SlotGap>> {
+        Ok(query(
+            r#"
+with slots as (
+    select slot from solana.indexed_slot where slot between $1 and $2
+    union all
+    select $1 - 1
+    union all
+    select $2 + 1
+),
+neighbours as (
+    select slot, lead(slot) over (order by slot) as next
+    from slots
+)
+select slot + 1 as first, next - 1 as last
+from neighbours
+where next > slot + 1
+order by slot;
+"#,
+        )
+        .bind(first.into())
+        .bind(last.into())
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| SlotGap {
+            first: r.get::<Slot, _>("first"),
+            last: r.get::<Slot, _>("last"),
+        })
+        .collect())
+    }
+}
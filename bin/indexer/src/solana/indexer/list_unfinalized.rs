@@ -0,0 +1,37 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::solana::indexer::IndexerRepo;
+use base::model::solana::{Commitment, IndexedSlot, Slot, SlotStatus};
+use common::model::BlockTimestamp;
+use common::repo::{RepoResult, Tx};
+use sqlx::{query, Row};
+
+impl IndexerRepo {
+    /// Lists the recorded slots up to and including the given slot, which are not finalized yet
+    pub async fn list_unfinalized<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        up_to: impl Into<Slot> + Send,
+    ) -> RepoResult<Vec<IndexedSlot>> {
+        Ok(query(
+            r#"
+select slot, status, commitment, timestamp
+from solana.indexed_slot
+where commitment < 3 and slot <= $1
+order by slot;
+"#,
+        )
+        .bind(up_to.into())
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| IndexedSlot {
+            slot: r.get::<Slot, _>("slot"),
+            status: r.get::<SlotStatus, _>("status"),
+            commitment: r.get::<Commitment, _>("commitment"),
+            timestamp: r.get::<Option<BlockTimestamp>, _>("timestamp"),
+        })
+        .collect())
+    }
+}
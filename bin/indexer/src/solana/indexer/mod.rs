@@ -1,10 +1,15 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+mod finalize_slots;
 mod get;
+mod insert_slots;
+mod list_gaps;
+mod list_unfinalized;
 mod set;
+mod update_watermark;
 
-#[derive(Clone)]
-#[derive(Default)]
-pub struct IndexerRepo {}
+pub use insert_slots::SlotToInsert;
 
+#[derive(Clone, Default)]
+pub struct IndexerRepo {}
@@ -7,11 +7,12 @@ use common::repo::{RepoResult, Tx};
 use sqlx::query;
 
 impl IndexerRepo {
+    /// Sets the latest indexed slot, it never moves backwards as backfilled slots are older than the latest one
     pub async fn set<'a>(&self, tx: &mut Tx<'a>, slot: impl Into<Slot> + Send) -> RepoResult<()> {
         query(
             r#"
 insert into solana.indexer (id, slot, updated_at) values (1, $1, now())
-on conflict (id) do update set slot = $1, updated_at = now();
+on conflict (id) do update set slot = greatest(solana.indexer.slot, $1), updated_at = now();
 "#,
         )
         .bind(slot.into())
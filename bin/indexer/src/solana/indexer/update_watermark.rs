@@ -0,0 +1,40 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::solana::indexer::IndexerRepo;
+use base::model::solana::Slot;
+use common::repo::{RepoResult, Tx};
+use sqlx::{query, Row};
+
+impl IndexerRepo {
+    /// Moves the watermark to the end of the contiguous run of finalized slots, starting from the current
+    /// watermark or the oldest finalized slot. A gap or a slot which is not finalized yet keeps the watermark in place.
+    pub async fn update_watermark<'a>(&self, tx: &mut Tx<'a>) -> RepoResult<Option<Slot>> {
+        Ok(query(
+            r#"
+with start as (
+    select coalesce(
+        (select watermark from solana.indexer where id = 1),
+        (select min(slot) from solana.indexed_slot where commitment = 3)
+    ) as slot
+),
+contiguous as (
+    select min(slot) as slot
+    from (
+        select slot, lead(slot) over (order by slot) as next
+        from solana.indexed_slot
+        where slot >= (select slot from start) and commitment = 3
+    ) s
+    where next is null or next > slot + 1
+)
+insert into solana.indexer (id, slot, watermark, updated_at)
+select 1, slot, slot, now() from contiguous where slot is not null
+on conflict (id) do update set watermark = excluded.watermark, updated_at = now()
+returning watermark;
+"#,
+        )
+        .fetch_optional(&mut **tx)
+        .await?
+        .map(|r| r.get::<Slot, _>("watermark")))
+    }
+}
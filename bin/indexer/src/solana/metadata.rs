@@ -0,0 +1,93 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::solana::state::State;
+use base::model::ChangeKind;
+use base::repo::UnresolvedMetadata;
+use base::TokenInfo;
+use common::repo::RepoResult;
+use futures_util::{stream, StreamExt};
+use solana::token_info::uri::TokenInfoUriLoader;
+use tracing::{debug, error};
+
+const MAX_ATTEMPTS: i16 = 5;
+const BATCH_SIZE: i64 = 100;
+const CONCURRENCY: usize = 10;
+
+/// Fetches the off-chain metadata json of tokens which got indexed with nothing but their metadata uri and fills
+/// in the token. Runs apart from block indexing, so that indexing never waits for slow or unavailable servers.
+/// The loader is expected to rate limit its requests
+pub async fn resolve_metadata(state: State, loader: &TokenInfoUriLoader) {
+    let unresolved = match list_unresolved(&state).await {
+        Ok(unresolved) => unresolved,
+        Err(err) => {
+            error!("unable to list tokens with unresolved metadata - {err}");
+            return;
+        }
+    };
+
+    if unresolved.is_empty() {
+        return;
+    }
+
+    debug!("resolves metadata of {} tokens", unresolved.len());
+
+    stream::iter(unresolved)
+        .map(|token| resolve_token(state.clone(), loader, token))
+        .buffer_unordered(CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+}
+
+async fn list_unresolved(state: &State) -> RepoResult<Vec<UnresolvedMetadata>> {
+    let mut tx = state.pool.begin().await?;
+    let result = state
+        .token_repo
+        .list_unresolved_metadata(&mut tx, MAX_ATTEMPTS, BATCH_SIZE)
+        .await?;
+    tx.commit().await?;
+    Ok(result)
+}
+
+async fn resolve_token(state: State, loader: &TokenInfoUriLoader, token: UnresolvedMetadata) {
+    let info = loader.load(token.metadata.clone()).await;
+
+    let result = match info {
+        Some(info) => resolved(&state, &token, info).await,
+        None => {
+            if token.attempts + 1 >= MAX_ATTEMPTS {
+                error!(
+                    "gives up resolving metadata of {} from {}",
+                    token.mint, token.metadata
+                );
+            }
+            failed(&state, &token).await
+        }
+    };
+
+    if let Err(err) = result {
+        error!("unable to record metadata of {} - {err}", token.mint);
+    }
+}
+
+/// The token pairs of the token get reported as changed, as the metadata might link social accounts
+async fn resolved(state: &State, token: &UnresolvedMetadata, info: TokenInfo) -> RepoResult<()> {
+    let mut tx = state.pool.begin().await?;
+    state
+        .token_repo
+        .resolve_metadata(&mut tx, token.id, info)
+        .await?;
+    state
+        .token_pair_change_repo
+        .insert_for_token(&mut tx, ChangeKind::Metadata, token.id)
+        .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+async fn failed(state: &State, token: &UnresolvedMetadata) -> RepoResult<()> {
+    let mut tx = state.pool.begin().await?;
+    state.token_repo.fail_metadata(&mut tx, token.id).await?;
+    tx.commit().await?;
+    Ok(())
+}
@@ -6,24 +6,42 @@ use tokio::runtime::Runtime;
 use tokio::{join, select};
 
 use crate::config::Config;
-use crate::solana::block::index_block;
+use crate::solana::block::{index_block, index_skipped};
+use crate::solana::finalize::finalize;
 use crate::solana::indexer::IndexerRepo;
+use crate::solana::metadata::resolve_metadata;
 use crate::solana::state::{State, StateInner};
-use base::repo::{AddressRepo, TokenBalanceRepo, TokenPairRepo, TokenRepo};
+use base::model::solana::Slot;
+use base::repo::{AddressRepo, TokenBalanceRepo, TokenPairChangeRepo, TokenPairRepo, TokenRepo};
 use common::repo::pool::setup_pool;
-use common::{ResolveOr, Signal};
+use common::{Limiter, ResolveOr, Signal};
+use solana::rpc::RpcClient;
 use solana::stream::{BlockStream, RpcBlockStream, RpcBlockStreamConfig, RpcSlotStream};
 use solana::token_info::rpc::TokenInfoRpcLoader;
+use solana::token_info::uri::TokenInfoUriLoader;
+use sqlx::PgPool;
+use std::time::Duration;
 use tokio::signal::unix::SignalKind;
+use tokio::time::sleep;
 use tracing::info;
 
+mod backfill;
 mod balance;
 pub mod block;
+mod dump;
+mod finalize;
 pub mod indexer;
 mod jupiter;
+mod metadata;
 mod pumpfun;
+mod raydium;
+pub mod replay;
 pub mod state;
 
+pub use backfill::backfill_solana;
+pub use dump::dump_solana;
+pub use replay::replay_solana;
+
 pub fn index_solana(runtime: Runtime, config: Config) {
     runtime.block_on(async move {
         let signal = Signal::new();
@@ -36,26 +54,7 @@ pub fn index_solana(runtime: Runtime, config: Config) {
 
         // tx.commit().await.unwrap();
 
-        let token_info_loader = TokenInfoRpcLoader::new(config.rpc.url.resolve());
-        let token_repo = TokenRepo::new(Box::new(token_info_loader));
-        let token_pair_repo = TokenPairRepo::new(token_repo.clone());
-
-        let wallet_repo = AddressRepo::new();
-
-        let pumpfun_swap_repo =
-            solana::pumpfun::repo::SwapRepo::new(token_pair_repo.clone(), wallet_repo.clone());
-        let jupiter_swap_repo =
-            solana::jupiter::repo::SwapRepo::new(token_pair_repo.clone(), wallet_repo.clone());
-
-        let state = State(Arc::new(StateInner {
-            pool: pool.clone(),
-            token_repo: token_repo.clone(),
-            address_repo: AddressRepo::new(),
-            token_balance_repo: TokenBalanceRepo::new(),
-            pumpfun_swap_repo,
-            pumpfun_current_repo: solana::pumpfun::repo::CurrentRepo::new(),
-            jupiter_swap_repo,
-        }));
+        let state = setup_state(pool.clone(), &config);
 
         // let jupiter_parser = JupiterParser::new();
         // let pumpfun_parser = PumpFunParser::new();
@@ -98,12 +97,58 @@ pub fn index_solana(runtime: Runtime, config: Config) {
         .stream(signal.clone())
         .await;
 
+        let finalize_state = state.clone();
+        let finalize_rpc_client = RpcClient::new(
+            config
+                .blockstream
+                .url
+                .resolve_or("http://api.mainnet-beta.solana.com".to_string()),
+        );
+        let mut finalize_signal = signal.clone();
+        let finalize_handle = tokio::spawn(async move {
+            loop {
+                select! {
+                    _ = sleep(Duration::from_secs(5)) => {
+                        finalize(finalize_state.clone(), &finalize_rpc_client).await;
+                    },
+                    _ = finalize_signal.recv() => {
+                        break
+                    }
+                }
+            }
+        });
+
+        let metadata_state = state.clone();
+        let metadata_loader = TokenInfoUriLoader::new_limited(Limiter::new_per_second(
+            config.metadata.rate.resolve_or(10usize),
+        ));
+        let mut metadata_signal = signal.clone();
+        let metadata_handle = tokio::spawn(async move {
+            loop {
+                select! {
+                    _ = sleep(Duration::from_secs(1)) => {
+                        resolve_metadata(metadata_state.clone(), &metadata_loader).await;
+                    },
+                    _ = metadata_signal.recv() => {
+                        break
+                    }
+                }
+            }
+        });
+
         // let mut exit = exit_tx.subscribe();
         let mut signal = signal.clone();
         let handle = tokio::spawn(async move {
+            // blocks arrive in slot order, every slot in between was skipped by its leader
+            let mut previous = previous_slot;
             loop {
                 select! {
                      Some(block) = blocks.recv() => {
+                        if let Some(previous) = previous {
+                            let skipped = (previous.0 + 1..block.slot.0).map(Slot).collect();
+                            index_skipped(state.clone(), skipped).await;
+                        }
+                        previous = Some(block.slot);
                         index_block(state.clone(),block).await;
                      },
                     _ = signal.recv() => {
@@ -115,7 +160,12 @@ pub fn index_solana(runtime: Runtime, config: Config) {
 
         //
         //
-        let _ = join!(block_stream_handle, handle);
+        let _ = join!(
+            block_stream_handle,
+            handle,
+            finalize_handle,
+            metadata_handle
+        );
 
         // let (rx, handle) = RpcBlockStream::new(RpcBlockStreamConfig {
         //     url: config.rpc.url_1.resolve().into(),
@@ -127,3 +177,30 @@ pub fn index_solana(runtime: Runtime, config: Config) {
         // while let Ok(block) = rx.recv_async().await {}
     });
 }
+
+pub(crate) fn setup_state(pool: PgPool, config: &Config) -> State {
+    let token_info_loader = TokenInfoRpcLoader::new(config.rpc.url.resolve());
+    let token_repo = TokenRepo::new(Box::new(token_info_loader));
+    let token_pair_repo = TokenPairRepo::new(token_repo.clone());
+
+    let wallet_repo = AddressRepo::new();
+
+    let pumpfun_swap_repo =
+        solana::pumpfun::repo::SwapRepo::new(token_pair_repo.clone(), wallet_repo.clone());
+    let jupiter_swap_repo =
+        solana::jupiter::repo::SwapRepo::new(token_pair_repo.clone(), wallet_repo.clone());
+    let raydium_swap_repo =
+        solana::raydium::repo::SwapRepo::new(token_pair_repo.clone(), wallet_repo.clone());
+
+    State(Arc::new(StateInner {
+        pool,
+        token_repo: token_repo.clone(),
+        address_repo: AddressRepo::new(),
+        token_balance_repo: TokenBalanceRepo::new(),
+        token_pair_change_repo: TokenPairChangeRepo::new(),
+        pumpfun_swap_repo,
+        pumpfun_current_repo: solana::pumpfun::repo::CurrentRepo::new(),
+        jupiter_swap_repo,
+        raydium_swap_repo,
+    }))
+}
@@ -0,0 +1,33 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::solana::state::State;
+use base::model::solana::Slot;
+use base::model::{ChangeKind, Mint};
+use common::model::Timestamp;
+use common::repo::Tx;
+use log::debug;
+
+pub(crate) async fn index_graduation<'a>(
+    tx: &mut Tx<'a>,
+    state: State,
+    mints: Vec<Mint>,
+    slot: Slot,
+    timestamp: Timestamp,
+) {
+    let token_pairs = state
+        .pumpfun_current_repo
+        .graduate(tx, mints, slot, timestamp)
+        .await
+        .unwrap();
+
+    for token_pair in &token_pairs {
+        debug!("token pair graduated: {token_pair}");
+    }
+
+    state
+        .token_pair_change_repo
+        .insert_at_slot(tx, ChangeKind::Graduation, slot, token_pairs)
+        .await
+        .unwrap();
+}
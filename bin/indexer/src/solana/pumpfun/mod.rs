@@ -1,7 +1,9 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
+pub(crate) use graduation::index_graduation;
 pub(crate)use mint::index_tokens;
 pub(crate) use swap::index_swap;
 
+mod graduation;
 mod swap;
 mod mint;
@@ -2,17 +2,30 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::solana::state::State;
+use base::model::ChangeKind;
 use common::repo::Tx;
 use solana::pumpfun::repo::SlotSwaps;
 
 pub(crate) async fn index_swap<'a>(tx: &mut Tx<'a>, state: State, swaps: SlotSwaps) {
+    let slot = swaps.slot;
     let inserted = state
         .pumpfun_swap_repo
         .insert_swaps(tx, swaps)
         .await
         .unwrap();
 
+    let token_pairs = inserted
+        .iter()
+        .map(|swap| swap.token_pair)
+        .collect::<Vec<_>>();
+
     for swap in inserted {
         state.pumpfun_current_repo.upsert(tx, swap).await.unwrap();
     }
+
+    state
+        .token_pair_change_repo
+        .insert_at_slot(tx, ChangeKind::Swap, slot, token_pairs)
+        .await
+        .unwrap();
 }
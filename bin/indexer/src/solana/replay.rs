@@ -0,0 +1,73 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::config::Config;
+use crate::solana::block::{index_block, index_skipped};
+use crate::solana::indexer::IndexerRepo;
+use crate::solana::setup_state;
+use crate::solana::state::State;
+use base::model::solana::{Slot, SlotStatus};
+use common::repo::pool::setup_pool;
+use common::Signal;
+use solana::stream::{ArchiveBlockStream, BlockStream};
+use std::path::PathBuf;
+use tokio::runtime::Runtime;
+use tracing::info;
+
+/// Indexes all blocks of an archive file, as if they were streamed from the chain
+pub fn replay_solana(runtime: Runtime, config: Config, path: PathBuf) {
+    runtime.block_on(async move {
+        let pool = setup_pool(&config.postgres).await;
+        let state = setup_state(pool, &config);
+
+        let count = replay(state, path.clone()).await;
+        info!("replayed {count} blocks from {}", path.to_string_lossy());
+    });
+}
+
+/// Indexes all blocks of the archive and finalizes them afterwards, returns the number of replayed blocks
+pub async fn replay(state: State, path: PathBuf) -> usize {
+    let (mut blocks, handle) = ArchiveBlockStream::new(path).stream(Signal::new()).await;
+
+    // archives are dumped in slot order, every slot in between was skipped by its leader
+    let mut previous: Option<Slot> = None;
+    let mut count = 0usize;
+    while let Some(block) = blocks.recv().await {
+        if let Some(previous) = previous {
+            let skipped = (previous.0 + 1..block.slot.0).map(Slot).collect();
+            index_skipped(state.clone(), skipped).await;
+        }
+        previous = Some(block.slot);
+        index_block(state.clone(), block).await;
+        count += 1;
+    }
+
+    let _ = handle.await;
+
+    // archives are replayed offline, their blocks are taken as final
+    if let Some(last) = previous {
+        let indexer_repo = IndexerRepo::default();
+        let mut tx = state.pool.begin().await.unwrap();
+        let unfinalized = indexer_repo.list_unfinalized(&mut tx, last).await.unwrap();
+        for status in [SlotStatus::Indexed, SlotStatus::Skipped] {
+            let slots = unfinalized
+                .iter()
+                .filter(|slot| slot.status == status)
+                .map(|slot| slot.slot);
+            indexer_repo
+                .finalize_slots(&mut tx, slots.collect::<Vec<_>>(), status)
+                .await
+                .unwrap();
+        }
+        if let Some(watermark) = indexer_repo.update_watermark(&mut tx).await.unwrap() {
+            state
+                .token_balance_repo
+                .finalize(&mut tx, watermark)
+                .await
+                .unwrap();
+        }
+        tx.commit().await.unwrap();
+    }
+
+    count
+}
@@ -1,7 +1,7 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use base::repo::{AddressRepo, TokenBalanceRepo, TokenRepo};
+use base::repo::{AddressRepo, TokenBalanceRepo, TokenPairChangeRepo, TokenRepo};
 use sqlx::PgPool;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -21,7 +21,9 @@ pub struct StateInner {
     pub token_repo: TokenRepo,
     pub address_repo: AddressRepo,
     pub token_balance_repo: TokenBalanceRepo,
+    pub token_pair_change_repo: TokenPairChangeRepo,
     pub pumpfun_swap_repo: solana::pumpfun::repo::SwapRepo,
     pub pumpfun_current_repo: solana::pumpfun::repo::CurrentRepo,
     pub jupiter_swap_repo: solana::jupiter::repo::SwapRepo,
+    pub raydium_swap_repo: solana::raydium::repo::SwapRepo,
 }
@@ -0,0 +1,67 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::repo::{AddressRepo, TokenBalanceRepo, TokenPairChangeRepo, TokenRepo};
+use base::test::NeverCalledTokenInfoLoader;
+use indexer::solana::indexer::IndexerRepo;
+use indexer::solana::replay::replay;
+use indexer::solana::state::{State, StateInner};
+use std::path::PathBuf;
+use std::sync::Arc;
+use testing::pumpfun::{count_swaps, list_with_signature};
+use testing::run_test_with_pool_on_empty_db;
+
+#[test_log::test(sqlx::test)]
+async fn test_replay_archive_326027759() {
+    // contains the transaction of block 326027759 which creates DnLM31nU9K4kczFoAfhbU4hrK7XaAxSJfkieUqnPpump
+    run_test_with_pool_on_empty_db(|pool| async move {
+        let token_repo = TokenRepo::testing_no_token_info();
+        let state = State(Arc::new(StateInner {
+            token_repo: token_repo.clone(),
+            address_repo: AddressRepo::new(),
+            token_balance_repo: TokenBalanceRepo::new(),
+            token_pair_change_repo: TokenPairChangeRepo::new(),
+            pool: pool.clone(),
+            pumpfun_swap_repo: solana::pumpfun::repo::SwapRepo::testing(Box::new(
+                NeverCalledTokenInfoLoader {},
+            )),
+            pumpfun_current_repo: Default::default(),
+            jupiter_swap_repo: solana::jupiter::repo::SwapRepo::testing(Box::new(
+                NeverCalledTokenInfoLoader {},
+            )),
+            raydium_swap_repo: solana::raydium::repo::SwapRepo::testing(Box::new(
+                NeverCalledTokenInfoLoader {},
+            )),
+        }));
+
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/solana/archive/archive_326027759.jsonl");
+
+        let count = replay(state, path).await;
+        assert_eq!(count, 1);
+
+        let mut tx = pool.begin().await.unwrap();
+
+        let token = token_repo
+            .get_by_mint(&mut tx, "DnLM31nU9K4kczFoAfhbU4hrK7XaAxSJfkieUqnPpump")
+            .await
+            .unwrap();
+        assert_eq!(token.name.unwrap(), "The forbidden pear");
+        assert_eq!(token.symbol.unwrap(), "PEAR");
+        assert_eq!(token.block.unwrap(), 326027759);
+
+        let swaps = list_with_signature(
+            &mut tx,
+            "3ZFJMighsLmqnWYBUo7sW6GRGNj7kPy4gbYHemNaiKc3CXsTgZufiypUDBKX3V54csC3RTPj1rmm1VXmmnBY5h2P",
+        )
+        .await;
+        assert_eq!(swaps.len(), 1);
+        assert_eq!(count_swaps(&mut tx).await, 1);
+
+        // replayed blocks are taken as final
+        let indexer = IndexerRepo::default().get(&mut tx).await.unwrap();
+        assert_eq!(indexer.slot, 326027759);
+        assert_eq!(indexer.watermark.unwrap(), 326027759);
+    })
+    .await
+}
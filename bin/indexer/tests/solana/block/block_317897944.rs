@@ -3,7 +3,7 @@
 
 use base::model::solana::Slot;
 use base::model::DecimalAmount;
-use base::repo::{AddressRepo, TokenBalanceRepo, TokenRepo};
+use base::repo::{AddressRepo, TokenBalanceRepo, TokenPairChangeRepo, TokenRepo};
 use base::test::NeverCalledTokenInfoLoader;
 use common::model::{BlockTimestamp, Timestamp};
 use indexer::solana::block::index_block;
@@ -128,15 +128,18 @@ insert into solana.token (mint, name, symbol, decimals, supply, metadata, descri
 		let token_repo = TokenRepo::testing_no_token_info();
 		let pumpfun_swap_repo = solana::pumpfun::repo::SwapRepo::testing(Box::new(NeverCalledTokenInfoLoader {}));
 		let jupiter_swap_repo = solana::jupiter::repo::SwapRepo::testing(Box::new(NeverCalledTokenInfoLoader {}));
+		let raydium_swap_repo = solana::raydium::repo::SwapRepo::testing(Box::new(NeverCalledTokenInfoLoader {}));
 
 		let state = State(Arc::new(StateInner {
 			token_repo: token_repo.clone(),
 			address_repo: AddressRepo::new(),
 			token_balance_repo: TokenBalanceRepo::new(),
+			token_pair_change_repo: TokenPairChangeRepo::new(),
 			pool: pool.clone(),
 			pumpfun_swap_repo,
 			pumpfun_current_repo: Default::default(),
 			jupiter_swap_repo,
+			raydium_swap_repo,
 		}));
 
 		index_block(state, block).await;
@@ -2,7 +2,7 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use base::model::solana::Slot;
-use base::repo::{AddressRepo, TokenBalanceRepo, TokenRepo};
+use base::repo::{AddressRepo, TokenBalanceRepo, TokenPairChangeRepo, TokenRepo};
 use base::test::NeverCalledTokenInfoLoader;
 use indexer::solana::block::index_block;
 use indexer::solana::state::{State, StateInner};
@@ -99,15 +99,18 @@ async fn test_index_block_326027759() {
 		let token_repo = TokenRepo::testing_no_token_info();
 		let pumpfun_swap_repo = solana::pumpfun::repo::SwapRepo::testing(Box::new(NeverCalledTokenInfoLoader {}));
 		let jupiter_swap_repo = solana::jupiter::repo::SwapRepo::testing(Box::new(NeverCalledTokenInfoLoader {}));
+		let raydium_swap_repo = solana::raydium::repo::SwapRepo::testing(Box::new(NeverCalledTokenInfoLoader {}));
 
 		let state = State(Arc::new(StateInner {
 			token_repo: token_repo.clone(),
 			address_repo: AddressRepo::new(),
 			token_balance_repo: TokenBalanceRepo::new(),
+			token_pair_change_repo: TokenPairChangeRepo::new(),
 			pool: pool.clone(),
 			pumpfun_swap_repo,
 			pumpfun_current_repo: Default::default(),
 			jupiter_swap_repo,
+			raydium_swap_repo,
 		}));
 
 		index_block(state, block).await;
@@ -47,6 +47,8 @@ async fn prepare_test() {
             solana::pumpfun::repo::SwapRepo::testing(Box::new(rpc_loader.clone()));
         let jupiter_swap_repo =
             solana::jupiter::repo::SwapRepo::testing(Box::new(rpc_loader.clone()));
+        let raydium_swap_repo =
+            solana::raydium::repo::SwapRepo::testing(Box::new(rpc_loader.clone()));
 
         let state = State(Arc::new(StateInner {
             token_repo: TokenRepo::new(Box::new(rpc_loader.clone())),
@@ -54,8 +56,10 @@ async fn prepare_test() {
             pumpfun_swap_repo,
             pumpfun_current_repo: Default::default(),
             jupiter_swap_repo,
+            raydium_swap_repo,
             address_repo: AddressRepo::new(),
             token_balance_repo: Default::default(),
+            token_pair_change_repo: Default::default(),
         }));
 
         index_block(state, block).await;
@@ -1,7 +1,9 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use indexer::solana::indexer::IndexerRepo;
+use base::model::solana::{Commitment, Slot, SlotGap, SlotStatus};
+use common::model::BlockTimestamp;
+use indexer::solana::indexer::{IndexerRepo, SlotToInsert};
 use testing::run_test_on_empty_db;
 
 #[test_log::test(sqlx::test)]
@@ -31,3 +33,180 @@ async fn test_set_indexer_multiple_times() {
     })
     .await
 }
+
+#[test_log::test(sqlx::test)]
+async fn test_set_indexer_never_moves_backwards() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = IndexerRepo::default();
+
+        test_instance.set(&mut tx, 100).await.unwrap();
+        test_instance.set(&mut tx, 42).await.unwrap();
+
+        let indexer = test_instance.get(&mut tx).await.unwrap();
+        assert_eq!(indexer.slot, 100);
+    })
+    .await
+}
+
+fn indexed(slots: &[i64]) -> Vec<SlotToInsert> {
+    slots
+        .iter()
+        .map(|slot| SlotToInsert::indexed(Slot(*slot), BlockTimestamp::now()))
+        .collect()
+}
+
+fn skipped(slots: &[i64]) -> Vec<SlotToInsert> {
+    slots
+        .iter()
+        .map(|slot| SlotToInsert::skipped(Slot(*slot)))
+        .collect()
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_gaps() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = IndexerRepo::default();
+
+        test_instance
+            .insert_slots(&mut tx, indexed(&[12, 13, 17]))
+            .await
+            .unwrap();
+        test_instance
+            .insert_slots(&mut tx, skipped(&[14]))
+            .await
+            .unwrap();
+
+        let result = test_instance.list_gaps(&mut tx, 10, 20).await.unwrap();
+        assert_eq!(
+            result,
+            vec![
+                SlotGap {
+                    first: Slot(10),
+                    last: Slot(11)
+                },
+                SlotGap {
+                    first: Slot(15),
+                    last: Slot(16)
+                },
+                SlotGap {
+                    first: Slot(18),
+                    last: Slot(20)
+                },
+            ]
+        );
+
+        let result = test_instance.list_gaps(&mut tx, 12, 14).await.unwrap();
+        assert_eq!(result, vec![]);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_unfinalized() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = IndexerRepo::default();
+
+        test_instance
+            .insert_slots(&mut tx, indexed(&[10, 12, 13]))
+            .await
+            .unwrap();
+        test_instance
+            .insert_slots(&mut tx, skipped(&[11]))
+            .await
+            .unwrap();
+        test_instance
+            .finalize_slots(&mut tx, [Slot(10)], SlotStatus::Indexed)
+            .await
+            .unwrap();
+
+        let result = test_instance.list_unfinalized(&mut tx, 12).await.unwrap();
+        assert_eq!(result.len(), 2);
+
+        assert_eq!(result[0].slot, 11);
+        assert_eq!(result[0].status, SlotStatus::Skipped);
+        assert_eq!(result[0].commitment, Commitment::Confirmed);
+        assert_eq!(result[0].timestamp, None);
+
+        assert_eq!(result[1].slot, 12);
+        assert_eq!(result[1].status, SlotStatus::Indexed);
+        assert_eq!(result[1].commitment, Commitment::Confirmed);
+        assert!(result[1].timestamp.is_some());
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_finalize_dropped_slot() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = IndexerRepo::default();
+
+        test_instance
+            .insert_slots(&mut tx, indexed(&[10]))
+            .await
+            .unwrap();
+        test_instance
+            .finalize_slots(&mut tx, [Slot(10)], SlotStatus::Skipped)
+            .await
+            .unwrap();
+
+        let result = test_instance.list_unfinalized(&mut tx, 10).await.unwrap();
+        assert_eq!(result, vec![]);
+
+        let result = test_instance.list_gaps(&mut tx, 10, 10).await.unwrap();
+        assert_eq!(result, vec![]);
+
+        let result = test_instance.update_watermark(&mut tx).await.unwrap();
+        assert_eq!(result, Some(Slot(10)));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_update_watermark() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = IndexerRepo::default();
+
+        let result = test_instance.update_watermark(&mut tx).await.unwrap();
+        assert_eq!(result, None);
+
+        test_instance.set(&mut tx, 13).await.unwrap();
+        test_instance
+            .insert_slots(&mut tx, indexed(&[10, 11, 13]))
+            .await
+            .unwrap();
+
+        // confirmed slots do not count towards the watermark
+        let result = test_instance.update_watermark(&mut tx).await.unwrap();
+        assert_eq!(result, None);
+
+        test_instance
+            .finalize_slots(&mut tx, [Slot(10), Slot(11), Slot(13)], SlotStatus::Indexed)
+            .await
+            .unwrap();
+
+        let result = test_instance.update_watermark(&mut tx).await.unwrap();
+        assert_eq!(result, Some(Slot(11)));
+
+        // skipped slot closes the gap once it got finalized
+        test_instance
+            .insert_slots(&mut tx, skipped(&[12]))
+            .await
+            .unwrap();
+
+        let result = test_instance.update_watermark(&mut tx).await.unwrap();
+        assert_eq!(result, Some(Slot(11)));
+
+        test_instance
+            .finalize_slots(&mut tx, [Slot(12)], SlotStatus::Skipped)
+            .await
+            .unwrap();
+
+        let result = test_instance.update_watermark(&mut tx).await.unwrap();
+        assert_eq!(result, Some(Slot(13)));
+
+        let indexer = test_instance.get(&mut tx).await.unwrap();
+        assert_eq!(indexer.slot, 13);
+        assert_eq!(indexer.watermark, Some(Slot(13)));
+    })
+    .await
+}
@@ -1,5 +1,6 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+mod archive;
 mod block;
 mod indexer;
@@ -4,17 +4,21 @@
 use crate::callback::action_button::action_button;
 use crate::callback::close::close_button;
 use crate::callback::refresh::refresh_button;
-use crate::{AppState};
-use base::model::{TelegramActionButtonConfig, TokenPairId};
+use crate::callback::rule::rule_button;
+use crate::{AppState, MessageDialogue};
+use base::model::{RuleId, RuleName, RuleStatus, TelegramActionButtonConfig, TokenPairId};
+pub use rule::RuleTemplate;
 pub use store::CallbackStore;
 use teloxide::payloads::EditMessageReplyMarkupSetters;
 use teloxide::prelude::CallbackQuery;
 use teloxide::prelude::Requester;
 use teloxide::types::InlineKeyboardMarkup;
+use teloxide::Bot;
 
 mod action_button;
 mod close;
 mod refresh;
+mod rule;
 mod store;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -22,6 +26,7 @@ pub enum Callback {
     ActionButton(CallbackActionButton),
     Close,
     Refresh(CallbackRefresh),
+    Rule(CallbackRule),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -29,6 +34,27 @@ pub enum CallbackRefresh {
     PumpfunSummary { pair: TokenPairId },
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub enum CallbackRule {
+    List,
+    Create,
+    Template {
+        name: RuleName,
+        template: RuleTemplate,
+    },
+    Show {
+        rule: RuleId,
+    },
+    Status {
+        rule: RuleId,
+        status: RuleStatus,
+    },
+    Threshold {
+        rule: RuleId,
+        condition: usize,
+    },
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct CallbackActionButton {
     pub config: TelegramActionButtonConfig,
@@ -39,7 +65,12 @@ pub type CallbackResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
 pub const IGNORE_CALLBACK: &str = "@!IGNORE!@";
 
-pub(crate) async fn callback(state: AppState, query: CallbackQuery) -> CallbackResult {
+pub(crate) async fn callback(
+    bot: Bot,
+    state: AppState,
+    dialogue: MessageDialogue,
+    query: CallbackQuery,
+) -> CallbackResult {
     if let Some(data) = query.data.as_ref() {
         if data == IGNORE_CALLBACK {
             state.bot.answer_callback_query(query.id.clone()).await?;
@@ -51,6 +82,15 @@ pub(crate) async fn callback(state: AppState, query: CallbackQuery) -> CallbackR
                 Callback::ActionButton(cb) => action_button(state, cb, query).await?,
                 Callback::Close => close_button(state, query).await?,
                 Callback::Refresh(callback) => refresh_button(state, callback, query).await?,
+                Callback::Rule(callback) => {
+                    // a template creates a new rule, so its button is handled only once
+                    if matches!(callback, CallbackRule::Template { .. })
+                        && state.callback_store.pop(data).await.is_none()
+                    {
+                        return Ok(());
+                    }
+                    rule_button(bot, state, dialogue, callback, query).await?
+                }
             }
         } else if let Some(msg) = &query.message {
             let bot = state.bot.clone();
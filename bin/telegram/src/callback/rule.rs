@@ -0,0 +1,188 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::callback::{CallbackResult, CallbackRule};
+use crate::message::{describe, send_rule, send_rules, thresholds};
+use crate::{AppState, MessageDialogue, MessageState};
+use base::model::Condition::{And, Compare};
+use base::model::Field::{CurveProgress, MarketCap, SwapAll};
+use base::model::Operator::MoreThan;
+use base::model::{Action, RuleStatus, Sequence, Value};
+use base::service::{RuleCreateCmd, RuleUpdateCmd};
+use common::model::Timeframe;
+use teloxide::dispatching::dialogue::GetChatId;
+use teloxide::payloads::EditMessageReplyMarkupSetters;
+use teloxide::prelude::{CallbackQuery, Requester};
+use teloxide::types::InlineKeyboardMarkup;
+use teloxide::Bot;
+
+/// Starting points for rules created from within telegram, the thresholds can be edited afterward
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RuleTemplate {
+    CurveProgress,
+    MarketCap,
+    SwapCount,
+}
+
+impl RuleTemplate {
+    pub(crate) fn all() -> [RuleTemplate; 3] {
+        [
+            RuleTemplate::CurveProgress,
+            RuleTemplate::MarketCap,
+            RuleTemplate::SwapCount,
+        ]
+    }
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            RuleTemplate::CurveProgress => "Bonding curve progress",
+            RuleTemplate::MarketCap => "Market cap",
+            RuleTemplate::SwapCount => "Swap count",
+        }
+    }
+
+    pub(crate) fn sequence(&self) -> Sequence {
+        let condition = match self {
+            RuleTemplate::CurveProgress => Compare {
+                field: CurveProgress,
+                operator: MoreThan,
+                value: Some(Value::percent(50.0)),
+                timeframe: None,
+            },
+            RuleTemplate::MarketCap => Compare {
+                field: MarketCap,
+                operator: MoreThan,
+                value: Some(Value::usd(50_000)),
+                timeframe: None,
+            },
+            RuleTemplate::SwapCount => Compare {
+                field: SwapAll,
+                operator: MoreThan,
+                value: Some(Value::count(50)),
+                timeframe: Some(Timeframe::M5),
+            },
+        };
+
+        Sequence {
+            condition: And {
+                conditions: vec![condition],
+            },
+            action: Action::NotifyTelegram { buttons: vec![] },
+        }
+    }
+}
+
+pub(crate) async fn rule_button(
+    bot: Bot,
+    state: AppState,
+    dialogue: MessageDialogue,
+    callback: CallbackRule,
+    query: CallbackQuery,
+) -> CallbackResult {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let Some(chat_id) = query.chat_id() else {
+        return Ok(());
+    };
+
+    let user = state.authenticated_user(query.from.id.0 as i64).await?;
+
+    match callback {
+        CallbackRule::List => {
+            let rules = state.rule_service().list_user(user.id).await?;
+            send_rules(&bot, &state, chat_id, &rules).await?;
+        }
+        CallbackRule::Create => {
+            dialogue.update(MessageState::RuleName).await?;
+            bot.send_message(
+                chat_id,
+                "Send me the name of your new rule or /cancel to abort",
+            )
+            .await?;
+        }
+        CallbackRule::Template { name, template } => {
+            // the other templates of the keyboard would create further rules with the same name
+            if let Some(msg) = &query.message {
+                bot.edit_message_reply_markup(msg.chat().id, msg.id())
+                    .reply_markup(InlineKeyboardMarkup::default())
+                    .await?;
+            }
+
+            let rule = state
+                .rule_service()
+                .create(
+                    RuleCreateCmd {
+                        name,
+                        sequence: template.sequence(),
+                    },
+                    user,
+                )
+                .await?;
+
+            send_rule(&bot, &state, chat_id, &rule).await?;
+        }
+        CallbackRule::Show { rule } => {
+            let rule = state.rule_service().get_by_id_user(rule, user.id).await?;
+            send_rule(&bot, &state, chat_id, &rule).await?;
+        }
+        CallbackRule::Status { rule, status } => {
+            let rule = state
+                .rule_service()
+                .update(
+                    rule,
+                    RuleUpdateCmd {
+                        name: None,
+                        sequence: None,
+                        status: Some(status),
+                        limit: None,
+                    },
+                    user.clone(),
+                )
+                .await?;
+
+            if status == RuleStatus::Archived {
+                bot.send_message(chat_id, format!("Archived {}", rule.name.0))
+                    .await?;
+                let rules = state.rule_service().list_user(user.id).await?;
+                send_rules(&bot, &state, chat_id, &rules).await?;
+            } else {
+                send_rule(&bot, &state, chat_id, &rule).await?;
+            }
+        }
+        CallbackRule::Threshold { rule, condition } => {
+            let rule = state.rule_service().get_by_id_user(rule, user.id).await?;
+
+            if let Some(threshold) = thresholds(&rule.sequence.condition).get(condition) {
+                dialogue
+                    .update(MessageState::RuleThreshold {
+                        rule: rule.id,
+                        condition,
+                    })
+                    .await?;
+
+                bot.send_message(
+                    chat_id,
+                    format!(
+                        "Currently: {}\nSend me the new value or /cancel to abort",
+                        describe(threshold)
+                    ),
+                )
+                .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::callback::RuleTemplate;
+
+    #[test]
+    fn test_templates_are_valid() {
+        for template in RuleTemplate::all() {
+            assert_eq!(template.sequence().validate(), vec![]);
+        }
+    }
+}
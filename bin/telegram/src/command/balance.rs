@@ -2,13 +2,27 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::command::CommandResult;
+use crate::AppState;
+use base::service::{WalletBalances, WalletTokenBalance};
+use bigdecimal::{BigDecimal, RoundingMode};
 use teloxide::payloads::SendMessageSetters;
 use teloxide::prelude::{Message, Requester};
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, WebAppInfo};
 use teloxide::Bot;
 use url::Url;
 
-pub(crate) async fn balance(bot: Bot, msg: Message) -> CommandResult {
+pub(crate) async fn balance(bot: Bot, msg: Message, state: AppState) -> CommandResult {
+    let Some(from) = &msg.from else {
+        return Ok(());
+    };
+
+    let user = state.authenticated_user(from.id.0 as i64).await?;
+    let wallet = state.user_service().get_wallet(user.id).await?;
+    let balances = state
+        .transfer_service()
+        .list_balances(wallet.public_key)
+        .await?;
+
     let keyboard = InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::web_app(
         "📊 Show details",
         WebAppInfo {
@@ -16,16 +30,59 @@ pub(crate) async fn balance(bot: Bot, msg: Message) -> CommandResult {
         },
     )]]);
 
-    bot.send_message(
-        msg.chat.id,
-        r#"
-Your balance is: $133.70
-====================================
-Sol:    10 ($     230)
-BTC:    23 ($ 214.413)
-"#,
-    )
-    .reply_markup(keyboard)
-    .await?;
+    bot.send_message(msg.chat.id, render(&balances))
+        .reply_markup(keyboard)
+        .await?;
     Ok(())
 }
+
+fn render(balances: &WalletBalances) -> String {
+    let mut lines = Vec::with_capacity(balances.tokens.len() + 3);
+
+    lines.push(format!(
+        "Your balance is: {}",
+        value(balances, &balances.total())
+    ));
+    lines.push("====================================".to_string());
+    lines.push(format!(
+        "SOL: {} ({})",
+        amount(&balances.sol.0),
+        value(balances, &balances.sol.0)
+    ));
+
+    for token in &balances.tokens {
+        lines.push(match token.value() {
+            Some(sol) => format!(
+                "{}: {} ({})",
+                symbol(token),
+                amount(&token.balance.0),
+                value(balances, &sol)
+            ),
+            None => format!("{}: {} (no price)", symbol(token), amount(&token.balance.0)),
+        });
+    }
+
+    lines.join("\n")
+}
+
+/// Value in USD, or in SOL while no SOL price is known
+fn value(balances: &WalletBalances, sol: &BigDecimal) -> String {
+    match balances.to_usd(sol) {
+        Some(usd) => format!("${}", usd.with_scale_round(2, RoundingMode::HalfUp)),
+        None => format!("{} SOL", amount(sol)),
+    }
+}
+
+fn amount(value: &BigDecimal) -> String {
+    value
+        .with_scale_round(4, RoundingMode::HalfUp)
+        .normalized()
+        .to_plain_string()
+}
+
+fn symbol(token: &WalletTokenBalance) -> String {
+    match &token.token.symbol {
+        Some(symbol) => symbol.to_string(),
+        None => token.token.mint.to_string(),
+    }
+}
@@ -0,0 +1,18 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::command::CommandResult;
+use crate::{MessageDialogue, MessageState};
+use teloxide::prelude::{Message, Requester};
+use teloxide::Bot;
+
+pub(crate) async fn cancel(bot: Bot, msg: Message, dialogue: MessageDialogue) -> CommandResult {
+    let text = match dialogue.get().await? {
+        Some(MessageState::Main) | None => "Nothing to cancel",
+        Some(_) => "Cancelled",
+    };
+
+    dialogue.update(MessageState::Main).await?;
+    bot.send_message(msg.chat.id, text).await?;
+    Ok(())
+}
@@ -0,0 +1,14 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::command::CommandResult;
+use crate::schema::Command;
+use teloxide::prelude::{Message, Requester};
+use teloxide::utils::command::BotCommands;
+use teloxide::Bot;
+
+pub(crate) async fn help(bot: Bot, msg: Message) -> CommandResult {
+    bot.send_message(msg.chat.id, Command::descriptions().to_string())
+        .await?;
+    Ok(())
+}
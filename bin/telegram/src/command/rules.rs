@@ -2,20 +2,20 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::command::CommandResult;
+use crate::message::rules_keyboard;
 use crate::AppState;
 use teloxide::payloads::SendMessageSetters;
 use teloxide::prelude::{Message, Requester};
-use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, WebAppInfo};
 use teloxide::Bot;
-use url::Url;
 
-pub(crate) async fn rules(bot: Bot, msg: Message, _state: AppState) -> CommandResult {
-    let options = InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::web_app(
-        "Open rules".to_string(),
-        WebAppInfo {
-            url: Url::parse("https://telegram.nyan.bot/rules").unwrap(),
-        },
-    )]]);
+pub(crate) async fn rules(bot: Bot, msg: Message, state: AppState) -> CommandResult {
+    let Some(from) = &msg.from else {
+        return Ok(());
+    };
+
+    let user = state.authenticated_user(from.id.0 as i64).await?;
+    let rules = state.rule_service().list_user(user.id).await?;
+    let options = rules_keyboard(&state, &rules).await;
 
     bot.send_message(
         msg.chat.id,
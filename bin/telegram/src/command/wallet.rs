@@ -0,0 +1,37 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::command::CommandResult;
+use crate::markdown;
+use crate::state::AppState;
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::{Message, Requester};
+use teloxide::types::ParseMode;
+use teloxide::Bot;
+
+pub(crate) async fn wallet(bot: Bot, msg: Message, state: AppState) -> CommandResult {
+    if let Some(user) = msg.from {
+        if !user.is_bot {
+            let (_user, wallet, _created) = state
+                .user_service()
+                .get_or_create_telegram_user(user.id.0 as i64)
+                .await?;
+
+            let address = wallet.public_key.to_string();
+
+            bot.send_message(
+                msg.chat.id,
+                markdown!(
+                    r#"
+Your address:
+;`{address};` (click to copy)
+"#
+                ),
+            )
+            .parse_mode(ParseMode::MarkdownV2)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
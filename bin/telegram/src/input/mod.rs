@@ -0,0 +1,10 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+pub(crate) use rule_name::rule_name;
+pub(crate) use rule_threshold::rule_threshold;
+
+mod rule_name;
+mod rule_threshold;
+
+pub type InputResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
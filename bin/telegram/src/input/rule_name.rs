@@ -0,0 +1,50 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::callback::{Callback, CallbackRule, RuleTemplate};
+use crate::input::InputResult;
+use crate::{AppState, MessageDialogue, MessageState};
+use base::model::RuleName;
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::{Message, Requester};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use teloxide::Bot;
+
+pub(crate) async fn rule_name(
+    bot: Bot,
+    msg: Message,
+    state: AppState,
+    dialogue: MessageDialogue,
+) -> InputResult {
+    let name = msg.text().map(str::trim).unwrap_or_default();
+
+    if name.is_empty() || name.starts_with('/') {
+        bot.send_message(
+            msg.chat.id,
+            "Send me the name of your new rule or /cancel to abort",
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let mut keyboard = Vec::with_capacity(RuleTemplate::all().len());
+    for template in RuleTemplate::all() {
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            template.label(),
+            state
+                .callback_store
+                .store(Callback::Rule(CallbackRule::Template {
+                    name: RuleName::from(name),
+                    template,
+                }))
+                .await,
+        )]);
+    }
+
+    dialogue.update(MessageState::Main).await?;
+
+    bot.send_message(msg.chat.id, format!("Choose a template for {name}"))
+        .reply_markup(InlineKeyboardMarkup::new(keyboard))
+        .await?;
+    Ok(())
+}
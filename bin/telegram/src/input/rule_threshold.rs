@@ -0,0 +1,151 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::input::InputResult;
+use crate::message::{nth_threshold_mut, send_rule};
+use crate::{AppState, MessageDialogue, MessageState};
+use base::model::{RuleId, Value};
+use base::service::RuleUpdateCmd;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+use teloxide::prelude::{Message, Requester};
+use teloxide::Bot;
+
+pub(crate) async fn rule_threshold(
+    bot: Bot,
+    msg: Message,
+    state: AppState,
+    dialogue: MessageDialogue,
+    (rule, condition): (RuleId, usize),
+) -> InputResult {
+    let Some(from) = &msg.from else {
+        return Ok(());
+    };
+
+    let text = msg.text().map(str::trim).unwrap_or_default();
+    if text.is_empty() || text.starts_with('/') {
+        bot.send_message(msg.chat.id, "Send me the new value or /cancel to abort")
+            .await?;
+        return Ok(());
+    }
+
+    let user = state.authenticated_user(from.id.0 as i64).await?;
+    let mut rule = state.rule_service().get_by_id_user(rule, user.id).await?;
+
+    let Some(current) = nth_threshold_mut(&mut rule.sequence.condition, condition) else {
+        // the rule was changed in the meantime and the condition is gone
+        dialogue.update(MessageState::Main).await?;
+        send_rule(&bot, &state, msg.chat.id, &rule).await?;
+        return Ok(());
+    };
+
+    let Some(value) = parse_value(current, text) else {
+        bot.send_message(
+            msg.chat.id,
+            format!(
+                "Invalid {} value, send me the new value or /cancel to abort",
+                current.value_type()
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    *current = value;
+
+    let rule = state
+        .rule_service()
+        .update(
+            rule.id,
+            RuleUpdateCmd {
+                name: None,
+                sequence: Some(rule.sequence),
+                status: None,
+                limit: None,
+            },
+            user,
+        )
+        .await?;
+
+    dialogue.update(MessageState::Main).await?;
+    send_rule(&bot, &state, msg.chat.id, &rule).await?;
+    Ok(())
+}
+
+/// Parses the text as a value of the same type as the current one, a duration keeps its unit
+fn parse_value(current: &Value, text: &str) -> Option<Value> {
+    match current {
+        Value::Boolean { .. } => match text.to_lowercase().as_str() {
+            "true" | "yes" => Some(Value::boolean(true)),
+            "false" | "no" => Some(Value::boolean(false)),
+            _ => None,
+        },
+        Value::Count { .. } => text.parse::<i64>().ok().map(Value::count),
+        Value::Duration { unit, .. } => text
+            .parse::<i64>()
+            .ok()
+            .map(|value| Value::duration(value, *unit)),
+        Value::Percent { .. } => text
+            .trim_end_matches('%')
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(Value::percent),
+        Value::Quote { .. } => BigDecimal::from_str(text).ok().map(Value::quote),
+        Value::Sol { .. } => BigDecimal::from_str(text).ok().map(Value::sol),
+        Value::String { .. } => Some(Value::string(text)),
+        Value::Usd { .. } => BigDecimal::from_str(text).ok().map(Value::usd),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::rule_threshold::parse_value;
+    use base::model::Value;
+    use common::model::TimeUnit;
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            parse_value(&Value::percent(50.0), "75.5 %"),
+            Some(Value::percent(75.5))
+        );
+        assert_eq!(parse_value(&Value::percent(50.0), "NaN"), None);
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(
+            parse_value(&Value::count(50), "100"),
+            Some(Value::count(100))
+        );
+        assert_eq!(parse_value(&Value::count(50), "1.5"), None);
+    }
+
+    #[test]
+    fn test_duration_keeps_unit() {
+        assert_eq!(
+            parse_value(&Value::duration(5, TimeUnit::Minute), "10"),
+            Some(Value::duration(10, TimeUnit::Minute))
+        );
+    }
+
+    #[test]
+    fn test_usd() {
+        assert_eq!(
+            parse_value(&Value::usd(50_000), "123456.78"),
+            Some(Value::usd(bigdecimal::BigDecimal::new(12345678.into(), 2)))
+        );
+        assert_eq!(parse_value(&Value::usd(50_000), "a lot"), None);
+    }
+
+    #[test]
+    fn test_boolean() {
+        assert_eq!(
+            parse_value(&Value::boolean(false), "Yes"),
+            Some(Value::boolean(true))
+        );
+        assert_eq!(parse_value(&Value::boolean(false), "maybe"), None);
+    }
+}
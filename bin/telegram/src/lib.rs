@@ -6,8 +6,10 @@ use crate::dispatch::dispatch;
 pub use crate::notify::{notify, send_notification};
 pub use crate::schema::schema;
 pub use crate::state::*;
+use base::model::RuleId;
 use common::Signal;
 use log::info;
+use teloxide::dispatching::dialogue::{Dialogue, InMemStorage};
 use tokio::try_join;
 
 mod callback;
@@ -16,17 +18,24 @@ mod config;
 mod dispatch;
 mod format;
 mod i18n;
+mod input;
 mod message;
 mod notify;
 mod schema;
 mod state;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum MessageState {
     #[default]
     Main,
+    /// Waiting for the name of a new rule
+    RuleName,
+    /// Waiting for the new threshold of the n-th comparison of a rule
+    RuleThreshold { rule: RuleId, condition: usize },
 }
 
+pub(crate) type MessageDialogue = Dialogue<MessageState, InMemStorage<MessageState>>;
+
 pub async fn run(state: AppState, signal: Signal) {
     let _ = try_join!(
         async { notify(state.clone(), signal.clone()).await },
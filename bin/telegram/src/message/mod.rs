@@ -2,11 +2,13 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::message::MessageError::UnableToSend;
+pub(crate) use rule::*;
 use std::fmt::{Display, Formatter};
 pub(crate) use summary::*;
 use teloxide::{ApiError, RequestError};
 use MessageError::Unknown;
 
+mod rule;
 mod summary;
 
 #[derive(Debug, Clone)]
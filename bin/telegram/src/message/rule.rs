@@ -0,0 +1,301 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::callback::{Callback, CallbackRule};
+use crate::message::MessageResult;
+use crate::AppState;
+use base::model::RuleStatus::{Active, ActiveExhausted, Inactive};
+use base::model::{Condition, Rule, RuleStatus, Value};
+use serde::Serialize;
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::Requester;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, Recipient, WebAppInfo};
+use teloxide::Bot;
+use url::Url;
+
+/// Keyboard listing every rule of a user, followed by the buttons to create a new one or to open the web app
+pub(crate) async fn rules_keyboard(state: &AppState, rules: &[Rule]) -> InlineKeyboardMarkup {
+    let mut result = Vec::with_capacity(rules.len() + 2);
+
+    for rule in rules {
+        result.push(vec![InlineKeyboardButton::callback(
+            format!("{} {}", status_emoji(rule.status), rule.name.0),
+            state
+                .callback_store
+                .store(Callback::Rule(CallbackRule::Show { rule: rule.id }))
+                .await,
+        )]);
+    }
+
+    result.push(vec![InlineKeyboardButton::callback(
+        "➕ New rule",
+        state
+            .callback_store
+            .store(Callback::Rule(CallbackRule::Create))
+            .await,
+    )]);
+
+    result.push(vec![InlineKeyboardButton::web_app(
+        "Open rules".to_string(),
+        WebAppInfo {
+            url: Url::parse("https://telegram.nyan.bot/rules").unwrap(),
+        },
+    )]);
+
+    InlineKeyboardMarkup::new(result)
+}
+
+pub(crate) async fn send_rules(
+    bot: &Bot,
+    state: &AppState,
+    recipient: impl Into<Recipient>,
+    rules: &[Rule],
+) -> MessageResult<()> {
+    let text = if rules.is_empty() {
+        "You have no rules yet"
+    } else {
+        "Your rules:"
+    };
+
+    bot.send_message(recipient, text)
+        .reply_markup(rules_keyboard(state, rules).await)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn send_rule(
+    bot: &Bot,
+    state: &AppState,
+    recipient: impl Into<Recipient>,
+    rule: &Rule,
+) -> MessageResult<()> {
+    let thresholds = thresholds(&rule.sequence.condition);
+
+    let mut text = format!(
+        "{} {}\nStatus: {}\n",
+        status_emoji(rule.status),
+        rule.name.0,
+        rule.status
+    );
+
+    if !thresholds.is_empty() {
+        text.push_str("\nConditions:\n");
+        for (idx, condition) in thresholds.iter().enumerate() {
+            text.push_str(&format!("{}. {}\n", idx + 1, describe(condition)));
+        }
+    }
+
+    let mut keyboard = Vec::new();
+
+    let mut edit = Vec::with_capacity(thresholds.len());
+    for idx in 0..thresholds.len() {
+        edit.push(InlineKeyboardButton::callback(
+            format!("✏️ {}", idx + 1),
+            state
+                .callback_store
+                .store(Callback::Rule(CallbackRule::Threshold {
+                    rule: rule.id,
+                    condition: idx,
+                }))
+                .await,
+        ));
+    }
+    keyboard.extend(edit.chunks(4).map(|row| row.to_vec()));
+
+    let (toggle_text, toggle_status) = match rule.status {
+        Active | ActiveExhausted => ("⏸ Deactivate", Inactive),
+        _ => ("▶️ Activate", Active),
+    };
+
+    keyboard.push(vec![
+        InlineKeyboardButton::callback(
+            toggle_text,
+            state
+                .callback_store
+                .store(Callback::Rule(CallbackRule::Status {
+                    rule: rule.id,
+                    status: toggle_status,
+                }))
+                .await,
+        ),
+        InlineKeyboardButton::callback(
+            "🗑 Archive",
+            state
+                .callback_store
+                .store(Callback::Rule(CallbackRule::Status {
+                    rule: rule.id,
+                    status: RuleStatus::Archived,
+                }))
+                .await,
+        ),
+    ]);
+
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        "« Back",
+        state
+            .callback_store
+            .store(Callback::Rule(CallbackRule::List))
+            .await,
+    )]);
+
+    bot.send_message(recipient, text)
+        .reply_markup(InlineKeyboardMarkup::new(keyboard))
+        .await?;
+    Ok(())
+}
+
+/// Every comparison with a value, in the order they appear in the condition tree
+pub(crate) fn thresholds(condition: &Condition) -> Vec<&Condition> {
+    let mut result = Vec::new();
+    collect_thresholds(condition, &mut result);
+    result
+}
+
+fn collect_thresholds<'a>(condition: &'a Condition, result: &mut Vec<&'a Condition>) {
+    match condition {
+        Condition::Compose { condition, .. } => collect_thresholds(condition, result),
+        Condition::And { conditions }
+        | Condition::Or { conditions }
+        | Condition::AndNot { conditions } => {
+            for condition in conditions {
+                collect_thresholds(condition, result);
+            }
+        }
+        Condition::Compare { value, .. } => {
+            if value.is_some() {
+                result.push(condition)
+            }
+        }
+    }
+}
+
+/// Value of the n-th comparison, counted the same way as [`thresholds`]
+pub(crate) fn nth_threshold_mut(condition: &mut Condition, n: usize) -> Option<&mut Value> {
+    let mut remaining = n;
+    find_threshold_mut(condition, &mut remaining)
+}
+
+fn find_threshold_mut<'a>(
+    condition: &'a mut Condition,
+    remaining: &mut usize,
+) -> Option<&'a mut Value> {
+    match condition {
+        Condition::Compose { condition, .. } => find_threshold_mut(condition, remaining),
+        Condition::And { conditions }
+        | Condition::Or { conditions }
+        | Condition::AndNot { conditions } => {
+            for condition in conditions {
+                if let Some(value) = find_threshold_mut(condition, remaining) {
+                    return Some(value);
+                }
+            }
+            None
+        }
+        Condition::Compare { value, .. } => {
+            let value = value.as_mut()?;
+            if *remaining == 0 {
+                Some(value)
+            } else {
+                *remaining -= 1;
+                None
+            }
+        }
+    }
+}
+
+pub(crate) fn describe(condition: &Condition) -> String {
+    match condition {
+        Condition::Compare {
+            field,
+            operator,
+            value,
+            timeframe,
+        } => {
+            let mut result = format!("{} {}", name(field), name(operator));
+            if let Some(value) = value {
+                result.push_str(&format!(" {value}"));
+            }
+            if let Some(timeframe) = timeframe {
+                result.push_str(&format!(" ({})", name(timeframe)));
+            }
+            result
+        }
+        _ => String::new(),
+    }
+}
+
+fn status_emoji(status: RuleStatus) -> &'static str {
+    match status {
+        Active | ActiveExhausted => "🟢",
+        _ => "⚪",
+    }
+}
+
+/// Name of a field, operator or timeframe as it appears in JSON
+fn name(value: impl Serialize) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(ToString::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::message::rule::{describe, nth_threshold_mut, thresholds};
+    use base::model::Condition::{And, Compare, Compose, Or};
+    use base::model::Field::{CurveProgress, SwapAll};
+    use base::model::Operator::MoreThan;
+    use base::model::Value;
+    use common::model::Timeframe::M5;
+
+    fn condition() -> base::model::Condition {
+        And {
+            conditions: vec![
+                Compare {
+                    field: CurveProgress,
+                    operator: MoreThan,
+                    value: Some(Value::percent(50.0)),
+                    timeframe: None,
+                },
+                Compare {
+                    field: CurveProgress,
+                    operator: MoreThan,
+                    value: None,
+                    timeframe: None,
+                },
+                Or {
+                    conditions: vec![Compose {
+                        composition: "SomeComposition".into(),
+                        condition: Box::new(Compare {
+                            field: SwapAll,
+                            operator: MoreThan,
+                            value: Some(Value::count(50)),
+                            timeframe: Some(M5),
+                        }),
+                    }],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let condition = condition();
+        let result = thresholds(&condition);
+        assert_eq!(result.len(), 2);
+        assert_eq!(describe(result[0]), "CURVE_PROGRESS MORE_THAN 50 %");
+        assert_eq!(describe(result[1]), "SWAP_ALL MORE_THAN 50 (M5)");
+    }
+
+    #[test]
+    fn test_nth_threshold_mut() {
+        let mut condition = condition();
+
+        *nth_threshold_mut(&mut condition, 1).unwrap() = Value::count(100);
+        assert!(nth_threshold_mut(&mut condition, 2).is_none());
+
+        let result = thresholds(&condition);
+        assert_eq!(describe(result[0]), "CURVE_PROGRESS MORE_THAN 50 %");
+        assert_eq!(describe(result[1]), "SWAP_ALL MORE_THAN 100 (M5)");
+    }
+}
@@ -16,7 +16,6 @@ pub async fn send_pumpfun_summary(
     pair: impl Into<TokenPairId>,
     keyboard: Option<InlineKeyboardMarkup>,
 ) -> MessageResult<()> {
-
     let recipient = recipient.into();
     let pair = pair.into();
 
@@ -26,13 +25,28 @@ pub async fn send_pumpfun_summary(
     let progress = token_summary.current.progress;
     let progress = format!("{:.2}", progress);
 
-    let caption = markdown!(
+    let liquidity = state
+        .jupiter_liquidity_service()
+        .list(pair)
+        .await
+        .unwrap_or_default()
+        .iter()
+        .map(|amm| amm.to_string())
+        .collect::<Vec<_>>();
+
+    let mut caption = markdown!(
         r#"
         ;*{symbol};*
         is ;*{progress}%;* along the bonding curve and on its way to graduate to Raydium 🔥🚀
     "#
     );
 
+    if !liquidity.is_empty() {
+        let liquidity = liquidity.join(", ");
+        caption.push('\n');
+        caption.push_str(&markdown!("Liquidity: {liquidity}"));
+    }
+
     let image_path = render(|img| {
         pumpfun_summary(
             img,
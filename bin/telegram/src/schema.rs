@@ -2,7 +2,7 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::callback::callback;
-use crate::{command, MessageState};
+use crate::{command, input, MessageState};
 use teloxide::dispatching::dialogue::InMemStorage;
 use teloxide::dispatching::{dialogue, UpdateFilterExt, UpdateHandler};
 use teloxide::dptree::case;
@@ -11,29 +11,48 @@ use teloxide::prelude::Update;
 use teloxide::{dptree, filter_command};
 
 #[derive(BotCommands, Clone)]
-#[command(rename_rule = "lowercase")]
-enum Command {
+#[command(
+    rename_rule = "lowercase",
+    description = "These commands are supported:"
+)]
+pub(crate) enum Command {
+    #[command(description = "show your balance")]
     Balance,
+    #[command(description = "cancel the current operation")]
     Cancel,
+    #[command(description = "show this text")]
     Help,
+    #[command(description = "manage your rules")]
     Rules,
+    #[command(description = "start the bot")]
     Start,
+    #[command(description = "show a token")]
     Token,
+    #[command(description = "show your wallet address")]
     Wallet,
 }
 
 pub fn schema() -> UpdateHandler<Box<dyn std::error::Error + Send + Sync + 'static>> {
     use dptree::case;
 
-    let command_handler = filter_command::<Command, _>().branch(
-        case![MessageState::Main]
-            .branch(case![Command::Rules].endpoint(command::rules))
-            .branch(case![Command::Start].endpoint(command::start))
-            .branch(case![Command::Balance].endpoint(command::balance))
-            .branch(case![Command::Token].endpoint(command::token)),
-    );
+    let command_handler = filter_command::<Command, _>()
+        .branch(case![Command::Cancel].endpoint(command::cancel))
+        .branch(case![Command::Help].endpoint(command::help))
+        .branch(
+            case![MessageState::Main]
+                .branch(case![Command::Rules].endpoint(command::rules))
+                .branch(case![Command::Start].endpoint(command::start))
+                .branch(case![Command::Balance].endpoint(command::balance))
+                .branch(case![Command::Token].endpoint(command::token))
+                .branch(case![Command::Wallet].endpoint(command::wallet)),
+        );
 
-    let message_handler = Update::filter_message().branch(command_handler);
+    let message_handler = Update::filter_message()
+        .branch(command_handler)
+        .branch(case![MessageState::RuleName].endpoint(input::rule_name))
+        .branch(
+            case![MessageState::RuleThreshold { rule, condition }].endpoint(input::rule_threshold),
+        );
 
     let callback_query_handler = Update::filter_callback_query().endpoint(callback);
 
@@ -4,14 +4,19 @@
 use crate::callback::CallbackStore;
 use crate::config::Config;
 use crate::{TelegramConfig, WalletConfig};
-use base::repo::{NotificationRepo, RuleRepo, TokenPairRepo, TokenRepo};
-use base::service::{NotificationService, RuleService, TokenService, UserService};
+use base::model::AuthenticatedUser;
+use base::repo::{
+    InvocationRepo, NotificationRepo, RuleRepo, TokenPairRepo, TokenRepo, TransferRepo,
+};
+use base::service::{NotificationService, RuleService, TokenService, TransferService, UserService};
 use base::test::NeverCalledTokenInfoLoader;
 use common::crypt::SecretKey;
 use common::repo::pool::{setup_pool, PostgresConfig};
+use common::service::ServiceResult;
 use common::ConfigValue;
-use solana::pumpfun;
+use solana::jupiter::repo::LiquidityRepo;
 use solana::pumpfun::repo::{CurrentRepo, SummaryRepo};
+use solana::{jupiter, pumpfun};
 use sqlx::{Pool, Postgres};
 use std::ops::Deref;
 use std::sync::Arc;
@@ -30,6 +35,10 @@ impl AppState {
         self.service.token.clone()
     }
 
+    pub fn jupiter_liquidity_service(&self) -> jupiter::service::LiquidityService {
+        self.service.jupiter_liquidity_service.clone()
+    }
+
     pub fn pumpfun_token_service(&self) -> pumpfun::service::TokenService {
         self.service.pumpfun_token_service.clone()
     }
@@ -38,9 +47,22 @@ impl AppState {
         self.service.rule.clone()
     }
 
+    pub fn transfer_service(&self) -> TransferService {
+        self.service.transfer.clone()
+    }
+
     pub fn user_service(&self) -> UserService {
         self.service.user.clone()
     }
+
+    /// Resolves the nyanbot user behind a telegram user, creating it on first contact
+    pub async fn authenticated_user(&self, telegram_id: i64) -> ServiceResult<AuthenticatedUser> {
+        let (user, _wallet, _created) = self
+            .user_service()
+            .get_or_create_telegram_user(telegram_id)
+            .await?;
+        Ok(AuthenticatedUser { id: user.id })
+    }
 }
 
 impl Deref for AppState {
@@ -58,10 +80,12 @@ pub struct AppStateInner {
 }
 
 pub struct Service {
+    pub jupiter_liquidity_service: jupiter::service::LiquidityService,
     pub notification: NotificationService,
     pub pumpfun_token_service: pumpfun::service::TokenService,
     pub rule: RuleService,
     pub token: TokenService,
+    pub transfer: TransferService,
     pub user: UserService,
 }
 
@@ -79,6 +103,10 @@ impl AppState {
             bot,
             callback_store: CallbackStore::new(Duration::from_secs(60 * 15)),
             service: Service {
+                jupiter_liquidity_service: jupiter::service::LiquidityService::new(
+                    pool.clone(),
+                    LiquidityRepo::new(),
+                ),
                 notification: NotificationService::new(pool.clone(), NotificationRepo::new()),
                 pumpfun_token_service: pumpfun::service::TokenService::new(
                     pool.clone(),
@@ -86,8 +114,9 @@ impl AppState {
                     CurrentRepo::new(),
                     SummaryRepo::new(),
                 ),
-                rule: RuleService::new(pool.clone(), RuleRepo::new()),
+                rule: RuleService::new(pool.clone(), RuleRepo::new(), InvocationRepo::new()),
                 token: TokenService::new(pool.clone(), token_pair_repo.clone()),
+                transfer: TransferService::new(pool.clone(), token_repo, TransferRepo::new()),
                 user: UserService::new(pool, secret),
             },
         }))
@@ -115,10 +144,14 @@ impl AppState {
             bot,
             callback_store: CallbackStore::new(Duration::from_secs(1)),
             service: Service {
+                jupiter_liquidity_service: jupiter::service::LiquidityService::testing(
+                    pool.clone(),
+                ),
                 notification: NotificationService::testing(pool.clone()),
                 pumpfun_token_service: pumpfun::service::TokenService::testing(pool.clone()),
                 rule: RuleService::testing(pool.clone()),
                 token: TokenService::testing(pool.clone()),
+                transfer: TransferService::testing(pool.clone()),
                 user: UserService::new(
                     pool.clone(),
                     SecretKey::from(
@@ -0,0 +1,140 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{DecimalAmount, Mint};
+use base::repo::{AddressRepo, SolBalanceToInsert, TokenBalanceRepo, TokenBalanceToInsert};
+use common::model::Timestamp;
+use sqlx::Executor;
+use telegram::{schema, AppState, MessageState};
+use teloxide::dispatching::dialogue::InMemStorage;
+use teloxide::dptree;
+use teloxide_tests::{MockBot, MockMessageText, MockUser};
+use testing::run_test_with_pool;
+use testing::token_pair::get_or_create_token_pair;
+
+#[test_log::test(tokio::test)]
+async fn test_balance_command() {
+    run_test_with_pool(|pool| async move {
+        let state = AppState::testing(pool.clone()).await;
+
+        let user = state.authenticated_user(MockUser::ID as i64).await.unwrap();
+        let wallet = state.user_service().get_wallet(user.id).await.unwrap();
+
+        let mut tx = pool.begin().await.unwrap();
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, wallet.public_key)
+            .await
+            .unwrap();
+
+        let priced = get_or_create_token_pair(
+            &mut tx,
+            "2Yt4cyQq3ZmvGuRxb6ZgCiUQMtKgCbqbAbJXanMSpump",
+            Mint::wsol(),
+        )
+        .await;
+        let unpriced = get_or_create_token_pair(
+            &mut tx,
+            "CpV7zK77DkyVvbCgGWtWrsqvh2VonFrqPg8cecrTpump",
+            Mint::wsol(),
+        )
+        .await;
+
+        tx.execute(
+            format!(
+                r#"
+                insert into pumpfun.current (id, slot, virtual_base_reserves, virtual_quote_reserves, progress, complete, price)
+                values ({}, 1, 1, 1, 10, false, 0.00001);
+                insert into solana.sol_price_1m (timestamp, usd) values ('2025-03-20 10:00:00+00', 133.7);
+                "#,
+                priced.id.0
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+
+        let repo = TokenBalanceRepo::new();
+        repo.insert_sol_balances(
+            &mut tx,
+            vec![SolBalanceToInsert {
+                slot: 100.into(),
+                timestamp: Timestamp::now(),
+                address: address.id,
+                pre: DecimalAmount::from(0i64),
+                post: DecimalAmount::from(2i64),
+            }],
+        )
+        .await
+        .unwrap();
+        repo.insert_token_balances(
+            &mut tx,
+            vec![
+                TokenBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp: Timestamp::now(),
+                    address: address.id,
+                    token: priced.base.id,
+                    pre: DecimalAmount::from(0i64),
+                    post: DecimalAmount::from(100_000i64),
+                },
+                TokenBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp: Timestamp::now(),
+                    address: address.id,
+                    token: unpriced.base.id,
+                    pre: DecimalAmount::from(0i64),
+                    post: DecimalAmount::from(42i64),
+                },
+            ],
+        )
+        .await
+        .unwrap();
+        tx.commit().await.unwrap();
+
+        let bot = MockBot::new(MockMessageText::new().text("/balance"), schema());
+        bot.dependencies(dptree::deps![InMemStorage::<MessageState>::new(), state]);
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+
+        let priced_symbol = priced.base.symbol.unwrap();
+        let unpriced_symbol = unpriced.base.symbol.unwrap();
+        assert_eq!(
+            message.text(),
+            Some(
+                format!(
+                    r#"Your balance is: $401.10
+====================================
+SOL: 2 ($267.40)
+{priced_symbol}: 100000 ($133.70)
+{unpriced_symbol}: 42 (no price)"#
+                )
+                .as_str()
+            )
+        );
+    })
+    .await
+}
+
+#[test_log::test(tokio::test)]
+async fn test_balance_command_empty_wallet() {
+    run_test_with_pool(|pool| async move {
+        let state = AppState::testing(pool).await;
+
+        let bot = MockBot::new(MockMessageText::new().text("/balance"), schema());
+        bot.dependencies(dptree::deps![InMemStorage::<MessageState>::new(), state]);
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+
+        assert_eq!(
+            message.text(),
+            Some(
+                r#"Your balance is: 0 SOL
+====================================
+SOL: 0 (0 SOL)"#
+            )
+        );
+    })
+    .await
+}
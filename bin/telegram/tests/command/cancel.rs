@@ -0,0 +1,42 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use telegram::{schema, AppState, MessageState};
+use teloxide::dispatching::dialogue::InMemStorage;
+use teloxide::dptree;
+use teloxide_tests::{MockBot, MockMessageText};
+use testing::run_test_with_pool;
+
+#[test_log::test(tokio::test)]
+async fn test_cancel_nothing() {
+    run_test_with_pool(|pool| async move {
+        let state = AppState::testing(pool).await;
+
+        let bot = MockBot::new(MockMessageText::new().text("/cancel"), schema());
+        bot.dependencies(dptree::deps![InMemStorage::<MessageState>::new(), state]);
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+
+        assert_eq!(message.text(), Some("Nothing to cancel"));
+    })
+    .await
+}
+
+#[test_log::test(tokio::test)]
+async fn test_cancel_dialogue() {
+    run_test_with_pool(|pool| async move {
+        let state = AppState::testing(pool).await;
+
+        let bot = MockBot::new(MockMessageText::new().text("/cancel"), schema());
+        bot.dependencies(dptree::deps![InMemStorage::<MessageState>::new(), state]);
+        bot.set_state(MessageState::RuleName).await;
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+
+        assert_eq!(message.text(), Some("Cancelled"));
+        assert_eq!(bot.get_state::<MessageState>().await, MessageState::Main);
+    })
+    .await
+}
@@ -0,0 +1,37 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use telegram::{schema, AppState, MessageState};
+use teloxide::dispatching::dialogue::InMemStorage;
+use teloxide::dptree;
+use teloxide_tests::{MockBot, MockMessageText};
+use testing::run_test_with_pool;
+
+#[test_log::test(tokio::test)]
+async fn test_help_command() {
+    run_test_with_pool(|pool| async move {
+        let state = AppState::testing(pool).await;
+
+        let bot = MockBot::new(MockMessageText::new().text("/help"), schema());
+        bot.dependencies(dptree::deps![InMemStorage::<MessageState>::new(), state]);
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+
+        assert_eq!(
+            message.text(),
+            Some(
+                r#"These commands are supported:
+
+/balance — show your balance
+/cancel — cancel the current operation
+/help — show this text
+/rules — manage your rules
+/start — start the bot
+/token — show a token
+/wallet — show your wallet address"#
+            )
+        );
+    })
+    .await
+}
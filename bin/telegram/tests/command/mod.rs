@@ -1,5 +1,9 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+mod balance;
+mod cancel;
+mod help;
 mod rules;
 mod start;
+mod wallet;
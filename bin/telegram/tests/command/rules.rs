@@ -1,13 +1,41 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+use base::model::Condition::{And, Compare};
+use base::model::Field::{CurveProgress, SwapAll};
+use base::model::Operator::MoreThan;
+use base::model::{Action, RuleStatus, Sequence, Value};
+use base::service::RuleCreateCmd;
+use common::model::Timeframe;
 use telegram::{schema, AppState, MessageState};
 use teloxide::dispatching::dialogue::InMemStorage;
 use teloxide::dptree;
-use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, WebAppInfo};
-use teloxide_tests::{MockBot, MockMessageText};
+use teloxide::types::{InlineKeyboardButtonKind, InlineKeyboardMarkup, Message};
+use teloxide_tests::{MockBot, MockCallbackQuery, MockMessageText, MockUser};
 use testing::run_test_with_pool;
-use url::Url;
+
+fn button_texts(message: &Message) -> Vec<Vec<String>> {
+    message
+        .reply_markup()
+        .unwrap()
+        .inline_keyboard
+        .iter()
+        .map(|row| row.iter().map(|button| button.text.clone()).collect())
+        .collect()
+}
+
+fn callback_data(markup: &InlineKeyboardMarkup, text: &str) -> String {
+    markup
+        .inline_keyboard
+        .iter()
+        .flatten()
+        .find(|button| button.text == text)
+        .and_then(|button| match &button.kind {
+            InlineKeyboardButtonKind::CallbackData(data) => Some(data.clone()),
+            _ => None,
+        })
+        .unwrap()
+}
 
 #[test_log::test(tokio::test)]
 async fn test_rules_command() {
@@ -38,16 +66,244 @@ Remember to DYOR!
             )
         );
         assert_eq!(
-            message.reply_markup(),
-            Some(&InlineKeyboardMarkup::new(vec![vec![
-                InlineKeyboardButton::web_app(
-                    "Open rules".to_string(),
-                    WebAppInfo {
-                        url: Url::parse("https://telegram.nyan.bot/rules").unwrap(),
+            button_texts(message),
+            vec![
+                vec!["➕ New rule".to_string()],
+                vec!["Open rules".to_string()]
+            ]
+        );
+    })
+    .await
+}
+
+#[test_log::test(tokio::test)]
+async fn test_rules_command_lists_rules() {
+    run_test_with_pool(|pool| async move {
+        let state = AppState::testing(pool).await;
+
+        let user = state.authenticated_user(MockUser::ID as i64).await.unwrap();
+        state
+            .rule_service()
+            .create(
+                RuleCreateCmd {
+                    name: "Early birds".into(),
+                    sequence: Sequence {
+                        condition: And {
+                            conditions: vec![Compare {
+                                field: CurveProgress,
+                                operator: MoreThan,
+                                value: Some(Value::percent(10.0)),
+                                timeframe: None,
+                            }],
+                        },
+                        action: Action::NotifyTelegram { buttons: vec![] },
                     },
-                )
-            ]]))
+                },
+                user,
+            )
+            .await
+            .unwrap();
+
+        let bot = MockBot::new(MockMessageText::new().text("/rules"), schema());
+        bot.dependencies(dptree::deps![InMemStorage::<MessageState>::new(), state]);
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+
+        assert_eq!(
+            button_texts(message),
+            vec![
+                vec!["⚪ Early birds".to_string()],
+                vec!["➕ New rule".to_string()],
+                vec!["Open rules".to_string()]
+            ]
+        );
+    })
+    .await
+}
+
+#[test_log::test(tokio::test)]
+async fn test_rules_dialogue() {
+    run_test_with_pool(|pool| async move {
+        let state = AppState::testing(pool).await;
+
+        let bot = MockBot::new(MockMessageText::new().text("/rules"), schema());
+        bot.dependencies(dptree::deps![
+            InMemStorage::<MessageState>::new(),
+            state.clone()
+        ]);
+        bot.dispatch().await;
+        let markup = bot
+            .get_responses()
+            .sent_messages
+            .last()
+            .unwrap()
+            .reply_markup()
+            .cloned()
+            .unwrap();
+
+        // create
+        bot.update(MockCallbackQuery::new().data(callback_data(&markup, "➕ New rule")));
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+        assert_eq!(
+            message.text(),
+            Some("Send me the name of your new rule or /cancel to abort")
+        );
+
+        bot.update(MockMessageText::new().text("Hot tokens"));
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+        assert_eq!(message.text(), Some("Choose a template for Hot tokens"));
+        assert_eq!(
+            button_texts(message),
+            vec![
+                vec!["Bonding curve progress".to_string()],
+                vec!["Market cap".to_string()],
+                vec!["Swap count".to_string()]
+            ]
+        );
+
+        let markup = message.reply_markup().cloned().unwrap();
+        bot.update(MockCallbackQuery::new().data(callback_data(&markup, "Swap count")));
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+        assert_eq!(
+            message.text(),
+            Some("⚪ Hot tokens\nStatus: Inactive\n\nConditions:\n1. SWAP_ALL MORE_THAN 50 (M5)\n")
+        );
+        assert_eq!(
+            button_texts(message),
+            vec![
+                vec!["✏️ 1".to_string()],
+                vec!["▶️ Activate".to_string(), "🗑 Archive".to_string()],
+                vec!["« Back".to_string()]
+            ]
+        );
+
+        // edit threshold
+        let markup = message.reply_markup().cloned().unwrap();
+        bot.update(MockCallbackQuery::new().data(callback_data(&markup, "✏️ 1")));
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+        assert_eq!(
+            message.text(),
+            Some(
+                "Currently: SWAP_ALL MORE_THAN 50 (M5)\nSend me the new value or /cancel to abort"
+            )
+        );
+
+        bot.update(MockMessageText::new().text("many"));
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+        assert_eq!(
+            message.text(),
+            Some("Invalid COUNT value, send me the new value or /cancel to abort")
+        );
+
+        bot.update(MockMessageText::new().text("100"));
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+        assert_eq!(
+            message.text(),
+            Some(
+                "⚪ Hot tokens\nStatus: Inactive\n\nConditions:\n1. SWAP_ALL MORE_THAN 100 (M5)\n"
+            )
         );
+
+        // activate
+        let markup = message.reply_markup().cloned().unwrap();
+        bot.update(MockCallbackQuery::new().data(callback_data(&markup, "▶️ Activate")));
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+        assert_eq!(
+            message.text(),
+            Some("🟢 Hot tokens\nStatus: Active\n\nConditions:\n1. SWAP_ALL MORE_THAN 100 (M5)\n")
+        );
+
+        let user = state.authenticated_user(MockUser::ID as i64).await.unwrap();
+        let rules = state.rule_service().list_user(user.id).await.unwrap();
+        assert_eq!(rules.len(), 1);
+
+        let rule = rules.first().unwrap();
+        assert_eq!(rule.status, RuleStatus::Active);
+        assert_eq!(
+            rule.sequence.condition,
+            And {
+                conditions: vec![Compare {
+                    field: SwapAll,
+                    operator: MoreThan,
+                    value: Some(Value::count(100)),
+                    timeframe: Some(Timeframe::M5),
+                }]
+            }
+        );
+    })
+    .await
+}
+
+#[test_log::test(tokio::test)]
+async fn test_rules_archive() {
+    run_test_with_pool(|pool| async move {
+        let state = AppState::testing(pool).await;
+
+        let user = state.authenticated_user(MockUser::ID as i64).await.unwrap();
+        state
+            .rule_service()
+            .create(
+                RuleCreateCmd {
+                    name: "Old rule".into(),
+                    sequence: Sequence {
+                        condition: And { conditions: vec![] },
+                        action: Action::NotifyTelegram { buttons: vec![] },
+                    },
+                },
+                user.clone(),
+            )
+            .await
+            .unwrap();
+
+        let bot = MockBot::new(MockMessageText::new().text("/rules"), schema());
+        bot.dependencies(dptree::deps![
+            InMemStorage::<MessageState>::new(),
+            state.clone()
+        ]);
+        bot.dispatch().await;
+        let markup = bot
+            .get_responses()
+            .sent_messages
+            .last()
+            .unwrap()
+            .reply_markup()
+            .cloned()
+            .unwrap();
+
+        bot.update(MockCallbackQuery::new().data(callback_data(&markup, "⚪ Old rule")));
+        bot.dispatch().await;
+        let markup = bot
+            .get_responses()
+            .sent_messages
+            .last()
+            .unwrap()
+            .reply_markup()
+            .cloned()
+            .unwrap();
+
+        bot.update(MockCallbackQuery::new().data(callback_data(&markup, "🗑 Archive")));
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+        assert_eq!(message.text(), Some("You have no rules yet"));
+
+        let rules = state.rule_service().list_user(user.id).await.unwrap();
+        assert!(rules.is_empty());
     })
     .await
 }
@@ -0,0 +1,33 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use telegram::{schema, AppState, MessageState};
+use teloxide::dispatching::dialogue::InMemStorage;
+use teloxide::dptree;
+use teloxide_tests::{MockBot, MockMessageText};
+use testing::run_test_with_pool;
+
+#[test_log::test(tokio::test)]
+async fn test_wallet_command() {
+    run_test_with_pool(|pool| async move {
+        let state = AppState::testing(pool).await;
+
+        let bot = MockBot::new(MockMessageText::new().text("/wallet"), schema());
+        bot.dependencies(dptree::deps![
+            InMemStorage::<MessageState>::new(),
+            state.clone()
+        ]);
+        bot.dispatch().await;
+        let responses = bot.get_responses();
+        let message = responses.sent_messages.last().unwrap();
+
+        let wallet = state.user_service().get_wallet(3).await.unwrap();
+        let address = wallet.public_key.to_string();
+
+        assert_eq!(
+            message.text(),
+            Some(format!("Your address:\n`{address}` \\(click to copy\\)").as_str())
+        );
+    })
+    .await
+}
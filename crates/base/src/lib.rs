@@ -1,6 +1,8 @@
 #![cfg_attr(not(debug_assertions), deny(warnings))]
 
-use crate::model::{Amount, Decimals, Description, Mint, Name, Symbol, Uri};
+use crate::model::{
+    Amount, Decimals, Description, Mint, Name, Symbol, TelegramHandle, TwitterHandle, Uri,
+};
 use async_trait::async_trait;
 use futures_util::future::join_all;
 use log::debug;
@@ -49,4 +51,6 @@ pub struct TokenInfo {
     pub metadata: Option<Uri>,
     pub image: Option<Uri>,
     pub website: Option<Uri>,
+    pub twitter: Option<TwitterHandle>,
+    pub telegram: Option<TelegramHandle>,
 }
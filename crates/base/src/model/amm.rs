@@ -0,0 +1,149 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{Fact, PublicKey};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Known AMM programs aggregators like Jupiter route swaps through
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum Amm {
+    Lifinity,
+    Meteora,
+    MeteoraDlmm,
+    OpenBook,
+    Orca,
+    OrcaWhirlpool,
+    Phoenix,
+    PumpFun,
+    PumpSwap,
+    Raydium,
+    RaydiumClmm,
+    RaydiumCpmm,
+}
+
+const PROGRAMS: [(Amm, &str); 12] = [
+    (
+        Amm::Lifinity,
+        "2wT8Yq49kHgDzXuPxZSaeLaH1qbmGXtEyPy64bL7aD3c",
+    ),
+    (Amm::Meteora, "Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB"),
+    (
+        Amm::MeteoraDlmm,
+        "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo",
+    ),
+    (Amm::OpenBook, "opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb"),
+    (Amm::Orca, "9W959DqEETiGZocYWCQPaJ6sBmUzgfxXfqGeTEdp3aQP"),
+    (
+        Amm::OrcaWhirlpool,
+        "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc",
+    ),
+    (Amm::Phoenix, "PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY"),
+    (Amm::PumpFun, "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+    (Amm::PumpSwap, "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA"),
+    (Amm::Raydium, "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"),
+    (
+        Amm::RaydiumClmm,
+        "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK",
+    ),
+    (
+        Amm::RaydiumCpmm,
+        "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C",
+    ),
+];
+
+impl Amm {
+    /// The AMM behind a program id, none if the program is not known
+    pub fn from_program(program: &PublicKey) -> Option<Self> {
+        PROGRAMS
+            .iter()
+            .find(|(_, id)| *id == program.0)
+            .map(|(amm, _)| *amm)
+    }
+
+    pub fn program(&self) -> PublicKey {
+        PROGRAMS
+            .iter()
+            .find(|(amm, _)| amm == self)
+            .map(|(_, id)| PublicKey::from(*id))
+            .unwrap()
+    }
+
+    /// Boolean fact which tells that a token pair has liquidity on this AMM, shared by all programs of a protocol
+    pub fn fact(&self) -> Fact {
+        match self {
+            Amm::Lifinity => Fact::LiquidityLifinity,
+            Amm::Meteora | Amm::MeteoraDlmm => Fact::LiquidityMeteora,
+            Amm::OpenBook => Fact::LiquidityOpenbook,
+            Amm::Orca | Amm::OrcaWhirlpool => Fact::LiquidityOrca,
+            Amm::Phoenix => Fact::LiquidityPhoenix,
+            Amm::PumpFun | Amm::PumpSwap => Fact::LiquidityPumpfun,
+            Amm::Raydium | Amm::RaydiumClmm | Amm::RaydiumCpmm => Fact::LiquidityRaydium,
+        }
+    }
+}
+
+impl Display for Amm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Amm::Lifinity => f.write_str("Lifinity"),
+            Amm::Meteora => f.write_str("Meteora"),
+            Amm::MeteoraDlmm => f.write_str("Meteora DLMM"),
+            Amm::OpenBook => f.write_str("OpenBook"),
+            Amm::Orca => f.write_str("Orca"),
+            Amm::OrcaWhirlpool => f.write_str("Orca Whirlpool"),
+            Amm::Phoenix => f.write_str("Phoenix"),
+            Amm::PumpFun => f.write_str("pump.fun"),
+            Amm::PumpSwap => f.write_str("PumpSwap"),
+            Amm::Raydium => f.write_str("Raydium"),
+            Amm::RaydiumClmm => f.write_str("Raydium CLMM"),
+            Amm::RaydiumCpmm => f.write_str("Raydium CPMM"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Amm, Fact, PublicKey};
+
+    #[test]
+    fn test_from_program() {
+        assert_eq!(
+            Amm::from_program(&PublicKey::from(
+                "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc"
+            )),
+            Some(Amm::OrcaWhirlpool)
+        );
+        assert_eq!(
+            Amm::from_program(&PublicKey::from(
+                "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"
+            )),
+            Some(Amm::Raydium)
+        );
+    }
+
+    #[test]
+    fn test_from_program_unknown() {
+        assert_eq!(
+            Amm::from_program(&PublicKey::from(
+                "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn test_program() {
+        assert_eq!(
+            Amm::MeteoraDlmm.program(),
+            "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo"
+        );
+    }
+
+    #[test]
+    fn test_fact() {
+        assert_eq!(Amm::Raydium.fact(), Fact::LiquidityRaydium);
+        assert_eq!(Amm::RaydiumClmm.fact(), Fact::LiquidityRaydium);
+        assert_eq!(Amm::OrcaWhirlpool.fact(), Fact::LiquidityOrca);
+    }
+}
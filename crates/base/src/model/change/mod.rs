@@ -0,0 +1,201 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{Fact, TokenPairId};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Reported by the indexer or aggregator whenever something about a token pair changed,
+/// so that the engine only has to evaluate the rules affected by it
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenPairChange {
+    pub token_pair: TokenPairId,
+    pub kind: ChangeKind,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize, sqlx::Type)]
+#[repr(i16)]
+pub enum ChangeKind {
+    /// A swap was indexed, which updates the current state of the token pair
+    Swap = 1,
+    /// The aggregated summaries of the token pair were recalculated
+    Summary = 2,
+    /// The Jupiter candles of the token pair were recalculated
+    Candle = 3,
+    /// The bonding curve of the token pair completed and got migrated away from pump.fun
+    Graduation = 4,
+    /// The metadata of the base token got resolved, which might link social accounts
+    Metadata = 5,
+}
+
+impl ChangeKind {
+    /// Facts which might have a different value after a change of this kind
+    pub fn facts(&self) -> Vec<Fact> {
+        match self {
+            ChangeKind::Swap => vec![
+                Fact::AgeBaseDuration,
+                Fact::AgeQuoteDuration,
+                Fact::CurveProgressPercent,
+                Fact::CurveProgressAgeDuration,
+                Fact::MarketCapQuote,
+                Fact::MarketCapSol,
+                Fact::MarketCapUsd,
+                Fact::PriceQuote,
+                Fact::PriceUsd,
+                Fact::VenuePumpfun,
+            ],
+            ChangeKind::Summary => vec![
+                Fact::CurveProgressOpenPercentAggregate,
+                Fact::CurveProgressHighPercentAggregate,
+                Fact::CurveProgressLowPercentAggregate,
+                Fact::CurveProgressClosePercentAggregate,
+                Fact::CurveProgressAvgPercentAggregate,
+                Fact::CurveProgressChangePercentAggregate,
+                Fact::MarketCapQuoteAggregate,
+                Fact::MarketCapSolAggregate,
+                Fact::MarketCapUsdAggregate,
+                Fact::MarketCapChangeQuoteAggregate,
+                Fact::MarketCapChangeUsdAggregate,
+                Fact::MarketCapChangePercentAggregate,
+                Fact::PriceOpenQuoteAggregate,
+                Fact::PriceOpenUsdAggregate,
+                Fact::PriceHighQuoteAggregate,
+                Fact::PriceHighUsdAggregate,
+                Fact::PriceLowQuoteAggregate,
+                Fact::PriceLowUsdAggregate,
+                Fact::PriceCloseQuoteAggregate,
+                Fact::PriceCloseUsdAggregate,
+                Fact::PriceAvgQuoteAggregate,
+                Fact::PriceAvgUsdAggregate,
+                Fact::PriceChangeQuoteAggregate,
+                Fact::PriceChangeUsdAggregate,
+                Fact::PriceChangePercentAggregate,
+                Fact::SwapAllCountAggregate,
+                Fact::SwapBuyCountAggregate,
+                Fact::SwapSellCountAggregate,
+                Fact::SwapAllChangeAggregate,
+                Fact::SwapBuyChangeAggregate,
+                Fact::SwapSellChangeAggregate,
+                Fact::SwapAllPercentAggregate,
+                Fact::SwapBuyPercentAggregate,
+                Fact::SwapSellPercentAggregate,
+                Fact::VolumeQuoteAggregate,
+                Fact::VolumeUsdAggregate,
+                Fact::VolumeBuyQuoteAggregate,
+                Fact::VolumeBuyUsdAggregate,
+                Fact::VolumeSellQuoteAggregate,
+                Fact::VolumeSellUsdAggregate,
+                Fact::VolumeChangeQuoteAggregate,
+                Fact::VolumeChangeUsdAggregate,
+                Fact::VolumeChangePercentAggregate,
+                Fact::VolumeBuyChangeQuoteAggregate,
+                Fact::VolumeBuyChangeUsdAggregate,
+                Fact::VolumeBuyChangePercentAggregate,
+                Fact::VolumeSellChangeQuoteAggregate,
+                Fact::VolumeSellChangeUsdAggregate,
+                Fact::VolumeSellChangePercentAggregate,
+                Fact::VolumeSellBuyRatioPercentAggregate,
+                Fact::VenuePumpfun,
+            ],
+            ChangeKind::Candle => vec![
+                Fact::AgeBaseDuration,
+                Fact::AgeQuoteDuration,
+                Fact::MarketCapQuote,
+                Fact::MarketCapUsd,
+                Fact::MarketCapQuoteAggregate,
+                Fact::MarketCapSolAggregate,
+                Fact::MarketCapUsdAggregate,
+                Fact::PriceQuote,
+                Fact::PriceUsd,
+                Fact::PriceAvgQuoteAggregate,
+                Fact::PriceAvgUsdAggregate,
+                Fact::SwapAllCountAggregate,
+                Fact::SwapBuyCountAggregate,
+                Fact::SwapSellCountAggregate,
+                Fact::SwapAllChangeAggregate,
+                Fact::SwapBuyChangeAggregate,
+                Fact::SwapSellChangeAggregate,
+                Fact::SwapAllPercentAggregate,
+                Fact::SwapBuyPercentAggregate,
+                Fact::SwapSellPercentAggregate,
+                Fact::VolumeChangeQuoteAggregate,
+                Fact::VenueJupiter,
+                Fact::LiquidityLifinity,
+                Fact::LiquidityMeteora,
+                Fact::LiquidityOpenbook,
+                Fact::LiquidityOrca,
+                Fact::LiquidityPhoenix,
+                Fact::LiquidityPumpfun,
+                Fact::LiquidityRaydium,
+            ],
+            ChangeKind::Graduation => vec![
+                Fact::CurveProgressPercent,
+                Fact::Graduated,
+                Fact::GraduationAgeDuration,
+                Fact::VenuePumpfun,
+            ],
+            ChangeKind::Metadata => vec![
+                Fact::TelegramGroup,
+                Fact::TelegramGroupHandle,
+                Fact::TwitterAccount,
+                Fact::TwitterAccountHandle,
+            ],
+        }
+    }
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangeKind::Swap => f.write_str("Swap"),
+            ChangeKind::Summary => f.write_str("Summary"),
+            ChangeKind::Candle => f.write_str("Candle"),
+            ChangeKind::Graduation => f.write_str("Graduation"),
+            ChangeKind::Metadata => f.write_str("Metadata"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{ChangeKind, Fact};
+
+    #[test]
+    fn test_summary_facts_have_timeframe() {
+        for fact in ChangeKind::Summary.facts() {
+            if fact != Fact::VenuePumpfun {
+                assert!(fact.has_timeframe(), "{fact:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_candle_facts_include_jupiter_venue() {
+        let facts = ChangeKind::Candle.facts();
+        assert!(facts.contains(&Fact::VenueJupiter));
+        assert!(!facts.contains(&Fact::VenuePumpfun));
+    }
+
+    #[test]
+    fn test_graduation_facts_include_graduated() {
+        let facts = ChangeKind::Graduation.facts();
+        assert!(facts.contains(&Fact::Graduated));
+        assert!(facts.contains(&Fact::GraduationAgeDuration));
+    }
+
+    #[test]
+    fn test_metadata_facts_include_socials() {
+        let facts = ChangeKind::Metadata.facts();
+        assert!(facts.contains(&Fact::TelegramGroup));
+        assert!(facts.contains(&Fact::TelegramGroupHandle));
+        assert!(facts.contains(&Fact::TwitterAccount));
+        assert!(facts.contains(&Fact::TwitterAccountHandle));
+    }
+
+    #[test]
+    fn test_swap_facts_without_timeframe() {
+        for fact in ChangeKind::Swap.facts() {
+            assert!(!fact.has_timeframe(), "{fact:?}");
+        }
+    }
+}
@@ -1,18 +1,30 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use common::model::CreatedAt;
+use common::model::{CreatedAt, Timestamp};
 
 use crate::model::{Sequence, RuleId, TokenPairId, UserId};
 pub use id::*;
 
 mod id;
 
+#[derive(Debug, Clone)]
 pub struct Invocation {
     pub id: InvocationId,
     pub rule: RuleId,
     pub token_pair: TokenPairId,
     pub user: UserId,
+    /// Position within a multi-step sequence, the initial match is stage 0
+    pub stage: i16,
     pub next: Option<Sequence>,
+    /// The next sequence gets dropped if its condition did not match until then
+    pub deadline: Option<Timestamp>,
     pub created_at: CreatedAt,
 }
+
+impl Invocation {
+    /// Whether the next sequence waited longer than allowed
+    pub fn expired(&self, timestamp: Timestamp) -> bool {
+        self.deadline.is_some_and(|deadline| deadline < timestamp)
+    }
+}
@@ -2,7 +2,9 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 pub use address::*;
+pub use amm::Amm;
 pub use auth::*;
+pub use change::*;
 pub use invocation::*;
 pub use key::*;
 pub use notification::*;
@@ -10,12 +12,16 @@ pub use rule::*;
 pub use sequence::*;
 pub use swap::*;
 pub use token::*;
+pub use trade::*;
+pub use transfer::*;
 pub use user::*;
 pub use venue::Venue;
 pub use wallet::*;
 
 mod address;
+mod amm;
 mod auth;
+mod change;
 mod invocation;
 mod key;
 mod notification;
@@ -24,6 +30,8 @@ mod sequence;
 pub mod solana;
 mod swap;
 mod token;
+mod trade;
+mod transfer;
 mod user;
 mod venue;
 mod wallet;
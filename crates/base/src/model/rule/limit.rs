@@ -0,0 +1,165 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::RuleRearm;
+use common::model::{Count, Timestamp};
+use serde::{Deserialize, Serialize};
+
+/// Restricts how often and when a rule gets invoked
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleLimit {
+    /// Number of token pairs the rule gets invoked for, before it becomes exhausted. Unlimited if absent
+    pub max_invocations: Option<i32>,
+    /// Seconds which need to pass before the rule gets invoked for the same token pair again
+    pub cooldown_seconds: Option<i64>,
+    /// Whether the rule gets invoked again for a token pair it was already invoked for
+    #[serde(default)]
+    pub rearm: RuleRearm,
+    /// Number of times the rule gets invoked for the same token pair. Unlimited if absent
+    pub max_invocations_per_token_pair: Option<i32>,
+    /// The rule does not get invoked before
+    pub start_at: Option<Timestamp>,
+    /// The rule does not get invoked after
+    pub end_at: Option<Timestamp>,
+}
+
+impl RuleLimit {
+    /// Whether the rule already got invoked as often as allowed
+    pub fn exhausted(&self, invocations: Count) -> bool {
+        self.max_invocations
+            .map(|max| invocations.0 >= max as i64)
+            .unwrap_or(false)
+    }
+
+    /// Whether the timestamp lies within the start and end of the rule
+    pub fn scheduled(&self, timestamp: Timestamp) -> bool {
+        self.start_at.is_none_or(|start| start <= timestamp)
+            && self.end_at.is_none_or(|end| timestamp <= end)
+    }
+
+    /// Whether the cooldown passed between the last invocation for a token pair and the timestamp
+    pub fn cooled_down(&self, last_invocation: Timestamp, timestamp: Timestamp) -> bool {
+        self.cooldown_seconds.is_none_or(|cooldown| {
+            timestamp.to_epoch_seconds() - last_invocation.to_epoch_seconds() >= cooldown
+        })
+    }
+
+    /// Whether the rule gets invoked again for a token pair, which it was invoked for `invocations` times already.
+    /// Edge triggered rules additionally require their condition to stop matching in between, which is up to the caller
+    pub fn rearmed(
+        &self,
+        invocations: Count,
+        last_invocation: Timestamp,
+        timestamp: Timestamp,
+    ) -> bool {
+        if invocations.0 == 0 {
+            return true;
+        }
+
+        if self.rearm == RuleRearm::Never {
+            return false;
+        }
+
+        if self
+            .max_invocations_per_token_pair
+            .is_some_and(|max| invocations.0 >= max as i64)
+        {
+            return false;
+        }
+
+        self.cooled_down(last_invocation, timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{RuleLimit, RuleRearm};
+    use common::model::{Count, Timestamp};
+
+    fn at(epoch_second: i64) -> Timestamp {
+        Timestamp::from_epoch_second(epoch_second).unwrap()
+    }
+
+    #[test]
+    fn test_unlimited() {
+        let test_instance = RuleLimit::default();
+        assert!(!test_instance.exhausted(Count(1_000_000)));
+        assert!(test_instance.scheduled(at(0)));
+        assert!(test_instance.cooled_down(at(10), at(10)));
+    }
+
+    #[test]
+    fn test_exhausted() {
+        let test_instance = RuleLimit {
+            max_invocations: Some(2),
+            ..Default::default()
+        };
+        assert!(!test_instance.exhausted(Count(0)));
+        assert!(!test_instance.exhausted(Count(1)));
+        assert!(test_instance.exhausted(Count(2)));
+        assert!(test_instance.exhausted(Count(3)));
+    }
+
+    #[test]
+    fn test_scheduled() {
+        let test_instance = RuleLimit {
+            start_at: Some(at(100)),
+            end_at: Some(at(200)),
+            ..Default::default()
+        };
+        assert!(!test_instance.scheduled(at(99)));
+        assert!(test_instance.scheduled(at(100)));
+        assert!(test_instance.scheduled(at(200)));
+        assert!(!test_instance.scheduled(at(201)));
+    }
+
+    #[test]
+    fn test_cooled_down() {
+        let test_instance = RuleLimit {
+            cooldown_seconds: Some(60),
+            ..Default::default()
+        };
+        assert!(!test_instance.cooled_down(at(100), at(159)));
+        assert!(test_instance.cooled_down(at(100), at(160)));
+    }
+
+    #[test]
+    fn test_rearmed_never() {
+        let test_instance = RuleLimit::default();
+        assert!(test_instance.rearmed(Count(0), at(100), at(100)));
+        assert!(!test_instance.rearmed(Count(1), at(100), at(1_000)));
+    }
+
+    #[test]
+    fn test_rearmed() {
+        let test_instance = RuleLimit {
+            rearm: RuleRearm::Always,
+            ..Default::default()
+        };
+        assert!(test_instance.rearmed(Count(1), at(100), at(100)));
+        assert!(test_instance.rearmed(Count(1_000), at(100), at(100)));
+    }
+
+    #[test]
+    fn test_rearmed_after_cooldown() {
+        let test_instance = RuleLimit {
+            rearm: RuleRearm::Edge,
+            cooldown_seconds: Some(60),
+            ..Default::default()
+        };
+        assert!(!test_instance.rearmed(Count(1), at(100), at(159)));
+        assert!(test_instance.rearmed(Count(1), at(100), at(160)));
+    }
+
+    #[test]
+    fn test_rearmed_at_most() {
+        let test_instance = RuleLimit {
+            rearm: RuleRearm::Always,
+            max_invocations_per_token_pair: Some(2),
+            ..Default::default()
+        };
+        assert!(test_instance.rearmed(Count(1), at(100), at(100)));
+        assert!(!test_instance.rearmed(Count(2), at(100), at(100)));
+    }
+}
@@ -2,14 +2,18 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 pub use crate::model::rule::id::RuleId;
+pub use crate::model::rule::limit::RuleLimit;
 pub use crate::model::rule::name::RuleName;
+pub use crate::model::rule::rearm::RuleRearm;
 pub use crate::model::rule::status::RuleStatus;
 pub use crate::model::rule::version::RuleVersion;
 use crate::model::{Sequence, UserId};
 use common::model::{CreatedAt, UpdatedAt};
 
 mod id;
+mod limit;
 mod name;
+mod rearm;
 mod status;
 mod version;
 
@@ -21,10 +25,7 @@ pub struct Rule {
     pub name: RuleName,
     pub sequence: Sequence,
     pub user: UserId,
-    // active
-    // executes max 10 times
-    // start at
-    // maybe ends at
+    pub limit: RuleLimit,
     pub created_at: CreatedAt,
     pub updated_at: UpdatedAt,
 }
@@ -0,0 +1,30 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+
+/// Whether a rule gets invoked again for a token pair, it was already invoked for
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Deserialize, Serialize, Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[repr(i16)]
+pub enum RuleRearm {
+    // Rule gets invoked at most once per token pair
+    #[default]
+    Never = 0,
+    // Rule gets invoked again, once its condition stopped matching and matches again
+    Edge = 1,
+    // Rule gets invoked again whenever its condition matches
+    Always = 2,
+}
+
+impl Display for RuleRearm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleRearm::Never => f.write_str("Never"),
+            RuleRearm::Edge => f.write_str("Edge"),
+            RuleRearm::Always => f.write_str("Always"),
+        }
+    }
+}
@@ -48,3 +48,57 @@ impl RuleStatus {
         }
     }
 }
+
+impl RuleStatus {
+    pub fn is_exhausted(&self) -> bool {
+        matches!(
+            self,
+            RuleStatus::ActiveExhausted
+                | RuleStatus::InactiveExhausted
+                | RuleStatus::ArchivedExhausted
+        )
+    }
+
+    /// The status once all invocations of the rule are exhausted
+    pub fn exhaust(&self) -> RuleStatus {
+        match self {
+            RuleStatus::Active => RuleStatus::ActiveExhausted,
+            RuleStatus::Inactive => RuleStatus::InactiveExhausted,
+            RuleStatus::Archived => RuleStatus::ArchivedExhausted,
+            exhausted => *exhausted,
+        }
+    }
+
+    /// The status once the rule is not exhausted anymore
+    pub fn replenish(&self) -> RuleStatus {
+        match self {
+            RuleStatus::ActiveExhausted => RuleStatus::Active,
+            RuleStatus::InactiveExhausted => RuleStatus::Inactive,
+            RuleStatus::ArchivedExhausted => RuleStatus::Archived,
+            replenished => *replenished,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::RuleStatus::{
+        Active, ActiveExhausted, Archived, ArchivedExhausted, Inactive, InactiveExhausted,
+    };
+
+    #[test]
+    fn test_exhaust() {
+        assert_eq!(Active.exhaust(), ActiveExhausted);
+        assert_eq!(Inactive.exhaust(), InactiveExhausted);
+        assert_eq!(Archived.exhaust(), ArchivedExhausted);
+        assert_eq!(ActiveExhausted.exhaust(), ActiveExhausted);
+    }
+
+    #[test]
+    fn test_replenish() {
+        assert_eq!(ActiveExhausted.replenish(), Active);
+        assert_eq!(InactiveExhausted.replenish(), Inactive);
+        assert_eq!(ArchivedExhausted.replenish(), Archived);
+        assert_eq!(Active.replenish(), Active);
+    }
+}
@@ -1,16 +1,193 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use crate::model::{Sequence, TelegramActionButtonConfig};
+use crate::model::{Sequence, TelegramActionButtonConfig, Value};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Action {
-	AndThen { action: Box<Action>, sequence: Box<Sequence> },
+	AndThen {
+		action: Box<Action>,
+		sequence: Box<Sequence>,
+		/// Duration within which the condition of the sequence has to match, otherwise the sequence gets dropped.
+		/// The sequence waits forever if absent
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		within: Option<Value>,
+	},
 	Buy,
 	NotifyTelegram {
 		buttons: Vec<TelegramActionButtonConfig>
 	},
 	Sell,
 }
+
+impl Action {
+	/// Splits the action into the action which has to be performed right away and
+	/// the sequence which continues once its condition matches later.
+	/// Nested AndThen actions are flattened, so that the returned action is never an AndThen.
+	pub fn split(&self) -> (&Action, Option<Sequence>) {
+		match self {
+			Action::AndThen { action, sequence, within } => {
+				let (immediate, inner) = action.split();
+				let next = match inner {
+					None => sequence.as_ref().clone(),
+					Some(inner) => inner.and_then(sequence.as_ref().clone(), within.clone()),
+				};
+				(immediate, Some(next))
+			}
+			action => (action, None),
+		}
+	}
+
+	/// Seconds within which the sequence returned by split has to match, none if it waits forever
+	pub fn window_seconds(&self) -> Option<i64> {
+		match self {
+			Action::AndThen { action, within, .. } => match action.as_ref() {
+				Action::AndThen { .. } => action.window_seconds(),
+				_ => within.as_ref().and_then(Value::as_seconds),
+			},
+			_ => None,
+		}
+	}
+}
+
+impl Sequence {
+	/// Appends the given sequence, so that it continues after this sequence was performed
+	pub fn and_then(self, next: Sequence, within: Option<Value>) -> Sequence {
+		Sequence {
+			condition: self.condition,
+			action: Action::AndThen {
+				action: Box::new(self.action),
+				sequence: Box::new(next),
+				within,
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::model::Condition::Compare;
+	use crate::model::Field::{PriceAvg, Volume};
+	use crate::model::Operator::MoreThan;
+	use crate::model::{Action, Sequence, Value};
+	use common::model::TimeUnit;
+
+	fn sequence(action: Action) -> Sequence {
+		Sequence {
+			condition: Compare {
+				field: PriceAvg,
+				operator: MoreThan,
+				value: Value::percent(50.0).into(),
+				timeframe: None,
+			},
+			action,
+		}
+	}
+
+	#[test]
+	fn test_split_single_action() {
+		let (immediate, next) = Action::Buy.split();
+		assert_eq!(immediate, &Action::Buy);
+		assert_eq!(next, None);
+	}
+
+	#[test]
+	fn test_split_and_then() {
+		let test_instance = Action::AndThen {
+			action: Box::new(Action::Buy),
+			sequence: Box::new(sequence(Action::Sell)),
+			within: None,
+		};
+
+		let (immediate, next) = test_instance.split();
+		assert_eq!(immediate, &Action::Buy);
+		assert_eq!(next, Some(sequence(Action::Sell)));
+	}
+
+	#[test]
+	fn test_split_multi_stage() {
+		let sell = sequence(Action::Sell);
+		let buy = Sequence {
+			condition: Compare {
+				field: Volume,
+				operator: MoreThan,
+				value: Value::percent(10.0).into(),
+				timeframe: None,
+			},
+			action: Action::AndThen {
+				action: Box::new(Action::Buy),
+				sequence: Box::new(sell.clone()),
+				within: None,
+			},
+		};
+
+		let test_instance = Action::AndThen {
+			action: Box::new(Action::NotifyTelegram { buttons: vec![] }),
+			sequence: Box::new(buy.clone()),
+			within: None,
+		};
+
+		let (immediate, next) = test_instance.split();
+		assert_eq!(immediate, &Action::NotifyTelegram { buttons: vec![] });
+
+		let next = next.unwrap();
+		assert_eq!(next, buy);
+
+		let (immediate, next) = next.action.split();
+		assert_eq!(immediate, &Action::Buy);
+		assert_eq!(next, Some(sell));
+	}
+
+	#[test]
+	fn test_split_nested_and_then() {
+		let test_instance = Action::AndThen {
+			action: Box::new(Action::AndThen {
+				action: Box::new(Action::Buy),
+				sequence: Box::new(sequence(Action::Sell)),
+				within: None,
+			}),
+			sequence: Box::new(sequence(Action::Buy)),
+			within: None,
+		};
+
+		let (immediate, next) = test_instance.split();
+		assert_eq!(immediate, &Action::Buy);
+
+		let next = next.unwrap();
+		assert_eq!(next, sequence(Action::Sell).and_then(sequence(Action::Buy), None));
+
+		let (immediate, next) = next.action.split();
+		assert_eq!(immediate, &Action::Sell);
+		assert_eq!(next, Some(sequence(Action::Buy)));
+	}
+
+	#[test]
+	fn test_window_seconds() {
+		let test_instance = Action::AndThen {
+			action: Box::new(Action::Buy),
+			sequence: Box::new(sequence(Action::Sell)),
+			within: Some(Value::duration(5, TimeUnit::Minute)),
+		};
+		assert_eq!(test_instance.window_seconds(), Some(300));
+		assert_eq!(Action::Buy.window_seconds(), None);
+	}
+
+	#[test]
+	fn test_window_seconds_nested() {
+		let test_instance = Action::AndThen {
+			action: Box::new(Action::AndThen {
+				action: Box::new(Action::Buy),
+				sequence: Box::new(sequence(Action::Sell)),
+				within: Some(Value::duration(1, TimeUnit::Minute)),
+			}),
+			sequence: Box::new(sequence(Action::Buy)),
+			within: Some(Value::duration(1, TimeUnit::Hour)),
+		};
+		assert_eq!(test_instance.window_seconds(), Some(60));
+
+		let (_, next) = test_instance.split();
+		assert_eq!(next.unwrap().action.window_seconds(), Some(3_600));
+	}
+}
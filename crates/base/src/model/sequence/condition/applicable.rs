@@ -31,7 +31,7 @@ mod tests {
     mod compose {
         use crate::model::Condition::Compare;
         use crate::model::Field::TwitterAccountHandle;
-        use crate::model::Operator::{Equal, NotEqual};
+        use crate::model::Operator::{Equal, MoreThan};
         use crate::model::{Condition, Value};
 
         #[test]
@@ -54,7 +54,7 @@ mod tests {
                 composition: "SomeComposition".into(),
                 condition: Box::new(Compare {
                     field: TwitterAccountHandle,
-                    operator: NotEqual,
+                    operator: MoreThan,
                     value: Value::string("AI_nyanbot").into(),
                     timeframe: None,
                 }),
@@ -80,7 +80,7 @@ mod tests {
     mod and {
         use crate::model::Condition::{And, Compare};
         use crate::model::Field::{SwapBuy, SwapAll, TwitterAccountHandle};
-        use crate::model::Operator::{Equal, LessThanEqual, MoreThan, MoreThanEqual, NotEqual};
+        use crate::model::Operator::{Equal, LessThanEqual, MoreThan, MoreThanEqual};
         use crate::model::{Condition, Value};
         use common::model::Timeframe::{H1, M15};
 
@@ -203,7 +203,7 @@ mod tests {
             let test_instance = Condition::And {
                 conditions: vec![Compare {
                     field: TwitterAccountHandle,
-                    operator: NotEqual,
+                    operator: MoreThan,
                     value: Value::string("AI_nyanbot").into(),
                     timeframe: None,
                 }],
@@ -246,7 +246,7 @@ mod tests {
                     // Not applicable
                     Compare {
                         field: TwitterAccountHandle,
-                        operator: NotEqual,
+                        operator: MoreThan,
                         value: Value::string("AI_nyanbot").into(),
                         timeframe: None,
                     },
@@ -272,7 +272,7 @@ mod tests {
         use crate::model::Condition::Compare;
         use crate::model::Field::{SwapBuy, SwapAll, TwitterAccountHandle};
         use crate::model::Operator::{
-            Equal, IncreasedByMoreThan, LessThanEqual, MoreThan, MoreThanEqual, NotEqual,
+            Equal, IncreasedByMoreThan, LessThanEqual, MoreThan, MoreThanEqual,
         };
         use crate::model::{Condition, Value};
         use common::model::Timeframe::{H1, M15};
@@ -397,7 +397,7 @@ mod tests {
             let test_instance = Or {
                 conditions: vec![Compare {
                     field: TwitterAccountHandle,
-                    operator: NotEqual,
+                    operator: MoreThan,
                     value: Value::string("AI_nyanbot").into(),
                     timeframe: None,
                 }],
@@ -440,7 +440,7 @@ mod tests {
                     // Not applicable
                     Compare {
                         field: TwitterAccountHandle,
-                        operator: NotEqual,
+                        operator: MoreThan,
                         value: Value::string("AI_nyanbot").into(),
                         timeframe: None,
                     },
@@ -466,7 +466,7 @@ mod tests {
         use crate::model::Condition::{And, Compare, Or};
         use crate::model::Field::{SwapBuy, SwapAll, TwitterAccountHandle};
         use crate::model::Operator::{
-            Equal, IncreasedByMoreThan, LessThanEqual, MoreThan, MoreThanEqual, NotEqual,
+            Equal, IncreasedByMoreThan, LessThanEqual, MoreThan, MoreThanEqual,
         };
         use crate::model::{Condition, Value};
         use common::model::Timeframe::{H1, M15};
@@ -591,7 +591,7 @@ mod tests {
             let test_instance = AndNot {
                 conditions: vec![Compare {
                     field: TwitterAccountHandle,
-                    operator: NotEqual,
+                    operator: MoreThan,
                     value: Value::string("AI_nyanbot").into(),
                     timeframe: None,
                 }],
@@ -634,7 +634,7 @@ mod tests {
                     // Not applicable
                     Compare {
                         field: TwitterAccountHandle,
-                        operator: NotEqual,
+                        operator: MoreThan,
                         value: Value::string("AI_nyanbot").into(),
                         timeframe: None,
                     },
@@ -0,0 +1,112 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{Condition, Fact};
+use std::collections::HashSet;
+
+impl Condition {
+    /// Facts the condition depends on, comparisons without a derivable fact are ignored
+    pub fn facts(&self) -> HashSet<Fact> {
+        let mut result = HashSet::new();
+        self.collect_facts(&mut result);
+        result
+    }
+
+    fn collect_facts(&self, result: &mut HashSet<Fact>) {
+        match self {
+            Condition::Compare { .. } => {
+                if let Ok(fact) = Fact::try_from(self) {
+                    result.insert(fact);
+                }
+            }
+            Condition::Compose { condition, .. } => condition.collect_facts(result),
+            Condition::And { conditions }
+            | Condition::Or { conditions }
+            | Condition::AndNot { conditions } => {
+                for condition in conditions {
+                    condition.collect_facts(result);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::Condition::{And, Compare, Compose, Or};
+    use crate::model::Fact::{CurveProgressPercent, SwapBuyCountAggregate, TwitterAccountHandle};
+    use crate::model::Field;
+    use crate::model::Operator::{Equal, MoreThan};
+    use crate::model::{Condition, Value};
+    use common::model::Timeframe::M15;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_compare() {
+        let test_instance = Compare {
+            field: Field::CurveProgress,
+            operator: MoreThan,
+            value: Value::percent(50.0).into(),
+            timeframe: None,
+        };
+        assert_eq!(test_instance.facts(), HashSet::from([CurveProgressPercent]));
+    }
+
+    #[test]
+    fn test_compare_not_derivable() {
+        let test_instance = Compare {
+            field: Field::TwitterAccountHandle,
+            operator: MoreThan,
+            value: Value::string("AI_nyanbot").into(),
+            timeframe: None,
+        };
+        assert!(test_instance.facts().is_empty());
+    }
+
+    #[test]
+    fn test_nested() {
+        let test_instance = Compose {
+            composition: "SomeComposition".into(),
+            condition: Box::new(And {
+                conditions: vec![
+                    Compare {
+                        field: Field::SwapBuy,
+                        operator: MoreThan,
+                        value: Value::count(1).into(),
+                        timeframe: Some(M15),
+                    },
+                    Or {
+                        conditions: vec![
+                            Compare {
+                                field: Field::TwitterAccountHandle,
+                                operator: Equal,
+                                value: Value::string("AI_nyanbot").into(),
+                                timeframe: None,
+                            },
+                            Compare {
+                                field: Field::CurveProgress,
+                                operator: MoreThan,
+                                value: Value::percent(50.0).into(),
+                                timeframe: None,
+                            },
+                        ],
+                    },
+                ],
+            }),
+        };
+        assert_eq!(
+            test_instance.facts(),
+            HashSet::from([
+                SwapBuyCountAggregate,
+                TwitterAccountHandle,
+                CurveProgressPercent
+            ])
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let test_instance = Condition::And { conditions: vec![] };
+        assert!(test_instance.facts().is_empty());
+    }
+}
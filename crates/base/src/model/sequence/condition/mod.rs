@@ -7,7 +7,9 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
 mod applicable;
+mod facts;
 mod test;
+mod validate;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(transparent)]
@@ -0,0 +1,329 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{Condition, Fact, ValidationError, ValueType};
+use serde::Serialize;
+
+impl Condition {
+    /// Every problem which prevents a condition from being applied, located by the JSON path of the
+    /// offending node. An empty AND/OR/AND_NOT is not a problem, it simply never matches
+    pub fn validate(&self, path: &str) -> Vec<ValidationError> {
+        let mut result = Vec::new();
+        self.validate_into(path, &mut result);
+        result
+    }
+
+    fn validate_into(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        match self {
+            Condition::Compose { condition, .. } => {
+                condition.validate_into(&format!("{path}.condition"), errors)
+            }
+            Condition::And { conditions }
+            | Condition::Or { conditions }
+            | Condition::AndNot { conditions } => {
+                for (idx, condition) in conditions.iter().enumerate() {
+                    condition.validate_into(&format!("{path}.conditions[{idx}]"), errors);
+                }
+            }
+            Condition::Compare {
+                field,
+                operator,
+                value,
+                timeframe,
+            } => {
+                let field_name = name(field);
+                let operator_name = name(operator);
+
+                let supported: Vec<(ValueType, bool)> = ValueType::all()
+                    .into_iter()
+                    .flat_map(|value_type| [(value_type, false), (value_type, true)])
+                    .filter(|(value_type, has_timeframe)| {
+                        Fact::from_value_type(field, operator, *value_type, *has_timeframe)
+                            .is_some()
+                    })
+                    .collect();
+
+                if supported.is_empty() {
+                    errors.push(ValidationError::new(
+                        format!("{path}.operator"),
+                        format!("field {field_name} does not support operator {operator_name}"),
+                    ));
+                    return;
+                }
+
+                let Some(value) = value else {
+                    errors.push(ValidationError::new(
+                        format!("{path}.value"),
+                        "value required",
+                    ));
+                    return;
+                };
+
+                let value_type = value.value_type();
+                let has_timeframe = timeframe.is_some();
+
+                if supported.contains(&(value_type, has_timeframe)) {
+                    return;
+                }
+
+                if !supported
+                    .iter()
+                    .any(|(supported, _)| *supported == value_type)
+                {
+                    errors.push(ValidationError::new(
+                        format!("{path}.value"),
+                        format!(
+                            "field {field_name} does not accept value type {value_type} with operator {operator_name}"
+                        ),
+                    ));
+                } else if has_timeframe {
+                    errors.push(ValidationError::new(
+                        format!("{path}.timeframe"),
+                        "timeframe not allowed",
+                    ));
+                } else {
+                    errors.push(ValidationError::new(
+                        format!("{path}.timeframe"),
+                        "timeframe required",
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Name of a field or operator as it appears in JSON
+fn name(value: impl Serialize) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(ToString::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::Condition::{And, Compare, Compose, Or};
+    use crate::model::Field::{Price, PriceClose, TwitterAccountHandle};
+    use crate::model::Operator::{Equal, IncreasedByMoreThan, MoreThan};
+    use crate::model::{Action, Sequence, ValidationError, Value};
+    use common::model::Timeframe::M5;
+
+    #[test]
+    fn test_valid() {
+        let test_instance = And {
+            conditions: vec![
+                Compare {
+                    field: Price,
+                    operator: MoreThan,
+                    value: Some(Value::quote(1)),
+                    timeframe: None,
+                },
+                Compare {
+                    field: Price,
+                    operator: IncreasedByMoreThan,
+                    value: Some(Value::percent(10.0)),
+                    timeframe: Some(M5),
+                },
+            ],
+        };
+        assert_eq!(test_instance.validate("$"), vec![]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let test_instance = And { conditions: vec![] };
+        assert_eq!(test_instance.validate("$"), vec![]);
+    }
+
+    #[test]
+    fn test_value_type_not_accepted() {
+        let test_instance = And {
+            conditions: vec![Compare {
+                field: Price,
+                operator: MoreThan,
+                value: Some(Value::count(1)),
+                timeframe: None,
+            }],
+        };
+        assert_eq!(
+            test_instance.validate("$"),
+            vec![ValidationError::new(
+                "$.conditions[0].value",
+                "field PRICE does not accept value type COUNT with operator MORE_THAN"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_operator_not_supported() {
+        let test_instance = Compare {
+            field: TwitterAccountHandle,
+            operator: MoreThan,
+            value: Some(Value::string("AI_nyanbot")),
+            timeframe: None,
+        };
+        assert_eq!(
+            test_instance.validate("$"),
+            vec![ValidationError::new(
+                "$.operator",
+                "field TWITTER_ACCOUNT_HANDLE does not support operator MORE_THAN"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_value_required() {
+        let test_instance = Compose {
+            composition: "SomeComposition".into(),
+            condition: Box::new(Compare {
+                field: TwitterAccountHandle,
+                operator: Equal,
+                value: None,
+                timeframe: None,
+            }),
+        };
+        assert_eq!(
+            test_instance.validate("$"),
+            vec![ValidationError::new("$.condition.value", "value required")]
+        );
+    }
+
+    #[test]
+    fn test_timeframe_required() {
+        let test_instance = Compare {
+            field: PriceClose,
+            operator: MoreThan,
+            value: Some(Value::quote(1)),
+            timeframe: None,
+        };
+        assert_eq!(
+            test_instance.validate("$"),
+            vec![ValidationError::new("$.timeframe", "timeframe required")]
+        );
+    }
+
+    #[test]
+    fn test_timeframe_not_allowed() {
+        let test_instance = Compare {
+            field: TwitterAccountHandle,
+            operator: Equal,
+            value: Some(Value::string("AI_nyanbot")),
+            timeframe: Some(M5),
+        };
+        assert_eq!(
+            test_instance.validate("$"),
+            vec![ValidationError::new("$.timeframe", "timeframe not allowed")]
+        );
+    }
+
+    #[test]
+    fn test_multiple_problems() {
+        let test_instance = Or {
+            conditions: vec![
+                Compare {
+                    field: Price,
+                    operator: MoreThan,
+                    value: Some(Value::quote(1)),
+                    timeframe: None,
+                },
+                And {
+                    conditions: vec![Compare {
+                        field: PriceClose,
+                        operator: MoreThan,
+                        value: Some(Value::quote(1)),
+                        timeframe: None,
+                    }],
+                },
+                Compare {
+                    field: Price,
+                    operator: MoreThan,
+                    value: None,
+                    timeframe: None,
+                },
+            ],
+        };
+        assert_eq!(
+            test_instance.validate("$"),
+            vec![
+                ValidationError::new(
+                    "$.conditions[1].conditions[0].timeframe",
+                    "timeframe required"
+                ),
+                ValidationError::new("$.conditions[2].value", "value required"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sequence() {
+        let invalid = Compare {
+            field: Price,
+            operator: MoreThan,
+            value: None,
+            timeframe: None,
+        };
+
+        let test_instance = Sequence {
+            condition: invalid.clone(),
+            action: Action::AndThen {
+                action: Box::new(Action::AndThen {
+                    action: Box::new(Action::Buy),
+                    sequence: Box::new(Sequence {
+                        condition: invalid.clone(),
+                        action: Action::Sell,
+                    }),
+                    within: None,
+                }),
+                sequence: Box::new(Sequence {
+                    condition: And {
+                        conditions: vec![invalid],
+                    },
+                    action: Action::Sell,
+                }),
+                within: None,
+            },
+        };
+
+        assert_eq!(
+            test_instance.validate(),
+            vec![
+                ValidationError::new("$.condition.value", "value required"),
+                ValidationError::new(
+                    "$.action.sequence.condition.conditions[0].value",
+                    "value required"
+                ),
+                ValidationError::new("$.action.action.sequence.condition.value", "value required"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sequence_within() {
+        let valid = Compare {
+            field: Price,
+            operator: MoreThan,
+            value: Some(Value::quote(1)),
+            timeframe: None,
+        };
+
+        let test_instance = Sequence {
+            condition: valid.clone(),
+            action: Action::AndThen {
+                action: Box::new(Action::Buy),
+                sequence: Box::new(Sequence {
+                    condition: valid,
+                    action: Action::Sell,
+                }),
+                within: Some(Value::count(5)),
+            },
+        };
+
+        assert_eq!(
+            test_instance.validate(),
+            vec![ValidationError::new(
+                "$.action.within",
+                "within requires a positive duration"
+            )]
+        );
+    }
+}
@@ -2,6 +2,7 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::model::{Condition, Fact, ValueType};
+pub use common::model::ValidationError;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum FactError {
@@ -8,15 +8,34 @@ use crate::model::ValueType::{Count, Duration, Percent, Quote, Sol, Usd};
 use crate::model::{Condition, FactError, Field, Operator, Value, ValueType};
 use serde::{Deserialize, Serialize};
 use Fact::{
-    AgeBaseDuration, AgeQuoteDuration, CurveProgressAgeDuration, MarketCapQuote,
-    MarketCapQuoteAggregate, MarketCapSol, MarketCapUsd, PriceAvgQuoteAggregate,
-    PriceAvgUsdAggregate, PriceQuote, PriceUsd, SwapAllChangeAggregate, SwapAllCountAggregate,
-    SwapAllPercentAggregate, SwapBuyChangeAggregate, SwapBuyCountAggregate,
+    AgeBaseDuration, AgeQuoteDuration, CurveProgressAgeDuration, CurveProgressAvgPercentAggregate,
+    CurveProgressChangePercentAggregate, CurveProgressClosePercentAggregate,
+    CurveProgressHighPercentAggregate, CurveProgressLowPercentAggregate,
+    CurveProgressOpenPercentAggregate, Graduated, GraduationAgeDuration, LiquidityLifinity,
+    LiquidityMeteora, LiquidityOpenbook, LiquidityOrca, LiquidityPhoenix, LiquidityPumpfun,
+    LiquidityRaydium,
+    MarketCapChangePercentAggregate, MarketCapChangeQuoteAggregate, MarketCapChangeUsdAggregate,
+    MarketCapQuote, MarketCapQuoteAggregate, MarketCapSol, MarketCapUsd, PriceAvgQuoteAggregate,
+    PriceAvgUsdAggregate, PriceChangePercentAggregate, PriceChangeQuoteAggregate,
+    PriceChangeUsdAggregate, PriceCloseQuoteAggregate, PriceCloseUsdAggregate,
+    PriceHighQuoteAggregate, PriceHighUsdAggregate, PriceLowQuoteAggregate, PriceLowUsdAggregate,
+    PriceOpenQuoteAggregate, PriceOpenUsdAggregate, PriceQuote, PriceUsd, SwapAllChangeAggregate,
+    SwapAllCountAggregate, SwapAllPercentAggregate, SwapBuyChangeAggregate, SwapBuyCountAggregate,
     SwapBuyPercentAggregate, SwapSellChangeAggregate, SwapSellCountAggregate,
     SwapSellPercentAggregate, TelegramGroup, TelegramGroupHandle, TwitterAccount,
-    TwitterAccountHandle, VenueJupiter, VenuePumpfun, VolumeChangeQuoteAggregate,
+    TwitterAccountHandle, VenueJupiter, VenuePumpfun, VolumeBuyChangePercentAggregate,
+    VolumeBuyChangeQuoteAggregate, VolumeBuyChangeUsdAggregate, VolumeBuyQuoteAggregate,
+    VolumeBuyUsdAggregate, VolumeChangePercentAggregate, VolumeChangeQuoteAggregate,
+    VolumeChangeUsdAggregate, VolumeQuoteAggregate, VolumeSellBuyRatioPercentAggregate,
+    VolumeSellChangePercentAggregate, VolumeSellChangeQuoteAggregate, VolumeSellChangeUsdAggregate,
+    VolumeSellQuoteAggregate, VolumeSellUsdAggregate, VolumeUsdAggregate,
+};
+use Field::{
+    AgeBase, CurveProgress, CurveProgressAge, CurveProgressAvg, CurveProgressClose,
+    CurveProgressHigh, CurveProgressLow, CurveProgressOpen, GraduationAge, MarketCap, Price,
+    PriceClose, PriceHigh, PriceLow, PriceOpen, SwapAll, SwapBuy, SwapSell, Volume, VolumeBuy,
+    VolumeSell, VolumeSellBuyRatio,
 };
-use Field::{AgeBase, CurveProgress, CurveProgressAge, MarketCap, Price, SwapAll, SwapBuy, SwapSell, Volume};
 use Operator::{
     DecreasedByLessThan, DecreasedByLessThanEqual, DecreasedByMoreThan, DecreasedByMoreThanEqual,
     Equal, IncreasedByLessThan, IncreasedByLessThanEqual, IncreasedByMoreThan,
@@ -35,6 +54,19 @@ pub enum Fact {
     /// Duration since last update of curve progress
     CurveProgressAgeDuration,
 
+    CurveProgressOpenPercentAggregate,
+    CurveProgressHighPercentAggregate,
+    CurveProgressLowPercentAggregate,
+    CurveProgressClosePercentAggregate,
+    CurveProgressAvgPercentAggregate,
+
+    CurveProgressChangePercentAggregate,
+
+    /// Whether the bonding curve completed and the token migrated away from pump.fun
+    Graduated,
+    /// Duration since the token graduated
+    GraduationAgeDuration,
+
     MarketCapQuote,
     MarketCapSol,
     MarketCapUsd,
@@ -43,12 +75,29 @@ pub enum Fact {
     MarketCapSolAggregate,
     MarketCapUsdAggregate,
 
+    MarketCapChangeQuoteAggregate,
+    MarketCapChangeUsdAggregate,
+    MarketCapChangePercentAggregate,
+
     PriceQuote,
     PriceUsd,
 
+    PriceOpenQuoteAggregate,
+    PriceOpenUsdAggregate,
+    PriceHighQuoteAggregate,
+    PriceHighUsdAggregate,
+    PriceLowQuoteAggregate,
+    PriceLowUsdAggregate,
+    PriceCloseQuoteAggregate,
+    PriceCloseUsdAggregate,
+
     PriceAvgQuoteAggregate,
     PriceAvgUsdAggregate,
 
+    PriceChangeQuoteAggregate,
+    PriceChangeUsdAggregate,
+    PriceChangePercentAggregate,
+
     SwapAllCountAggregate,
     SwapBuyCountAggregate,
     SwapSellCountAggregate,
@@ -61,7 +110,25 @@ pub enum Fact {
     SwapBuyPercentAggregate,
     SwapSellPercentAggregate,
 
+    VolumeQuoteAggregate,
+    VolumeUsdAggregate,
+    VolumeBuyQuoteAggregate,
+    VolumeBuyUsdAggregate,
+    VolumeSellQuoteAggregate,
+    VolumeSellUsdAggregate,
+
     VolumeChangeQuoteAggregate,
+    VolumeChangeUsdAggregate,
+    VolumeChangePercentAggregate,
+    VolumeBuyChangeQuoteAggregate,
+    VolumeBuyChangeUsdAggregate,
+    VolumeBuyChangePercentAggregate,
+    VolumeSellChangeQuoteAggregate,
+    VolumeSellChangeUsdAggregate,
+    VolumeSellChangePercentAggregate,
+
+    /// Sell volume in percent of the buy volume
+    VolumeSellBuyRatioPercentAggregate,
 
     TelegramGroup,
     TelegramGroupHandle,
@@ -71,6 +138,14 @@ pub enum Fact {
 
     VenuePumpfun,
     VenueJupiter,
+
+    LiquidityLifinity,
+    LiquidityMeteora,
+    LiquidityOpenbook,
+    LiquidityOrca,
+    LiquidityPhoenix,
+    LiquidityPumpfun,
+    LiquidityRaydium,
 }
 
 impl Fact {
@@ -82,6 +157,17 @@ impl Fact {
             CurveProgressPercent => false,
             CurveProgressAgeDuration => false,
 
+            CurveProgressOpenPercentAggregate => true,
+            CurveProgressHighPercentAggregate => true,
+            CurveProgressLowPercentAggregate => true,
+            CurveProgressClosePercentAggregate => true,
+            CurveProgressAvgPercentAggregate => true,
+
+            CurveProgressChangePercentAggregate => true,
+
+            Graduated => false,
+            GraduationAgeDuration => false,
+
             MarketCapQuote => false,
             MarketCapSol => false,
             MarketCapUsd => false,
@@ -90,16 +176,29 @@ impl Fact {
             MarketCapSolAggregate => true,
             MarketCapUsdAggregate => true,
 
+            MarketCapChangeQuoteAggregate => true,
+            MarketCapChangeUsdAggregate => true,
+            MarketCapChangePercentAggregate => true,
+
             PriceQuote => false,
             PriceUsd => false,
 
+            PriceOpenQuoteAggregate => true,
+            PriceOpenUsdAggregate => true,
+            PriceHighQuoteAggregate => true,
+            PriceHighUsdAggregate => true,
+            PriceLowQuoteAggregate => true,
+            PriceLowUsdAggregate => true,
+            PriceCloseQuoteAggregate => true,
+            PriceCloseUsdAggregate => true,
+
             PriceAvgQuoteAggregate => true,
             PriceAvgUsdAggregate => true,
 
-            //
-            // PriceChangeQuote => true,
-            // PriceChangePercent => true,
-            // PriceChangeUsd => true,
+            PriceChangeQuoteAggregate => true,
+            PriceChangeUsdAggregate => true,
+            PriceChangePercentAggregate => true,
+
             SwapAllCountAggregate => true,
             SwapBuyCountAggregate => true,
             SwapSellCountAggregate => true,
@@ -112,11 +211,25 @@ impl Fact {
             SwapBuyPercentAggregate => true,
             SwapSellPercentAggregate => true,
 
-            // VolumeQuote => false,
-            // VolumeUsd => false,
+            VolumeQuoteAggregate => true,
+            VolumeUsdAggregate => true,
+            VolumeBuyQuoteAggregate => true,
+            VolumeBuyUsdAggregate => true,
+            VolumeSellQuoteAggregate => true,
+            VolumeSellUsdAggregate => true,
+
             VolumeChangeQuoteAggregate => true,
-            // VolumeChangePercent => true,
-            // VolumeChangeUsd => true,
+            VolumeChangeUsdAggregate => true,
+            VolumeChangePercentAggregate => true,
+            VolumeBuyChangeQuoteAggregate => true,
+            VolumeBuyChangeUsdAggregate => true,
+            VolumeBuyChangePercentAggregate => true,
+            VolumeSellChangeQuoteAggregate => true,
+            VolumeSellChangeUsdAggregate => true,
+            VolumeSellChangePercentAggregate => true,
+
+            VolumeSellBuyRatioPercentAggregate => true,
+
             TelegramGroup => false,
             TelegramGroupHandle => false,
 
@@ -125,9 +238,26 @@ impl Fact {
 
             VenuePumpfun => false,
             VenueJupiter => false,
+
+            LiquidityLifinity => false,
+            LiquidityMeteora => false,
+            LiquidityOpenbook => false,
+            LiquidityOrca => false,
+            LiquidityPhoenix => false,
+            LiquidityPumpfun => false,
+            LiquidityRaydium => false,
         }
     }
 
+    /// Whether the value of the fact changes with the passage of time alone,
+    /// without any new swap or summary of the token pair
+    pub fn is_time_dependent(&self) -> bool {
+        matches!(
+            self,
+            AgeBaseDuration | AgeQuoteDuration | CurveProgressAgeDuration | GraduationAgeDuration
+        )
+    }
+
     pub fn value_type(&self) -> ValueType {
         match self {
             AgeBaseDuration => Duration,
@@ -136,6 +266,17 @@ impl Fact {
             CurveProgressPercent => Percent,
             CurveProgressAgeDuration => Duration,
 
+            CurveProgressOpenPercentAggregate => Percent,
+            CurveProgressHighPercentAggregate => Percent,
+            CurveProgressLowPercentAggregate => Percent,
+            CurveProgressClosePercentAggregate => Percent,
+            CurveProgressAvgPercentAggregate => Percent,
+
+            CurveProgressChangePercentAggregate => Percent,
+
+            Graduated => Boolean,
+            GraduationAgeDuration => Duration,
+
             MarketCapQuote => Quote,
             MarketCapSol => Sol,
             MarketCapUsd => Usd,
@@ -144,15 +285,29 @@ impl Fact {
             MarketCapSolAggregate => Sol,
             MarketCapUsdAggregate => Usd,
 
+            MarketCapChangeQuoteAggregate => Quote,
+            MarketCapChangeUsdAggregate => Usd,
+            MarketCapChangePercentAggregate => Percent,
+
             PriceQuote => Quote,
             PriceUsd => Usd,
 
+            PriceOpenQuoteAggregate => Quote,
+            PriceOpenUsdAggregate => Usd,
+            PriceHighQuoteAggregate => Quote,
+            PriceHighUsdAggregate => Usd,
+            PriceLowQuoteAggregate => Quote,
+            PriceLowUsdAggregate => Usd,
+            PriceCloseQuoteAggregate => Quote,
+            PriceCloseUsdAggregate => Usd,
+
             PriceAvgQuoteAggregate => Quote,
             PriceAvgUsdAggregate => Usd,
-            //
-            // PriceChangeQuote => Quote,
-            // PriceChangeUsd => Usd,
-            // PriceChangePercent => Percent,
+
+            PriceChangeQuoteAggregate => Quote,
+            PriceChangeUsdAggregate => Usd,
+            PriceChangePercentAggregate => Percent,
+
             SwapAllCountAggregate => Count,
             SwapBuyCountAggregate => Count,
             SwapSellCountAggregate => Count,
@@ -165,11 +320,25 @@ impl Fact {
             SwapBuyPercentAggregate => Percent,
             SwapSellPercentAggregate => Percent,
 
-            // VolumeQuote => Quote,
-            // VolumeUsd => Usd,
+            VolumeQuoteAggregate => Quote,
+            VolumeUsdAggregate => Usd,
+            VolumeBuyQuoteAggregate => Quote,
+            VolumeBuyUsdAggregate => Usd,
+            VolumeSellQuoteAggregate => Quote,
+            VolumeSellUsdAggregate => Usd,
+
             VolumeChangeQuoteAggregate => Quote,
-            // VolumeChangePercent => Percent,
-            // VolumeChangeUsd => Usd,
+            VolumeChangeUsdAggregate => Usd,
+            VolumeChangePercentAggregate => Percent,
+            VolumeBuyChangeQuoteAggregate => Quote,
+            VolumeBuyChangeUsdAggregate => Usd,
+            VolumeBuyChangePercentAggregate => Percent,
+            VolumeSellChangeQuoteAggregate => Quote,
+            VolumeSellChangeUsdAggregate => Usd,
+            VolumeSellChangePercentAggregate => Percent,
+
+            VolumeSellBuyRatioPercentAggregate => Percent,
+
             TelegramGroup => Boolean,
             TelegramGroupHandle => ValueType::String,
             TwitterAccount => Boolean,
@@ -177,6 +346,14 @@ impl Fact {
 
             VenuePumpfun => Boolean,
             VenueJupiter => Boolean,
+
+            LiquidityLifinity => Boolean,
+            LiquidityMeteora => Boolean,
+            LiquidityOpenbook => Boolean,
+            LiquidityOrca => Boolean,
+            LiquidityPhoenix => Boolean,
+            LiquidityPumpfun => Boolean,
+            LiquidityRaydium => Boolean,
         }
     }
 }
@@ -215,7 +392,16 @@ impl Fact {
         value: &Value,
         has_timeframe: bool,
     ) -> Option<Self> {
-        let fact = match (field, operator, value.value_type(), has_timeframe) {
+        Fact::from_value_type(field, operator, value.value_type(), has_timeframe)
+    }
+
+    pub(crate) fn from_value_type(
+        field: &Field,
+        operator: &Operator,
+        value_type: ValueType,
+        has_timeframe: bool,
+    ) -> Option<Self> {
+        let fact = match (field, operator, value_type, has_timeframe) {
             // AgeBaseDuration
             (AgeBase, MoreThan, Duration, false) => AgeBaseDuration,
             (AgeBase, MoreThanEqual, Duration, false) => AgeBaseDuration,
@@ -240,6 +426,56 @@ impl Fact {
             (CurveProgressAge, LessThan, Duration, false) => CurveProgressAgeDuration,
             (CurveProgressAge, LessThanEqual, Duration, false) => CurveProgressAgeDuration,
 
+            // CurveProgressOpenPercentAggregate
+            (CurveProgressOpen, MoreThan, Percent, true) => CurveProgressOpenPercentAggregate,
+            (CurveProgressOpen, MoreThanEqual, Percent, true) => CurveProgressOpenPercentAggregate,
+            (CurveProgressOpen, LessThan, Percent, true) => CurveProgressOpenPercentAggregate,
+            (CurveProgressOpen, LessThanEqual, Percent, true) => CurveProgressOpenPercentAggregate,
+
+            // CurveProgressHighPercentAggregate
+            (CurveProgressHigh, MoreThan, Percent, true) => CurveProgressHighPercentAggregate,
+            (CurveProgressHigh, MoreThanEqual, Percent, true) => CurveProgressHighPercentAggregate,
+            (CurveProgressHigh, LessThan, Percent, true) => CurveProgressHighPercentAggregate,
+            (CurveProgressHigh, LessThanEqual, Percent, true) => CurveProgressHighPercentAggregate,
+
+            // CurveProgressLowPercentAggregate
+            (CurveProgressLow, MoreThan, Percent, true) => CurveProgressLowPercentAggregate,
+            (CurveProgressLow, MoreThanEqual, Percent, true) => CurveProgressLowPercentAggregate,
+            (CurveProgressLow, LessThan, Percent, true) => CurveProgressLowPercentAggregate,
+            (CurveProgressLow, LessThanEqual, Percent, true) => CurveProgressLowPercentAggregate,
+
+            // CurveProgressClosePercentAggregate
+            (CurveProgressClose, MoreThan, Percent, true) => CurveProgressClosePercentAggregate,
+            (CurveProgressClose, MoreThanEqual, Percent, true) => CurveProgressClosePercentAggregate,
+            (CurveProgressClose, LessThan, Percent, true) => CurveProgressClosePercentAggregate,
+            (CurveProgressClose, LessThanEqual, Percent, true) => CurveProgressClosePercentAggregate,
+
+            // CurveProgressAvgPercentAggregate
+            (CurveProgressAvg, MoreThan, Percent, true) => CurveProgressAvgPercentAggregate,
+            (CurveProgressAvg, MoreThanEqual, Percent, true) => CurveProgressAvgPercentAggregate,
+            (CurveProgressAvg, LessThan, Percent, true) => CurveProgressAvgPercentAggregate,
+            (CurveProgressAvg, LessThanEqual, Percent, true) => CurveProgressAvgPercentAggregate,
+
+            // CurveProgressChangePercentAggregate
+            (CurveProgress, IncreasedByMoreThan, Percent, true) => CurveProgressChangePercentAggregate,
+            (CurveProgress, IncreasedByMoreThanEqual, Percent, true) => CurveProgressChangePercentAggregate,
+            (CurveProgress, IncreasedByLessThan, Percent, true) => CurveProgressChangePercentAggregate,
+            (CurveProgress, IncreasedByLessThanEqual, Percent, true) => CurveProgressChangePercentAggregate,
+            (CurveProgress, DecreasedByMoreThan, Percent, true) => CurveProgressChangePercentAggregate,
+            (CurveProgress, DecreasedByMoreThanEqual, Percent, true) => CurveProgressChangePercentAggregate,
+            (CurveProgress, DecreasedByLessThan, Percent, true) => CurveProgressChangePercentAggregate,
+            (CurveProgress, DecreasedByLessThanEqual, Percent, true) => CurveProgressChangePercentAggregate,
+
+            // Graduated
+            (Field::Graduated, Equal, Boolean, false) => Graduated,
+            (Field::Graduated, NotEqual, Boolean, false) => Graduated,
+
+            // GraduationAgeDuration
+            (GraduationAge, MoreThan, Duration, false) => GraduationAgeDuration,
+            (GraduationAge, MoreThanEqual, Duration, false) => GraduationAgeDuration,
+            (GraduationAge, LessThan, Duration, false) => GraduationAgeDuration,
+            (GraduationAge, LessThanEqual, Duration, false) => GraduationAgeDuration,
+
             // MarketCapQuote
             (MarketCap, MoreThan, Quote, false) => MarketCapQuote,
             (MarketCap, MoreThanEqual, Quote, false) => MarketCapQuote,
@@ -276,6 +512,36 @@ impl Fact {
             (MarketCap, LessThan, Usd, true) => MarketCapUsdAggregate,
             (MarketCap, LessThanEqual, Usd, true) => MarketCapUsdAggregate,
 
+            // MarketCapChangeQuoteAggregate
+            (MarketCap, IncreasedByMoreThan, Quote, true) => MarketCapChangeQuoteAggregate,
+            (MarketCap, IncreasedByMoreThanEqual, Quote, true) => MarketCapChangeQuoteAggregate,
+            (MarketCap, IncreasedByLessThan, Quote, true) => MarketCapChangeQuoteAggregate,
+            (MarketCap, IncreasedByLessThanEqual, Quote, true) => MarketCapChangeQuoteAggregate,
+            (MarketCap, DecreasedByMoreThan, Quote, true) => MarketCapChangeQuoteAggregate,
+            (MarketCap, DecreasedByMoreThanEqual, Quote, true) => MarketCapChangeQuoteAggregate,
+            (MarketCap, DecreasedByLessThan, Quote, true) => MarketCapChangeQuoteAggregate,
+            (MarketCap, DecreasedByLessThanEqual, Quote, true) => MarketCapChangeQuoteAggregate,
+
+            // MarketCapChangeUsdAggregate
+            (MarketCap, IncreasedByMoreThan, Usd, true) => MarketCapChangeUsdAggregate,
+            (MarketCap, IncreasedByMoreThanEqual, Usd, true) => MarketCapChangeUsdAggregate,
+            (MarketCap, IncreasedByLessThan, Usd, true) => MarketCapChangeUsdAggregate,
+            (MarketCap, IncreasedByLessThanEqual, Usd, true) => MarketCapChangeUsdAggregate,
+            (MarketCap, DecreasedByMoreThan, Usd, true) => MarketCapChangeUsdAggregate,
+            (MarketCap, DecreasedByMoreThanEqual, Usd, true) => MarketCapChangeUsdAggregate,
+            (MarketCap, DecreasedByLessThan, Usd, true) => MarketCapChangeUsdAggregate,
+            (MarketCap, DecreasedByLessThanEqual, Usd, true) => MarketCapChangeUsdAggregate,
+
+            // MarketCapChangePercentAggregate
+            (MarketCap, IncreasedByMoreThan, Percent, true) => MarketCapChangePercentAggregate,
+            (MarketCap, IncreasedByMoreThanEqual, Percent, true) => MarketCapChangePercentAggregate,
+            (MarketCap, IncreasedByLessThan, Percent, true) => MarketCapChangePercentAggregate,
+            (MarketCap, IncreasedByLessThanEqual, Percent, true) => MarketCapChangePercentAggregate,
+            (MarketCap, DecreasedByMoreThan, Percent, true) => MarketCapChangePercentAggregate,
+            (MarketCap, DecreasedByMoreThanEqual, Percent, true) => MarketCapChangePercentAggregate,
+            (MarketCap, DecreasedByLessThan, Percent, true) => MarketCapChangePercentAggregate,
+            (MarketCap, DecreasedByLessThanEqual, Percent, true) => MarketCapChangePercentAggregate,
+
             // PriceQuote
             (Price, MoreThan, Quote, false) => PriceQuote,
             (Price, MoreThanEqual, Quote, false) => PriceQuote,
@@ -288,6 +554,54 @@ impl Fact {
             (Price, LessThan, Usd, false) => PriceUsd,
             (Price, LessThanEqual, Usd, false) => PriceUsd,
 
+            // PriceOpenQuoteAggregate
+            (PriceOpen, MoreThan, Quote, true) => PriceOpenQuoteAggregate,
+            (PriceOpen, MoreThanEqual, Quote, true) => PriceOpenQuoteAggregate,
+            (PriceOpen, LessThan, Quote, true) => PriceOpenQuoteAggregate,
+            (PriceOpen, LessThanEqual, Quote, true) => PriceOpenQuoteAggregate,
+
+            // PriceOpenUsdAggregate
+            (PriceOpen, MoreThan, Usd, true) => PriceOpenUsdAggregate,
+            (PriceOpen, MoreThanEqual, Usd, true) => PriceOpenUsdAggregate,
+            (PriceOpen, LessThan, Usd, true) => PriceOpenUsdAggregate,
+            (PriceOpen, LessThanEqual, Usd, true) => PriceOpenUsdAggregate,
+
+            // PriceHighQuoteAggregate
+            (PriceHigh, MoreThan, Quote, true) => PriceHighQuoteAggregate,
+            (PriceHigh, MoreThanEqual, Quote, true) => PriceHighQuoteAggregate,
+            (PriceHigh, LessThan, Quote, true) => PriceHighQuoteAggregate,
+            (PriceHigh, LessThanEqual, Quote, true) => PriceHighQuoteAggregate,
+
+            // PriceHighUsdAggregate
+            (PriceHigh, MoreThan, Usd, true) => PriceHighUsdAggregate,
+            (PriceHigh, MoreThanEqual, Usd, true) => PriceHighUsdAggregate,
+            (PriceHigh, LessThan, Usd, true) => PriceHighUsdAggregate,
+            (PriceHigh, LessThanEqual, Usd, true) => PriceHighUsdAggregate,
+
+            // PriceLowQuoteAggregate
+            (PriceLow, MoreThan, Quote, true) => PriceLowQuoteAggregate,
+            (PriceLow, MoreThanEqual, Quote, true) => PriceLowQuoteAggregate,
+            (PriceLow, LessThan, Quote, true) => PriceLowQuoteAggregate,
+            (PriceLow, LessThanEqual, Quote, true) => PriceLowQuoteAggregate,
+
+            // PriceLowUsdAggregate
+            (PriceLow, MoreThan, Usd, true) => PriceLowUsdAggregate,
+            (PriceLow, MoreThanEqual, Usd, true) => PriceLowUsdAggregate,
+            (PriceLow, LessThan, Usd, true) => PriceLowUsdAggregate,
+            (PriceLow, LessThanEqual, Usd, true) => PriceLowUsdAggregate,
+
+            // PriceCloseQuoteAggregate
+            (PriceClose, MoreThan, Quote, true) => PriceCloseQuoteAggregate,
+            (PriceClose, MoreThanEqual, Quote, true) => PriceCloseQuoteAggregate,
+            (PriceClose, LessThan, Quote, true) => PriceCloseQuoteAggregate,
+            (PriceClose, LessThanEqual, Quote, true) => PriceCloseQuoteAggregate,
+
+            // PriceCloseUsdAggregate
+            (PriceClose, MoreThan, Usd, true) => PriceCloseUsdAggregate,
+            (PriceClose, MoreThanEqual, Usd, true) => PriceCloseUsdAggregate,
+            (PriceClose, LessThan, Usd, true) => PriceCloseUsdAggregate,
+            (PriceClose, LessThanEqual, Usd, true) => PriceCloseUsdAggregate,
+
             // PriceAvgQuoteAggregate
             (PriceAvg, MoreThan, Quote, true) => PriceAvgQuoteAggregate,
             (PriceAvg, MoreThanEqual, Quote, true) => PriceAvgQuoteAggregate,
@@ -300,6 +614,36 @@ impl Fact {
             (PriceAvg, LessThan, Usd, true) => PriceAvgUsdAggregate,
             (PriceAvg, LessThanEqual, Usd, true) => PriceAvgUsdAggregate,
 
+            // PriceChangeQuoteAggregate
+            (Price, IncreasedByMoreThan, Quote, true) => PriceChangeQuoteAggregate,
+            (Price, IncreasedByMoreThanEqual, Quote, true) => PriceChangeQuoteAggregate,
+            (Price, IncreasedByLessThan, Quote, true) => PriceChangeQuoteAggregate,
+            (Price, IncreasedByLessThanEqual, Quote, true) => PriceChangeQuoteAggregate,
+            (Price, DecreasedByMoreThan, Quote, true) => PriceChangeQuoteAggregate,
+            (Price, DecreasedByMoreThanEqual, Quote, true) => PriceChangeQuoteAggregate,
+            (Price, DecreasedByLessThan, Quote, true) => PriceChangeQuoteAggregate,
+            (Price, DecreasedByLessThanEqual, Quote, true) => PriceChangeQuoteAggregate,
+
+            // PriceChangeUsdAggregate
+            (Price, IncreasedByMoreThan, Usd, true) => PriceChangeUsdAggregate,
+            (Price, IncreasedByMoreThanEqual, Usd, true) => PriceChangeUsdAggregate,
+            (Price, IncreasedByLessThan, Usd, true) => PriceChangeUsdAggregate,
+            (Price, IncreasedByLessThanEqual, Usd, true) => PriceChangeUsdAggregate,
+            (Price, DecreasedByMoreThan, Usd, true) => PriceChangeUsdAggregate,
+            (Price, DecreasedByMoreThanEqual, Usd, true) => PriceChangeUsdAggregate,
+            (Price, DecreasedByLessThan, Usd, true) => PriceChangeUsdAggregate,
+            (Price, DecreasedByLessThanEqual, Usd, true) => PriceChangeUsdAggregate,
+
+            // PriceChangePercentAggregate
+            (Price, IncreasedByMoreThan, Percent, true) => PriceChangePercentAggregate,
+            (Price, IncreasedByMoreThanEqual, Percent, true) => PriceChangePercentAggregate,
+            (Price, IncreasedByLessThan, Percent, true) => PriceChangePercentAggregate,
+            (Price, IncreasedByLessThanEqual, Percent, true) => PriceChangePercentAggregate,
+            (Price, DecreasedByMoreThan, Percent, true) => PriceChangePercentAggregate,
+            (Price, DecreasedByMoreThanEqual, Percent, true) => PriceChangePercentAggregate,
+            (Price, DecreasedByLessThan, Percent, true) => PriceChangePercentAggregate,
+            (Price, DecreasedByLessThanEqual, Percent, true) => PriceChangePercentAggregate,
+
             // SwapAllCountAggregate
             (SwapAll, MoreThan, Count, true) => SwapAllCountAggregate,
             (SwapAll, MoreThanEqual, Count, true) => SwapAllCountAggregate,
@@ -378,6 +722,42 @@ impl Fact {
             (SwapSell, DecreasedByLessThan, Percent, true) => SwapSellPercentAggregate,
             (SwapSell, DecreasedByLessThanEqual, Percent, true) => SwapSellPercentAggregate,
 
+            // VolumeQuoteAggregate
+            (Volume, MoreThan, Quote, true) => VolumeQuoteAggregate,
+            (Volume, MoreThanEqual, Quote, true) => VolumeQuoteAggregate,
+            (Volume, LessThan, Quote, true) => VolumeQuoteAggregate,
+            (Volume, LessThanEqual, Quote, true) => VolumeQuoteAggregate,
+
+            // VolumeUsdAggregate
+            (Volume, MoreThan, Usd, true) => VolumeUsdAggregate,
+            (Volume, MoreThanEqual, Usd, true) => VolumeUsdAggregate,
+            (Volume, LessThan, Usd, true) => VolumeUsdAggregate,
+            (Volume, LessThanEqual, Usd, true) => VolumeUsdAggregate,
+
+            // VolumeBuyQuoteAggregate
+            (VolumeBuy, MoreThan, Quote, true) => VolumeBuyQuoteAggregate,
+            (VolumeBuy, MoreThanEqual, Quote, true) => VolumeBuyQuoteAggregate,
+            (VolumeBuy, LessThan, Quote, true) => VolumeBuyQuoteAggregate,
+            (VolumeBuy, LessThanEqual, Quote, true) => VolumeBuyQuoteAggregate,
+
+            // VolumeBuyUsdAggregate
+            (VolumeBuy, MoreThan, Usd, true) => VolumeBuyUsdAggregate,
+            (VolumeBuy, MoreThanEqual, Usd, true) => VolumeBuyUsdAggregate,
+            (VolumeBuy, LessThan, Usd, true) => VolumeBuyUsdAggregate,
+            (VolumeBuy, LessThanEqual, Usd, true) => VolumeBuyUsdAggregate,
+
+            // VolumeSellQuoteAggregate
+            (VolumeSell, MoreThan, Quote, true) => VolumeSellQuoteAggregate,
+            (VolumeSell, MoreThanEqual, Quote, true) => VolumeSellQuoteAggregate,
+            (VolumeSell, LessThan, Quote, true) => VolumeSellQuoteAggregate,
+            (VolumeSell, LessThanEqual, Quote, true) => VolumeSellQuoteAggregate,
+
+            // VolumeSellUsdAggregate
+            (VolumeSell, MoreThan, Usd, true) => VolumeSellUsdAggregate,
+            (VolumeSell, MoreThanEqual, Usd, true) => VolumeSellUsdAggregate,
+            (VolumeSell, LessThan, Usd, true) => VolumeSellUsdAggregate,
+            (VolumeSell, LessThanEqual, Usd, true) => VolumeSellUsdAggregate,
+
             // VolumeChangeQuoteAggregate
             (Volume, IncreasedByMoreThan, Quote, true) => VolumeChangeQuoteAggregate,
             (Volume, IncreasedByMoreThanEqual, Quote, true) => VolumeChangeQuoteAggregate,
@@ -388,11 +768,103 @@ impl Fact {
             (Volume, DecreasedByLessThan, Quote, true) => VolumeChangeQuoteAggregate,
             (Volume, DecreasedByLessThanEqual, Quote, true) => VolumeChangeQuoteAggregate,
 
+            // VolumeChangeUsdAggregate
+            (Volume, IncreasedByMoreThan, Usd, true) => VolumeChangeUsdAggregate,
+            (Volume, IncreasedByMoreThanEqual, Usd, true) => VolumeChangeUsdAggregate,
+            (Volume, IncreasedByLessThan, Usd, true) => VolumeChangeUsdAggregate,
+            (Volume, IncreasedByLessThanEqual, Usd, true) => VolumeChangeUsdAggregate,
+            (Volume, DecreasedByMoreThan, Usd, true) => VolumeChangeUsdAggregate,
+            (Volume, DecreasedByMoreThanEqual, Usd, true) => VolumeChangeUsdAggregate,
+            (Volume, DecreasedByLessThan, Usd, true) => VolumeChangeUsdAggregate,
+            (Volume, DecreasedByLessThanEqual, Usd, true) => VolumeChangeUsdAggregate,
+
+            // VolumeChangePercentAggregate
+            (Volume, IncreasedByMoreThan, Percent, true) => VolumeChangePercentAggregate,
+            (Volume, IncreasedByMoreThanEqual, Percent, true) => VolumeChangePercentAggregate,
+            (Volume, IncreasedByLessThan, Percent, true) => VolumeChangePercentAggregate,
+            (Volume, IncreasedByLessThanEqual, Percent, true) => VolumeChangePercentAggregate,
+            (Volume, DecreasedByMoreThan, Percent, true) => VolumeChangePercentAggregate,
+            (Volume, DecreasedByMoreThanEqual, Percent, true) => VolumeChangePercentAggregate,
+            (Volume, DecreasedByLessThan, Percent, true) => VolumeChangePercentAggregate,
+            (Volume, DecreasedByLessThanEqual, Percent, true) => VolumeChangePercentAggregate,
+
+            // VolumeBuyChangeQuoteAggregate
+            (VolumeBuy, IncreasedByMoreThan, Quote, true) => VolumeBuyChangeQuoteAggregate,
+            (VolumeBuy, IncreasedByMoreThanEqual, Quote, true) => VolumeBuyChangeQuoteAggregate,
+            (VolumeBuy, IncreasedByLessThan, Quote, true) => VolumeBuyChangeQuoteAggregate,
+            (VolumeBuy, IncreasedByLessThanEqual, Quote, true) => VolumeBuyChangeQuoteAggregate,
+            (VolumeBuy, DecreasedByMoreThan, Quote, true) => VolumeBuyChangeQuoteAggregate,
+            (VolumeBuy, DecreasedByMoreThanEqual, Quote, true) => VolumeBuyChangeQuoteAggregate,
+            (VolumeBuy, DecreasedByLessThan, Quote, true) => VolumeBuyChangeQuoteAggregate,
+            (VolumeBuy, DecreasedByLessThanEqual, Quote, true) => VolumeBuyChangeQuoteAggregate,
+
+            // VolumeBuyChangeUsdAggregate
+            (VolumeBuy, IncreasedByMoreThan, Usd, true) => VolumeBuyChangeUsdAggregate,
+            (VolumeBuy, IncreasedByMoreThanEqual, Usd, true) => VolumeBuyChangeUsdAggregate,
+            (VolumeBuy, IncreasedByLessThan, Usd, true) => VolumeBuyChangeUsdAggregate,
+            (VolumeBuy, IncreasedByLessThanEqual, Usd, true) => VolumeBuyChangeUsdAggregate,
+            (VolumeBuy, DecreasedByMoreThan, Usd, true) => VolumeBuyChangeUsdAggregate,
+            (VolumeBuy, DecreasedByMoreThanEqual, Usd, true) => VolumeBuyChangeUsdAggregate,
+            (VolumeBuy, DecreasedByLessThan, Usd, true) => VolumeBuyChangeUsdAggregate,
+            (VolumeBuy, DecreasedByLessThanEqual, Usd, true) => VolumeBuyChangeUsdAggregate,
+
+            // VolumeBuyChangePercentAggregate
+            (VolumeBuy, IncreasedByMoreThan, Percent, true) => VolumeBuyChangePercentAggregate,
+            (VolumeBuy, IncreasedByMoreThanEqual, Percent, true) => VolumeBuyChangePercentAggregate,
+            (VolumeBuy, IncreasedByLessThan, Percent, true) => VolumeBuyChangePercentAggregate,
+            (VolumeBuy, IncreasedByLessThanEqual, Percent, true) => VolumeBuyChangePercentAggregate,
+            (VolumeBuy, DecreasedByMoreThan, Percent, true) => VolumeBuyChangePercentAggregate,
+            (VolumeBuy, DecreasedByMoreThanEqual, Percent, true) => VolumeBuyChangePercentAggregate,
+            (VolumeBuy, DecreasedByLessThan, Percent, true) => VolumeBuyChangePercentAggregate,
+            (VolumeBuy, DecreasedByLessThanEqual, Percent, true) => VolumeBuyChangePercentAggregate,
+
+            // VolumeSellChangeQuoteAggregate
+            (VolumeSell, IncreasedByMoreThan, Quote, true) => VolumeSellChangeQuoteAggregate,
+            (VolumeSell, IncreasedByMoreThanEqual, Quote, true) => VolumeSellChangeQuoteAggregate,
+            (VolumeSell, IncreasedByLessThan, Quote, true) => VolumeSellChangeQuoteAggregate,
+            (VolumeSell, IncreasedByLessThanEqual, Quote, true) => VolumeSellChangeQuoteAggregate,
+            (VolumeSell, DecreasedByMoreThan, Quote, true) => VolumeSellChangeQuoteAggregate,
+            (VolumeSell, DecreasedByMoreThanEqual, Quote, true) => VolumeSellChangeQuoteAggregate,
+            (VolumeSell, DecreasedByLessThan, Quote, true) => VolumeSellChangeQuoteAggregate,
+            (VolumeSell, DecreasedByLessThanEqual, Quote, true) => VolumeSellChangeQuoteAggregate,
+
+            // VolumeSellChangeUsdAggregate
+            (VolumeSell, IncreasedByMoreThan, Usd, true) => VolumeSellChangeUsdAggregate,
+            (VolumeSell, IncreasedByMoreThanEqual, Usd, true) => VolumeSellChangeUsdAggregate,
+            (VolumeSell, IncreasedByLessThan, Usd, true) => VolumeSellChangeUsdAggregate,
+            (VolumeSell, IncreasedByLessThanEqual, Usd, true) => VolumeSellChangeUsdAggregate,
+            (VolumeSell, DecreasedByMoreThan, Usd, true) => VolumeSellChangeUsdAggregate,
+            (VolumeSell, DecreasedByMoreThanEqual, Usd, true) => VolumeSellChangeUsdAggregate,
+            (VolumeSell, DecreasedByLessThan, Usd, true) => VolumeSellChangeUsdAggregate,
+            (VolumeSell, DecreasedByLessThanEqual, Usd, true) => VolumeSellChangeUsdAggregate,
+
+            // VolumeSellChangePercentAggregate
+            (VolumeSell, IncreasedByMoreThan, Percent, true) => VolumeSellChangePercentAggregate,
+            (VolumeSell, IncreasedByMoreThanEqual, Percent, true) => VolumeSellChangePercentAggregate,
+            (VolumeSell, IncreasedByLessThan, Percent, true) => VolumeSellChangePercentAggregate,
+            (VolumeSell, IncreasedByLessThanEqual, Percent, true) => VolumeSellChangePercentAggregate,
+            (VolumeSell, DecreasedByMoreThan, Percent, true) => VolumeSellChangePercentAggregate,
+            (VolumeSell, DecreasedByMoreThanEqual, Percent, true) => VolumeSellChangePercentAggregate,
+            (VolumeSell, DecreasedByLessThan, Percent, true) => VolumeSellChangePercentAggregate,
+            (VolumeSell, DecreasedByLessThanEqual, Percent, true) => VolumeSellChangePercentAggregate,
+
+            // VolumeSellBuyRatioPercentAggregate
+            (VolumeSellBuyRatio, MoreThan, Percent, true) => VolumeSellBuyRatioPercentAggregate,
+            (VolumeSellBuyRatio, MoreThanEqual, Percent, true) => VolumeSellBuyRatioPercentAggregate,
+            (VolumeSellBuyRatio, LessThan, Percent, true) => VolumeSellBuyRatioPercentAggregate,
+            (VolumeSellBuyRatio, LessThanEqual, Percent, true) => VolumeSellBuyRatioPercentAggregate,
+
             // Telegram
+            (Field::TelegramExists, Equal, Boolean, false) => TelegramGroup,
+            (Field::TelegramExists, NotEqual, Boolean, false) => TelegramGroup,
             (Field::TelegramGroupHandle, Equal, ValueType::String, false) => TelegramGroupHandle,
+            (Field::TelegramGroupHandle, NotEqual, ValueType::String, false) => TelegramGroupHandle,
 
             // Twitter
+            (Field::TwitterExists, Equal, Boolean, false) => TwitterAccount,
+            (Field::TwitterExists, NotEqual, Boolean, false) => TwitterAccount,
             (Field::TwitterAccountHandle, Equal, ValueType::String, false) => TwitterAccountHandle,
+            (Field::TwitterAccountHandle, NotEqual, ValueType::String, false) => TwitterAccountHandle,
 
             // Venue
             (Field::VenuePumpfun, Equal, Boolean, false) => VenuePumpfun,
@@ -400,6 +872,22 @@ impl Fact {
             (Field::VenueJupiter, Equal, Boolean, false) => VenueJupiter,
             (Field::VenueJupiter, NotEqual, Boolean, false) => VenueJupiter,
 
+            // Liquidity
+            (Field::LiquidityLifinity, Equal, Boolean, false) => LiquidityLifinity,
+            (Field::LiquidityLifinity, NotEqual, Boolean, false) => LiquidityLifinity,
+            (Field::LiquidityMeteora, Equal, Boolean, false) => LiquidityMeteora,
+            (Field::LiquidityMeteora, NotEqual, Boolean, false) => LiquidityMeteora,
+            (Field::LiquidityOpenbook, Equal, Boolean, false) => LiquidityOpenbook,
+            (Field::LiquidityOpenbook, NotEqual, Boolean, false) => LiquidityOpenbook,
+            (Field::LiquidityOrca, Equal, Boolean, false) => LiquidityOrca,
+            (Field::LiquidityOrca, NotEqual, Boolean, false) => LiquidityOrca,
+            (Field::LiquidityPhoenix, Equal, Boolean, false) => LiquidityPhoenix,
+            (Field::LiquidityPhoenix, NotEqual, Boolean, false) => LiquidityPhoenix,
+            (Field::LiquidityPumpfun, Equal, Boolean, false) => LiquidityPumpfun,
+            (Field::LiquidityPumpfun, NotEqual, Boolean, false) => LiquidityPumpfun,
+            (Field::LiquidityRaydium, Equal, Boolean, false) => LiquidityRaydium,
+            (Field::LiquidityRaydium, NotEqual, Boolean, false) => LiquidityRaydium,
+
             _ => return None,
         };
 
@@ -540,6 +1028,140 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_graduated() {
+        assert_eq!(
+            Fact::from_comparison(&Field::Graduated, &Equal, &Value::boolean(true), false),
+            Some(Graduated)
+        );
+
+        assert_eq!(
+            Fact::from_comparison(&Field::Graduated, &NotEqual, &Value::boolean(true), false),
+            Some(Graduated)
+        );
+    }
+
+    #[test]
+    fn test_telegram() {
+        assert_eq!(
+            Fact::from_comparison(&Field::TelegramExists, &Equal, &Value::boolean(true), false),
+            Some(TelegramGroup)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Field::TelegramExists,
+                &NotEqual,
+                &Value::boolean(true),
+                false
+            ),
+            Some(TelegramGroup)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Field::TelegramGroupHandle,
+                &Equal,
+                &Value::string("nyanbot"),
+                false
+            ),
+            Some(TelegramGroupHandle)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Field::TelegramGroupHandle,
+                &NotEqual,
+                &Value::string("nyanbot"),
+                false
+            ),
+            Some(TelegramGroupHandle)
+        );
+    }
+
+    #[test]
+    fn test_twitter() {
+        assert_eq!(
+            Fact::from_comparison(&Field::TwitterExists, &Equal, &Value::boolean(true), false),
+            Some(TwitterAccount)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Field::TwitterExists,
+                &NotEqual,
+                &Value::boolean(true),
+                false
+            ),
+            Some(TwitterAccount)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Field::TwitterAccountHandle,
+                &Equal,
+                &Value::string("AI_nyanbot"),
+                false
+            ),
+            Some(TwitterAccountHandle)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Field::TwitterAccountHandle,
+                &NotEqual,
+                &Value::string("AI_nyanbot"),
+                false
+            ),
+            Some(TwitterAccountHandle)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Field::TwitterAccountHandle,
+                &MoreThan,
+                &Value::string("AI_nyanbot"),
+                false
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_graduation_age() {
+        assert_eq!(
+            Fact::from_comparison(
+                &GraduationAge,
+                &MoreThan,
+                &Value::duration(1, Minute),
+                false
+            ),
+            Some(GraduationAgeDuration)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &GraduationAge,
+                &MoreThanEqual,
+                &Value::duration(1, Minute),
+                false
+            ),
+            Some(GraduationAgeDuration)
+        );
+
+        assert_eq!(
+            Fact::from_comparison(
+                &GraduationAge,
+                &LessThan,
+                &Value::duration(1, Minute),
+                false
+            ),
+            Some(GraduationAgeDuration)
+        );
+
+        assert_eq!(
+            Fact::from_comparison(
+                &GraduationAge,
+                &LessThanEqual,
+                &Value::duration(1, Minute),
+                false
+            ),
+            Some(GraduationAgeDuration)
+        );
+    }
+
     #[test]
     fn test_market_cap_quote() {
         assert_eq!(
@@ -1247,4 +1869,1307 @@ mod tests {
             Some(VenueJupiter)
         );
     }
+
+    #[test]
+    fn test_liquidity() {
+        assert_eq!(
+            Fact::from_comparison(&Field::LiquidityRaydium, &Equal, &Value::boolean(true), false),
+            Some(LiquidityRaydium)
+        );
+
+        assert_eq!(
+            Fact::from_comparison(
+                &Field::LiquidityOrca,
+                &NotEqual,
+                &Value::boolean(true),
+                false
+            ),
+            Some(LiquidityOrca)
+        );
+
+        assert_eq!(
+            Fact::from_comparison(&Field::LiquidityMeteora, &Equal, &Value::boolean(true), true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_curve_progress_open_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressOpen, &MoreThan, &Value::percent(99.24), true),
+            Some(CurveProgressOpenPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressOpen,
+                &MoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressOpenPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressOpen, &LessThan, &Value::percent(99.24), true),
+            Some(CurveProgressOpenPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressOpen,
+                &LessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressOpenPercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_curve_progress_high_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressHigh, &MoreThan, &Value::percent(99.24), true),
+            Some(CurveProgressHighPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressHigh,
+                &MoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressHighPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressHigh, &LessThan, &Value::percent(99.24), true),
+            Some(CurveProgressHighPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressHigh,
+                &LessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressHighPercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_curve_progress_low_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressLow, &MoreThan, &Value::percent(99.24), true),
+            Some(CurveProgressLowPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressLow,
+                &MoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressLowPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressLow, &LessThan, &Value::percent(99.24), true),
+            Some(CurveProgressLowPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressLow,
+                &LessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressLowPercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_curve_progress_close_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressClose, &MoreThan, &Value::percent(99.24), true),
+            Some(CurveProgressClosePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressClose,
+                &MoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressClosePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressClose, &LessThan, &Value::percent(99.24), true),
+            Some(CurveProgressClosePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressClose,
+                &LessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressClosePercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_curve_progress_avg_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressAvg, &MoreThan, &Value::percent(99.24), true),
+            Some(CurveProgressAvgPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressAvg,
+                &MoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressAvgPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&CurveProgressAvg, &LessThan, &Value::percent(99.24), true),
+            Some(CurveProgressAvgPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgressAvg,
+                &LessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressAvgPercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_curve_progress_change_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgress,
+                &IncreasedByMoreThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgress,
+                &IncreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgress,
+                &IncreasedByLessThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgress,
+                &IncreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgress,
+                &DecreasedByMoreThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgress,
+                &DecreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgress,
+                &DecreasedByLessThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &CurveProgress,
+                &DecreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(CurveProgressChangePercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_market_cap_change_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &IncreasedByMoreThan, &Value::quote(99), true),
+            Some(MarketCapChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &IncreasedByMoreThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(MarketCapChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &IncreasedByLessThan, &Value::quote(99), true),
+            Some(MarketCapChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &IncreasedByLessThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(MarketCapChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &DecreasedByMoreThan, &Value::quote(99), true),
+            Some(MarketCapChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &DecreasedByMoreThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(MarketCapChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &DecreasedByLessThan, &Value::quote(99), true),
+            Some(MarketCapChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &DecreasedByLessThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(MarketCapChangeQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_market_cap_change_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &IncreasedByMoreThan, &Value::usd(99), true),
+            Some(MarketCapChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &IncreasedByMoreThanEqual, &Value::usd(99), true),
+            Some(MarketCapChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &IncreasedByLessThan, &Value::usd(99), true),
+            Some(MarketCapChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &IncreasedByLessThanEqual, &Value::usd(99), true),
+            Some(MarketCapChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &DecreasedByMoreThan, &Value::usd(99), true),
+            Some(MarketCapChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &DecreasedByMoreThanEqual, &Value::usd(99), true),
+            Some(MarketCapChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &DecreasedByLessThan, &Value::usd(99), true),
+            Some(MarketCapChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&MarketCap, &DecreasedByLessThanEqual, &Value::usd(99), true),
+            Some(MarketCapChangeUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_market_cap_change_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &IncreasedByMoreThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(MarketCapChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &IncreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(MarketCapChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &IncreasedByLessThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(MarketCapChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &IncreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(MarketCapChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &DecreasedByMoreThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(MarketCapChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &DecreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(MarketCapChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &DecreasedByLessThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(MarketCapChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &MarketCap,
+                &DecreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(MarketCapChangePercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_open_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&PriceOpen, &MoreThan, &Value::quote(99), true),
+            Some(PriceOpenQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceOpen, &MoreThanEqual, &Value::quote(99), true),
+            Some(PriceOpenQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceOpen, &LessThan, &Value::quote(99), true),
+            Some(PriceOpenQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceOpen, &LessThanEqual, &Value::quote(99), true),
+            Some(PriceOpenQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_open_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&PriceOpen, &MoreThan, &Value::usd(99), true),
+            Some(PriceOpenUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceOpen, &MoreThanEqual, &Value::usd(99), true),
+            Some(PriceOpenUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceOpen, &LessThan, &Value::usd(99), true),
+            Some(PriceOpenUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceOpen, &LessThanEqual, &Value::usd(99), true),
+            Some(PriceOpenUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_high_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&PriceHigh, &MoreThan, &Value::quote(99), true),
+            Some(PriceHighQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceHigh, &MoreThanEqual, &Value::quote(99), true),
+            Some(PriceHighQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceHigh, &LessThan, &Value::quote(99), true),
+            Some(PriceHighQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceHigh, &LessThanEqual, &Value::quote(99), true),
+            Some(PriceHighQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_high_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&PriceHigh, &MoreThan, &Value::usd(99), true),
+            Some(PriceHighUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceHigh, &MoreThanEqual, &Value::usd(99), true),
+            Some(PriceHighUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceHigh, &LessThan, &Value::usd(99), true),
+            Some(PriceHighUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceHigh, &LessThanEqual, &Value::usd(99), true),
+            Some(PriceHighUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_low_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&PriceLow, &MoreThan, &Value::quote(99), true),
+            Some(PriceLowQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceLow, &MoreThanEqual, &Value::quote(99), true),
+            Some(PriceLowQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceLow, &LessThan, &Value::quote(99), true),
+            Some(PriceLowQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceLow, &LessThanEqual, &Value::quote(99), true),
+            Some(PriceLowQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_low_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&PriceLow, &MoreThan, &Value::usd(99), true),
+            Some(PriceLowUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceLow, &MoreThanEqual, &Value::usd(99), true),
+            Some(PriceLowUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceLow, &LessThan, &Value::usd(99), true),
+            Some(PriceLowUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceLow, &LessThanEqual, &Value::usd(99), true),
+            Some(PriceLowUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_close_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&PriceClose, &MoreThan, &Value::quote(99), true),
+            Some(PriceCloseQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceClose, &MoreThanEqual, &Value::quote(99), true),
+            Some(PriceCloseQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceClose, &LessThan, &Value::quote(99), true),
+            Some(PriceCloseQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceClose, &LessThanEqual, &Value::quote(99), true),
+            Some(PriceCloseQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_close_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&PriceClose, &MoreThan, &Value::usd(99), true),
+            Some(PriceCloseUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceClose, &MoreThanEqual, &Value::usd(99), true),
+            Some(PriceCloseUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceClose, &LessThan, &Value::usd(99), true),
+            Some(PriceCloseUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&PriceClose, &LessThanEqual, &Value::usd(99), true),
+            Some(PriceCloseUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_change_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByMoreThan, &Value::quote(99), true),
+            Some(PriceChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByMoreThanEqual, &Value::quote(99), true),
+            Some(PriceChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByLessThan, &Value::quote(99), true),
+            Some(PriceChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByLessThanEqual, &Value::quote(99), true),
+            Some(PriceChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByMoreThan, &Value::quote(99), true),
+            Some(PriceChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByMoreThanEqual, &Value::quote(99), true),
+            Some(PriceChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByLessThan, &Value::quote(99), true),
+            Some(PriceChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByLessThanEqual, &Value::quote(99), true),
+            Some(PriceChangeQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_change_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByMoreThan, &Value::usd(99), true),
+            Some(PriceChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByMoreThanEqual, &Value::usd(99), true),
+            Some(PriceChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByLessThan, &Value::usd(99), true),
+            Some(PriceChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByLessThanEqual, &Value::usd(99), true),
+            Some(PriceChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByMoreThan, &Value::usd(99), true),
+            Some(PriceChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByMoreThanEqual, &Value::usd(99), true),
+            Some(PriceChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByLessThan, &Value::usd(99), true),
+            Some(PriceChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByLessThanEqual, &Value::usd(99), true),
+            Some(PriceChangeUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_price_change_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByMoreThan, &Value::percent(99.24), true),
+            Some(PriceChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Price,
+                &IncreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(PriceChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &IncreasedByLessThan, &Value::percent(99.24), true),
+            Some(PriceChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Price,
+                &IncreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(PriceChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByMoreThan, &Value::percent(99.24), true),
+            Some(PriceChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Price,
+                &DecreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(PriceChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Price, &DecreasedByLessThan, &Value::percent(99.24), true),
+            Some(PriceChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Price,
+                &DecreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(PriceChangePercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&Volume, &MoreThan, &Value::quote(99), true),
+            Some(VolumeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &MoreThanEqual, &Value::quote(99), true),
+            Some(VolumeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &LessThan, &Value::quote(99), true),
+            Some(VolumeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &LessThanEqual, &Value::quote(99), true),
+            Some(VolumeQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&Volume, &MoreThan, &Value::usd(99), true),
+            Some(VolumeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &MoreThanEqual, &Value::usd(99), true),
+            Some(VolumeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &LessThan, &Value::usd(99), true),
+            Some(VolumeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &LessThanEqual, &Value::usd(99), true),
+            Some(VolumeUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_buy_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &MoreThan, &Value::quote(99), true),
+            Some(VolumeBuyQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &MoreThanEqual, &Value::quote(99), true),
+            Some(VolumeBuyQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &LessThan, &Value::quote(99), true),
+            Some(VolumeBuyQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &LessThanEqual, &Value::quote(99), true),
+            Some(VolumeBuyQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_buy_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &MoreThan, &Value::usd(99), true),
+            Some(VolumeBuyUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &MoreThanEqual, &Value::usd(99), true),
+            Some(VolumeBuyUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &LessThan, &Value::usd(99), true),
+            Some(VolumeBuyUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &LessThanEqual, &Value::usd(99), true),
+            Some(VolumeBuyUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_sell_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &MoreThan, &Value::quote(99), true),
+            Some(VolumeSellQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &MoreThanEqual, &Value::quote(99), true),
+            Some(VolumeSellQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &LessThan, &Value::quote(99), true),
+            Some(VolumeSellQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &LessThanEqual, &Value::quote(99), true),
+            Some(VolumeSellQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_sell_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &MoreThan, &Value::usd(99), true),
+            Some(VolumeSellUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &MoreThanEqual, &Value::usd(99), true),
+            Some(VolumeSellUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &LessThan, &Value::usd(99), true),
+            Some(VolumeSellUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &LessThanEqual, &Value::usd(99), true),
+            Some(VolumeSellUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_change_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&Volume, &IncreasedByMoreThan, &Value::usd(99), true),
+            Some(VolumeChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &IncreasedByMoreThanEqual, &Value::usd(99), true),
+            Some(VolumeChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &IncreasedByLessThan, &Value::usd(99), true),
+            Some(VolumeChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &IncreasedByLessThanEqual, &Value::usd(99), true),
+            Some(VolumeChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &DecreasedByMoreThan, &Value::usd(99), true),
+            Some(VolumeChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &DecreasedByMoreThanEqual, &Value::usd(99), true),
+            Some(VolumeChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &DecreasedByLessThan, &Value::usd(99), true),
+            Some(VolumeChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &DecreasedByLessThanEqual, &Value::usd(99), true),
+            Some(VolumeChangeUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_change_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&Volume, &IncreasedByMoreThan, &Value::percent(99.24), true),
+            Some(VolumeChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Volume,
+                &IncreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &IncreasedByLessThan, &Value::percent(99.24), true),
+            Some(VolumeChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Volume,
+                &IncreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &DecreasedByMoreThan, &Value::percent(99.24), true),
+            Some(VolumeChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Volume,
+                &DecreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&Volume, &DecreasedByLessThan, &Value::percent(99.24), true),
+            Some(VolumeChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &Volume,
+                &DecreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeChangePercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_buy_change_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &IncreasedByMoreThan, &Value::quote(99), true),
+            Some(VolumeBuyChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &IncreasedByMoreThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(VolumeBuyChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &IncreasedByLessThan, &Value::quote(99), true),
+            Some(VolumeBuyChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &IncreasedByLessThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(VolumeBuyChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &DecreasedByMoreThan, &Value::quote(99), true),
+            Some(VolumeBuyChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &DecreasedByMoreThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(VolumeBuyChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &DecreasedByLessThan, &Value::quote(99), true),
+            Some(VolumeBuyChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &DecreasedByLessThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(VolumeBuyChangeQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_buy_change_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &IncreasedByMoreThan, &Value::usd(99), true),
+            Some(VolumeBuyChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &IncreasedByMoreThanEqual, &Value::usd(99), true),
+            Some(VolumeBuyChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &IncreasedByLessThan, &Value::usd(99), true),
+            Some(VolumeBuyChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &IncreasedByLessThanEqual, &Value::usd(99), true),
+            Some(VolumeBuyChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &DecreasedByMoreThan, &Value::usd(99), true),
+            Some(VolumeBuyChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &DecreasedByMoreThanEqual, &Value::usd(99), true),
+            Some(VolumeBuyChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &DecreasedByLessThan, &Value::usd(99), true),
+            Some(VolumeBuyChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeBuy, &DecreasedByLessThanEqual, &Value::usd(99), true),
+            Some(VolumeBuyChangeUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_buy_change_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &IncreasedByMoreThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeBuyChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &IncreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeBuyChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &IncreasedByLessThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeBuyChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &IncreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeBuyChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &DecreasedByMoreThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeBuyChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &DecreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeBuyChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &DecreasedByLessThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeBuyChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeBuy,
+                &DecreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeBuyChangePercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_sell_change_quote_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &IncreasedByMoreThan, &Value::quote(99), true),
+            Some(VolumeSellChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &IncreasedByMoreThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(VolumeSellChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &IncreasedByLessThan, &Value::quote(99), true),
+            Some(VolumeSellChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &IncreasedByLessThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(VolumeSellChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &DecreasedByMoreThan, &Value::quote(99), true),
+            Some(VolumeSellChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &DecreasedByMoreThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(VolumeSellChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &DecreasedByLessThan, &Value::quote(99), true),
+            Some(VolumeSellChangeQuoteAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &DecreasedByLessThanEqual,
+                &Value::quote(99),
+                true
+            ),
+            Some(VolumeSellChangeQuoteAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_sell_change_usd_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &IncreasedByMoreThan, &Value::usd(99), true),
+            Some(VolumeSellChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &IncreasedByMoreThanEqual,
+                &Value::usd(99),
+                true
+            ),
+            Some(VolumeSellChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &IncreasedByLessThan, &Value::usd(99), true),
+            Some(VolumeSellChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &IncreasedByLessThanEqual,
+                &Value::usd(99),
+                true
+            ),
+            Some(VolumeSellChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &DecreasedByMoreThan, &Value::usd(99), true),
+            Some(VolumeSellChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &DecreasedByMoreThanEqual,
+                &Value::usd(99),
+                true
+            ),
+            Some(VolumeSellChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSell, &DecreasedByLessThan, &Value::usd(99), true),
+            Some(VolumeSellChangeUsdAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &DecreasedByLessThanEqual,
+                &Value::usd(99),
+                true
+            ),
+            Some(VolumeSellChangeUsdAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_sell_change_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &IncreasedByMoreThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &IncreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &IncreasedByLessThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &IncreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &DecreasedByMoreThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &DecreasedByMoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &DecreasedByLessThan,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellChangePercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSell,
+                &DecreasedByLessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellChangePercentAggregate)
+        );
+    }
+
+    #[test]
+    fn test_volume_sell_buy_ratio_percent_aggregate() {
+        assert_eq!(
+            Fact::from_comparison(&VolumeSellBuyRatio, &MoreThan, &Value::percent(99.24), true),
+            Some(VolumeSellBuyRatioPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSellBuyRatio,
+                &MoreThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellBuyRatioPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(&VolumeSellBuyRatio, &LessThan, &Value::percent(99.24), true),
+            Some(VolumeSellBuyRatioPercentAggregate)
+        );
+        assert_eq!(
+            Fact::from_comparison(
+                &VolumeSellBuyRatio,
+                &LessThanEqual,
+                &Value::percent(99.24),
+                true
+            ),
+            Some(VolumeSellBuyRatioPercentAggregate)
+        );
+    }
 }
@@ -73,6 +73,20 @@ impl Facts {
         self.set(fact, value.into(), None).unwrap()
     }
 
+    /// Adds all values of other which are not set yet, existing values are kept
+    pub fn merge(&mut self, other: Facts) {
+        for (fact, value) in other.values {
+            self.values.entry(fact).or_insert(value);
+        }
+
+        for (fact, values) in other.timeframe_values {
+            let existing = self.timeframe_values.entry(fact).or_default();
+            for (timeframe, value) in values {
+                existing.entry(timeframe).or_insert(value);
+            }
+        }
+    }
+
     pub fn set(
         &mut self,
         fact: Fact,
@@ -137,6 +151,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_merge_keeps_existing_values() {
+        let mut test_instance = Facts::new()
+            .with_value(Fact::PriceQuote, Value::quote(1))
+            .with_timeframe_value(Fact::SwapAllCountAggregate, Value::count(1), Timeframe::M1);
+
+        test_instance.merge(
+            Facts::new()
+                .with_value(Fact::PriceQuote, Value::quote(2))
+                .with_value(Fact::VenueJupiter, Value::boolean(true))
+                .with_timeframe_value(Fact::SwapAllCountAggregate, Value::count(2), Timeframe::M1)
+                .with_timeframe_value(Fact::SwapAllCountAggregate, Value::count(3), Timeframe::H1),
+        );
+
+        assert_eq!(test_instance.get(&Fact::PriceQuote), Some(&Value::quote(1)));
+        assert_eq!(
+            test_instance.get(&Fact::VenueJupiter),
+            Some(&Value::boolean(true))
+        );
+        assert_eq!(
+            test_instance.get_with_timeframe(&Fact::SwapAllCountAggregate, &Timeframe::M1),
+            Some(&Value::count(1))
+        );
+        assert_eq!(
+            test_instance.get_with_timeframe(&Fact::SwapAllCountAggregate, &Timeframe::H1),
+            Some(&Value::count(3))
+        );
+    }
+
     #[test]
     fn test_set_fact_with_timeframe_when_not_allowed() {
         let mut test_instance = Facts::new();
@@ -11,10 +11,22 @@ pub enum Field {
 
     CurveProgress,
     CurveProgressAge,
+    CurveProgressOpen,
+    CurveProgressHigh,
+    CurveProgressLow,
+    CurveProgressClose,
+    CurveProgressAvg,
+
+    Graduated,
+    GraduationAge,
 
     MarketCap,
 
     Price,
+    PriceOpen,
+    PriceHigh,
+    PriceLow,
+    PriceClose,
     PriceAvg,
 
     SwapAll,
@@ -22,6 +34,10 @@ pub enum Field {
     SwapSell,
 
     Volume,
+    VolumeBuy,
+    VolumeSell,
+    /// Sell volume in percent of the buy volume
+    VolumeSellBuyRatio,
 
     //
     TelegramExists,
@@ -30,5 +46,13 @@ pub enum Field {
     TwitterAccountHandle,
 
     VenuePumpfun,
-    VenueJupiter
+    VenueJupiter,
+
+    LiquidityLifinity,
+    LiquidityMeteora,
+    LiquidityOpenbook,
+    LiquidityOrca,
+    LiquidityPhoenix,
+    LiquidityPumpfun,
+    LiquidityRaydium
 }
@@ -10,6 +10,7 @@ pub use field::*;
 pub use operator::*;
 use serde::{Deserialize, Serialize};
 use sqlx::types::JsonValue;
+use std::collections::HashSet;
 pub use value::*;
 
 mod action;
@@ -44,4 +45,51 @@ impl Sequence {
     pub fn applicable(&self) -> bool {
         self.condition.applicable()
     }
+
+    /// Problems of the conditions of all stages, located by the JSON path relative to the sequence
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut result = self.condition.validate("$.condition");
+        let mut action = &self.action;
+        let mut path = "$.action".to_string();
+        while let Action::AndThen {
+            action: immediate,
+            sequence,
+            within,
+        } = action
+        {
+            if let Some(within) = within {
+                if within.as_seconds().is_none_or(|seconds| seconds <= 0) {
+                    result.push(ValidationError::new(
+                        format!("{path}.within"),
+                        "within requires a positive duration",
+                    ));
+                }
+            }
+            for err in sequence.validate() {
+                result.push(ValidationError::new(
+                    err.path.replacen('$', &format!("{path}.sequence"), 1),
+                    err.message,
+                ));
+            }
+            action = immediate;
+            path = format!("{path}.action");
+        }
+        result
+    }
+
+    /// Facts the conditions of all stages depend on
+    pub fn facts(&self) -> HashSet<Fact> {
+        let mut result = self.condition.facts();
+        let mut action = &self.action;
+        while let Action::AndThen {
+            action: immediate,
+            sequence,
+            ..
+        } = action
+        {
+            result.extend(sequence.facts());
+            action = immediate;
+        }
+        result
+    }
 }
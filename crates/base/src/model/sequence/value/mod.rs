@@ -23,6 +23,36 @@ pub enum ValueType {
     Usd,
 }
 
+impl ValueType {
+    pub fn all() -> [ValueType; 8] {
+        [
+            ValueType::Boolean,
+            ValueType::Count,
+            ValueType::Duration,
+            ValueType::Quote,
+            ValueType::Percent,
+            ValueType::Sol,
+            ValueType::String,
+            ValueType::Usd,
+        ]
+    }
+}
+
+impl Display for ValueType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueType::Boolean => f.write_str("BOOLEAN"),
+            ValueType::Count => f.write_str("COUNT"),
+            ValueType::Duration => f.write_str("DURATION"),
+            ValueType::Quote => f.write_str("QUOTE"),
+            ValueType::Percent => f.write_str("PERCENT"),
+            ValueType::Sol => f.write_str("SOL"),
+            ValueType::String => f.write_str("STRING"),
+            ValueType::Usd => f.write_str("USD"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Value {
@@ -93,6 +123,14 @@ impl From<Count> for Value {
 }
 
 impl Value {
+    /// Length of a duration in seconds, none for any other value
+    pub fn as_seconds(&self) -> Option<i64> {
+        match self {
+            Value::Duration { value, unit } => Some(value * unit.in_seconds() as i64),
+            _ => None,
+        }
+    }
+
     pub fn value_type(&self) -> ValueType {
         match self {
             Value::Boolean { .. } => ValueType::Boolean,
@@ -2,9 +2,57 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::model::solana::Slot;
-use common::model::UpdatedAt;
+use common::model::{BlockTimestamp, UpdatedAt};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
 
 pub struct Indexer {
     pub slot: Slot,
+    // Highest slot up to which every slot was finalized as either indexed or skipped, everything at or below is safe to aggregate
+    pub watermark: Option<Slot>,
     pub updated_at: UpdatedAt,
 }
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize, Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[repr(i16)]
+pub enum SlotStatus {
+    // Block was downloaded and indexed
+    Indexed = 1,
+    // Leader did not produce a block for this slot
+    Skipped = 2,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize, Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[repr(i16)]
+pub enum Commitment {
+    // Block was produced but might not end up on the majority fork
+    Processed = 1,
+    // Block was voted on by a supermajority, it is very unlikely but still possible that the fork gets dropped
+    Confirmed = 2,
+    // Block is rooted and can not be rolled back anymore
+    Finalized = 3,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct IndexedSlot {
+    pub slot: Slot,
+    pub status: SlotStatus,
+    pub commitment: Commitment,
+    // Block time, none if the slot was skipped
+    pub timestamp: Option<BlockTimestamp>,
+}
+
+/// Inclusive range of slots which were neither indexed nor recorded as skipped
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SlotGap {
+    pub first: Slot,
+    pub last: Slot,
+}
+
+impl SlotGap {
+    pub fn slots(&self) -> impl Iterator<Item = Slot> {
+        (self.first.0..=self.last.0).map(Slot)
+    }
+}
@@ -3,7 +3,7 @@
 
 pub use account::*;
 use common::model::BlockTimestamp;
-pub use indexer::Indexer;
+pub use indexer::{Commitment, IndexedSlot, Indexer, SlotGap, SlotStatus};
 use serde::{Deserialize, Serialize};
 pub use signature::*;
 pub use slot::*;
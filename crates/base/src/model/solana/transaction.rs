@@ -2,7 +2,7 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::model::solana::Signature;
-use crate::model::{DecimalAmount, Mint, PublicKey};
+use crate::model::{DecimalAmount, Decimals, Mint, PublicKey};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
@@ -39,6 +39,7 @@ pub struct TransactionSolBalance {
 pub struct TransactionTokenBalance {
     pub mint: Mint,
     pub address: PublicKey,
+    pub decimals: Decimals,
     pub pre: DecimalAmount,
     pub post: DecimalAmount,
 }
@@ -46,12 +47,25 @@ pub struct TransactionTokenBalance {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Keys {
     pub static_account: Vec<PublicKey>,
+    /// Addresses loaded from lookup tables, writable ones first, then readonly ones
+    #[serde(default)]
+    pub loaded_account: Vec<PublicKey>,
     pub log_account: Vec<PublicKey>,
 }
 
 impl Keys {
     pub fn contains(&self, public_key: &PublicKey) -> bool {
-        self.static_account.contains(public_key) || self.log_account.contains(public_key)
+        self.static_account.contains(public_key)
+            || self.loaded_account.contains(public_key)
+            || self.log_account.contains(public_key)
+    }
+
+    /// Resolves an account index of an instruction against the static and loaded addresses
+    pub fn account(&self, index: u8) -> Option<&PublicKey> {
+        let index = index as usize;
+        self.static_account
+            .get(index)
+            .or_else(|| self.loaded_account.get(index - self.static_account.len()))
     }
 }
 
@@ -9,6 +9,7 @@ pub use description::Description;
 pub use id::{TokenId, TokenPairId};
 pub use mint::{determine_mints, Mint, TokenPairMint};
 pub use name::Name;
+pub use social::{add_social_to_facts, TelegramHandle, TwitterHandle};
 use std::fmt::{Display, Formatter};
 pub use symbol::Symbol;
 pub use uri::Uri;
@@ -19,6 +20,7 @@ mod description;
 mod id;
 mod mint;
 mod name;
+mod social;
 mod symbol;
 mod uri;
 
@@ -34,6 +36,8 @@ pub struct Token {
     pub metadata: Option<Uri>,
     pub image: Option<Uri>,
     pub website: Option<Uri>,
+    pub twitter: Option<TwitterHandle>,
+    pub telegram: Option<TelegramHandle>,
     pub creator: Option<AddressId>,
     pub block: Option<BlockId>,
     pub block_time: Option<BlockTimestamp>,
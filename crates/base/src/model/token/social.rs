@@ -0,0 +1,301 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::Fact::{
+    TelegramGroup, TelegramGroupHandle, TwitterAccount, TwitterAccountHandle,
+};
+use crate::model::{Facts, Token, Value};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Whether the token links a twitter account / telegram group, and which one
+pub fn add_social_to_facts(facts: &mut Facts, token: &Token) {
+    facts.set_value(TwitterAccount, Value::boolean(token.twitter.is_some()));
+    if let Some(twitter) = &token.twitter {
+        facts.set_value(TwitterAccountHandle, Value::string(twitter.0.as_str()));
+    }
+
+    facts.set_value(TelegramGroup, Value::boolean(token.telegram.is_some()));
+    if let Some(telegram) = &token.telegram {
+        facts.set_value(TelegramGroupHandle, Value::string(telegram.0.as_str()));
+    }
+}
+
+/// Twitter / X account handle without the leading @
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(transparent)]
+pub struct TwitterHandle(pub String);
+
+impl TwitterHandle {
+    /// Accepts a profile url (twitter.com / x.com), @handle or a plain handle
+    pub fn parse(value: &str) -> Option<Self> {
+        let handle = match strip_host(value, &["twitter.com", "x.com"]) {
+            Some(path) => {
+                let segment = first_segment(path)?;
+                if RESERVED_TWITTER_PATHS.contains(&segment.to_lowercase().as_str()) {
+                    return None;
+                }
+                segment
+            }
+            None => value.trim().trim_start_matches('@'),
+        };
+
+        if is_handle(handle, 1, 15) {
+            Some(Self(handle.to_string()))
+        } else {
+            None
+        }
+    }
+}
+
+impl From<&str> for TwitterHandle {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for TwitterHandle {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl PartialEq<&str> for TwitterHandle {
+    fn eq(&self, other: &&str) -> bool {
+        self.0.as_str() == *other
+    }
+}
+
+impl Display for TwitterHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Telegram group or channel name without the leading @
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(transparent)]
+pub struct TelegramHandle(pub String);
+
+impl TelegramHandle {
+    /// Accepts a public link (t.me / telegram.me), @name or a plain name; private invite links have no handle
+    pub fn parse(value: &str) -> Option<Self> {
+        let handle = match strip_host(value, &["t.me", "telegram.me"]) {
+            Some(path) => {
+                let path = path.strip_prefix("s/").unwrap_or(path);
+                let segment = first_segment(path)?;
+                if segment.eq_ignore_ascii_case("joinchat") {
+                    return None;
+                }
+                segment
+            }
+            None => value.trim().trim_start_matches('@'),
+        };
+
+        if is_handle(handle, 5, 32) {
+            Some(Self(handle.to_string()))
+        } else {
+            None
+        }
+    }
+}
+
+impl From<&str> for TelegramHandle {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for TelegramHandle {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl PartialEq<&str> for TelegramHandle {
+    fn eq(&self, other: &&str) -> bool {
+        self.0.as_str() == *other
+    }
+}
+
+impl Display for TelegramHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+const RESERVED_TWITTER_PATHS: [&str; 9] = [
+    "i", "home", "search", "intent", "hashtag", "share", "explore", "messages", "settings",
+];
+
+/// Returns the path after the host, if the value points to one of the given hosts
+fn strip_host<'a>(value: &'a str, hosts: &[&str]) -> Option<&'a str> {
+    let value = value.trim();
+    let value = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+        .unwrap_or(value);
+
+    let (host, path) = value.split_once('/').unwrap_or((value, ""));
+    let host = host.to_lowercase();
+    let host = host
+        .strip_prefix("www.")
+        .or_else(|| host.strip_prefix("mobile."))
+        .unwrap_or(&host);
+
+    if hosts.contains(&host) {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+fn first_segment(path: &str) -> Option<&str> {
+    path.split(['/', '?', '#'])
+        .next()
+        .filter(|segment| !segment.is_empty())
+}
+
+fn is_handle(value: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&value.len())
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::Fact::{
+        TelegramGroup, TelegramGroupHandle, TwitterAccount, TwitterAccountHandle,
+    };
+    use crate::model::{add_social_to_facts, Facts, TelegramHandle, Token, TwitterHandle, Value};
+
+    #[test]
+    fn test_social_facts() {
+        let mut token = Token {
+            id: 1.into(),
+            mint: "So11111111111111111111111111111111111111112".into(),
+            name: None,
+            symbol: None,
+            decimals: 6.into(),
+            supply: None,
+            description: None,
+            metadata: None,
+            image: None,
+            website: None,
+            twitter: Some(TwitterHandle::from("AI_nyanbot")),
+            telegram: None,
+            creator: None,
+            block: None,
+            block_time: None,
+        };
+
+        let mut facts = Facts::new();
+        add_social_to_facts(&mut facts, &token);
+        assert_eq!(facts.get(&TwitterAccount), Some(&Value::boolean(true)));
+        assert_eq!(
+            facts.get(&TwitterAccountHandle),
+            Some(&Value::string("AI_nyanbot"))
+        );
+        assert_eq!(facts.get(&TelegramGroup), Some(&Value::boolean(false)));
+        assert_eq!(facts.get(&TelegramGroupHandle), None);
+
+        token.twitter = None;
+        token.telegram = Some(TelegramHandle::from("nyanbot"));
+
+        let mut facts = Facts::new();
+        add_social_to_facts(&mut facts, &token);
+        assert_eq!(facts.get(&TwitterAccount), Some(&Value::boolean(false)));
+        assert_eq!(facts.get(&TwitterAccountHandle), None);
+        assert_eq!(facts.get(&TelegramGroup), Some(&Value::boolean(true)));
+        assert_eq!(
+            facts.get(&TelegramGroupHandle),
+            Some(&Value::string("nyanbot"))
+        );
+    }
+
+    #[test]
+    fn test_twitter_url() {
+        for value in [
+            "https://x.com/nyanbot",
+            "https://twitter.com/nyanbot",
+            "http://www.twitter.com/nyanbot/",
+            "x.com/nyanbot?s=21",
+            "https://mobile.x.com/nyanbot/status/1234",
+        ] {
+            assert_eq!(
+                TwitterHandle::parse(value),
+                Some(TwitterHandle::from("nyanbot")),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_twitter_handle() {
+        assert_eq!(
+            TwitterHandle::parse("@NyanBot"),
+            Some(TwitterHandle::from("NyanBot"))
+        );
+        assert_eq!(
+            TwitterHandle::parse(" nyan_bot "),
+            Some(TwitterHandle::from("nyan_bot"))
+        );
+    }
+
+    #[test]
+    fn test_twitter_invalid() {
+        for value in [
+            "",
+            "@",
+            "https://x.com/",
+            "https://x.com/i/communities/1234",
+            "https://x.com/search?q=nyan",
+            "https://nyanbot.com/nyanbot",
+            "this_handle_is_too_long",
+            "nyan bot",
+        ] {
+            assert_eq!(TwitterHandle::parse(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_telegram_url() {
+        for value in [
+            "https://t.me/nyanbot",
+            "https://telegram.me/nyanbot/",
+            "t.me/s/nyanbot",
+            "https://www.t.me/nyanbot?start=1",
+        ] {
+            assert_eq!(
+                TelegramHandle::parse(value),
+                Some(TelegramHandle::from("nyanbot")),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_telegram_handle() {
+        assert_eq!(
+            TelegramHandle::parse("@NyanBot"),
+            Some(TelegramHandle::from("NyanBot"))
+        );
+        assert_eq!(
+            TelegramHandle::parse("nyan_bot"),
+            Some(TelegramHandle::from("nyan_bot"))
+        );
+    }
+
+    #[test]
+    fn test_telegram_invalid() {
+        for value in [
+            "",
+            "https://t.me/+AbCdEfGh123",
+            "https://t.me/joinchat/AbCdEfGh123",
+            "https://t.me/",
+            "nyan",
+            "https://nyanbot.com/nyanbot",
+        ] {
+            assert_eq!(TelegramHandle::parse(value), None, "{value}");
+        }
+    }
+}
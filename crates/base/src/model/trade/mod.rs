@@ -0,0 +1,33 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Signature;
+use crate::model::{DecimalAmount, InvocationId, TokenPairId, UserId, Venue, WalletId};
+use common::model::{CreatedAt, PriceQuote, UpdatedAt};
+
+pub use id::*;
+pub use side::*;
+pub use status::*;
+
+mod id;
+mod side;
+mod status;
+
+#[derive(Debug, Clone)]
+pub struct Trade {
+    pub id: TradeId,
+    pub invocation: InvocationId,
+    pub user: UserId,
+    pub wallet: WalletId,
+    pub token_pair: TokenPairId,
+    pub venue: Venue,
+    pub side: TradeSide,
+    pub status: TradeStatus,
+    pub amount_base: Option<DecimalAmount>,
+    pub amount_quote: Option<DecimalAmount>,
+    pub price: Option<PriceQuote>,
+    pub signature: Option<Signature>,
+    pub error: Option<String>,
+    pub created_at: CreatedAt,
+    pub updated_at: UpdatedAt,
+}
@@ -0,0 +1,25 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize, Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[repr(i16)]
+pub enum TradeSide {
+    // Spends quote token to receive base token
+    Buy = 1,
+    // Spends base token to receive quote token
+    Sell = 2,
+}
+
+impl Display for TradeSide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TradeSide::Buy => f.write_str("Buy"),
+            TradeSide::Sell => f.write_str("Sell"),
+        }
+    }
+}
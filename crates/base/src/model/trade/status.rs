@@ -0,0 +1,28 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize, Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[repr(i16)]
+pub enum TradeStatus {
+    // Transaction was accepted by the rpc node
+    Submitted = 1,
+    // Transaction could not be built or was rejected by the rpc node
+    Failed = 2,
+    // Trade was recorded before its transaction got sent
+    Pending = 3,
+}
+
+impl Display for TradeStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TradeStatus::Submitted => f.write_str("Submitted"),
+            TradeStatus::Failed => f.write_str("Failed"),
+            TradeStatus::Pending => f.write_str("Pending"),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Signature;
+use crate::model::{DecimalAmount, PublicKey, TokenId, UserId, WalletId};
+use common::model::{CreatedAt, UpdatedAt};
+
+pub use id::*;
+pub use status::*;
+
+mod id;
+mod status;
+
+/// Withdrawal from a custodial wallet to an external address
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    pub id: TransferId,
+    pub user: UserId,
+    pub wallet: WalletId,
+    /// None if native SOL was transferred
+    pub token: Option<TokenId>,
+    pub recipient: PublicKey,
+    pub amount: DecimalAmount,
+    pub status: TransferStatus,
+    /// None until the transaction got sent
+    pub signature: Option<Signature>,
+    pub error: Option<String>,
+    pub created_at: CreatedAt,
+    pub updated_at: UpdatedAt,
+}
@@ -0,0 +1,28 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize, Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[repr(i16)]
+pub enum TransferStatus {
+    // Transaction was accepted by the rpc node
+    Submitted = 1,
+    // Transfer was rejected before sending or its transaction was rejected by the rpc node
+    Failed = 2,
+    // Transfer was recorded before its transaction got sent
+    Pending = 3,
+}
+
+impl Display for TransferStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransferStatus::Submitted => f.write_str("Submitted"),
+            TransferStatus::Failed => f.write_str("Failed"),
+            TransferStatus::Pending => f.write_str("Pending"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+use crate::model::RuleId;
 use crate::repo::invocation::InvocationRepo;
 use common::model::Count;
 use common::repo::{RepoResult, Tx};
@@ -13,4 +14,19 @@ impl InvocationRepo {
             .await?
             .get::<Count, _>("count"))
     }
+
+    /// Number of token pairs for which the initial stage of the rule was performed
+    pub async fn count_by_rule<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        rule: impl Into<RuleId> + Send,
+    ) -> RepoResult<Count> {
+        Ok(
+            sqlx::query("select count(distinct token_pair_id) from solana.invocation where rule_id = $1 and stage = 0;")
+                .bind(rule.into())
+                .fetch_one(&mut **tx)
+                .await?
+                .get::<Count, _>("count"),
+        )
+    }
 }
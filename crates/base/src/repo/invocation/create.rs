@@ -3,8 +3,10 @@
 
 use crate::model::TokenPairId;
 use crate::model::{Invocation, InvocationId, Sequence};
-use crate::model::{RuleId, UserId};
+use crate::model::{RuleId, RuleRearm, UserId};
 use crate::repo::invocation::InvocationRepo;
+use common::model::Timestamp;
+use common::repo::error::RepoError;
 use common::repo::{RepoResult, Tx};
 use sqlx::types::JsonValue;
 use sqlx::{query, Row};
@@ -13,16 +15,44 @@ pub struct InvocationCreateCmd {
     pub user: UserId,
     pub rule: RuleId,
     pub token_pair: TokenPairId,
+    pub stage: i16,
     pub next: Option<Sequence>,
+    pub deadline: Option<Timestamp>,
 }
 
 impl InvocationRepo {
+    /// Fails with AlreadyExists, if a rule which never rearms was already invoked for the token pair
     pub async fn create<'a>(&self, tx: &mut Tx<'a>, cmd: InvocationCreateCmd) -> RepoResult<Invocation> {
-        let invocation_id = query("insert into solana.invocation (user_id, rule_id, token_pair_id, next) values ($1, $2, $3, $4) returning id")
+        if cmd.stage == 0 {
+            // locking the rule serializes concurrent invocations of it until the transaction ends
+            let never_rearms = query("select rearm = $2 as never_rearms from solana.rule where id = $1 for update")
+                .bind(cmd.rule)
+                .bind(RuleRearm::Never)
+                .fetch_optional(&mut **tx)
+                .await?
+                .is_some_and(|r| r.get::<bool, _>("never_rearms"));
+
+            if never_rearms {
+                let invoked = query("select exists(select 1 from solana.invocation where rule_id = $1 and token_pair_id = $2 and stage = 0) as invoked")
+                    .bind(cmd.rule)
+                    .bind(cmd.token_pair)
+                    .fetch_one(&mut **tx)
+                    .await?
+                    .get::<bool, _>("invoked");
+
+                if invoked {
+                    return Err(RepoError::AlreadyExists);
+                }
+            }
+        }
+
+        let invocation_id = query("insert into solana.invocation (user_id, rule_id, token_pair_id, stage, next, deadline) values ($1, $2, $3, $4, $5, $6) returning id")
             .bind(cmd.user)
             .bind(cmd.rule)
             .bind(cmd.token_pair)
-            .bind::<JsonValue>(cmd.next.into())
+            .bind(cmd.stage)
+            .bind(cmd.next.map(JsonValue::from))
+            .bind(cmd.deadline)
             .fetch_one(&mut **tx)
             .await
             .map(|r| r.get::<InvocationId, _>("id"))?;
@@ -0,0 +1,90 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::TokenPairId;
+use crate::model::{Invocation, InvocationId, Sequence};
+use crate::model::{RuleId, UserId};
+use crate::repo::invocation::InvocationRepo;
+use common::model::{Count, CreatedAt, Limit, Timestamp};
+use common::repo::{RepoResult, Tx};
+use sqlx::postgres::PgRow;
+use sqlx::types::JsonValue;
+use sqlx::{query, Row};
+
+pub struct InvokedTokenPair {
+    pub token_pair: TokenPairId,
+    /// Number of times the initial stage was performed
+    pub count: Count,
+    pub last: CreatedAt,
+}
+
+impl InvocationRepo {
+    /// Invocations of a rule which still wait for the condition of their next sequence
+    pub async fn list_pending<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        rule: impl Into<RuleId> + Send,
+    ) -> RepoResult<Vec<Invocation>> {
+        Ok(query("select * from solana.invocation where rule_id = $1 and next is not null and next != 'null'::jsonb order by id;")
+            .bind(rule.into())
+            .fetch_all(&mut **tx)
+            .await?
+            .iter()
+            .map(to_invocation)
+            .collect())
+    }
+
+    /// Token pairs for which the initial stage of a rule was performed, with how often and when it was performed last
+    pub async fn list_invoked<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        rule: impl Into<RuleId> + Send,
+    ) -> RepoResult<Vec<InvokedTokenPair>> {
+        Ok(query("select token_pair_id, count(*) as count, max(created_at) as last from solana.invocation where rule_id = $1 and stage = 0 group by token_pair_id order by token_pair_id;")
+            .bind(rule.into())
+            .fetch_all(&mut **tx)
+            .await?
+            .iter()
+            .map(|r| InvokedTokenPair {
+                token_pair: r.get::<TokenPairId, _>("token_pair_id"),
+                count: r.get::<Count, _>("count"),
+                last: r.get::<CreatedAt, _>("last"),
+            })
+            .collect())
+    }
+
+    /// Invocations created after the given one, oldest first
+    pub async fn list_after<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        after: impl Into<InvocationId> + Send,
+        limit: impl Into<Limit> + Send,
+    ) -> RepoResult<Vec<Invocation>> {
+        Ok(
+            query("select * from solana.invocation where id > $1 order by id limit $2;")
+                .bind(after.into())
+                .bind(limit.into())
+                .fetch_all(&mut **tx)
+                .await?
+                .iter()
+                .map(to_invocation)
+                .collect(),
+        )
+    }
+}
+
+pub(super) fn to_invocation(r: &PgRow) -> Invocation {
+    Invocation {
+        id: r.get::<InvocationId, _>("id"),
+        user: r.get::<UserId, _>("user_id"),
+        rule: r.get::<RuleId, _>("rule_id"),
+        token_pair: r.get::<TokenPairId, _>("token_pair_id"),
+        stage: r.get::<i16, _>("stage"),
+        next: r
+            .get::<Option<JsonValue>, _>("next")
+            .filter(|value| *value != JsonValue::Null)
+            .map(Sequence::from),
+        deadline: r.get::<Option<Timestamp>, _>("deadline"),
+        created_at: r.get::<CreatedAt, _>("created_at"),
+    }
+}
@@ -3,6 +3,7 @@
 
 use common::model::Limit;
 pub use create::*;
+pub use list::InvokedTokenPair;
 
 use std::ops::Deref;
 use std::sync::Arc;
@@ -10,6 +11,8 @@ use std::sync::Arc;
 mod count;
 mod create;
 mod get;
+mod list;
+mod update;
 
 pub struct InvocationQueryAll {
     pub limit: Limit,
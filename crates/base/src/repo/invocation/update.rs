@@ -0,0 +1,27 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::InvocationId;
+use crate::repo::invocation::InvocationRepo;
+use common::repo::error::RepoError;
+use common::repo::{RepoResult, Tx};
+use sqlx::query;
+
+impl InvocationRepo {
+    /// Marks the next sequence of an invocation as consumed, so that it is no longer pending
+    pub async fn complete<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        id: impl Into<InvocationId> + Send,
+    ) -> RepoResult<()> {
+        let result = query("update solana.invocation set next = null, updated_at = now() where id = $1 and next is not null;")
+            .bind(id.into())
+            .execute(&mut **tx)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepoError::NotFound);
+        }
+        Ok(())
+    }
+}
@@ -3,15 +3,24 @@
 
 pub use crate::repo::address::{AddressQuery, AddressRepo};
 pub use crate::repo::auth::{AuthCreateCmd, AuthQueryAll, AuthRepo};
-pub use crate::repo::invocation::{InvocationCreateCmd, InvocationQueryAll, InvocationRepo};
+pub use crate::repo::invocation::{
+    InvocationCreateCmd, InvocationQueryAll, InvocationRepo, InvokedTokenPair,
+};
 pub use crate::repo::notification::{
     NotificationCreateCmd, NotificationQueryAll, NotificationQueryUser, NotificationRepo,
 };
 pub use crate::repo::rule::{RuleCreateCmd, RuleQueryAll, RuleQueryUser, RuleRepo, RuleUpdateCmd};
 pub use crate::repo::sol::{SolQuery, SolRepo};
-pub use crate::repo::token::{TokenQuery, TokenRepo, TokenToInsert};
-pub use crate::repo::token_balance::{TokenBalanceRepo, TokenBalanceToInsert};
+pub use crate::repo::token::{TokenQuery, TokenRepo, TokenToInsert, UnresolvedMetadata};
+pub use crate::repo::token_balance::{
+    SolBalanceToInsert, TokenBalanceRepo, TokenBalanceToInsert, TokenBalanceWithPrice,
+};
 pub use crate::repo::token_pair::{TokenPairQuery, TokenPairRepo};
+pub use crate::repo::token_pair_change::TokenPairChangeRepo;
+pub use crate::repo::trade::{TradeCreateCmd, TradeQueryAll, TradeRepo, TradeUpdateCmd};
+pub use crate::repo::transfer::{
+    TransferCreateCmd, TransferQueryWallet, TransferRepo, TransferUpdateCmd,
+};
 pub use crate::repo::user::{UserCreateTelegramCmd, UserQueryAll, UserRepo};
 pub use crate::repo::wallet::{WalletCreateCmd, WalletQueryAll, WalletRepo};
 
@@ -25,5 +34,8 @@ mod sol;
 mod token;
 mod token_balance;
 mod token_pair;
+mod token_pair_change;
+mod trade;
+mod transfer;
 mod user;
 mod wallet;
@@ -1,13 +1,11 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use crate::model::UserId;
-use crate::model::{Rule, RuleId, RuleName, RuleStatus, RuleVersion};
+use crate::model::{Rule, RuleId};
+use crate::repo::rule::list::to_rule;
 use crate::repo::rule::RuleRepo;
-use common::model::{CreatedAt, UpdatedAt};
 use common::repo::{RepoResult, Tx};
-use sqlx::types::JsonValue;
-use sqlx::{query, Row};
+use sqlx::query;
 
 impl RuleRepo {
     pub async fn get_by_id<'a>(
@@ -19,15 +17,6 @@ impl RuleRepo {
             .bind(id.into())
             .fetch_one(&mut **tx)
             .await
-            .map(|r| Rule {
-                id: r.get::<RuleId, _>("id"),
-                version: r.get::<RuleVersion, _>("version"),
-                status: r.get::<RuleStatus, _>("status"),
-                name: r.get::<RuleName, _>("name"),
-                user: r.get::<UserId, _>("user_id"),
-                sequence: r.get::<JsonValue, _>("sequence").into(),
-                created_at: r.get::<CreatedAt, _>("created_at"),
-                updated_at: r.get::<UpdatedAt, _>("updated_at"),
-            })?)
+            .map(|r| to_rule(&r))?)
     }
 }
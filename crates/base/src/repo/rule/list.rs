@@ -1,11 +1,12 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use crate::model::{Rule, RuleId, RuleName, RuleVersion};
+use crate::model::{Rule, RuleId, RuleLimit, RuleName, RuleRearm, RuleVersion};
 use crate::model::{RuleStatus, UserId};
 use crate::repo::rule::{RuleQueryAll, RuleQueryUser, RuleRepo};
-use common::model::{CreatedAt, UpdatedAt};
+use common::model::{CreatedAt, Timestamp, UpdatedAt};
 use common::repo::{RepoResult, Tx};
+use sqlx::postgres::PgRow;
 use sqlx::types::JsonValue;
 use sqlx::Row;
 
@@ -16,21 +17,32 @@ impl RuleRepo {
         query: RuleQueryAll,
     ) -> RepoResult<Box<[Rule]>> {
         Ok(
-            sqlx::query("select * from solana.rule where status = 1 order by id desc limit $1;")
+            sqlx::query("select * from solana.rule where status = $1 order by id desc limit $2;")
+                .bind(RuleStatus::Active)
                 .bind(query.limit)
                 .fetch_all(&mut **tx)
                 .await?
                 .iter()
-                .map(|r| Rule {
-                    id: r.get::<RuleId, _>("id"),
-                    version: r.get::<RuleVersion, _>("version"),
-                    status: r.get::<RuleStatus, _>("status"),
-                    name: r.get::<RuleName, _>("name"),
-                    user: r.get::<UserId, _>("user_id"),
-                    sequence: r.get::<JsonValue, _>("sequence").into(),
-                    created_at: r.get::<CreatedAt, _>("created_at"),
-                    updated_at: r.get::<UpdatedAt, _>("updated_at"),
-                })
+                .map(to_rule)
+                .collect::<Vec<_>>()
+                .into_boxed_slice(),
+        )
+    }
+
+    /// Rules which are active but exhausted, and might be replenished
+    pub async fn list_active_exhausted<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        query: RuleQueryAll,
+    ) -> RepoResult<Box<[Rule]>> {
+        Ok(
+            sqlx::query("select * from solana.rule where status = $1 order by id desc limit $2;")
+                .bind(RuleStatus::ActiveExhausted)
+                .bind(query.limit)
+                .fetch_all(&mut **tx)
+                .await?
+                .iter()
+                .map(to_rule)
                 .collect::<Vec<_>>()
                 .into_boxed_slice(),
         )
@@ -48,17 +60,30 @@ impl RuleRepo {
                 .fetch_all(&mut **tx)
                 .await?
                 .iter()
-                .map(|r| Rule {
-                    id: r.get::<RuleId, _>("id"),
-                    version: r.get::<RuleVersion, _>("version"),
-                    status: r.get::<RuleStatus, _>("status"),
-                    name: r.get::<RuleName, _>("name"),
-                    user: r.get::<UserId, _>("user_id"),
-                    sequence: r.get::<JsonValue, _>("sequence").into(),
-                    created_at: r.get::<CreatedAt, _>("created_at"),
-                    updated_at: r.get::<UpdatedAt, _>("updated_at"),
-                })
+                .map(to_rule)
                 .collect::<Vec<_>>(),
         )
     }
 }
+
+pub(super) fn to_rule(r: &PgRow) -> Rule {
+    Rule {
+        id: r.get::<RuleId, _>("id"),
+        version: r.get::<RuleVersion, _>("version"),
+        status: r.get::<RuleStatus, _>("status"),
+        name: r.get::<RuleName, _>("name"),
+        user: r.get::<UserId, _>("user_id"),
+        sequence: r.get::<JsonValue, _>("sequence").into(),
+        limit: RuleLimit {
+            max_invocations: r.get::<Option<i32>, _>("max_invocations"),
+            cooldown_seconds: r.get::<Option<i64>, _>("cooldown_seconds"),
+            rearm: r.get::<RuleRearm, _>("rearm"),
+            max_invocations_per_token_pair: r
+                .get::<Option<i32>, _>("max_invocations_per_token_pair"),
+            start_at: r.get::<Option<Timestamp>, _>("start_at"),
+            end_at: r.get::<Option<Timestamp>, _>("end_at"),
+        },
+        created_at: r.get::<CreatedAt, _>("created_at"),
+        updated_at: r.get::<UpdatedAt, _>("updated_at"),
+    }
+}
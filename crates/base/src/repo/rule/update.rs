@@ -1,7 +1,7 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use crate::model::{Rule, RuleId, RuleName, RuleStatus, Sequence, UserId};
+use crate::model::{Rule, RuleId, RuleLimit, RuleName, RuleStatus, Sequence, UserId};
 use crate::repo::RuleRepo;
 use common::repo::{RepoResult, Tx};
 use sqlx::types::JsonValue;
@@ -14,20 +14,46 @@ pub struct RuleUpdateCmd {
     pub name: RuleName,
     pub sequence: Sequence,
     pub status: RuleStatus,
+    pub limit: RuleLimit,
 }
 
 impl RuleRepo {
     pub async fn update<'a>(&self, tx: &mut Tx<'a>, cmd: RuleUpdateCmd) -> RepoResult<Rule> {
-        let rule_id = query("update solana.rule set name = $3, status = $4, sequence = $5 where id = $1 and user_id = $2 returning id, updated_at = now();")
+        let rule_id = query("update solana.rule set name = $3, status = $4, sequence = $5, max_invocations = $6, cooldown_seconds = $7, start_at = $8, end_at = $9, rearm = $10, max_invocations_per_token_pair = $11 where id = $1 and user_id = $2 returning id, updated_at = now();")
             .bind(cmd.id)
             .bind(cmd.user)
             .bind(cmd.name)
             .bind(cmd.status)
             .bind::<JsonValue>(cmd.sequence.into())
+            .bind(cmd.limit.max_invocations)
+            .bind(cmd.limit.cooldown_seconds)
+            .bind(cmd.limit.start_at)
+            .bind(cmd.limit.end_at)
+            .bind(cmd.limit.rearm)
+            .bind(cmd.limit.max_invocations_per_token_pair)
             .fetch_one(&mut **tx)
             .await
             .map(|r| r.get::<RuleId, _>("id"))?;
 
         self.get_by_id(tx, rule_id).await
     }
+
+    /// Changes the status of a rule, regardless of its user
+    pub async fn update_status<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        id: impl Into<RuleId> + Send,
+        status: RuleStatus,
+    ) -> RepoResult<Rule> {
+        let rule_id = query(
+            "update solana.rule set status = $2, updated_at = now() where id = $1 returning id;",
+        )
+        .bind(id.into())
+        .bind(status)
+        .fetch_one(&mut **tx)
+        .await
+        .map(|r| r.get::<RuleId, _>("id"))?;
+
+        self.get_by_id(tx, rule_id).await
+    }
 }
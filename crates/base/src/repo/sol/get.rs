@@ -0,0 +1,22 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::repo::SolRepo;
+use common::model::PriceUsd;
+use common::repo::{RepoResult, Tx};
+use sqlx::Row;
+
+impl SolRepo {
+    /// Most recent SOL price in USD, None if no price was calculated yet
+    pub async fn get_latest_usd<'a>(&self, tx: &mut Tx<'a>) -> RepoResult<Option<PriceUsd>> {
+        Ok(sqlx::query(
+            r#"select usd
+            from solana.sol_price_1m
+            order by timestamp desc
+            limit 1;"#,
+        )
+        .fetch_optional(&mut **tx)
+        .await?
+        .map(|r| r.get::<PriceUsd, _>("usd")))
+    }
+}
@@ -6,6 +6,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 mod calculate;
+mod get;
 
 pub struct SolQuery {
     pub limit: Limit,
@@ -5,7 +5,8 @@
 // Original AGPL 3 License Copyright (c) blockworks-foundation 2024.
 
 use crate::model::{
-    AddressId, DecimalAmount, Decimals, Description, Mint, Name, Symbol, Token, TokenId, Uri,
+    AddressId, DecimalAmount, Decimals, Description, Mint, Name, Symbol, TelegramHandle, Token,
+    TokenId, TwitterHandle, Uri,
 };
 use crate::repo::TokenRepo;
 use common::model::{BlockId, BlockTimestamp, Timestamp};
@@ -25,6 +26,8 @@ pub struct TokenToInsert {
     pub description: Option<Description>,
     pub image: Option<Uri>,
     pub website: Option<Uri>,
+    pub twitter: Option<TwitterHandle>,
+    pub telegram: Option<TelegramHandle>,
     pub creator: Option<AddressId>,
 }
 
@@ -48,6 +51,8 @@ impl TokenRepo {
         let mut descriptions = Vec::with_capacity(token_list.len());
         let mut images = Vec::with_capacity(token_list.len());
         let mut websites = Vec::with_capacity(token_list.len());
+        let mut twitters = Vec::with_capacity(token_list.len());
+        let mut telegrams = Vec::with_capacity(token_list.len());
         let mut creators = Vec::with_capacity(token_list.len());
         let mut blocks = Vec::with_capacity(token_list.len());
         let mut block_times = Vec::with_capacity(token_list.len());
@@ -68,6 +73,8 @@ impl TokenRepo {
             descriptions.push(to_insert.description.unwrap_or("null_value".into()));
             images.push(to_insert.image.unwrap_or("null_value".into()));
             websites.push(to_insert.website.unwrap_or("null_value".into()));
+            twitters.push(to_insert.twitter.unwrap_or("null_value".into()));
+            telegrams.push(to_insert.telegram.unwrap_or("null_value".into()));
             creators.push(to_insert.creator.unwrap_or(AddressId::from(-1)));
             blocks.push(to_insert.block.unwrap_or(BlockId::from(-1)));
             block_times.push(
@@ -79,7 +86,7 @@ impl TokenRepo {
 
         Ok(sqlx::query(
             r#"with new_token as (
-            insert into solana.token (mint, name, symbol, decimals, supply, metadata, description, image, website, creator_id, block_id, block_time)
+            insert into solana.token (mint, name, symbol, decimals, supply, metadata, description, image, website, twitter, telegram, creator_id, block_id, block_time)
             select
                 unnest($1::text[]) as mint,
                 unnest(array_replace($2::text[], 'null_value', null)) as name,
@@ -90,9 +97,11 @@ impl TokenRepo {
                 unnest(array_replace($7::text[], 'null_value', null)) as description,
                 unnest(array_replace($8::text[], 'null_value', null)) as image,
                 unnest(array_replace($9::text[], 'null_value', null)) as website,
-                unnest(array_replace($10::int8[], -1, null)) as creator_id,
-                unnest(array_replace($11::int8[], -1, null)) as block_id,
-                unnest(array_replace($12::timestamptz[], '1970-01-01 00:00:00+00'::timestamptz, null)) as block_time
+                unnest(array_replace($10::text[], 'null_value', null)) as twitter,
+                unnest(array_replace($11::text[], 'null_value', null)) as telegram,
+                unnest(array_replace($12::int8[], -1, null)) as creator_id,
+                unnest(array_replace($13::int8[], -1, null)) as block_id,
+                unnest(array_replace($14::timestamptz[], '1970-01-01 00:00:00+00'::timestamptz, null)) as block_time
             on conflict (mint) do update set
                 mint = excluded.mint,
                 name = excluded.name,
@@ -103,6 +112,8 @@ impl TokenRepo {
                 description = excluded.description,
                 image = excluded.image,
                 website = excluded.website,
+                twitter = excluded.twitter,
+                telegram = excluded.telegram,
                 creator_id = excluded.creator_id,
                 block_id = excluded.block_id,
                 block_time = excluded.block_time
@@ -117,6 +128,8 @@ impl TokenRepo {
                 description,
                 image,
                 website,
+                twitter,
+                telegram,
                 creator_id,
                 block_id,
                 block_time
@@ -132,6 +145,8 @@ impl TokenRepo {
         .bind(descriptions)
         .bind(images)
         .bind(websites)
+        .bind(twitters)
+        .bind(telegrams)
         .bind(creators)
         .bind(blocks)
         .bind(block_times)
@@ -149,6 +164,8 @@ impl TokenRepo {
             metadata: r.try_get::<Uri, _>("metadata").ok(),
             image: r.try_get::<Uri, _>("image").ok(),
             website: r.try_get::<Uri, _>("website").ok(),
+            twitter: r.try_get::<TwitterHandle, _>("twitter").ok(),
+            telegram: r.try_get::<TelegramHandle, _>("telegram").ok(),
             creator: r.try_get::<AddressId, _>("creator_id").ok(),
             block: r.try_get::<BlockId, _>("block_id").ok(),
             block_time: r.try_get::<BlockTimestamp, _>("block_time").ok()
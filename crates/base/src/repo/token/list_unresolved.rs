@@ -0,0 +1,56 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{Mint, TokenId, Uri};
+use crate::repo::TokenRepo;
+use common::model::Limit;
+use common::repo::{RepoResult, Tx};
+use sqlx::{query, Row};
+
+/// Token whose off-chain metadata has not been fetched yet
+#[derive(Debug, Clone)]
+pub struct UnresolvedMetadata {
+    pub id: TokenId,
+    pub mint: Mint,
+    pub metadata: Uri,
+    pub attempts: i16,
+}
+
+impl TokenRepo {
+    /// Lists tokens with a metadata uri which has not been resolved yet, fewest attempts first and newest tokens
+    /// first. A failed token becomes due again after a minute per previous attempt and is given up on after
+    /// `max_attempts`
+    pub async fn list_unresolved_metadata<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        max_attempts: i16,
+        limit: impl Into<Limit>,
+    ) -> RepoResult<Vec<UnresolvedMetadata>> {
+        let limit = limit.into();
+        Ok(query(
+            r#"
+select id, mint, metadata, metadata_attempts
+from solana.token
+where
+    metadata is not null
+    and metadata_resolved_at is null
+    and metadata_attempts < $1
+    and (metadata_attempted_at is null or metadata_attempted_at < now() - interval '1 minute' * metadata_attempts)
+order by metadata_attempts, id desc
+limit $2;
+"#,
+        )
+        .bind(max_attempts)
+        .bind(limit)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| UnresolvedMetadata {
+            id: r.get::<TokenId, _>("id"),
+            mint: r.get::<Mint, _>("mint"),
+            metadata: r.get::<Uri, _>("metadata"),
+            attempts: r.get::<i16, _>("metadata_attempts"),
+        })
+        .collect())
+    }
+}
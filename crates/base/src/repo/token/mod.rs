@@ -5,6 +5,7 @@ use crate::model::{Mint, Token, TokenId};
 use crate::repo::cache::Cache;
 use common::model::Limit;
 pub use insert::TokenToInsert;
+pub use list_unresolved::UnresolvedMetadata;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -17,7 +18,9 @@ mod get_or_populate;
 mod insert;
 mod list;
 mod list_or_populate;
+mod list_unresolved;
 mod populate;
+mod resolve;
 mod shared;
 
 pub struct TokenQuery {
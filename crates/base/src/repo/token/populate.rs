@@ -33,7 +33,7 @@ impl TokenRepo {
                     mint: info.mint.expect("token mint required"),
                     name: info.name,
                     symbol: info.symbol,
-                    decimals: decimals.clone(),
+                    decimals,
                     supply: info
                         .supply
                         .map(|amount| DecimalAmount::new(amount, decimals)),
@@ -41,6 +41,8 @@ impl TokenRepo {
                     description: info.description,
                     image: info.image,
                     website: info.website,
+                    twitter: info.twitter,
+                    telegram: info.telegram,
                     creator: None,
                     block: None,
                     block_time: None,
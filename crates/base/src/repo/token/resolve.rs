@@ -0,0 +1,105 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{
+    AddressId, DecimalAmount, Decimals, Description, Mint, Name, Symbol, TelegramHandle, Token,
+    TokenId, TwitterHandle, Uri,
+};
+use crate::repo::TokenRepo;
+use crate::TokenInfo;
+use common::model::{BlockId, BlockTimestamp};
+use common::repo::{RepoResult, Tx};
+use sqlx::{query, Row};
+
+impl TokenRepo {
+    /// Fills in the off-chain metadata of a token, values which are already known are kept
+    pub async fn resolve_metadata<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        id: impl Into<TokenId> + Send,
+        info: TokenInfo,
+    ) -> RepoResult<Token> {
+        let r = query(
+            r#"
+update solana.token set
+    description = coalesce(description, $2),
+    image = coalesce(image, $3),
+    website = coalesce(website, $4),
+    twitter = coalesce(twitter, $5),
+    telegram = coalesce(telegram, $6),
+    metadata_attempts = metadata_attempts + 1,
+    metadata_attempted_at = now(),
+    metadata_resolved_at = now()
+where id = $1
+returning
+    id,
+    mint,
+    name,
+    symbol,
+    decimals,
+    supply,
+    description,
+    metadata,
+    image,
+    website,
+    twitter,
+    telegram,
+    creator_id,
+    block_id,
+    block_time
+"#,
+        )
+        .bind(id.into())
+        .bind(info.description)
+        .bind(info.image)
+        .bind(info.website)
+        .bind(info.twitter)
+        .bind(info.telegram)
+        .fetch_one(&mut **tx)
+        .await?;
+
+        let token = Token {
+            id: r.get::<TokenId, _>("id"),
+            mint: r.get::<Mint, _>("mint"),
+            name: r.try_get::<Name, _>("name").ok(),
+            symbol: r.try_get::<Symbol, _>("symbol").ok(),
+            decimals: r.get::<Decimals, _>("decimals"),
+            supply: r.try_get::<DecimalAmount, _>("supply").ok(),
+            description: r.try_get::<Description, _>("description").ok(),
+            metadata: r.try_get::<Uri, _>("metadata").ok(),
+            image: r.try_get::<Uri, _>("image").ok(),
+            website: r.try_get::<Uri, _>("website").ok(),
+            twitter: r.try_get::<TwitterHandle, _>("twitter").ok(),
+            telegram: r.try_get::<TelegramHandle, _>("telegram").ok(),
+            creator: r.try_get::<AddressId, _>("creator_id").ok(),
+            block: r.try_get::<BlockId, _>("block_id").ok(),
+            block_time: r.try_get::<BlockTimestamp, _>("block_time").ok(),
+        };
+
+        self.cache
+            .put(token.id, token.mint.clone(), token.clone())
+            .await;
+
+        Ok(token)
+    }
+
+    /// Records a failed attempt to fetch the off-chain metadata of a token
+    pub async fn fail_metadata<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        id: impl Into<TokenId> + Send,
+    ) -> RepoResult<()> {
+        query(
+            r#"
+update solana.token set
+    metadata_attempts = metadata_attempts + 1,
+    metadata_attempted_at = now()
+where id = $1
+"#,
+        )
+        .bind(id.into())
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+}
@@ -5,7 +5,8 @@
 // Original AGPL 3 License Copyright (c) blockworks-foundation 2024.
 
 use crate::model::{
-    AddressId, DecimalAmount, Decimals, Description, Mint, Name, Symbol, Token, TokenId, Uri,
+    AddressId, DecimalAmount, Decimals, Description, Mint, Name, Symbol, TelegramHandle, Token,
+    TokenId, TwitterHandle, Uri,
 };
 use crate::repo::cache::Cache;
 use crate::repo::TokenRepo;
@@ -89,10 +90,12 @@ impl TokenRepo {
                 symbol,
                 decimals,
                 supply,
-                description
+                description,
                 metadata,
                 image,
                 website,
+                twitter,
+                telegram,
                 creator_id,
                 block_id,
                 block_time
@@ -114,6 +117,8 @@ impl TokenRepo {
             metadata: r.try_get::<Uri, _>("metadata").ok(),
             image: r.try_get::<Uri, _>("image").ok(),
             website: r.try_get::<Uri, _>("website").ok(),
+            twitter: r.try_get::<TwitterHandle, _>("twitter").ok(),
+            telegram: r.try_get::<TelegramHandle, _>("telegram").ok(),
             creator: r.try_get::<AddressId, _>("creator_id").ok(),
             block: r.try_get::<BlockId, _>("block_id").ok(),
             block_time: r.try_get::<BlockTimestamp, _>("block_time").ok(),
@@ -138,10 +143,12 @@ impl TokenRepo {
                 symbol,
                 decimals,
                 supply,
-                description
+                description,
                 metadata,
                 image,
                 website,
+                twitter,
+                telegram,
                 creator_id,
                 block_id,
                 block_time
@@ -163,6 +170,8 @@ impl TokenRepo {
             metadata: r.try_get::<Uri, _>("metadata").ok(),
             image: r.try_get::<Uri, _>("image").ok(),
             website: r.try_get::<Uri, _>("website").ok(),
+            twitter: r.try_get::<TwitterHandle, _>("twitter").ok(),
+            telegram: r.try_get::<TelegramHandle, _>("telegram").ok(),
             creator: r.try_get::<AddressId, _>("creator_id").ok(),
             block: r.try_get::<BlockId, _>("block_id").ok(),
             block_time: r.try_get::<BlockTimestamp, _>("block_time").ok(),
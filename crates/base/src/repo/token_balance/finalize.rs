@@ -0,0 +1,21 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Slot;
+use crate::repo::TokenBalanceRepo;
+use common::repo::{RepoResult, Tx};
+
+impl TokenBalanceRepo {
+    /// Forgets the previous balances of all slots up to the watermark, finalized slots never get rolled back
+    pub async fn finalize<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        watermark: impl Into<Slot>,
+    ) -> RepoResult<()> {
+        sqlx::query("delete from solana.token_balance_previous where slot <= $1;")
+            .bind(watermark.into())
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+}
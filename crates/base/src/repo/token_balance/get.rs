@@ -0,0 +1,47 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{DecimalAmount, PublicKey, TokenId};
+use crate::repo::TokenBalanceRepo;
+use common::repo::{RepoResult, Tx};
+use sqlx::Row;
+
+impl TokenBalanceRepo {
+    /// Most recent indexed balance of token held by owner, None if nothing was indexed yet
+    pub async fn get_token_balance<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        owner: impl Into<PublicKey> + Send,
+        token: impl Into<TokenId> + Send,
+    ) -> RepoResult<Option<DecimalAmount>> {
+        Ok(sqlx::query(
+            r#"select tb.balance
+            from solana.token_balance tb
+            join solana.address a on a.id = tb.address_id
+            where a.address = $1 and tb.token_id = $2;"#,
+        )
+        .bind(owner.into())
+        .bind(token.into())
+        .fetch_optional(&mut **tx)
+        .await?
+        .map(|r| r.get::<DecimalAmount, _>("balance")))
+    }
+
+    /// Most recent indexed SOL balance of owner, None if nothing was indexed yet
+    pub async fn get_sol_balance<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        owner: impl Into<PublicKey> + Send,
+    ) -> RepoResult<Option<DecimalAmount>> {
+        Ok(sqlx::query(
+            r#"select b.balance
+            from solana.wallet_balance_sol_most_recent b
+            join solana.address a on a.id = b.wallet_id
+            where a.address = $1;"#,
+        )
+        .bind(owner.into())
+        .fetch_optional(&mut **tx)
+        .await?
+        .map(|r| r.get::<DecimalAmount, _>("balance")))
+    }
+}
@@ -2,8 +2,8 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::model::solana::Slot;
-use crate::repo::balance::BalanceRepo;
-use base::model::{AddressId, DecimalAmount};
+use crate::model::{AddressId, DecimalAmount};
+use crate::repo::TokenBalanceRepo;
 use common::model::Timestamp;
 use common::repo::{RepoResult, Tx};
 use std::collections::HashMap;
@@ -16,7 +16,8 @@ pub struct SolBalanceToInsert {
     pub post: DecimalAmount,
 }
 
-impl BalanceRepo {
+impl TokenBalanceRepo {
+    /// Inserts the SOL balance of each address at the end of the slot, addresses whose balance did not change are skipped
     pub async fn insert_sol_balances<'a>(
         &self,
         tx: &mut Tx<'a>,
@@ -55,29 +56,30 @@ impl BalanceRepo {
         for (address, (pre, post, block_id, timestamp)) in aggregates {
             let balance = post.clone();
             let delta = post - pre;
-            addresses.push(address);
-            balances.push(balance);
-            deltas.push(delta);
-            slots.push(block_id);
-            timestamps.push(timestamp);
+            if delta != 0 {
+                addresses.push(address);
+                balances.push(balance);
+                deltas.push(delta);
+                slots.push(block_id);
+                timestamps.push(timestamp);
+            }
         }
 
         sqlx::query(
             r#"
-            insert into solana.balance_sol (
-                address_id,balance, delta, slot, timestamp
+            insert into solana.wallet_balance_sol (
+                wallet_id, balance, delta, slot, timestamp
             )
             select *
             from (
                 select
-                    unnest($1::int8[]) as address_id,
+                    unnest($1::int8[]) as wallet_id,
                     unnest($2::numeric(36, 12)[]) as balance,
                     unnest($3::numeric(36, 12)[]) as delta,
                     unnest($4::int8[]) as slot,
                     unnest($5::timestamptz[]) as timestamp
             ) as rows
-            where delta != 0
-            on conflict (address_id, slot) do update
+            on conflict (wallet_id, slot) do update
             set
                 balance = excluded.balance,
                 delta = excluded.delta
@@ -68,23 +68,35 @@ impl TokenBalanceRepo {
             }
         }
 
+        // the balance before the update is kept, until the slot is finalized
         sqlx::query(
             r#"
-            insert into solana.token_balance (
-                address_id, token_id, balance, slot, timestamp
-            )
-            select *
-            from (
+            with rows as (
                 select
                     unnest($1::int8[]) as address_id,
                     unnest($2::int8[]) as token_id,
                     unnest($3::numeric(36, 12)[]) as balance,
                     unnest($4::int8[]) as slot,
                     unnest($5::timestamptz[]) as timestamp
-            ) as rows
+            ), previous as (
+                insert into solana.token_balance_previous (
+                    address_id, token_id, slot, previous_balance, previous_slot, previous_timestamp
+                )
+                select r.address_id, r.token_id, r.slot, tb.balance, tb.slot, tb.timestamp
+                from rows r
+                left join solana.token_balance tb on tb.token_id = r.token_id and tb.address_id = r.address_id
+                on conflict (token_id, address_id, slot) do nothing
+            )
+            insert into solana.token_balance (
+                address_id, token_id, balance, slot, timestamp
+            )
+            select address_id, token_id, balance, slot, timestamp
+            from rows
             on conflict (token_id, address_id) do update
             set
                 balance = excluded.balance,
+                slot = excluded.slot,
+                timestamp = excluded.timestamp,
                 updated_at = now()
             "#,
         )
@@ -0,0 +1,65 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{DecimalAmount, Mint, PublicKey, TokenId};
+use crate::repo::TokenBalanceRepo;
+use common::model::PriceQuote;
+use common::repo::{RepoResult, Tx};
+use sqlx::Row;
+
+#[derive(Debug, Clone)]
+pub struct TokenBalanceWithPrice {
+    pub token: TokenId,
+    pub balance: DecimalAmount,
+    /// Price in SOL of the most recent pump.fun or Jupiter swap against WSOL, None if the token was never traded
+    pub price: Option<PriceQuote>,
+}
+
+impl TokenBalanceRepo {
+    /// Every token with a positive indexed balance held by owner
+    pub async fn list_token_balances<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        owner: impl Into<PublicKey> + Send,
+    ) -> RepoResult<Vec<TokenBalanceWithPrice>> {
+        Ok(sqlx::query(
+            r#"
+with wsol as (
+    select id from solana.token where mint = $2
+)
+select
+    tb.token_id,
+    tb.balance,
+    (
+        select price from (
+            select c.price, c.updated_at as at
+            from pumpfun.current c
+            join solana.token_pair tp on tp.id = c.id
+            where tp.base_id = tb.token_id and tp.quote_id = (select id from wsol) and c.price is not null
+            union all
+            select s.price, s.timestamp as at
+            from jupiter.swap_most_recent s
+            join solana.token_pair tp on tp.id = s.token_pair_id
+            where tp.base_id = tb.token_id and tp.quote_id = (select id from wsol)
+        ) prices
+        order by at desc
+        limit 1
+    ) as price
+from solana.token_balance tb
+join solana.address a on a.id = tb.address_id
+where a.address = $1 and tb.balance > 0
+order by tb.token_id;"#,
+        )
+        .bind(owner.into())
+        .bind(Mint::wsol())
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| TokenBalanceWithPrice {
+            token: r.get::<TokenId, _>("token_id"),
+            balance: r.get::<DecimalAmount, _>("balance"),
+            price: r.get::<Option<PriceQuote>, _>("price"),
+        })
+        .collect())
+    }
+}
@@ -1,9 +1,16 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+pub use insert_sol::SolBalanceToInsert;
 pub use insert_token::TokenBalanceToInsert;
+pub use list::TokenBalanceWithPrice;
 
+mod finalize;
+mod get;
+mod insert_sol;
 mod insert_token;
+mod list;
+mod rollback;
 
 #[derive(Clone, Default)]
 pub struct TokenBalanceRepo {}
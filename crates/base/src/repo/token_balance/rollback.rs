@@ -0,0 +1,128 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Slot;
+use crate::model::AddressId;
+use crate::repo::TokenBalanceRepo;
+use common::model::Timestamp;
+use common::repo::{RepoResult, Tx};
+use sqlx::Row;
+
+impl TokenBalanceRepo {
+    /// Removes the balances of slots which got dropped with their fork. The most recent SOL balance of the
+    /// affected addresses falls back to their previous balance. Token balances fall back to the balance they had
+    /// before the first dropped update, or get removed if they did not exist before. The block times of the slots bound the search.
+    pub async fn rollback<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        slots: &[Slot],
+        from: Timestamp,
+        to: Timestamp,
+    ) -> RepoResult<()> {
+        if slots.is_empty() {
+            return Ok(());
+        }
+
+        let addresses = sqlx::query(
+            r#"
+with deleted as (
+    delete from solana.wallet_balance_sol
+    where slot = any($1::int8[]) and timestamp >= $2 and timestamp <= $3
+    returning wallet_id
+)
+select distinct wallet_id from deleted;
+"#,
+        )
+        .bind(slots)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| r.get::<AddressId, _>("wallet_id"))
+        .collect::<Vec<_>>();
+
+        if !addresses.is_empty() {
+            sqlx::query(
+                r#"
+delete from solana.wallet_balance_sol_most_recent
+where wallet_id = any($1::int8[]) and slot = any($2::int8[]);
+"#,
+            )
+            .bind(&addresses)
+            .bind(slots)
+            .execute(&mut **tx)
+            .await?;
+
+            sqlx::query(
+                r#"
+insert into solana.wallet_balance_sol_most_recent (wallet_id, balance, delta, slot, timestamp, created_at, updated_at)
+select distinct on (wallet_id) wallet_id, balance, delta, slot, timestamp, created_at, now()
+from solana.wallet_balance_sol
+where wallet_id = any($1::int8[])
+order by wallet_id, slot desc
+on conflict (wallet_id) do nothing;
+"#,
+            )
+            .bind(&addresses)
+            .execute(&mut **tx)
+            .await?;
+        }
+
+        // follows the updates back until the first one, which was not dropped
+        sqlx::query(
+            r#"
+with recursive restored as (
+    select p.token_id, p.address_id, p.previous_balance, p.previous_slot, p.previous_timestamp
+    from solana.token_balance tb
+    join solana.token_balance_previous p on p.token_id = tb.token_id and p.address_id = tb.address_id and p.slot = tb.slot
+    where tb.slot = any($1::int8[])
+    union all
+    select p.token_id, p.address_id, p.previous_balance, p.previous_slot, p.previous_timestamp
+    from restored r
+    join solana.token_balance_previous p on p.token_id = r.token_id and p.address_id = r.address_id and p.slot = r.previous_slot
+    where r.previous_slot = any($1::int8[])
+), updated as (
+    update solana.token_balance tb set
+        balance = r.previous_balance,
+        slot = r.previous_slot,
+        timestamp = r.previous_timestamp,
+        updated_at = now()
+    from restored r
+    where tb.token_id = r.token_id
+      and tb.address_id = r.address_id
+      and r.previous_slot is not null
+      and not r.previous_slot = any($1::int8[])
+)
+delete from solana.token_balance tb
+using restored r
+where tb.token_id = r.token_id
+  and tb.address_id = r.address_id
+  and r.previous_slot is null;
+"#,
+        )
+        .bind(slots)
+        .execute(&mut **tx)
+        .await?;
+
+        // balances without a recorded previous state can not be restored
+        sqlx::query(
+            r#"
+delete from solana.token_balance
+where slot = any($1::int8[]) and timestamp >= $2 and timestamp <= $3;
+"#,
+        )
+        .bind(slots)
+        .bind(from)
+        .bind(to)
+        .execute(&mut **tx)
+        .await?;
+
+        sqlx::query("delete from solana.token_balance_previous where slot = any($1::int8[]);")
+            .bind(slots)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
+}
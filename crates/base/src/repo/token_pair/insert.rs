@@ -5,8 +5,8 @@
 // Original AGPL 3 License Copyright (c) blockworks-foundation 2024.
 
 use crate::model::{
-    AddressId, DecimalAmount, Decimals, Description, Mint, Name, Symbol, Token, TokenId, TokenPair,
-    TokenPairId, TokenPairMint, Uri,
+    AddressId, DecimalAmount, Decimals, Description, Mint, Name, Symbol, TelegramHandle, Token,
+    TokenId, TokenPair, TokenPairId, TokenPairMint, TwitterHandle, Uri,
 };
 use crate::repo::TokenPairRepo;
 use common::model::{BlockId, BlockTimestamp};
@@ -79,6 +79,8 @@ impl TokenPairRepo {
                 base.description as base_description,
                 base.image as base_image,
                 base.website as base_website,
+                base.twitter as base_twitter,
+                base.telegram as base_telegram,
                 base.creator_id as base_creator_id,
                 base.block_id as base_block_id,
                 base.block_time as base_block_time,
@@ -92,6 +94,8 @@ impl TokenPairRepo {
                 quote.description as quote_description,
                 quote.image as quote_image,
                 quote.website as quote_website,
+                quote.twitter as quote_twitter,
+                quote.telegram as quote_telegram,
                 quote.creator_id as quote_creator_id,
                 quote.block_id as quote_block_id,
                 quote.block_time as quote_block_time
@@ -119,6 +123,8 @@ impl TokenPairRepo {
                 metadata: r.try_get::<Uri, _>("base_metadata").ok(),
                 image: r.try_get::<Uri, _>("base_image").ok(),
                 website: r.try_get::<Uri, _>("base_website").ok(),
+                twitter: r.try_get::<TwitterHandle, _>("base_twitter").ok(),
+                telegram: r.try_get::<TelegramHandle, _>("base_telegram").ok(),
                 creator: r.try_get::<AddressId, _>("base_creator_id").ok(),
                 block: r.try_get::<BlockId, _>("base_block_id").ok(),
                 block_time: r.try_get::<BlockTimestamp, _>("base_block_time").ok()
@@ -134,6 +140,8 @@ impl TokenPairRepo {
                 metadata: r.try_get::<Uri, _>("quote_metadata").ok(),
                 image: r.try_get::<Uri, _>("quote_image").ok(),
                 website: r.try_get::<Uri, _>("quote_website").ok(),
+                twitter: r.try_get::<TwitterHandle, _>("quote_twitter").ok(),
+                telegram: r.try_get::<TelegramHandle, _>("quote_telegram").ok(),
                 creator: r.try_get::<AddressId, _>("quote_creator_id").ok(),
                 block: r.try_get::<BlockId, _>("quote_block_id").ok(),
                 block_time: r.try_get::<BlockTimestamp, _>("quote_block_time").ok()
@@ -0,0 +1,18 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::repo::TokenPairChangeRepo;
+use common::model::Count;
+use common::repo::{RepoResult, Tx};
+use sqlx::Row;
+
+impl TokenPairChangeRepo {
+    pub async fn count_all<'a>(&self, tx: &mut Tx<'a>) -> RepoResult<Count> {
+        Ok(
+            sqlx::query("select count(*) from solana.token_pair_change;")
+                .fetch_one(&mut **tx)
+                .await?
+                .get::<Count, _>("count"),
+        )
+    }
+}
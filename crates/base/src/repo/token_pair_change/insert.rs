@@ -0,0 +1,88 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Slot;
+use crate::model::{ChangeKind, TokenId, TokenPairId};
+use crate::repo::TokenPairChangeRepo;
+use common::repo::{RepoResult, Tx};
+
+impl TokenPairChangeRepo {
+    /// Records that the token pairs changed. A change which is still waiting to be popped
+    /// is not recorded a second time
+    pub async fn insert<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        kind: ChangeKind,
+        token_pairs: impl IntoIterator<Item = TokenPairId>,
+    ) -> RepoResult<()> {
+        self.insert_changes(tx, kind, None, token_pairs).await
+    }
+
+    /// Records that the token pairs changed in a slot, which is not finalized yet.
+    /// The change does not get popped before the watermark passed the slot
+    pub async fn insert_at_slot<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        kind: ChangeKind,
+        slot: impl Into<Slot>,
+        token_pairs: impl IntoIterator<Item = TokenPairId>,
+    ) -> RepoResult<()> {
+        self.insert_changes(tx, kind, Some(slot.into()), token_pairs)
+            .await
+    }
+
+    /// Records that every token pair of which token is the base token changed
+    pub async fn insert_for_token<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        kind: ChangeKind,
+        token: impl Into<TokenId> + Send,
+    ) -> RepoResult<()> {
+        sqlx::query(
+            r#"
+            insert into solana.token_pair_change (token_pair_id, kind, slot)
+            select id, $2, null from solana.token_pair where base_id = $1
+            on conflict (token_pair_id, kind) do nothing
+            "#,
+        )
+        .bind(token.into())
+        .bind(kind)
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn insert_changes<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        kind: ChangeKind,
+        slot: Option<Slot>,
+        token_pairs: impl IntoIterator<Item = TokenPairId>,
+    ) -> RepoResult<()> {
+        let mut ids = token_pairs.into_iter().map(|id| id.0).collect::<Vec<_>>();
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        ids.sort();
+        ids.dedup();
+
+        // a waiting change is held back until the latest slot it covers is finalized
+        sqlx::query(
+            r#"
+            insert into solana.token_pair_change (token_pair_id, kind, slot)
+            select unnest($1::int8[]), $2, $3
+            on conflict (token_pair_id, kind) do update set
+                slot = greatest(solana.token_pair_change.slot, excluded.slot)
+            "#,
+        )
+        .bind(ids)
+        .bind(kind)
+        .bind(slot)
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+mod count;
+mod insert;
+mod pop;
+
+#[derive(Debug, Clone)]
+pub struct TokenPairChangeRepo(pub Arc<TokenPairChangeRepoInner>);
+
+impl Deref for TokenPairChangeRepo {
+    type Target = TokenPairChangeRepoInner;
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+#[derive(Debug)]
+pub struct TokenPairChangeRepoInner {}
+
+impl Default for TokenPairChangeRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TokenPairChangeRepo {
+    pub fn new() -> Self {
+        Self(Arc::new(TokenPairChangeRepoInner {}))
+    }
+}
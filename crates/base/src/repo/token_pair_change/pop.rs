@@ -0,0 +1,45 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{ChangeKind, TokenPairChange, TokenPairId};
+use crate::repo::TokenPairChangeRepo;
+use common::model::Limit;
+use common::repo::{RepoResult, Tx};
+use sqlx::{query, Row};
+
+impl TokenPairChangeRepo {
+    /// Removes and returns the oldest changes, changes of slots past the watermark are left for later
+    pub async fn pop<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        limit: impl Into<Limit>,
+    ) -> RepoResult<Box<[TokenPairChange]>> {
+        let limit = limit.into();
+        Ok(query(
+            r#"
+            with watermark as (
+                select coalesce((select watermark from solana.indexer where id = 1), 9223372036854775807) as slot
+            )
+            delete from solana.token_pair_change
+            where (token_pair_id, kind) in (
+                select token_pair_id, kind from solana.token_pair_change
+                where slot is null or slot <= (select slot from watermark)
+                order by created_at asc
+                limit $1
+                for update skip locked
+            )
+            returning token_pair_id, kind
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| TokenPairChange {
+            token_pair: r.get::<TokenPairId, _>("token_pair_id"),
+            kind: r.get::<ChangeKind, _>("kind"),
+        })
+        .collect::<Vec<_>>()
+        .into_boxed_slice())
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Signature;
+use crate::model::{DecimalAmount, InvocationId, TokenPairId, UserId, Venue, WalletId};
+use crate::model::{Trade, TradeId, TradeSide, TradeStatus};
+use crate::repo::trade::TradeRepo;
+use common::model::PriceQuote;
+use common::repo::{RepoResult, Tx};
+use sqlx::{query, Row};
+
+pub struct TradeCreateCmd {
+    pub invocation: InvocationId,
+    pub user: UserId,
+    pub wallet: WalletId,
+    pub token_pair: TokenPairId,
+    pub venue: Venue,
+    pub side: TradeSide,
+    pub status: TradeStatus,
+    pub amount_base: Option<DecimalAmount>,
+    pub amount_quote: Option<DecimalAmount>,
+    pub price: Option<PriceQuote>,
+    pub signature: Option<Signature>,
+    pub error: Option<String>,
+}
+
+impl TradeRepo {
+    pub async fn create<'a>(&self, tx: &mut Tx<'a>, cmd: TradeCreateCmd) -> RepoResult<Trade> {
+        let trade_id = query(
+            r#"insert into solana.trade
+            (invocation_id, user_id, wallet_id, token_pair_id, venue, side, status, amount_base, amount_quote, price, signature, error)
+            values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            returning id"#,
+        )
+        .bind(cmd.invocation)
+        .bind(cmd.user)
+        .bind(cmd.wallet)
+        .bind(cmd.token_pair)
+        .bind(cmd.venue)
+        .bind(cmd.side)
+        .bind(cmd.status)
+        .bind(cmd.amount_base)
+        .bind(cmd.amount_quote)
+        .bind(cmd.price)
+        .bind(cmd.signature)
+        .bind(cmd.error)
+        .fetch_one(&mut **tx)
+        .await
+        .map(|r| r.get::<TradeId, _>("id"))?;
+
+        self.get_by_id(tx, trade_id).await
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{Trade, TradeId};
+use crate::repo::trade::list::to_trade;
+use crate::repo::trade::TradeRepo;
+use common::repo::{RepoResult, Tx};
+use sqlx::query;
+
+impl TradeRepo {
+    pub async fn get_by_id<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        id: impl Into<TradeId> + Send,
+    ) -> RepoResult<Trade> {
+        Ok(query("select * from solana.trade where id = $1;")
+            .bind(id.into())
+            .fetch_one(&mut **tx)
+            .await
+            .map(|r| to_trade(&r))?)
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Signature;
+use crate::model::{DecimalAmount, InvocationId, TokenPairId, UserId, Venue, WalletId};
+use crate::model::{Trade, TradeId, TradeSide, TradeStatus};
+use crate::repo::trade::{TradeQueryAll, TradeRepo};
+use common::model::{CreatedAt, PriceQuote, UpdatedAt};
+use common::repo::{RepoResult, Tx};
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+
+impl TradeRepo {
+    pub async fn list_all<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        query: TradeQueryAll,
+    ) -> RepoResult<Box<[Trade]>> {
+        Ok(
+            sqlx::query("select * from solana.trade order by id desc limit $1;")
+                .bind(query.limit)
+                .fetch_all(&mut **tx)
+                .await?
+                .iter()
+                .map(to_trade)
+                .collect::<Vec<_>>()
+                .into_boxed_slice(),
+        )
+    }
+
+    pub async fn list_by_invocation<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        invocation: impl Into<InvocationId> + Send,
+    ) -> RepoResult<Box<[Trade]>> {
+        Ok(
+            sqlx::query("select * from solana.trade where invocation_id = $1 order by id;")
+                .bind(invocation.into())
+                .fetch_all(&mut **tx)
+                .await?
+                .iter()
+                .map(to_trade)
+                .collect::<Vec<_>>()
+                .into_boxed_slice(),
+        )
+    }
+}
+
+pub(super) fn to_trade(r: &PgRow) -> Trade {
+    Trade {
+        id: r.get::<TradeId, _>("id"),
+        invocation: r.get::<InvocationId, _>("invocation_id"),
+        user: r.get::<UserId, _>("user_id"),
+        wallet: r.get::<WalletId, _>("wallet_id"),
+        token_pair: r.get::<TokenPairId, _>("token_pair_id"),
+        venue: r.get::<Venue, _>("venue"),
+        side: r.get::<TradeSide, _>("side"),
+        status: r.get::<TradeStatus, _>("status"),
+        amount_base: r.get::<Option<DecimalAmount>, _>("amount_base"),
+        amount_quote: r.get::<Option<DecimalAmount>, _>("amount_quote"),
+        price: r.get::<Option<PriceQuote>, _>("price"),
+        signature: r.get::<Option<Signature>, _>("signature"),
+        error: r.get::<Option<String>, _>("error"),
+        created_at: r.get::<CreatedAt, _>("created_at"),
+        updated_at: r.get::<UpdatedAt, _>("updated_at"),
+    }
+}
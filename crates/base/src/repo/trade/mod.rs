@@ -0,0 +1,44 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use common::model::Limit;
+pub use create::*;
+pub use update::*;
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+mod count;
+mod create;
+mod get;
+mod list;
+mod update;
+
+pub struct TradeQueryAll {
+    pub limit: Limit,
+}
+
+#[derive(Debug, Clone)]
+pub struct TradeRepo(pub Arc<TradeRepoInner>);
+
+impl Deref for TradeRepo {
+    type Target = TradeRepoInner;
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+#[derive(Debug)]
+pub struct TradeRepoInner {}
+
+impl Default for TradeRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TradeRepo {
+    pub fn new() -> Self {
+        Self(Arc::new(TradeRepoInner {}))
+    }
+}
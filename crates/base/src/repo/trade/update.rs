@@ -0,0 +1,49 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Signature;
+use crate::model::{DecimalAmount, Venue};
+use crate::model::{Trade, TradeId, TradeStatus};
+use crate::repo::trade::TradeRepo;
+use common::model::PriceQuote;
+use common::repo::error::RepoError;
+use common::repo::{RepoResult, Tx};
+use sqlx::query;
+
+pub struct TradeUpdateCmd {
+    pub id: TradeId,
+    pub venue: Venue,
+    pub status: TradeStatus,
+    pub amount_base: Option<DecimalAmount>,
+    pub amount_quote: Option<DecimalAmount>,
+    pub price: Option<PriceQuote>,
+    pub signature: Option<Signature>,
+    pub error: Option<String>,
+}
+
+impl TradeRepo {
+    /// Records the outcome of a pending trade
+    pub async fn update<'a>(&self, tx: &mut Tx<'a>, cmd: TradeUpdateCmd) -> RepoResult<Trade> {
+        let result = query(
+            r#"update solana.trade set
+            venue = $2, status = $3, amount_base = $4, amount_quote = $5, price = $6, signature = $7, error = $8, updated_at = now()
+            where id = $1"#,
+        )
+        .bind(cmd.id)
+        .bind(cmd.venue)
+        .bind(cmd.status)
+        .bind(cmd.amount_base)
+        .bind(cmd.amount_quote)
+        .bind(cmd.price)
+        .bind(cmd.signature)
+        .bind(cmd.error)
+        .execute(&mut **tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepoError::NotFound);
+        }
+
+        self.get_by_id(tx, cmd.id).await
+    }
+}
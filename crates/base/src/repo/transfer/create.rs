@@ -0,0 +1,50 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Signature;
+use crate::model::{
+    DecimalAmount, PublicKey, TokenId, Transfer, TransferId, TransferStatus, UserId, WalletId,
+};
+use crate::repo::transfer::TransferRepo;
+use common::repo::{RepoResult, Tx};
+use sqlx::{query, Row};
+
+pub struct TransferCreateCmd {
+    pub user: UserId,
+    pub wallet: WalletId,
+    pub token: Option<TokenId>,
+    pub recipient: PublicKey,
+    pub amount: DecimalAmount,
+    pub status: TransferStatus,
+    pub signature: Option<Signature>,
+    pub error: Option<String>,
+}
+
+impl TransferRepo {
+    /// Fails with AlreadyExists, if another transfer of the wallet is pending
+    pub async fn create<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        cmd: TransferCreateCmd,
+    ) -> RepoResult<Transfer> {
+        let transfer_id = query(
+            r#"insert into solana.transfer
+            (user_id, wallet_id, token_id, recipient, amount, status, signature, error)
+            values ($1, $2, $3, $4, $5, $6, $7, $8)
+            returning id"#,
+        )
+        .bind(cmd.user)
+        .bind(cmd.wallet)
+        .bind(cmd.token)
+        .bind(cmd.recipient)
+        .bind(cmd.amount)
+        .bind(cmd.status)
+        .bind(cmd.signature)
+        .bind(cmd.error)
+        .fetch_one(&mut **tx)
+        .await
+        .map(|r| r.get::<TransferId, _>("id"))?;
+
+        self.get_by_id(tx, transfer_id).await
+    }
+}
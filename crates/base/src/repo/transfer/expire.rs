@@ -0,0 +1,29 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::WalletId;
+use crate::repo::transfer::TransferRepo;
+use common::model::Count;
+use common::repo::{RepoResult, Tx};
+
+impl TransferRepo {
+    /// Fails the pending transfer of the wallet, if its outcome was not recorded within 2 minutes.
+    /// By then the blockhash of its transaction expired, so that it either landed or never will,
+    /// but the outcome is unknown as the signature gets recorded only after sending.
+    pub async fn expire_pending<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        wallet: impl Into<WalletId> + Send,
+    ) -> RepoResult<Count> {
+        let result = sqlx::query(
+            r#"update solana.transfer set
+            status = 2, error = 'Expired while pending, the transfer might have been sent', updated_at = now()
+            where wallet_id = $1 and status = 3 and created_at < now() - interval '2 minutes'"#,
+        )
+        .bind(wallet.into())
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(Count(result.rows_affected() as i64))
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{Transfer, TransferId};
+use crate::repo::transfer::list::to_transfer;
+use crate::repo::transfer::TransferRepo;
+use common::repo::{RepoResult, Tx};
+use sqlx::query;
+
+impl TransferRepo {
+    pub async fn get_by_id<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        id: impl Into<TransferId> + Send,
+    ) -> RepoResult<Transfer> {
+        Ok(query("select * from solana.transfer where id = $1;")
+            .bind(id.into())
+            .fetch_one(&mut **tx)
+            .await
+            .map(|r| to_transfer(&r))?)
+    }
+}
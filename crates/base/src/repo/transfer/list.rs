@@ -0,0 +1,48 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Signature;
+use crate::model::{
+    DecimalAmount, PublicKey, TokenId, Transfer, TransferId, TransferStatus, UserId, WalletId,
+};
+use crate::repo::transfer::{TransferQueryWallet, TransferRepo};
+use common::model::{CreatedAt, UpdatedAt};
+use common::repo::{RepoResult, Tx};
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+
+impl TransferRepo {
+    pub async fn list_by_wallet<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        query: TransferQueryWallet,
+    ) -> RepoResult<Box<[Transfer]>> {
+        Ok(sqlx::query(
+            "select * from solana.transfer where wallet_id = $1 order by id desc limit $2;",
+        )
+        .bind(query.wallet)
+        .bind(query.limit)
+        .fetch_all(&mut **tx)
+        .await?
+        .iter()
+        .map(to_transfer)
+        .collect::<Vec<_>>()
+        .into_boxed_slice())
+    }
+}
+
+pub(super) fn to_transfer(r: &PgRow) -> Transfer {
+    Transfer {
+        id: r.get::<TransferId, _>("id"),
+        user: r.get::<UserId, _>("user_id"),
+        wallet: r.get::<WalletId, _>("wallet_id"),
+        token: r.get::<Option<TokenId>, _>("token_id"),
+        recipient: r.get::<PublicKey, _>("recipient"),
+        amount: r.get::<DecimalAmount, _>("amount"),
+        status: r.get::<TransferStatus, _>("status"),
+        signature: r.get::<Option<Signature>, _>("signature"),
+        error: r.get::<Option<String>, _>("error"),
+        created_at: r.get::<CreatedAt, _>("created_at"),
+        updated_at: r.get::<UpdatedAt, _>("updated_at"),
+    }
+}
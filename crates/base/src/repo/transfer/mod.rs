@@ -0,0 +1,47 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::WalletId;
+use common::model::Limit;
+pub use create::*;
+pub use update::*;
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+mod count;
+mod create;
+mod expire;
+mod get;
+mod list;
+mod update;
+
+pub struct TransferQueryWallet {
+    pub wallet: WalletId,
+    pub limit: Limit,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransferRepo(pub Arc<TransferRepoInner>);
+
+impl Deref for TransferRepo {
+    type Target = TransferRepoInner;
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+#[derive(Debug)]
+pub struct TransferRepoInner {}
+
+impl Default for TransferRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransferRepo {
+    pub fn new() -> Self {
+        Self(Arc::new(TransferRepoInner {}))
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::solana::Signature;
+use crate::model::{Transfer, TransferId, TransferStatus};
+use crate::repo::transfer::TransferRepo;
+use common::repo::error::RepoError;
+use common::repo::{RepoResult, Tx};
+use sqlx::query;
+
+pub struct TransferUpdateCmd {
+    pub id: TransferId,
+    pub status: TransferStatus,
+    pub signature: Option<Signature>,
+    pub error: Option<String>,
+}
+
+impl TransferRepo {
+    /// Records the outcome of a pending transfer
+    pub async fn update<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        cmd: TransferUpdateCmd,
+    ) -> RepoResult<Transfer> {
+        let result = query(
+            r#"update solana.transfer set
+            status = $2, signature = $3, error = $4, updated_at = now()
+            where id = $1"#,
+        )
+        .bind(cmd.id)
+        .bind(cmd.status)
+        .bind(cmd.signature)
+        .bind(cmd.error)
+        .execute(&mut **tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepoError::NotFound);
+        }
+
+        self.get_by_id(tx, cmd.id).await
+    }
+}
@@ -2,7 +2,7 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::model::{PrivateKey, PublicKey};
-use crate::model::{UserId, Wallet, WalletId};
+use crate::model::{UserId, Wallet, WalletId, WalletUnsafe};
 use crate::repo::wallet::WalletRepo;
 use common::crypt::{decrypt_string, Nonce};
 use common::model::{CreatedAt, UpdatedAt};
@@ -63,4 +63,28 @@ impl WalletRepo {
             decrypt_string(&self.secret, &nonce, encrypted_key).unwrap(),
         ))
     }
+
+    pub async fn get_unsafe_by_id<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        id: impl Into<WalletId> + Send,
+    ) -> RepoResult<WalletUnsafe> {
+        let row = query("select * from solana.wallet where id = $1;")
+            .bind(id.into())
+            .fetch_one(&mut **tx)
+            .await?;
+
+        let nonce = Nonce::from(row.get::<String, _>("nonce"));
+        let encrypted_key = row.get::<String, _>("private_key");
+
+        Ok(WalletUnsafe {
+            id: row.get::<WalletId, _>("id"),
+            user_id: row.get::<UserId, _>("user_id"),
+            public_key: row.get::<PublicKey, _>("public_key"),
+            private_key: PrivateKey(decrypt_string(&self.secret, &nonce, encrypted_key).unwrap()),
+            created_at: row.get::<CreatedAt, _>("created_at"),
+            updated_at: row.get::<UpdatedAt, _>("updated_at"),
+            nonce,
+        })
+    }
 }
@@ -5,6 +5,7 @@ pub use auth::AuthService;
 pub use notification::{NotificationError, NotificationResult, NotificationRuleMatched, NotificationService};
 pub use rule::{RuleCreateCmd, RuleService, RuleUpdateCmd};
 pub use token::TokenService;
+pub use transfer::{TransferService, WalletBalance, WalletBalances, WalletTokenBalance};
 pub use user::{AuthenticateUserTelegramCmd, UserService};
 
 mod auth;
@@ -12,3 +13,4 @@ mod notification;
 mod rule;
 mod user;
 mod token;
+mod transfer;
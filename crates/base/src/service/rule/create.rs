@@ -4,7 +4,7 @@
 use crate::model::AuthenticatedUser;
 use crate::model::{Rule, RuleName, Sequence};
 use crate::repo;
-use crate::service::rule::RuleService;
+use crate::service::rule::{validate_sequence, RuleService};
 use common::service::ServiceResult;
 
 pub struct RuleCreateCmd {
@@ -14,6 +14,8 @@ pub struct RuleCreateCmd {
 
 impl RuleService {
     pub async fn create(&self, cmd: RuleCreateCmd, user: AuthenticatedUser) -> ServiceResult<Rule> {
+        validate_sequence(&cmd.sequence)?;
+
         let mut tx = self.pool.begin().await?;
         let result = self
             .repo
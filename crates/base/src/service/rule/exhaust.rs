@@ -0,0 +1,37 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{Rule, RuleId};
+use crate::service::RuleService;
+use common::repo::error::RepoError;
+use common::service::{ServiceError, ServiceResult};
+
+impl RuleService {
+    /// Moves the rule into the exhausted state once it was invoked as often as allowed
+    /// and out of it again, if it can be invoked again
+    pub async fn update_exhaustion(&self, id: impl Into<RuleId> + Send) -> ServiceResult<Rule> {
+        let mut tx = self.pool.begin().await?;
+
+        let rule = match self.repo.get_by_id(&mut tx, id).await {
+            Ok(rule) => rule,
+            Err(RepoError::NotFound) => return Err(ServiceError::not_found("Rule not found")),
+            Err(err) => return Err(err.into()),
+        };
+
+        let invocations = self.invocation_repo.count_by_rule(&mut tx, rule.id).await?;
+        let status = if rule.limit.exhausted(invocations) {
+            rule.status.exhaust()
+        } else {
+            rule.status.replenish()
+        };
+
+        if status == rule.status {
+            tx.commit().await?;
+            return Ok(rule);
+        }
+
+        let result = self.repo.update_status(&mut tx, rule.id, status).await?;
+        tx.commit().await?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{Invocation, InvocationId};
+use crate::service::RuleService;
+use common::model::Limit;
+use common::service::ServiceResult;
+
+impl RuleService {
+    /// Id of the most recent invocation, none if no rule matched yet
+    pub async fn latest_invocation(&self) -> ServiceResult<Option<InvocationId>> {
+        let mut tx = self.pool.begin().await?;
+        let result = self.invocation_repo.get_latest_id(&mut tx).await?;
+        tx.commit().await?;
+        Ok(result)
+    }
+
+    /// Invocations of all users created after the given one, oldest first
+    pub async fn list_invocations_after(
+        &self,
+        after: impl Into<InvocationId> + Send,
+        limit: impl Into<Limit> + Send,
+    ) -> ServiceResult<Vec<Invocation>> {
+        let mut tx = self.pool.begin().await?;
+        let result = self
+            .invocation_repo
+            .list_after(&mut tx, after, limit)
+            .await?;
+        tx.commit().await?;
+        Ok(result)
+    }
+}
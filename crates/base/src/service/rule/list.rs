@@ -23,6 +23,22 @@ impl RuleService {
         Ok(result)
     }
 
+    /// Rules which are active but exhausted
+    pub async fn list_active_exhausted(&self) -> ServiceResult<Box<[Rule]>> {
+        let mut tx = self.pool.begin().await?;
+        let result = self
+            .repo
+            .list_active_exhausted(
+                &mut tx,
+                RuleQueryAll {
+                    limit: Limit::unlimited(),
+                },
+            )
+            .await?;
+        tx.commit().await?;
+        Ok(result)
+    }
+
     pub async fn list_user(&self, user: impl Into<UserId>) -> ServiceResult<Vec<Rule>> {
         let mut tx = self.pool.begin().await?;
         let result = self
@@ -5,14 +5,18 @@ pub use create::RuleCreateCmd;
 pub use update::RuleUpdateCmd;
 
 mod create;
+mod exhaust;
 mod get;
+mod invocation;
 mod list;
 mod update;
+mod validate;
 
-use crate::repo::RuleRepo;
+use crate::repo::{InvocationRepo, RuleRepo};
 use sqlx::PgPool;
 use std::ops::Deref;
 use std::sync::Arc;
+use validate::{validate_limit, validate_sequence};
 
 #[derive(Debug, Clone)]
 pub struct RuleService(pub Arc<RuleServiceInner>);
@@ -28,17 +32,23 @@ impl Deref for RuleService {
 pub struct RuleServiceInner {
     pool: PgPool,
     repo: RuleRepo,
+    invocation_repo: InvocationRepo,
 }
 
 impl RuleService {
-    pub fn new(pool: PgPool, repo: RuleRepo) -> Self {
-        Self(Arc::new(RuleServiceInner { pool, repo }))
+    pub fn new(pool: PgPool, repo: RuleRepo, invocation_repo: InvocationRepo) -> Self {
+        Self(Arc::new(RuleServiceInner {
+            pool,
+            repo,
+            invocation_repo,
+        }))
     }
 
     pub fn testing(pool: PgPool) -> Self {
         Self(Arc::new(RuleServiceInner {
             pool,
             repo: RuleRepo::new(),
+            invocation_repo: InvocationRepo::new(),
         }))
     }
 }
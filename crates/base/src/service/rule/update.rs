@@ -2,8 +2,9 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use crate::model::RuleStatus::InactiveExhausted;
-use crate::model::{AuthenticatedUser, Rule, RuleId, RuleName, RuleStatus, Sequence};
+use crate::model::{AuthenticatedUser, Rule, RuleId, RuleLimit, RuleName, RuleStatus, Sequence};
 use crate::repo;
+use crate::service::rule::{validate_limit, validate_sequence};
 use crate::service::RuleService;
 use common::service::{ServiceError, ServiceResult};
 use log::warn;
@@ -13,6 +14,7 @@ pub struct RuleUpdateCmd {
     pub name: Option<RuleName>,
     pub sequence: Option<Sequence>,
     pub status: Option<RuleStatus>,
+    pub limit: Option<RuleLimit>,
 }
 
 impl RuleService {
@@ -22,6 +24,14 @@ impl RuleService {
         cmd: RuleUpdateCmd,
         user: AuthenticatedUser,
     ) -> ServiceResult<Rule> {
+        if let Some(sequence) = &cmd.sequence {
+            validate_sequence(sequence)?;
+        }
+
+        if let Some(limit) = &cmd.limit {
+            validate_limit(limit)?;
+        }
+
         let mut tx = self.pool.begin().await?;
         let id = id.into();
 
@@ -34,6 +44,15 @@ impl RuleService {
             return Err(ServiceError::not_found("Rule not found"));
         }
 
+        let limit = cmd.limit.unwrap_or(rule.limit);
+        let invocations = self.invocation_repo.count_by_rule(&mut tx, id).await?;
+        let status = update_status(rule.status, cmd.status);
+        let status = if limit.exhausted(invocations) {
+            status.exhaust()
+        } else {
+            status.replenish()
+        };
+
         let result = self
             .repo
             .update(
@@ -43,7 +62,8 @@ impl RuleService {
                     user: user.id,
                     name: cmd.name.unwrap_or(rule.name),
                     sequence: cmd.sequence.unwrap_or(rule.sequence),
-                    status: update_status(rule.status, cmd.status),
+                    status,
+                    limit,
                 },
             )
             .await?;
@@ -0,0 +1,72 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{RuleLimit, RuleRearm, Sequence};
+use common::model::ValidationError;
+use common::service::{ServiceError, ServiceResult};
+
+/// Rejects a sequence with problems, listing all of them in the error
+pub(crate) fn validate_sequence(sequence: &Sequence) -> ServiceResult<()> {
+    let errors = sequence.validate();
+    if errors.is_empty() {
+        return Ok(());
+    }
+    Err(ServiceError::validation(errors))
+}
+
+/// Rejects a limit with problems, listing all of them located by the JSON path relative to the limit
+pub(crate) fn validate_limit(limit: &RuleLimit) -> ServiceResult<()> {
+    let mut errors = vec![];
+
+    if limit.max_invocations.is_some_and(|max| max <= 0) {
+        errors.push(ValidationError::new(
+            "$.maxInvocations",
+            "must be greater than 0",
+        ));
+    }
+
+    if limit
+        .max_invocations_per_token_pair
+        .is_some_and(|max| max <= 0)
+    {
+        errors.push(ValidationError::new(
+            "$.maxInvocationsPerTokenPair",
+            "must be greater than 0",
+        ));
+    }
+
+    if limit.cooldown_seconds.is_some_and(|cooldown| cooldown < 0) {
+        errors.push(ValidationError::new(
+            "$.cooldownSeconds",
+            "must not be negative",
+        ));
+    }
+
+    // a rule which never rearms is invoked once per token pair, neither setting would have any effect
+    if limit.rearm == RuleRearm::Never {
+        if limit.cooldown_seconds.is_some() {
+            errors.push(ValidationError::new(
+                "$.cooldownSeconds",
+                "requires the rule to rearm",
+            ));
+        }
+
+        if limit.max_invocations_per_token_pair.is_some() {
+            errors.push(ValidationError::new(
+                "$.maxInvocationsPerTokenPair",
+                "requires the rule to rearm",
+            ));
+        }
+    }
+
+    if let (Some(start), Some(end)) = (limit.start_at, limit.end_at) {
+        if end <= start {
+            errors.push(ValidationError::new("$.endAt", "must be after startAt"));
+        }
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+    Err(ServiceError::validation(errors))
+}
@@ -0,0 +1,148 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::{DecimalAmount, Mint, PublicKey, Token};
+use crate::service::transfer::TransferService;
+use bigdecimal::BigDecimal;
+use common::model::{PriceQuote, PriceUsd};
+use common::repo::error::RepoError;
+use common::service::{ServiceError, ServiceResult};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct WalletBalance {
+    /// None for native SOL
+    pub token: Option<Token>,
+    pub balance: DecimalAmount,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletBalances {
+    pub sol: DecimalAmount,
+    /// None if no SOL price was calculated yet
+    pub sol_usd: Option<PriceUsd>,
+    pub tokens: Vec<WalletTokenBalance>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletTokenBalance {
+    pub token: Token,
+    pub balance: DecimalAmount,
+    /// Price in SOL, None if the token was never traded against WSOL
+    pub price: Option<PriceQuote>,
+}
+
+impl WalletBalances {
+    /// Value of the SOL balance and of every priced token, in SOL
+    pub fn total(&self) -> BigDecimal {
+        self.tokens
+            .iter()
+            .filter_map(WalletTokenBalance::value)
+            .fold(self.sol.0.clone(), |acc, value| acc + value)
+    }
+
+    /// Converts a value in SOL to USD, None if no SOL price is known
+    pub fn to_usd(&self, sol: &BigDecimal) -> Option<BigDecimal> {
+        self.sol_usd.as_ref().map(|usd| sol * &usd.0)
+    }
+}
+
+impl WalletTokenBalance {
+    /// Value of the balance in SOL, None if the token has no price
+    pub fn value(&self) -> Option<BigDecimal> {
+        self.price.as_ref().map(|price| &self.balance.0 * &price.0)
+    }
+}
+
+impl TransferService {
+    /// Indexed balance of the wallet for mint, or for native SOL if mint is None.
+    /// Wallets without an indexed balance hold nothing
+    pub async fn get_balance(
+        &self,
+        wallet: impl Into<PublicKey> + Send,
+        mint: Option<Mint>,
+    ) -> ServiceResult<WalletBalance> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = match mint {
+            None => WalletBalance {
+                token: None,
+                balance: self
+                    .token_balance_repo
+                    .get_sol_balance(&mut tx, wallet)
+                    .await?
+                    .unwrap_or(DecimalAmount::from(0i64)),
+            },
+            Some(mint) => {
+                let token = match self.token_repo.get_by_mint(&mut tx, mint).await {
+                    Ok(token) => token,
+                    Err(RepoError::NotFound) => {
+                        return Err(ServiceError::not_found("Token not found"))
+                    }
+                    Err(err) => return Err(err.into()),
+                };
+
+                let balance = self
+                    .token_balance_repo
+                    .get_token_balance(&mut tx, wallet, token.id)
+                    .await?
+                    .unwrap_or(DecimalAmount::from(0i64));
+
+                WalletBalance {
+                    token: Some(token),
+                    balance,
+                }
+            }
+        };
+
+        tx.commit().await?;
+        Ok(result)
+    }
+
+    /// Indexed SOL balance and every token held by the wallet, priced with the most recent swap
+    pub async fn list_balances(
+        &self,
+        wallet: impl Into<PublicKey> + Send,
+    ) -> ServiceResult<WalletBalances> {
+        let wallet = wallet.into();
+        let mut tx = self.pool.begin().await?;
+
+        let sol = self
+            .token_balance_repo
+            .get_sol_balance(&mut tx, wallet.clone())
+            .await?
+            .unwrap_or(DecimalAmount::from(0i64));
+
+        let sol_usd = self.sol_repo.get_latest_usd(&mut tx).await?;
+
+        let balances = self
+            .token_balance_repo
+            .list_token_balances(&mut tx, wallet)
+            .await?;
+
+        let mut tokens: HashMap<_, _> = self
+            .token_repo
+            .list_by_ids(&mut tx, balances.iter().map(|b| b.token))
+            .await?
+            .into_iter()
+            .map(|token| (token.id, token))
+            .collect();
+
+        tx.commit().await?;
+
+        Ok(WalletBalances {
+            sol,
+            sol_usd,
+            tokens: balances
+                .into_iter()
+                .filter_map(|b| {
+                    tokens.remove(&b.token).map(|token| WalletTokenBalance {
+                        token,
+                        balance: b.balance,
+                        price: b.price,
+                    })
+                })
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::model::Transfer;
+use crate::repo::{TransferCreateCmd, TransferUpdateCmd};
+use crate::service::transfer::TransferService;
+use common::repo::error::RepoError;
+use common::service::{ServiceError, ServiceResult};
+use log::warn;
+
+impl TransferService {
+    /// Records a transfer, so that withdrawals can be audited.
+    /// Fails with a conflict, if another transfer of the wallet is still pending.
+    /// A pending transfer whose outcome never got recorded, e.g. due to a crash, expires after 2 minutes
+    pub async fn create(&self, cmd: TransferCreateCmd) -> ServiceResult<Transfer> {
+        let mut tx = self.pool.begin().await?;
+        let expired = self
+            .transfer_repo
+            .expire_pending(&mut tx, cmd.wallet)
+            .await?;
+        if expired.0 > 0 {
+            warn!("expired pending transfer of wallet {}", cmd.wallet.0);
+        }
+        let result = match self.transfer_repo.create(&mut tx, cmd).await {
+            Ok(transfer) => transfer,
+            Err(RepoError::AlreadyExists) => {
+                return Err(ServiceError::conflict(
+                    "Another transfer of the wallet is in progress",
+                ))
+            }
+            Err(err) => return Err(err.into()),
+        };
+        tx.commit().await?;
+        Ok(result)
+    }
+
+    /// Records the outcome of a pending transfer
+    pub async fn update(&self, cmd: TransferUpdateCmd) -> ServiceResult<Transfer> {
+        let mut tx = self.pool.begin().await?;
+        let result = self.transfer_repo.update(&mut tx, cmd).await?;
+        tx.commit().await?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::repo::{SolRepo, TokenBalanceRepo, TokenRepo, TransferRepo};
+use sqlx::PgPool;
+use std::ops::Deref;
+use std::sync::Arc;
+
+pub use balance::{WalletBalance, WalletBalances, WalletTokenBalance};
+
+mod balance;
+mod create;
+
+#[derive(Clone)]
+pub struct TransferService(pub Arc<TransferServiceInner>);
+
+impl Deref for TransferService {
+    type Target = TransferServiceInner;
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+pub struct TransferServiceInner {
+    pool: PgPool,
+    sol_repo: SolRepo,
+    token_repo: TokenRepo,
+    token_balance_repo: TokenBalanceRepo,
+    transfer_repo: TransferRepo,
+}
+
+impl TransferService {
+    pub fn new(pool: PgPool, token_repo: TokenRepo, transfer_repo: TransferRepo) -> Self {
+        Self(Arc::new(TransferServiceInner {
+            pool,
+            sol_repo: SolRepo::new(),
+            token_repo,
+            token_balance_repo: TokenBalanceRepo::new(),
+            transfer_repo,
+        }))
+    }
+
+    pub fn testing(pool: PgPool) -> Self {
+        Self::new(
+            pool,
+            TokenRepo::testing_no_token_info(),
+            TransferRepo::new(),
+        )
+    }
+}
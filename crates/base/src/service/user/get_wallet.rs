@@ -1,8 +1,9 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use crate::model::{UserId, Wallet};
+use crate::model::{UserId, Wallet, WalletId, WalletUnsafe};
 use crate::service::UserService;
+use common::repo::error::RepoError;
 use common::repo::Tx;
 use common::service::{ServiceError, ServiceResult};
 
@@ -25,4 +26,27 @@ impl UserService {
             Err(_) => Err(ServiceError::not_found("Wallet not found".to_string())),
         }
     }
+
+    /// Returns the wallet including its decrypted private key, if it belongs to the given user
+    pub async fn get_wallet_unsafe(
+        &self,
+        id: impl Into<WalletId> + Send,
+        user: impl Into<UserId> + Send,
+    ) -> ServiceResult<WalletUnsafe> {
+        let mut tx = self.pool.begin().await?;
+
+        let wallet = match self.wallet_repo.get_by_id(&mut tx, id).await {
+            Ok(wallet) => wallet,
+            Err(RepoError::NotFound) => return Err(ServiceError::not_found("Wallet not found")),
+            Err(err) => return Err(err.into()),
+        };
+
+        if wallet.user_id != user.into() {
+            return Err(ServiceError::not_found("Wallet not found"));
+        }
+
+        let result = self.wallet_repo.get_unsafe_by_id(&mut tx, wallet.id).await?;
+        tx.commit().await?;
+        Ok(result)
+    }
 }
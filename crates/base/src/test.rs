@@ -39,6 +39,8 @@ impl LoadTokenInfo for SuccessfulTokenInfoLoader {
             metadata: Some(Uri(format!("http://metadata-{counter}"))),
             image: Some(Uri(format!("http://image-{counter}"))),
             website: Some(Uri(format!("http://website-{counter}"))),
+            twitter: None,
+            telegram: None,
         })
     }
 }
@@ -0,0 +1,57 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::Mint;
+use base::repo::{InvocationCreateCmd, InvocationRepo};
+use testing::rule::{create_inactive_rule_for_test_user, create_rearming_rule_for_test_user};
+use testing::run_test;
+use testing::token_pair::get_or_create_token_pair;
+use testing::user::get_or_create_test_user;
+
+#[test_log::test(sqlx::test)]
+async fn test_count_by_rule() {
+    run_test(|mut tx| async move {
+        let user = get_or_create_test_user(&mut tx).await;
+        let rule = create_rearming_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let another_rule = create_inactive_rule_for_test_user(&mut tx, "AnotherMoneyMaker").await;
+        let usdt = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+        let wsol = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::wsol()).await;
+
+        let test_instance = InvocationRepo::new();
+        for (rule, token_pair, stage) in [
+            (rule.id, usdt.id, 0),
+            (rule.id, usdt.id, 1),
+            (rule.id, wsol.id, 0),
+            (rule.id, wsol.id, 0),
+            (another_rule.id, wsol.id, 0),
+        ] {
+            test_instance
+                .create(
+                    &mut tx,
+                    InvocationCreateCmd {
+                        user: user.id,
+                        rule,
+                        token_pair,
+                        stage,
+                        next: None,
+                        deadline: None,
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        let count = test_instance.count_by_rule(&mut tx, rule.id).await.unwrap();
+        assert_eq!(count, 2);
+
+        let count = test_instance
+            .count_by_rule(&mut tx, another_rule.id)
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+
+        let count = test_instance.count_by_rule(&mut tx, 404).await.unwrap();
+        assert_eq!(count, 0);
+    })
+    .await
+}
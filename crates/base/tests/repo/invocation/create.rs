@@ -6,10 +6,11 @@ use base::model::Field::PriceAvg;
 use base::model::Operator::MoreThan;
 use base::model::{Action, Mint, Sequence, Value};
 use base::repo::{InvocationCreateCmd, InvocationRepo};
+use common::model::Timestamp;
 use common::repo::error::RepoError;
 use sqlx::Acquire;
 use testing::invocation::count_all;
-use testing::rule::create_inactive_rule_for_test_user;
+use testing::rule::{create_inactive_rule_for_test_user, create_rearming_rule_for_test_user};
 use testing::run_test;
 use testing::token_pair::get_or_create_token_pair;
 use testing::user::get_or_create_test_user;
@@ -29,6 +30,7 @@ async fn test_create() {
                     user: user.id,
                     rule: rule.id,
                     token_pair: token_pair.id,
+                    stage: 0,
                     next: Some(Sequence {
                         condition: Compare {
                             field: PriceAvg,
@@ -38,6 +40,7 @@ async fn test_create() {
                         },
                         action: Action::Buy,
                     }),
+                    deadline: None,
                 },
             )
             .await
@@ -81,7 +84,9 @@ async fn test_next_is_none() {
                     user: user.id,
                     rule: rule.id,
                     token_pair: token_pair.id,
+                    stage: 0,
                     next: None,
+                    deadline: None,
                 },
             )
             .await
@@ -99,6 +104,60 @@ async fn test_next_is_none() {
     .await
 }
 
+#[test_log::test(sqlx::test)]
+async fn test_rule_never_rearms() {
+    run_test(|mut tx| async move {
+        let user = get_or_create_test_user(&mut tx).await;
+        let rule = create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let token_pair = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+
+        let test_instance = InvocationRepo::new();
+        let cmd = || InvocationCreateCmd {
+            user: user.id,
+            rule: rule.id,
+            token_pair: token_pair.id,
+            stage: 0,
+            next: None,
+            deadline: None,
+        };
+
+        test_instance.create(&mut tx, cmd()).await.unwrap();
+
+        let result = test_instance.create(&mut tx, cmd()).await;
+        assert_eq!(result.err(), Some(RepoError::AlreadyExists));
+
+        let count = count_all(&mut tx).await;
+        assert_eq!(count, 1)
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_rule_rearms() {
+    run_test(|mut tx| async move {
+        let user = get_or_create_test_user(&mut tx).await;
+        let rule = create_rearming_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let token_pair = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+
+        let test_instance = InvocationRepo::new();
+        let cmd = || InvocationCreateCmd {
+            user: user.id,
+            rule: rule.id,
+            token_pair: token_pair.id,
+            stage: 0,
+            next: None,
+            deadline: None,
+        };
+
+        test_instance.create(&mut tx, cmd()).await.unwrap();
+        test_instance.create(&mut tx, cmd()).await.unwrap();
+
+        let count = count_all(&mut tx).await;
+        assert_eq!(count, 2)
+    })
+    .await
+}
+
 #[test_log::test(sqlx::test)]
 async fn test_invocation_requires_existing_user() {
     run_test(|mut tx| async move {
@@ -114,6 +173,7 @@ async fn test_invocation_requires_existing_user() {
                     user: 1234567.into(),
                     rule: rule.id,
                     token_pair: token_pair.id,
+                    stage: 0,
                     next: Some(Sequence {
                         condition: Compare {
                             field: PriceAvg,
@@ -123,6 +183,7 @@ async fn test_invocation_requires_existing_user() {
                         },
                         action: Action::Buy,
                     }),
+                    deadline: None,
                 },
             )
             .await;
@@ -149,6 +210,7 @@ async fn test_invocation_requires_existing_rule() {
                     user: user.id,
                     rule: 12345678.into(),
                     token_pair: token_pair.id,
+                    stage: 0,
                     next: Some(Sequence {
                         condition: Compare {
                             field: PriceAvg,
@@ -158,6 +220,7 @@ async fn test_invocation_requires_existing_rule() {
                         },
                         action: Action::Buy,
                     }),
+                    deadline: None,
                 },
             )
             .await;
@@ -184,6 +247,7 @@ async fn test_invocation_requires_existing_token_pair() {
                     user: user.id,
                     rule: rule.id,
                     token_pair: 12345679.into(),
+                    stage: 0,
                     next: Some(Sequence {
                         condition: Compare {
                             field: PriceAvg,
@@ -193,6 +257,7 @@ async fn test_invocation_requires_existing_token_pair() {
                         },
                         action: Action::Buy,
                     }),
+                    deadline: None,
                 },
             )
             .await;
@@ -203,3 +268,42 @@ async fn test_invocation_requires_existing_token_pair() {
     })
     .await
 }
+
+#[test_log::test(sqlx::test)]
+async fn test_create_with_deadline() {
+    run_test(|mut tx| async move {
+        let user = get_or_create_test_user(&mut tx).await;
+        let rule = create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let token_pair = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+
+        let deadline = Timestamp::from_epoch_second(1_700_000_000).unwrap();
+
+        let result = InvocationRepo::new()
+            .create(
+                &mut tx,
+                InvocationCreateCmd {
+                    user: user.id,
+                    rule: rule.id,
+                    token_pair: token_pair.id,
+                    stage: 0,
+                    next: Some(Sequence {
+                        condition: Compare {
+                            field: PriceAvg,
+                            operator: MoreThan,
+                            value: Value::percent(23.0).into(),
+                            timeframe: None,
+                        },
+                        action: Action::Buy,
+                    }),
+                    deadline: Some(deadline),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.deadline, Some(deadline));
+        assert!(!result.expired(Timestamp::from_epoch_second(1_700_000_000).unwrap()));
+        assert!(result.expired(Timestamp::from_epoch_second(1_700_000_001).unwrap()));
+    })
+    .await
+}
@@ -0,0 +1,255 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::Condition::Compare;
+use base::model::Field::PriceAvg;
+use base::model::Operator::MoreThan;
+use base::model::{Action, Mint, Sequence, Value};
+use base::repo::{InvocationCreateCmd, InvocationRepo};
+use common::repo::error::RepoError;
+use sqlx::Acquire;
+use testing::invocation::count_all;
+use testing::rule::{create_inactive_rule_for_test_user, create_rearming_rule_for_test_user};
+use testing::run_test;
+use testing::token_pair::get_or_create_token_pair;
+use testing::user::get_or_create_test_user;
+
+fn sell_sequence() -> Sequence {
+    Sequence {
+        condition: Compare {
+            field: PriceAvg,
+            operator: MoreThan,
+            value: Value::percent(50.0).into(),
+            timeframe: None,
+        },
+        action: Action::Sell,
+    }
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_pending() {
+    run_test(|mut tx| async move {
+        let user = get_or_create_test_user(&mut tx).await;
+        let rule = create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let usdt = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+        let wsol = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::wsol()).await;
+
+        let test_instance = InvocationRepo::new();
+        test_instance
+            .create(
+                &mut tx,
+                InvocationCreateCmd {
+                    user: user.id,
+                    rule: rule.id,
+                    token_pair: usdt.id,
+                    stage: 0,
+                    next: Some(sell_sequence()),
+                    deadline: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        test_instance
+            .create(
+                &mut tx,
+                InvocationCreateCmd {
+                    user: user.id,
+                    rule: rule.id,
+                    token_pair: wsol.id,
+                    stage: 0,
+                    next: None,
+                    deadline: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let result = test_instance.list_pending(&mut tx, rule.id).await.unwrap();
+        assert_eq!(result.len(), 1);
+
+        let pending = result.first().unwrap();
+        assert_eq!(pending.token_pair, usdt.id);
+        assert_eq!(pending.stage, 0);
+        assert_eq!(pending.next, Some(sell_sequence()));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_invoked() {
+    run_test(|mut tx| async move {
+        let user = get_or_create_test_user(&mut tx).await;
+        let rule = create_rearming_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let usdt = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+        let wsol = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::wsol()).await;
+
+        let test_instance = InvocationRepo::new();
+        for (token_pair, stage) in [(usdt.id, 0), (usdt.id, 1), (wsol.id, 0), (wsol.id, 0)] {
+            test_instance
+                .create(
+                    &mut tx,
+                    InvocationCreateCmd {
+                        user: user.id,
+                        rule: rule.id,
+                        token_pair,
+                        stage,
+                        next: None,
+                        deadline: None,
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        let result = test_instance.list_invoked(&mut tx, rule.id).await.unwrap();
+        assert_eq!(result.len(), 2);
+
+        let invoked = result.iter().find(|i| i.token_pair == usdt.id).unwrap();
+        assert_eq!(invoked.count, 1);
+
+        let invoked = result.iter().find(|i| i.token_pair == wsol.id).unwrap();
+        assert_eq!(invoked.count, 2);
+
+        let result = test_instance.list_invoked(&mut tx, 404).await.unwrap();
+        assert_eq!(result.len(), 0);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_complete() {
+    run_test(|mut tx| async move {
+        let user = get_or_create_test_user(&mut tx).await;
+        let rule = create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let token_pair = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+
+        let test_instance = InvocationRepo::new();
+        let first = test_instance
+            .create(
+                &mut tx,
+                InvocationCreateCmd {
+                    user: user.id,
+                    rule: rule.id,
+                    token_pair: token_pair.id,
+                    stage: 0,
+                    next: Some(sell_sequence()),
+                    deadline: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        test_instance.complete(&mut tx, first.id).await.unwrap();
+
+        let second = test_instance
+            .create(
+                &mut tx,
+                InvocationCreateCmd {
+                    user: user.id,
+                    rule: rule.id,
+                    token_pair: token_pair.id,
+                    stage: 1,
+                    next: None,
+                    deadline: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(second.stage, 1);
+
+        let result = test_instance.list_pending(&mut tx, rule.id).await.unwrap();
+        assert_eq!(result.len(), 0);
+
+        let first = test_instance.get_by_id(&mut tx, first.id).await.unwrap();
+        assert_eq!(first.next, None);
+
+        let result = test_instance
+            .complete(&mut tx.begin().await.unwrap(), first.id)
+            .await;
+        assert_eq!(result.err(), Some(RepoError::NotFound));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_invocations_are_appended() {
+    run_test(|mut tx| async move {
+        let user = get_or_create_test_user(&mut tx).await;
+        let rule = create_rearming_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let token_pair = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+
+        let test_instance = InvocationRepo::new();
+        let mut ids = vec![];
+        for stage in [0, 1, 0, 1] {
+            let invocation = test_instance
+                .create(
+                    &mut tx,
+                    InvocationCreateCmd {
+                        user: user.id,
+                        rule: rule.id,
+                        token_pair: token_pair.id,
+                        stage,
+                        next: None,
+                        deadline: None,
+                    },
+                )
+                .await
+                .unwrap();
+            ids.push(invocation.id);
+        }
+
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+        assert_eq!(count_all(&mut tx).await, 4);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_after() {
+    run_test(|mut tx| async move {
+        let user = get_or_create_test_user(&mut tx).await;
+        let rule = create_rearming_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let token_pair = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+
+        let test_instance = InvocationRepo::new();
+        assert_eq!(test_instance.get_latest_id(&mut tx).await.unwrap(), None);
+
+        for stage in [0, 1, 0] {
+            test_instance
+                .create(
+                    &mut tx,
+                    InvocationCreateCmd {
+                        user: user.id,
+                        rule: rule.id,
+                        token_pair: token_pair.id,
+                        stage,
+                        next: None,
+                        deadline: None,
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            test_instance.get_latest_id(&mut tx).await.unwrap(),
+            Some(3.into())
+        );
+
+        let result = test_instance.list_after(&mut tx, 1, 10).await.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].id, 2);
+        assert_eq!(result[0].stage, 1);
+        assert_eq!(result[1].id, 3);
+        assert_eq!(result[1].stage, 0);
+
+        let result = test_instance.list_after(&mut tx, 0, 1).await.unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, 1);
+
+        let result = test_instance.list_after(&mut tx, 3, 10).await.unwrap();
+        assert!(result.is_empty());
+    })
+    .await
+}
@@ -7,6 +7,10 @@ mod invocation;
 mod notification;
 mod rule;
 mod token;
+mod token_balance;
 mod token_pair;
+mod token_pair_change;
+mod trade;
+mod transfer;
 mod user;
 mod wallet;
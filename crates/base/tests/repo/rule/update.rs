@@ -5,9 +5,12 @@ use base::model::Action::NotifyTelegram;
 use base::model::Condition::Compare;
 use base::model::Field::{PriceAvg, Volume};
 use base::model::Operator::{Equal, MoreThan};
-use base::model::{Action, RuleStatus, Sequence, TelegramActionButtonConfig, Value};
+use base::model::{
+    Action, RuleLimit, RuleRearm, RuleStatus, Sequence, TelegramActionButtonConfig, Value,
+};
 use base::repo::{RuleRepo, RuleUpdateCmd};
 use common::model::Timeframe::{H1, M15};
+use common::model::Timestamp;
 use common::repo::error::RepoError;
 use testing::rule::{create_inactive_rule_for_test_user, get_rule_by_id};
 use testing::run_test;
@@ -36,6 +39,14 @@ async fn test_update() {
                 RuleUpdateCmd {
                     id: test_rule.id,
                     status: Inactive,
+                    limit: RuleLimit {
+                        max_invocations: Some(10),
+                        cooldown_seconds: Some(60),
+                        rearm: RuleRearm::Always,
+                        max_invocations_per_token_pair: Some(3),
+                        start_at: Some(Timestamp::from_epoch_second(1_700_000_000).unwrap()),
+                        end_at: None,
+                    },
                     user: user.id,
                     name: "UPDATED".into(),
                     sequence: Sequence {
@@ -66,6 +77,15 @@ async fn test_update() {
         assert_eq!(result.name, "UPDATED");
         assert_eq!(result.status, Inactive);
         assert_eq!(result.version, 2);
+        assert_eq!(result.limit.max_invocations, Some(10));
+        assert_eq!(result.limit.cooldown_seconds, Some(60));
+        assert_eq!(result.limit.rearm, RuleRearm::Always);
+        assert_eq!(result.limit.max_invocations_per_token_pair, Some(3));
+        assert_eq!(
+            result.limit.start_at,
+            Some(Timestamp::from_epoch_second(1_700_000_000).unwrap())
+        );
+        assert_eq!(result.limit.end_at, None);
         assert_eq!(result.user, 1);
         assert_eq!(
             result.sequence.condition,
@@ -115,6 +135,7 @@ async fn test_nothing_changed() {
                 RuleUpdateCmd {
                     id: test_rule.id,
                     status: RuleStatus::Active,
+                    limit: RuleLimit::default(),
                     user: user.id,
                     name: "A".into(),
                     sequence: Sequence {
@@ -169,6 +190,7 @@ async fn test_different_user() {
                 RuleUpdateCmd {
                     id: test_rule.id,
                     status: RuleStatus::Active,
+                    limit: RuleLimit::default(),
                     user: another_user.id,
                     name: "UPDATED".into(),
                     sequence: Sequence {
@@ -221,6 +243,7 @@ async fn test_rule_not_found() {
                 RuleUpdateCmd {
                     id: 1234.into(),
                     status: RuleStatus::Active,
+                    limit: RuleLimit::default(),
                     user: user.id,
                     name: "UPDATED".into(),
                     sequence: Sequence {
@@ -242,3 +265,34 @@ async fn test_rule_not_found() {
     })
     .await
 }
+
+#[test_log::test(sqlx::test)]
+async fn test_update_status() {
+    run_test(|mut tx| async move {
+        let test_instance = RuleRepo::new();
+
+        let test_rule = create_inactive_rule_for_test_user(&mut tx, "A").await;
+
+        let result = test_instance
+            .update_status(&mut tx, test_rule.id, RuleStatus::InactiveExhausted)
+            .await
+            .unwrap();
+        assert_eq!(result.id, test_rule.id);
+        assert_eq!(result.status, RuleStatus::InactiveExhausted);
+        assert_eq!(result.version, 2);
+        assert_eq!(result.name, "A");
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_update_status_not_found() {
+    run_test(|mut tx| async move {
+        let test_instance = RuleRepo::new();
+        let result = test_instance
+            .update_status(&mut tx, 404, RuleStatus::ActiveExhausted)
+            .await;
+        assert_eq!(result.err(), Some(RepoError::NotFound));
+    })
+    .await
+}
@@ -29,6 +29,8 @@ async fn test_insert_has_no_age_but_getting_the_token_does() {
                     description: None,
                     image: None,
                     website: None,
+                    twitter: None,
+                    telegram: None,
                     creator: None,
                 }],
             )
@@ -0,0 +1,81 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{Decimals, Mint, TelegramHandle, TwitterHandle};
+use base::repo::{TokenRepo, TokenToInsert};
+use base::test::SuccessfulTokenInfoLoader;
+use testing::run_test_on_empty_db;
+
+fn token(twitter: Option<TwitterHandle>, telegram: Option<TelegramHandle>) -> TokenToInsert {
+    TokenToInsert {
+        block: None,
+        block_time: None,
+        mint: "44J6Um1tTiTbtL9nd4hU6MqDyPppeWtGr3rMFQ6ppump".into(),
+        name: None,
+        symbol: None,
+        decimals: Decimals::from(6),
+        supply: None,
+        metadata: None,
+        description: None,
+        image: None,
+        website: None,
+        twitter,
+        telegram,
+        creator: None,
+    }
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_insert_social() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenRepo::testing(Box::new(SuccessfulTokenInfoLoader::default()));
+
+        let mut result = test_instance
+            .insert_token(
+                &mut tx,
+                [token(
+                    Some(TwitterHandle::from("AI_nyanbot")),
+                    Some(TelegramHandle::from("nyanbot")),
+                )],
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+
+        let result = result.pop().unwrap();
+        assert_eq!(result.twitter.unwrap(), "AI_nyanbot");
+        assert_eq!(result.telegram.unwrap(), "nyanbot");
+
+        let mut result = test_instance
+            .read_token_mints_from_db(
+                &mut tx,
+                &[Mint::from("44J6Um1tTiTbtL9nd4hU6MqDyPppeWtGr3rMFQ6ppump")],
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+
+        let result = result.pop().unwrap();
+        assert_eq!(result.twitter.unwrap(), "AI_nyanbot");
+        assert_eq!(result.telegram.unwrap(), "nyanbot");
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_insert_without_social() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenRepo::testing(Box::new(SuccessfulTokenInfoLoader::default()));
+
+        let mut result = test_instance
+            .insert_token(&mut tx, [token(None, None)])
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+
+        let result = result.pop().unwrap();
+        assert_eq!(result.twitter, None);
+        assert_eq!(result.telegram, None);
+    })
+    .await
+}
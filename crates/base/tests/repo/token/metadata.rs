@@ -0,0 +1,218 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{Decimals, Description, Mint, TwitterHandle, Uri};
+use base::repo::{TokenRepo, TokenToInsert};
+use base::test::NeverCalledTokenInfoLoader;
+use base::TokenInfo;
+use common::repo::Tx;
+use testing::run_test_on_empty_db;
+
+async fn insert(
+    tx: &mut Tx<'_>,
+    repo: &TokenRepo,
+    mint: &str,
+    metadata: Option<&str>,
+    description: Option<&str>,
+) {
+    repo.insert_token(
+        tx,
+        [TokenToInsert {
+            block: None,
+            block_time: None,
+            mint: mint.into(),
+            name: None,
+            symbol: None,
+            decimals: Decimals::from(6),
+            supply: None,
+            metadata: metadata.map(Uri::from),
+            description: description.map(Description::from),
+            image: None,
+            website: None,
+            twitter: None,
+            telegram: None,
+            creator: None,
+        }],
+    )
+    .await
+    .unwrap();
+}
+
+fn info() -> TokenInfo {
+    TokenInfo {
+        mint: None,
+        name: None,
+        symbol: None,
+        decimals: None,
+        supply: None,
+        description: Some(Description::from("fetched description")),
+        metadata: None,
+        image: Some(Uri::from("https://ipfs.io/ipfs/image")),
+        website: Some(Uri::from("https://nyanbot.com")),
+        twitter: Some(TwitterHandle::from("AI_nyanbot")),
+        telegram: None,
+    }
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_unresolved() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenRepo::testing(Box::new(NeverCalledTokenInfoLoader {}));
+
+        insert(
+            &mut tx,
+            &test_instance,
+            "Mint1111111111111111111111111111111111111pump",
+            Some("https://ipfs.io/ipfs/1"),
+            None,
+        )
+        .await;
+        insert(
+            &mut tx,
+            &test_instance,
+            "Mint2222222222222222222222222222222222222pump",
+            None,
+            None,
+        )
+        .await;
+        insert(
+            &mut tx,
+            &test_instance,
+            "Mint3333333333333333333333333333333333333pump",
+            Some("https://ipfs.io/ipfs/3"),
+            None,
+        )
+        .await;
+
+        let result = test_instance
+            .list_unresolved_metadata(&mut tx, 5, 10)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 2);
+
+        // newest first
+        assert_eq!(
+            result[0].mint,
+            "Mint3333333333333333333333333333333333333pump"
+        );
+        assert_eq!(result[0].metadata, "https://ipfs.io/ipfs/3");
+        assert_eq!(result[0].attempts, 0);
+        assert_eq!(
+            result[1].mint,
+            "Mint1111111111111111111111111111111111111pump"
+        );
+
+        let result = test_instance
+            .list_unresolved_metadata(&mut tx, 5, 1)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_resolve() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenRepo::testing(Box::new(NeverCalledTokenInfoLoader {}));
+
+        insert(
+            &mut tx,
+            &test_instance,
+            "Mint1111111111111111111111111111111111111pump",
+            Some("https://ipfs.io/ipfs/1"),
+            Some("on-chain description"),
+        )
+        .await;
+
+        let unresolved = test_instance
+            .list_unresolved_metadata(&mut tx, 5, 10)
+            .await
+            .unwrap();
+        assert_eq!(unresolved.len(), 1);
+
+        let result = test_instance
+            .resolve_metadata(&mut tx, unresolved[0].id, info())
+            .await
+            .unwrap();
+
+        // known values are kept
+        assert_eq!(result.description.unwrap(), "on-chain description");
+        assert_eq!(result.image.unwrap(), "https://ipfs.io/ipfs/image");
+        assert_eq!(result.website.unwrap(), "https://nyanbot.com");
+        assert_eq!(result.twitter.unwrap(), "AI_nyanbot");
+        assert_eq!(result.telegram, None);
+        assert_eq!(result.metadata.unwrap(), "https://ipfs.io/ipfs/1");
+
+        let result = test_instance
+            .list_unresolved_metadata(&mut tx, 5, 10)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 0);
+
+        let result = test_instance
+            .list_by_mints(
+                &mut tx,
+                [Mint::from("Mint1111111111111111111111111111111111111pump")],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            result[0].image.clone().unwrap(),
+            "https://ipfs.io/ipfs/image"
+        );
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_fail() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenRepo::testing(Box::new(NeverCalledTokenInfoLoader {}));
+
+        insert(
+            &mut tx,
+            &test_instance,
+            "Mint1111111111111111111111111111111111111pump",
+            Some("https://ipfs.io/ipfs/1"),
+            None,
+        )
+        .await;
+
+        let unresolved = test_instance
+            .list_unresolved_metadata(&mut tx, 5, 10)
+            .await
+            .unwrap();
+        test_instance
+            .fail_metadata(&mut tx, unresolved[0].id)
+            .await
+            .unwrap();
+
+        // not due again right away
+        let result = test_instance
+            .list_unresolved_metadata(&mut tx, 5, 10)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 0);
+
+        sqlx::query("update solana.token set metadata_attempted_at = now() - interval '2 minutes'")
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .list_unresolved_metadata(&mut tx, 5, 10)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].attempts, 1);
+
+        // given up on
+        let result = test_instance
+            .list_unresolved_metadata(&mut tx, 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 0);
+    })
+    .await
+}
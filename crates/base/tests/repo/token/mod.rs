@@ -2,4 +2,6 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 mod list_or_populate;
-mod get_or_populate;
\ No newline at end of file
+mod get_or_populate;
+mod insert;
+mod metadata;
\ No newline at end of file
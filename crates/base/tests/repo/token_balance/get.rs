@@ -0,0 +1,96 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{DecimalAmount, Mint, PublicKey};
+use base::repo::{AddressRepo, TokenBalanceRepo, TokenBalanceToInsert, TokenRepo};
+use common::model::Timestamp;
+use testing::run_test;
+
+const OWNER: &str = "CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM";
+
+#[test_log::test(sqlx::test)]
+async fn test_get_token_balance() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        let token = TokenRepo::testing_no_token_info()
+            .get_by_mint(&mut tx, Mint::usdc())
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        test_instance
+            .insert_token_balances(
+                &mut tx,
+                vec![TokenBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp: Timestamp::now(),
+                    address: address.id,
+                    token: token.id,
+                    pre: DecimalAmount::from(0i64),
+                    post: DecimalAmount::from(42i64),
+                }],
+            )
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .get_token_balance(&mut tx, PublicKey::from(OWNER), token.id)
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::from(42i64)));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_get_token_balance_not_indexed() {
+    run_test(|mut tx| async move {
+        let token = TokenRepo::testing_no_token_info()
+            .get_by_mint(&mut tx, Mint::usdc())
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        let result = test_instance
+            .get_token_balance(&mut tx, PublicKey::from(OWNER), token.id)
+            .await
+            .unwrap();
+        assert_eq!(result, None);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_get_sol_balance() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        sqlx::query(
+            "insert into solana.wallet_balance_sol_most_recent (wallet_id, balance, delta, slot) values ($1, 1.5, 1.5, 100);",
+        )
+        .bind(address.id)
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        let result = test_instance
+            .get_sol_balance(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::new(1_500_000_000u64, 9)));
+
+        let result = test_instance
+            .get_sol_balance(&mut tx, PublicKey::from("So11111111111111111111111111111111111111112"))
+            .await
+            .unwrap();
+        assert_eq!(result, None);
+    })
+    .await
+}
@@ -0,0 +1,151 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{AddressId, DecimalAmount, PublicKey};
+use base::repo::{AddressRepo, SolBalanceToInsert, TokenBalanceRepo};
+use common::model::Timestamp;
+use common::repo::Tx;
+use sqlx::Row;
+use testing::run_test;
+
+const OWNER: &str = "CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM";
+
+async fn count_sol_balances(tx: &mut Tx<'_>, address: AddressId) -> i64 {
+    sqlx::query("select count(*) from solana.wallet_balance_sol where wallet_id = $1")
+        .bind(address)
+        .fetch_one(&mut **tx)
+        .await
+        .unwrap()
+        .get::<i64, _>(0)
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_insert_sol_balances() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        let timestamp = Timestamp::now();
+        test_instance
+            .insert_sol_balances(
+                &mut tx,
+                vec![
+                    SolBalanceToInsert {
+                        slot: 100.into(),
+                        timestamp,
+                        address: address.id,
+                        pre: DecimalAmount::from(1i64),
+                        post: DecimalAmount::from(2i64),
+                    },
+                    SolBalanceToInsert {
+                        slot: 100.into(),
+                        timestamp,
+                        address: address.id,
+                        pre: DecimalAmount::from(2i64),
+                        post: DecimalAmount::from(3i64),
+                    },
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(count_sol_balances(&mut tx, address.id).await, 1);
+
+        let row = sqlx::query(
+            "select balance, delta, slot from solana.wallet_balance_sol where wallet_id = $1",
+        )
+        .bind(address.id)
+        .fetch_one(&mut *tx)
+        .await
+        .unwrap();
+        assert_eq!(
+            row.get::<DecimalAmount, _>("balance"),
+            DecimalAmount::from(3i64)
+        );
+        assert_eq!(
+            row.get::<DecimalAmount, _>("delta"),
+            DecimalAmount::from(2i64)
+        );
+        assert_eq!(row.get::<i64, _>("slot"), 100);
+
+        let result = test_instance
+            .get_sol_balance(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::from(3i64)));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_insert_sol_balances_unchanged() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        test_instance
+            .insert_sol_balances(
+                &mut tx,
+                vec![SolBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp: Timestamp::now(),
+                    address: address.id,
+                    pre: DecimalAmount::from(1i64),
+                    post: DecimalAmount::from(1i64),
+                }],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(count_sol_balances(&mut tx, address.id).await, 0);
+
+        let result = test_instance
+            .get_sol_balance(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert_eq!(result, None);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_insert_sol_balances_keeps_most_recent() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        for (slot, post) in [(200, 5i64), (100, 3i64)] {
+            test_instance
+                .insert_sol_balances(
+                    &mut tx,
+                    vec![SolBalanceToInsert {
+                        slot: slot.into(),
+                        timestamp: Timestamp::now(),
+                        address: address.id,
+                        pre: DecimalAmount::from(1i64),
+                        post: DecimalAmount::from(post),
+                    }],
+                )
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(count_sol_balances(&mut tx, address.id).await, 2);
+
+        let result = test_instance
+            .get_sol_balance(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::from(5i64)));
+    })
+    .await
+}
@@ -0,0 +1,188 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{AddressId, DecimalAmount, Mint, PublicKey, TokenPairId};
+use base::repo::{AddressRepo, TokenBalanceRepo, TokenBalanceToInsert};
+use common::model::{PriceQuote, Timestamp};
+use common::repo::Tx;
+use sqlx::Executor;
+use testing::run_test;
+use testing::token_pair::get_or_create_token_pair;
+
+const OWNER: &str = "CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM";
+
+async fn insert_pumpfun_price(tx: &mut Tx<'_>, pair: TokenPairId, price: &str) {
+    tx.execute(
+        format!(
+            r#"insert into pumpfun.current (id, slot, virtual_base_reserves, virtual_quote_reserves, progress, complete, price)
+            values ({}, 1, 1, 1, 10, false, {price});"#,
+            pair.0
+        )
+        .as_str(),
+    )
+    .await
+    .unwrap();
+}
+
+async fn insert_jupiter_price(
+    tx: &mut Tx<'_>,
+    address: AddressId,
+    pair: TokenPairId,
+    price: &str,
+    timestamp: &str,
+) {
+    tx.execute(
+        format!(
+            r#"insert into jupiter.swap (slot, address_id, token_pair_id, amount_base, amount_quote, price, is_buy, timestamp, signature)
+            values (1, {}, {}, 1, 1, {price}, true, '{timestamp}', 'signature');"#,
+            address.0, pair.0
+        )
+        .as_str(),
+    )
+    .await
+    .unwrap();
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_token_balances() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let pumpfun = get_or_create_token_pair(
+            &mut tx,
+            "2Yt4cyQq3ZmvGuRxb6ZgCiUQMtKgCbqbAbJXanMSpump",
+            Mint::wsol(),
+        )
+        .await;
+        insert_pumpfun_price(&mut tx, pumpfun.id, "0.5").await;
+
+        let jupiter = get_or_create_token_pair(
+            &mut tx,
+            "HNg5PYJmtqcmzXrv6S9zP1CDKk5BgDuyFBxbvNApump",
+            Mint::wsol(),
+        )
+        .await;
+        insert_jupiter_price(
+            &mut tx,
+            address.id,
+            jupiter.id,
+            "2",
+            "2025-03-20 10:00:00+00",
+        )
+        .await;
+
+        let unpriced = get_or_create_token_pair(
+            &mut tx,
+            "CpV7zK77DkyVvbCgGWtWrsqvh2VonFrqPg8cecrTpump",
+            Mint::wsol(),
+        )
+        .await;
+
+        let empty = get_or_create_token_pair(
+            &mut tx,
+            "4Ct1Mg3A3qSoc8gPYqpiBVEmE4WbmV1BYvGygXQppump",
+            Mint::wsol(),
+        )
+        .await;
+
+        let test_instance = TokenBalanceRepo::new();
+        test_instance
+            .insert_token_balances(
+                &mut tx,
+                [
+                    (pumpfun.base.id, 10, 20),
+                    (jupiter.base.id, 0, 3),
+                    (unpriced.base.id, 0, 7),
+                    (empty.base.id, 5, 0),
+                ]
+                .into_iter()
+                .map(|(token, pre, post)| TokenBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp: Timestamp::now(),
+                    address: address.id,
+                    token,
+                    pre: DecimalAmount::from(pre as i64),
+                    post: DecimalAmount::from(post as i64),
+                }),
+            )
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .list_token_balances(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 3);
+
+        assert_eq!(result[0].token, pumpfun.base.id);
+        assert_eq!(result[0].balance, DecimalAmount::from(20i64));
+        assert_eq!(result[0].price.clone().unwrap(), "0.5");
+
+        assert_eq!(result[1].token, jupiter.base.id);
+        assert_eq!(result[1].balance, DecimalAmount::from(3i64));
+        assert_eq!(result[1].price, Some(PriceQuote::from(2i64)));
+
+        assert_eq!(result[2].token, unpriced.base.id);
+        assert_eq!(result[2].balance, DecimalAmount::from(7i64));
+        assert_eq!(result[2].price, None);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_token_balances_most_recent_price() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let pair = get_or_create_token_pair(
+            &mut tx,
+            "2Yt4cyQq3ZmvGuRxb6ZgCiUQMtKgCbqbAbJXanMSpump",
+            Mint::wsol(),
+        )
+        .await;
+        insert_pumpfun_price(&mut tx, pair.id, "0.5").await;
+        insert_jupiter_price(&mut tx, address.id, pair.id, "2", "2099-01-01 00:00:00+00").await;
+
+        let test_instance = TokenBalanceRepo::new();
+        test_instance
+            .insert_token_balances(
+                &mut tx,
+                vec![TokenBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp: Timestamp::now(),
+                    address: address.id,
+                    token: pair.base.id,
+                    pre: DecimalAmount::from(0i64),
+                    post: DecimalAmount::from(1i64),
+                }],
+            )
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .list_token_balances(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].price, Some(PriceQuote::from(2i64)));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_token_balances_nothing_indexed() {
+    run_test(|mut tx| async move {
+        let result = TokenBalanceRepo::new()
+            .list_token_balances(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+    })
+    .await
+}
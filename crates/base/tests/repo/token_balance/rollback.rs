@@ -0,0 +1,236 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::solana::Slot;
+use base::model::{DecimalAmount, PublicKey};
+use base::repo::{AddressRepo, SolBalanceToInsert, TokenBalanceRepo, TokenBalanceToInsert};
+use common::model::Timestamp;
+use testing::run_test;
+
+const OWNER: &str = "CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM";
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback_sol_balance() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        let timestamp = Timestamp::now();
+
+        for (slot, pre, post) in [(100, 1i64, 2i64), (101, 2i64, 5i64)] {
+            test_instance
+                .insert_sol_balances(
+                    &mut tx,
+                    vec![SolBalanceToInsert {
+                        slot: slot.into(),
+                        timestamp,
+                        address: address.id,
+                        pre: DecimalAmount::from(pre),
+                        post: DecimalAmount::from(post),
+                    }],
+                )
+                .await
+                .unwrap();
+        }
+
+        let result = test_instance
+            .get_sol_balance(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::from(5i64)));
+
+        test_instance
+            .rollback(&mut tx, &[Slot(101)], timestamp, timestamp)
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .get_sol_balance(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::from(2i64)));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback_outside_of_time_range() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        let timestamp = Timestamp::now();
+
+        test_instance
+            .insert_sol_balances(
+                &mut tx,
+                vec![SolBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp,
+                    address: address.id,
+                    pre: DecimalAmount::from(1i64),
+                    post: DecimalAmount::from(2i64),
+                }],
+            )
+            .await
+            .unwrap();
+
+        let later = Timestamp::from_epoch_micros(timestamp.to_epoch_micros() + 1_000_000);
+        test_instance
+            .rollback(&mut tx, &[Slot(100)], later, later)
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .get_sol_balance(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::from(2i64)));
+    })
+    .await
+}
+
+async fn insert_token_balance<'a>(
+    tx: &mut common::repo::Tx<'a>,
+    test_instance: &TokenBalanceRepo,
+    address: base::model::AddressId,
+    slot: i64,
+    timestamp: Timestamp,
+    pre: i64,
+    post: i64,
+) {
+    test_instance
+        .insert_token_balances(
+            tx,
+            vec![TokenBalanceToInsert {
+                slot: slot.into(),
+                timestamp,
+                address,
+                token: 1.into(),
+                pre: DecimalAmount::from(pre),
+                post: DecimalAmount::from(post),
+            }],
+        )
+        .await
+        .unwrap();
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback_token_balance() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        let timestamp = Timestamp::now();
+
+        insert_token_balance(&mut tx, &test_instance, address.id, 100, timestamp, 1, 2).await;
+        insert_token_balance(&mut tx, &test_instance, address.id, 101, timestamp, 2, 5).await;
+        insert_token_balance(&mut tx, &test_instance, address.id, 102, timestamp, 5, 7).await;
+
+        test_instance
+            .rollback(&mut tx, &[Slot(101), Slot(102)], timestamp, timestamp)
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .get_token_balance(&mut tx, PublicKey::from(OWNER), 1)
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::from(2i64)));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback_token_balance_keeps_later_update() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        let timestamp = Timestamp::now();
+
+        insert_token_balance(&mut tx, &test_instance, address.id, 100, timestamp, 1, 2).await;
+        insert_token_balance(&mut tx, &test_instance, address.id, 101, timestamp, 2, 5).await;
+        insert_token_balance(&mut tx, &test_instance, address.id, 102, timestamp, 5, 7).await;
+
+        test_instance
+            .rollback(&mut tx, &[Slot(101)], timestamp, timestamp)
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .get_token_balance(&mut tx, PublicKey::from(OWNER), 1)
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::from(7i64)));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback_token_balance_which_did_not_exist_before() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        let timestamp = Timestamp::now();
+
+        insert_token_balance(&mut tx, &test_instance, address.id, 100, timestamp, 0, 2).await;
+
+        test_instance
+            .rollback(&mut tx, &[Slot(100)], timestamp, timestamp)
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .get_token_balance(&mut tx, PublicKey::from(OWNER), 1)
+            .await
+            .unwrap();
+        assert_eq!(result, None);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback_token_balance_after_finalize() {
+    run_test(|mut tx| async move {
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let test_instance = TokenBalanceRepo::new();
+        let timestamp = Timestamp::now();
+
+        insert_token_balance(&mut tx, &test_instance, address.id, 100, timestamp, 1, 2).await;
+        test_instance.finalize(&mut tx, 100).await.unwrap();
+        insert_token_balance(&mut tx, &test_instance, address.id, 101, timestamp, 2, 5).await;
+
+        test_instance
+            .rollback(&mut tx, &[Slot(101)], timestamp, timestamp)
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .get_token_balance(&mut tx, PublicKey::from(OWNER), 1)
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DecimalAmount::from(2i64)));
+    })
+    .await
+}
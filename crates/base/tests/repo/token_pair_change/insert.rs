@@ -0,0 +1,99 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{ChangeKind, TokenId, TokenPairId};
+use base::repo::TokenPairChangeRepo;
+use common::repo::error::RepoError;
+use testing::run_test_on_empty_db;
+
+#[test_log::test(sqlx::test)]
+async fn test_insert() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenPairChangeRepo::new();
+
+        test_instance
+            .insert(
+                &mut tx,
+                ChangeKind::Swap,
+                [TokenPairId(1), TokenPairId(2), TokenPairId(1)],
+            )
+            .await
+            .unwrap();
+
+        test_instance
+            .insert(&mut tx, ChangeKind::Summary, [TokenPairId(1)])
+            .await
+            .unwrap();
+
+        let count = test_instance.count_all(&mut tx).await.unwrap();
+        assert_eq!(count, 3);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_insert_for_token() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenPairChangeRepo::new();
+
+        // WSOL is the base token of WSOL/USDT and WSOL/USDC
+        test_instance
+            .insert_for_token(&mut tx, ChangeKind::Metadata, TokenId(1))
+            .await
+            .unwrap();
+
+        let count = test_instance.count_all(&mut tx).await.unwrap();
+        assert_eq!(count, 2);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_insert_pending_change_again() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenPairChangeRepo::new();
+
+        test_instance
+            .insert(&mut tx, ChangeKind::Swap, [TokenPairId(1)])
+            .await
+            .unwrap();
+
+        test_instance
+            .insert(&mut tx, ChangeKind::Swap, [TokenPairId(1)])
+            .await
+            .unwrap();
+
+        let count = test_instance.count_all(&mut tx).await.unwrap();
+        assert_eq!(count, 1);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_insert_nothing() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenPairChangeRepo::new();
+
+        test_instance
+            .insert(&mut tx, ChangeKind::Swap, [])
+            .await
+            .unwrap();
+
+        let count = test_instance.count_all(&mut tx).await.unwrap();
+        assert_eq!(count, 0);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_token_pair_does_not_exist() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenPairChangeRepo::new();
+
+        let result = test_instance
+            .insert(&mut tx, ChangeKind::Swap, [TokenPairId(404)])
+            .await;
+        assert_eq!(result.err(), Some(RepoError::ForeignKeyViolation));
+    })
+    .await
+}
@@ -0,0 +1,130 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{ChangeKind, TokenPairId};
+use base::repo::TokenPairChangeRepo;
+use testing::run_test_on_empty_db;
+
+#[test_log::test(sqlx::test)]
+async fn test_no_changes() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenPairChangeRepo::new();
+
+        let result = test_instance.pop(&mut tx, 10).await.unwrap();
+        assert_eq!(result.len(), 0);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_pop() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenPairChangeRepo::new();
+
+        test_instance
+            .insert(&mut tx, ChangeKind::Swap, [TokenPairId(1), TokenPairId(3)])
+            .await
+            .unwrap();
+
+        let result = test_instance.pop(&mut tx, 10).await.unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|c| c.kind == ChangeKind::Swap));
+        assert!(result.iter().any(|c| c.token_pair == 1));
+        assert!(result.iter().any(|c| c.token_pair == 3));
+
+        let count = test_instance.count_all(&mut tx).await.unwrap();
+        assert_eq!(count, 0);
+
+        // once popped, the same change can be recorded again
+        test_instance
+            .insert(&mut tx, ChangeKind::Swap, [TokenPairId(1)])
+            .await
+            .unwrap();
+
+        let count = test_instance.count_all(&mut tx).await.unwrap();
+        assert_eq!(count, 1);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_pop_limit() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenPairChangeRepo::new();
+
+        test_instance
+            .insert(
+                &mut tx,
+                ChangeKind::Summary,
+                [TokenPairId(1), TokenPairId(2), TokenPairId(3)],
+            )
+            .await
+            .unwrap();
+
+        let result = test_instance.pop(&mut tx, 2).await.unwrap();
+        assert_eq!(result.len(), 2);
+
+        let count = test_instance.count_all(&mut tx).await.unwrap();
+        assert_eq!(count, 1);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_pop_waits_for_watermark() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = TokenPairChangeRepo::new();
+
+        sqlx::query("insert into solana.indexer (id, slot, watermark) values (1, 110, 100);")
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+
+        test_instance
+            .insert_at_slot(&mut tx, ChangeKind::Swap, 100, [TokenPairId(1)])
+            .await
+            .unwrap();
+        test_instance
+            .insert_at_slot(&mut tx, ChangeKind::Swap, 105, [TokenPairId(2)])
+            .await
+            .unwrap();
+        test_instance
+            .insert(&mut tx, ChangeKind::Summary, [TokenPairId(3)])
+            .await
+            .unwrap();
+
+        let mut result = test_instance
+            .pop(&mut tx, 10)
+            .await
+            .unwrap()
+            .iter()
+            .map(|c| c.token_pair)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 3]);
+
+        // a later swap holds back the change until its slot is finalized as well
+        test_instance
+            .insert_at_slot(&mut tx, ChangeKind::Swap, 108, [TokenPairId(2)])
+            .await
+            .unwrap();
+
+        sqlx::query("update solana.indexer set watermark = 106 where id = 1;")
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+
+        let result = test_instance.pop(&mut tx, 10).await.unwrap();
+        assert_eq!(result.len(), 0);
+
+        sqlx::query("update solana.indexer set watermark = 108 where id = 1;")
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+
+        let result = test_instance.pop(&mut tx, 10).await.unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].token_pair, 2);
+    })
+    .await
+}
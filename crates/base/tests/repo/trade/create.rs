@@ -0,0 +1,148 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::repo::trade::create_invocation;
+use base::model::solana::Signature;
+use base::model::{DecimalAmount, TradeSide, TradeStatus, Venue};
+use base::repo::{TradeCreateCmd, TradeRepo};
+use common::model::PriceQuote;
+use common::repo::error::RepoError;
+use sqlx::Acquire;
+use testing::run_test;
+use testing::trade::count_all;
+
+#[test_log::test(sqlx::test)]
+async fn test_create_submitted() {
+    run_test(|mut tx| async move {
+        let invocation = create_invocation(&mut tx).await;
+
+        let test_instance = TradeRepo::new();
+        let result = test_instance
+            .create(
+                &mut tx,
+                TradeCreateCmd {
+                    invocation: invocation.id,
+                    user: invocation.user,
+                    wallet: 1.into(),
+                    token_pair: invocation.token_pair,
+                    venue: Venue::PumpFun,
+                    side: TradeSide::Buy,
+                    status: TradeStatus::Submitted,
+                    amount_base: Some(DecimalAmount::new(3_564_784_053_156u64, 6)),
+                    amount_quote: Some(DecimalAmount::new(100_000_000u64, 9)),
+                    price: Some(PriceQuote::from(1i64)),
+                    signature: Some(Signature::from(
+                        "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn",
+                    )),
+                    error: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.id, 1);
+        assert_eq!(result.invocation, invocation.id);
+        assert_eq!(result.user, 1);
+        assert_eq!(result.wallet, 1);
+        assert_eq!(result.token_pair, 3);
+        assert_eq!(result.venue, Venue::PumpFun);
+        assert_eq!(result.side, TradeSide::Buy);
+        assert_eq!(result.status, TradeStatus::Submitted);
+        assert_eq!(
+            result.amount_base,
+            Some(DecimalAmount::new(3_564_784_053_156u64, 6))
+        );
+        assert_eq!(
+            result.amount_quote,
+            Some(DecimalAmount::new(100_000_000u64, 9))
+        );
+        assert_eq!(result.price.unwrap(), 1);
+        assert_eq!(
+            result.signature.unwrap(),
+            "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn"
+        );
+        assert_eq!(result.error, None);
+
+        let count = count_all(&mut tx).await;
+        assert_eq!(count, 1)
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_create_failed() {
+    run_test(|mut tx| async move {
+        let invocation = create_invocation(&mut tx).await;
+
+        let test_instance = TradeRepo::new();
+        let result = test_instance
+            .create(
+                &mut tx,
+                TradeCreateCmd {
+                    invocation: invocation.id,
+                    user: invocation.user,
+                    wallet: 1.into(),
+                    token_pair: invocation.token_pair,
+                    venue: Venue::PumpFun,
+                    side: TradeSide::Sell,
+                    status: TradeStatus::Failed,
+                    amount_base: None,
+                    amount_quote: None,
+                    price: None,
+                    signature: None,
+                    error: Some("Nothing to sell".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.side, TradeSide::Sell);
+        assert_eq!(result.status, TradeStatus::Failed);
+        assert_eq!(result.amount_base, None);
+        assert_eq!(result.amount_quote, None);
+        assert_eq!(result.price, None);
+        assert_eq!(result.signature, None);
+        assert_eq!(result.error.unwrap(), "Nothing to sell");
+
+        let trades = test_instance
+            .list_by_invocation(&mut tx, invocation.id)
+            .await
+            .unwrap();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].id, result.id);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_trade_requires_existing_invocation() {
+    run_test(|mut tx| async move {
+        let invocation = create_invocation(&mut tx).await;
+
+        let test_instance = TradeRepo::new();
+        let result = test_instance
+            .create(
+                &mut tx.begin().await.unwrap(),
+                TradeCreateCmd {
+                    invocation: 1234567.into(),
+                    user: invocation.user,
+                    wallet: 1.into(),
+                    token_pair: invocation.token_pair,
+                    venue: Venue::PumpFun,
+                    side: TradeSide::Buy,
+                    status: TradeStatus::Failed,
+                    amount_base: None,
+                    amount_quote: None,
+                    price: None,
+                    signature: None,
+                    error: None,
+                },
+            )
+            .await;
+        assert_eq!(result.err(), Some(RepoError::ForeignKeyViolation));
+
+        let count = count_all(&mut tx).await;
+        assert_eq!(count, 0)
+    })
+    .await
+}
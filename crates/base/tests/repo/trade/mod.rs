@@ -0,0 +1,33 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{Invocation, Mint};
+use base::repo::{InvocationCreateCmd, InvocationRepo};
+use common::repo::Tx;
+use testing::rule::create_inactive_rule_for_test_user;
+use testing::token_pair::get_or_create_token_pair;
+use testing::user::get_or_create_test_user;
+
+mod create;
+mod update;
+
+pub(crate) async fn create_invocation<'a>(tx: &mut Tx<'a>) -> Invocation {
+    let user = get_or_create_test_user(tx).await;
+    let rule = create_inactive_rule_for_test_user(tx, "MoneyMaker").await;
+    let token_pair = get_or_create_token_pair(tx, Mint::usdc(), Mint::usdt()).await;
+
+    InvocationRepo::new()
+        .create(
+            tx,
+            InvocationCreateCmd {
+                user: user.id,
+                rule: rule.id,
+                token_pair: token_pair.id,
+                stage: 0,
+                next: None,
+                deadline: None,
+            },
+        )
+        .await
+        .unwrap()
+}
@@ -0,0 +1,97 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::repo::trade::create_invocation;
+use base::model::solana::Signature;
+use base::model::{DecimalAmount, TradeSide, TradeStatus, Venue};
+use base::repo::{TradeCreateCmd, TradeRepo, TradeUpdateCmd};
+use common::model::PriceQuote;
+use common::repo::error::RepoError;
+use testing::run_test;
+
+#[test_log::test(sqlx::test)]
+async fn test_update_pending() {
+    run_test(|mut tx| async move {
+        let invocation = create_invocation(&mut tx).await;
+
+        let test_instance = TradeRepo::new();
+        let pending = test_instance
+            .create(
+                &mut tx,
+                TradeCreateCmd {
+                    invocation: invocation.id,
+                    user: invocation.user,
+                    wallet: 1.into(),
+                    token_pair: invocation.token_pair,
+                    venue: Venue::PumpFun,
+                    side: TradeSide::Buy,
+                    status: TradeStatus::Pending,
+                    amount_base: None,
+                    amount_quote: None,
+                    price: None,
+                    signature: None,
+                    error: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(pending.status, TradeStatus::Pending);
+
+        let result = test_instance
+            .update(
+                &mut tx,
+                TradeUpdateCmd {
+                    id: pending.id,
+                    venue: Venue::Raydium,
+                    status: TradeStatus::Submitted,
+                    amount_base: Some(DecimalAmount::new(3_564_784_053_156u64, 6)),
+                    amount_quote: Some(DecimalAmount::new(100_000_000u64, 9)),
+                    price: Some(PriceQuote::from(1i64)),
+                    signature: Some(Signature::from(
+                        "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn",
+                    )),
+                    error: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.id, pending.id);
+        assert_eq!(result.venue, Venue::Raydium);
+        assert_eq!(result.side, TradeSide::Buy);
+        assert_eq!(result.status, TradeStatus::Submitted);
+        assert_eq!(
+            result.amount_base,
+            Some(DecimalAmount::new(3_564_784_053_156u64, 6))
+        );
+        assert_eq!(
+            result.signature.unwrap(),
+            "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn"
+        );
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_update_not_found() {
+    run_test(|mut tx| async move {
+        let test_instance = TradeRepo::new();
+        let result = test_instance
+            .update(
+                &mut tx,
+                TradeUpdateCmd {
+                    id: 1234567.into(),
+                    venue: Venue::PumpFun,
+                    status: TradeStatus::Failed,
+                    amount_base: None,
+                    amount_quote: None,
+                    price: None,
+                    signature: None,
+                    error: Some("Nothing to sell".to_string()),
+                },
+            )
+            .await;
+        assert_eq!(result.err(), Some(RepoError::NotFound));
+    })
+    .await
+}
@@ -0,0 +1,119 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::solana::Signature;
+use base::model::{DecimalAmount, Mint, PublicKey, TransferStatus};
+use base::repo::{TokenRepo, TransferCreateCmd, TransferRepo};
+use common::repo::error::RepoError;
+use sqlx::Acquire;
+use testing::run_test;
+use testing::transfer::count_all;
+
+#[test_log::test(sqlx::test)]
+async fn test_create_sol() {
+    run_test(|mut tx| async move {
+        let test_instance = TransferRepo::new();
+        let result = test_instance
+            .create(
+                &mut tx,
+                TransferCreateCmd {
+                    user: 1.into(),
+                    wallet: 1.into(),
+                    token: None,
+                    recipient: PublicKey::from("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"),
+                    amount: DecimalAmount::new(1_500_000_000u64, 9),
+                    status: TransferStatus::Submitted,
+                    signature: Some(Signature::from(
+                        "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn",
+                    )),
+                    error: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.id, 1);
+        assert_eq!(result.user, 1);
+        assert_eq!(result.wallet, 1);
+        assert_eq!(result.token, None);
+        assert_eq!(
+            result.recipient,
+            "CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"
+        );
+        assert_eq!(result.amount, DecimalAmount::new(1_500_000_000u64, 9));
+        assert_eq!(
+            result.signature,
+            Some(Signature::from(
+                "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn"
+            ))
+        );
+
+        let count = count_all(&mut tx).await;
+        assert_eq!(count, 1);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_create_token() {
+    run_test(|mut tx| async move {
+        let token = TokenRepo::testing_no_token_info()
+            .get_by_mint(&mut tx, Mint::usdc())
+            .await
+            .unwrap();
+
+        let test_instance = TransferRepo::new();
+        let result = test_instance
+            .create(
+                &mut tx,
+                TransferCreateCmd {
+                    user: 1.into(),
+                    wallet: 1.into(),
+                    token: Some(token.id),
+                    recipient: PublicKey::from("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"),
+                    amount: DecimalAmount::new(2_000_000u64, 6),
+                    status: TransferStatus::Submitted,
+                    signature: Some(Signature::from(
+                        "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn",
+                    )),
+                    error: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.token, Some(token.id));
+        assert_eq!(result.amount, DecimalAmount::from(2i64));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_wallet_not_found() {
+    run_test(|mut tx| async move {
+        let test_instance = TransferRepo::new();
+        let result = test_instance
+            .create(
+                &mut tx.begin().await.unwrap(),
+                TransferCreateCmd {
+                    user: 1.into(),
+                    wallet: 404.into(),
+                    token: None,
+                    recipient: PublicKey::from("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"),
+                    amount: DecimalAmount::new(1_500_000_000u64, 9),
+                    status: TransferStatus::Submitted,
+                    signature: Some(Signature::from(
+                        "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn",
+                    )),
+                    error: None,
+                },
+            )
+            .await;
+
+        assert_eq!(result.err().unwrap(), RepoError::ForeignKeyViolation);
+
+        let count = count_all(&mut tx).await;
+        assert_eq!(count, 0);
+    })
+    .await
+}
@@ -0,0 +1,65 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{DecimalAmount, PublicKey, TransferStatus};
+use base::repo::{TransferCreateCmd, TransferRepo};
+use common::model::Count;
+use sqlx::Executor;
+use testing::run_test;
+
+fn pending() -> TransferCreateCmd {
+    TransferCreateCmd {
+        user: 1.into(),
+        wallet: 1.into(),
+        token: None,
+        recipient: PublicKey::from("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"),
+        amount: DecimalAmount::new(1_500_000_000u64, 9),
+        status: TransferStatus::Pending,
+        signature: None,
+        error: None,
+    }
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_expire_stale_pending() {
+    run_test(|mut tx| async move {
+        let test_instance = TransferRepo::new();
+        let stale = test_instance.create(&mut tx, pending()).await.unwrap();
+        tx.execute(
+            format!(
+                "update solana.transfer set created_at = now() - interval '3 minutes' where id = {}",
+                stale.id.0
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+
+        let result = test_instance.expire_pending(&mut tx, 1).await.unwrap();
+        assert_eq!(result, Count(1));
+
+        let expired = test_instance.get_by_id(&mut tx, stale.id).await.unwrap();
+        assert_eq!(expired.status, TransferStatus::Failed);
+        assert!(expired.error.is_some());
+
+        // the wallet can send again
+        let result = test_instance.create(&mut tx, pending()).await;
+        assert!(result.is_ok());
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_expire_keeps_recent_pending() {
+    run_test(|mut tx| async move {
+        let test_instance = TransferRepo::new();
+        let recent = test_instance.create(&mut tx, pending()).await.unwrap();
+
+        let result = test_instance.expire_pending(&mut tx, 1).await.unwrap();
+        assert_eq!(result, Count(0));
+
+        let recent = test_instance.get_by_id(&mut tx, recent.id).await.unwrap();
+        assert_eq!(recent.status, TransferStatus::Pending);
+    })
+    .await
+}
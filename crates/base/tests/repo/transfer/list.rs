@@ -0,0 +1,78 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::solana::Signature;
+use base::model::{DecimalAmount, PublicKey, TransferStatus, WalletId};
+use base::repo::{TransferCreateCmd, TransferQueryWallet, TransferRepo};
+use common::model::Limit;
+use common::repo::Tx;
+use testing::run_test;
+
+async fn create_transfer<'a>(tx: &mut Tx<'a>, user: i64, wallet: i64, signature: &str) {
+    TransferRepo::new()
+        .create(
+            tx,
+            TransferCreateCmd {
+                user: user.into(),
+                wallet: wallet.into(),
+                token: None,
+                recipient: PublicKey::from("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"),
+                amount: DecimalAmount::from(1i64),
+                status: TransferStatus::Submitted,
+                signature: Some(Signature::from(signature)),
+                error: None,
+            },
+        )
+        .await
+        .unwrap();
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_by_wallet() {
+    run_test(|mut tx| async move {
+        create_transfer(&mut tx, 1, 1, "Signature1").await;
+        create_transfer(&mut tx, 2, 2, "Signature2").await;
+        create_transfer(&mut tx, 1, 1, "Signature3").await;
+
+        let test_instance = TransferRepo::new();
+        let result = test_instance
+            .list_by_wallet(
+                &mut tx,
+                TransferQueryWallet {
+                    wallet: WalletId(1),
+                    limit: Limit::default(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].signature, Some(Signature::from("Signature3")));
+        assert_eq!(result[1].signature, Some(Signature::from("Signature1")));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_by_wallet_limit() {
+    run_test(|mut tx| async move {
+        create_transfer(&mut tx, 1, 1, "Signature1").await;
+        create_transfer(&mut tx, 1, 1, "Signature2").await;
+
+        let test_instance = TransferRepo::new();
+        let result = test_instance
+            .list_by_wallet(
+                &mut tx,
+                TransferQueryWallet {
+                    wallet: WalletId(1),
+                    limit: Limit(1),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].signature, Some(Signature::from("Signature2")));
+    })
+    .await
+}
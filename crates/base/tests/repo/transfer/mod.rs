@@ -0,0 +1,7 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+mod create;
+mod expire;
+mod list;
+mod update;
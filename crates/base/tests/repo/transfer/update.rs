@@ -0,0 +1,98 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::solana::Signature;
+use base::model::{DecimalAmount, PublicKey, Transfer, TransferStatus};
+use base::repo::{TransferCreateCmd, TransferRepo, TransferUpdateCmd};
+use common::repo::error::RepoError;
+use common::repo::Tx;
+use sqlx::Acquire;
+use testing::run_test;
+
+async fn create_pending<'a>(tx: &mut Tx<'a>) -> Result<Transfer, RepoError> {
+    TransferRepo::new()
+        .create(
+            tx,
+            TransferCreateCmd {
+                user: 1.into(),
+                wallet: 1.into(),
+                token: None,
+                recipient: PublicKey::from("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"),
+                amount: DecimalAmount::new(1_500_000_000u64, 9),
+                status: TransferStatus::Pending,
+                signature: None,
+                error: None,
+            },
+        )
+        .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_update_pending() {
+    run_test(|mut tx| async move {
+        let pending = create_pending(&mut tx).await.unwrap();
+        assert_eq!(pending.status, TransferStatus::Pending);
+        assert_eq!(pending.signature, None);
+
+        let test_instance = TransferRepo::new();
+        let result = test_instance
+            .update(
+                &mut tx,
+                TransferUpdateCmd {
+                    id: pending.id,
+                    status: TransferStatus::Submitted,
+                    signature: Some(Signature::from(
+                        "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn",
+                    )),
+                    error: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.id, pending.id);
+        assert_eq!(result.status, TransferStatus::Submitted);
+        assert_eq!(
+            result.signature,
+            Some(Signature::from(
+                "2XCr9sEsVm4X8uZAwrZDmN5y7WbVmPm1UvWNPN5YViSNpJ9kn"
+            ))
+        );
+
+        // the wallet can send again, once the previous transfer is no longer pending
+        let result = create_pending(&mut tx).await;
+        assert!(result.is_ok());
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_one_pending_transfer_per_wallet() {
+    run_test(|mut tx| async move {
+        create_pending(&mut tx).await.unwrap();
+
+        let result = create_pending(&mut tx.begin().await.unwrap()).await;
+        assert_eq!(result.err(), Some(RepoError::AlreadyExists));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_update_not_found() {
+    run_test(|mut tx| async move {
+        let test_instance = TransferRepo::new();
+        let result = test_instance
+            .update(
+                &mut tx,
+                TransferUpdateCmd {
+                    id: 404.into(),
+                    status: TransferStatus::Failed,
+                    signature: None,
+                    error: Some("rejected".to_string()),
+                },
+            )
+            .await;
+        assert_eq!(result.err(), Some(RepoError::NotFound));
+    })
+    .await
+}
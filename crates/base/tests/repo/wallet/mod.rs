@@ -11,6 +11,7 @@ use std::str::FromStr;
 mod create;
 mod get_by_id;
 mod get_by_user_id;
+mod get_unsafe_by_id;
 
 lazy_static! {
     static ref PUBLIC_KEY: PublicKey =
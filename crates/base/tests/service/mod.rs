@@ -5,4 +5,5 @@ mod auth;
 mod notification;
 mod rule;
 mod token;
+mod transfer;
 mod user;
@@ -3,3 +3,5 @@
 
 mod get_by_id;
 mod get_by_id_user;
+mod update;
+mod update_exhaustion;
@@ -0,0 +1,53 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{AuthenticatedUser, RuleLimit};
+use base::service::{RuleService, RuleUpdateCmd};
+use common::model::ValidationError;
+use common::service::ServiceError;
+use testing::rule::create_inactive_rule_for_test_user;
+use testing::run_test_with_pool;
+
+#[test_log::test(sqlx::test)]
+async fn test_invalid_limit() {
+    run_test_with_pool(|pool| async move {
+        let mut tx = pool.begin().await.unwrap();
+        let rule = create_inactive_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        tx.commit().await.unwrap();
+
+        let test_instance = RuleService::testing(pool.clone());
+        let result = test_instance
+            .update(
+                rule.id,
+                RuleUpdateCmd {
+                    name: None,
+                    sequence: None,
+                    status: None,
+                    limit: Some(RuleLimit {
+                        max_invocations: Some(0),
+                        cooldown_seconds: Some(-1),
+                        ..Default::default()
+                    }),
+                },
+                AuthenticatedUser { id: rule.user },
+            )
+            .await;
+
+        assert_eq!(
+            result.err().unwrap(),
+            ServiceError::Validation(vec![
+                ValidationError::new("$.maxInvocations", "must be greater than 0"),
+                ValidationError::new("$.cooldownSeconds", "must not be negative"),
+                ValidationError::new("$.cooldownSeconds", "requires the rule to rearm"),
+            ])
+        );
+
+        // nothing got updated
+        let result = test_instance
+            .get_by_id_user(rule.id, rule.user)
+            .await
+            .unwrap();
+        assert_eq!(result.limit, RuleLimit::default());
+    })
+    .await
+}
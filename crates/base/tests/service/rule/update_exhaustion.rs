@@ -0,0 +1,101 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{Mint, Rule, RuleLimit, RuleStatus};
+use base::repo::{InvocationCreateCmd, InvocationRepo, RuleRepo, RuleUpdateCmd};
+use base::service::RuleService;
+use common::repo::Tx;
+use common::service::ServiceError;
+use testing::rule::create_active_rule_for_test_user;
+use testing::run_test_with_pool;
+use testing::token_pair::get_or_create_token_pair;
+
+async fn update_limit<'a>(tx: &mut Tx<'a>, rule: Rule, max_invocations: Option<i32>) -> Rule {
+    RuleRepo::new()
+        .update(
+            tx,
+            RuleUpdateCmd {
+                id: rule.id,
+                user: rule.user,
+                name: rule.name,
+                sequence: rule.sequence,
+                status: rule.status,
+                limit: RuleLimit {
+                    max_invocations,
+                    ..Default::default()
+                },
+            },
+        )
+        .await
+        .unwrap()
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_exhaust_and_replenish() {
+    run_test_with_pool(|pool| async move {
+        let mut tx = pool.begin().await.unwrap();
+        let rule = create_active_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        let rule = update_limit(&mut tx, rule, Some(1)).await;
+        let token_pair = get_or_create_token_pair(&mut tx, Mint::usdc(), Mint::usdt()).await;
+        InvocationRepo::new()
+            .create(
+                &mut tx,
+                InvocationCreateCmd {
+                    user: rule.user,
+                    rule: rule.id,
+                    token_pair: token_pair.id,
+                    stage: 0,
+                    next: None,
+                    deadline: None,
+                },
+            )
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+
+        let test_instance = RuleService::testing(pool.clone());
+
+        let result = test_instance.update_exhaustion(rule.id).await.unwrap();
+        assert_eq!(result.status, RuleStatus::ActiveExhausted);
+
+        // nothing changes as long as the rule is exhausted
+        let result = test_instance.update_exhaustion(rule.id).await.unwrap();
+        assert_eq!(result.status, RuleStatus::ActiveExhausted);
+
+        let mut tx = pool.begin().await.unwrap();
+        update_limit(&mut tx, result, Some(2)).await;
+        tx.commit().await.unwrap();
+
+        let result = test_instance.update_exhaustion(rule.id).await.unwrap();
+        assert_eq!(result.status, RuleStatus::Active);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_unlimited() {
+    run_test_with_pool(|pool| async move {
+        let mut tx = pool.begin().await.unwrap();
+        let rule = create_active_rule_for_test_user(&mut tx, "MoneyMaker").await;
+        tx.commit().await.unwrap();
+
+        let test_instance = RuleService::testing(pool.clone());
+        let result = test_instance.update_exhaustion(rule.id).await.unwrap();
+        assert_eq!(result.status, RuleStatus::Active);
+        assert_eq!(result.version, rule.version);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_not_found() {
+    run_test_with_pool(|pool| async move {
+        let test_instance = RuleService::testing(pool.clone());
+        let result = test_instance.update_exhaustion(404).await;
+        assert_eq!(
+            result.err(),
+            Some(ServiceError::not_found("Rule not found"))
+        );
+    })
+    .await
+}
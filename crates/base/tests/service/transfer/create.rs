@@ -0,0 +1,52 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::solana::Signature;
+use base::model::{DecimalAmount, PublicKey, TransferStatus};
+use base::repo::{TransferCreateCmd, TransferUpdateCmd};
+use base::service::TransferService;
+use common::service::ServiceError;
+use testing::run_test_with_pool;
+
+fn pending() -> TransferCreateCmd {
+    TransferCreateCmd {
+        user: 1.into(),
+        wallet: 1.into(),
+        token: None,
+        recipient: PublicKey::from("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"),
+        amount: DecimalAmount::new(1_500_000_000u64, 9),
+        status: TransferStatus::Pending,
+        signature: None,
+        error: None,
+    }
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_transfer_in_progress() {
+    run_test_with_pool(|pool| async move {
+        let test_instance = TransferService::testing(pool);
+        let transfer = test_instance.create(pending()).await.unwrap();
+
+        let result = test_instance.create(pending()).await;
+        assert_eq!(
+            result.err(),
+            Some(ServiceError::conflict(
+                "Another transfer of the wallet is in progress"
+            ))
+        );
+
+        test_instance
+            .update(TransferUpdateCmd {
+                id: transfer.id,
+                status: TransferStatus::Submitted,
+                signature: Some(Signature::from("Signature1")),
+                error: None,
+            })
+            .await
+            .unwrap();
+
+        let result = test_instance.create(pending()).await;
+        assert!(result.is_ok());
+    })
+    .await
+}
@@ -0,0 +1,92 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{DecimalAmount, Mint, PublicKey};
+use base::repo::{AddressRepo, TokenBalanceRepo, TokenBalanceToInsert, TokenRepo};
+use base::service::TransferService;
+use common::model::Timestamp;
+use common::service::ServiceError;
+use testing::run_test_with_pool;
+
+const OWNER: &str = "CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM";
+
+#[test_log::test(sqlx::test)]
+async fn test_token() {
+    run_test_with_pool(|pool| async move {
+        let mut tx = pool.begin().await.unwrap();
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+        let token = TokenRepo::testing_no_token_info()
+            .get_by_mint(&mut tx, Mint::usdc())
+            .await
+            .unwrap();
+        TokenBalanceRepo::new()
+            .insert_token_balances(
+                &mut tx,
+                vec![TokenBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp: Timestamp::now(),
+                    address: address.id,
+                    token: token.id,
+                    pre: DecimalAmount::from(0i64),
+                    post: DecimalAmount::from(42i64),
+                }],
+            )
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+
+        let test_instance = TransferService::testing(pool);
+        let result = test_instance
+            .get_balance(PublicKey::from(OWNER), Some(Mint::usdc()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.token.unwrap().id, token.id);
+        assert_eq!(result.balance, DecimalAmount::from(42i64));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_nothing_indexed() {
+    run_test_with_pool(|pool| async move {
+        let test_instance = TransferService::testing(pool);
+
+        let result = test_instance
+            .get_balance(PublicKey::from(OWNER), Some(Mint::usdt()))
+            .await
+            .unwrap();
+        assert!(result.token.is_some());
+        assert_eq!(result.balance, 0);
+
+        let result = test_instance
+            .get_balance(PublicKey::from(OWNER), None)
+            .await
+            .unwrap();
+        assert!(result.token.is_none());
+        assert_eq!(result.balance, 0);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_token_not_found() {
+    run_test_with_pool(|pool| async move {
+        let test_instance = TransferService::testing(pool);
+
+        let result = test_instance
+            .get_balance(
+                PublicKey::from(OWNER),
+                Some(Mint::from("CpV7zK77DkyVvbCgGWtWrsqvh2VonFrqPg8cecrTpump")),
+            )
+            .await;
+        assert_eq!(
+            result.err().unwrap(),
+            ServiceError::not_found("Token not found")
+        );
+    })
+    .await
+}
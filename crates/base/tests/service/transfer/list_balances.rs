@@ -0,0 +1,132 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{DecimalAmount, Mint, PublicKey};
+use base::repo::{AddressRepo, SolBalanceToInsert, TokenBalanceRepo, TokenBalanceToInsert};
+use base::service::TransferService;
+use bigdecimal::BigDecimal;
+use common::model::Timestamp;
+use sqlx::Executor;
+use testing::run_test_with_pool;
+use testing::token_pair::get_or_create_token_pair;
+
+const OWNER: &str = "CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM";
+
+#[test_log::test(sqlx::test)]
+async fn test_list_balances() {
+    run_test_with_pool(|pool| async move {
+        let mut tx = pool.begin().await.unwrap();
+        let address = AddressRepo::new()
+            .get_or_populate_by_key(&mut tx, PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        let priced = get_or_create_token_pair(
+            &mut tx,
+            "2Yt4cyQq3ZmvGuRxb6ZgCiUQMtKgCbqbAbJXanMSpump",
+            Mint::wsol(),
+        )
+        .await;
+        let unpriced = get_or_create_token_pair(
+            &mut tx,
+            "CpV7zK77DkyVvbCgGWtWrsqvh2VonFrqPg8cecrTpump",
+            Mint::wsol(),
+        )
+        .await;
+
+        tx.execute(
+            format!(
+                r#"
+                insert into pumpfun.current (id, slot, virtual_base_reserves, virtual_quote_reserves, progress, complete, price)
+                values ({}, 1, 1, 1, 10, false, 0.5);
+                insert into solana.sol_price_1m (timestamp, usd) values
+                    ('2025-03-20 10:00:00+00', 100),
+                    ('2025-03-20 10:01:00+00', 150);
+                "#,
+                priced.id.0
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+
+        let repo = TokenBalanceRepo::new();
+        repo.insert_sol_balances(
+            &mut tx,
+            vec![SolBalanceToInsert {
+                slot: 100.into(),
+                timestamp: Timestamp::now(),
+                address: address.id,
+                pre: DecimalAmount::from(0i64),
+                post: DecimalAmount::from(2i64),
+            }],
+        )
+        .await
+        .unwrap();
+        repo.insert_token_balances(
+            &mut tx,
+            vec![
+                TokenBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp: Timestamp::now(),
+                    address: address.id,
+                    token: priced.base.id,
+                    pre: DecimalAmount::from(0i64),
+                    post: DecimalAmount::from(4i64),
+                },
+                TokenBalanceToInsert {
+                    slot: 100.into(),
+                    timestamp: Timestamp::now(),
+                    address: address.id,
+                    token: unpriced.base.id,
+                    pre: DecimalAmount::from(0i64),
+                    post: DecimalAmount::from(9i64),
+                },
+            ],
+        )
+        .await
+        .unwrap();
+        tx.commit().await.unwrap();
+
+        let test_instance = TransferService::testing(pool);
+        let result = test_instance
+            .list_balances(PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        assert_eq!(result.sol, DecimalAmount::from(2i64));
+        assert_eq!(result.sol_usd.clone().unwrap(), 150);
+        assert_eq!(result.tokens.len(), 2);
+
+        let token = &result.tokens[0];
+        assert_eq!(token.token.id, priced.base.id);
+        assert_eq!(token.balance, DecimalAmount::from(4i64));
+        assert_eq!(token.value(), Some(BigDecimal::from(2)));
+
+        let token = &result.tokens[1];
+        assert_eq!(token.token.id, unpriced.base.id);
+        assert_eq!(token.value(), None);
+
+        assert_eq!(result.total(), BigDecimal::from(4));
+        assert_eq!(result.to_usd(&result.total()), Some(BigDecimal::from(600)));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_balances_nothing_indexed() {
+    run_test_with_pool(|pool| async move {
+        let test_instance = TransferService::testing(pool);
+        let result = test_instance
+            .list_balances(PublicKey::from(OWNER))
+            .await
+            .unwrap();
+
+        assert_eq!(result.sol, 0);
+        assert!(result.sol_usd.is_none());
+        assert!(result.tokens.is_empty());
+        assert_eq!(result.total(), BigDecimal::from(0));
+        assert_eq!(result.to_usd(&result.total()), None);
+    })
+    .await
+}
@@ -1,7 +1,9 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+use base::model::KeyPair;
 use base::service::UserService;
+use common::crypt::SecretKey;
 use common::service::ServiceError;
 use testing::run_test_with_pool;
 
@@ -27,3 +29,46 @@ async fn test_wallet_not_found() {
     })
     .await
 }
+
+#[test_log::test(sqlx::test)]
+async fn test_unsafe_ok() {
+    run_test_with_pool(|pool| async move {
+        let test_instance = UserService::new(
+            pool,
+            SecretKey::from("3d7948d31771b3924dbeec3de83d905580d988c84964a6afd4c9cedd06776e91"),
+        );
+
+        let wallet = test_instance.get_wallet(1).await.unwrap();
+
+        let result = test_instance.get_wallet_unsafe(1, 1).await.unwrap();
+        assert_eq!(result.id, 1);
+        assert_eq!(result.user_id, 1);
+        assert_eq!(result.public_key, wallet.public_key);
+
+        let key_pair = KeyPair::from_base58(&result.private_key.0);
+        assert_eq!(key_pair.public, wallet.public_key);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_unsafe_wallet_of_another_user() {
+    run_test_with_pool(|pool| async move {
+        let test_instance = UserService::testing(pool);
+
+        let err = test_instance.get_wallet_unsafe(2, 1).await.err().unwrap();
+        assert_eq!(err, ServiceError::not_found("Wallet not found"));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_unsafe_wallet_not_found() {
+    run_test_with_pool(|pool| async move {
+        let test_instance = UserService::testing(pool);
+
+        let err = test_instance.get_wallet_unsafe(404, 1).await.err().unwrap();
+        assert_eq!(err, ServiceError::not_found("Wallet not found"));
+    })
+    .await
+}
@@ -14,6 +14,7 @@ pub use timeframe::*;
 pub use timestamp::*;
 pub use timeunit::TimeUnit;
 pub use url::*;
+pub use validation::*;
 pub use volume::*;
 
 mod age;
@@ -29,4 +30,5 @@ mod timeframe;
 mod timestamp;
 mod timeunit;
 mod url;
+mod validation;
 mod volume;
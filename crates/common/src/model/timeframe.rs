@@ -44,6 +44,18 @@ impl Timeframe {
             Timeframe::D1 => "1d",
         }
     }
+
+    pub fn seconds(&self) -> i64 {
+        match self {
+            Timeframe::S1 => 1,
+            Timeframe::M1 => 60,
+            Timeframe::M5 => 300,
+            Timeframe::M15 => 900,
+            Timeframe::H1 => 3_600,
+            Timeframe::H6 => 21_600,
+            Timeframe::D1 => 86_400,
+        }
+    }
 }
 
 impl Distribution<Timeframe> for StandardUniform {
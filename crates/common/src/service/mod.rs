@@ -1,6 +1,7 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+use crate::model::ValidationError;
 use crate::repo::error::RepoError;
 use log::error;
 use std::fmt::{Display, Formatter};
@@ -9,7 +10,9 @@ use std::fmt::{Display, Formatter};
 pub enum ServiceError {
 	Conflict(String),
 	Internal(String),
+	Invalid(String),
 	NotFound(String),
+	Validation(Vec<ValidationError>),
 }
 
 impl ServiceError {
@@ -21,9 +24,17 @@ impl ServiceError {
 		Self::Internal(s.into())
 	}
 
+	pub fn invalid(s: impl Into<String>) -> Self {
+		Self::Invalid(s.into())
+	}
+
 	pub fn not_found(s: impl Into<String>) -> Self {
 		Self::NotFound(s.into())
 	}
+
+	pub fn validation(errors: impl Into<Vec<ValidationError>>) -> Self {
+		Self::Validation(errors.into())
+	}
 }
 
 impl From<sqlx::Error> for ServiceError {
@@ -50,7 +61,12 @@ impl Display for ServiceError {
 		match self {
 			ServiceError::Conflict(err) => f.write_fmt(format_args!("conflict error: {err}")),
 			ServiceError::Internal(err) => f.write_fmt(format_args!("internal error: {err}")),
+			ServiceError::Invalid(err) => f.write_fmt(format_args!("invalid error: {err}")),
 			ServiceError::NotFound(err) => f.write_fmt(format_args!("not found error: {err}")),
+			ServiceError::Validation(errors) => f.write_fmt(format_args!(
+				"validation error: {}",
+				errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+			)),
 		}
 	}
 }
@@ -0,0 +1,197 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{TokenPairId, Venue};
+use bigdecimal::{BigDecimal, FromPrimitive};
+use common::model::Timestamp;
+use common::repo::{RepoResult, Tx};
+use sqlx::postgres::PgRow;
+use sqlx::{Postgres, QueryBuilder, Row};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Ohlc {
+    pub(crate) open: BigDecimal,
+    pub(crate) high: BigDecimal,
+    pub(crate) low: BigDecimal,
+    pub(crate) close: BigDecimal,
+    pub(crate) avg: BigDecimal,
+}
+
+/// 1 minute candle of a token pair, joined with its usd, market cap and curve progress candles
+#[derive(Debug, Clone)]
+pub(crate) struct Candle {
+    pub(crate) token_pair: TokenPairId,
+    /// Start of the candle in seconds since epoch
+    pub(crate) timestamp: i64,
+    pub(crate) price: Ohlc,
+    pub(crate) price_usd: Option<Ohlc>,
+    pub(crate) market_cap: Option<Ohlc>,
+    pub(crate) market_cap_usd: Option<Ohlc>,
+    pub(crate) progress: Option<Ohlc>,
+    pub(crate) swap_buy: i64,
+    pub(crate) swap_sell: i64,
+    pub(crate) volume_buy: BigDecimal,
+    pub(crate) volume_sell: BigDecimal,
+    pub(crate) volume_buy_usd: Option<BigDecimal>,
+    pub(crate) volume_sell_usd: Option<BigDecimal>,
+}
+
+/// Lists the 1 minute candles of the token pairs within the given time range, ordered by token pair and timestamp
+pub(crate) async fn list_candles<'a>(
+    tx: &mut Tx<'a>,
+    venue: Venue,
+    token_pairs: &[TokenPairId],
+    from: Timestamp,
+    to: Timestamp,
+) -> RepoResult<Vec<Candle>> {
+    let schema = match venue {
+        Venue::PumpFun => "pumpfun",
+        Venue::Jupiter => "jupiter",
+        Venue::Raydium => "raydium",
+    };
+
+    let (progress_columns, progress_join) = match venue {
+        Venue::PumpFun => (
+            "p.open as progress_open, p.high as progress_high, p.low as progress_low, p.close as progress_close, p.avg as progress_avg",
+            "left join pumpfun.candle_progress_1m p on p.token_pair_id = c.token_pair_id and p.timestamp = c.timestamp",
+        ),
+        _ => (
+            "null::real as progress_open, null::real as progress_high, null::real as progress_low, null::real as progress_close, null::real as progress_avg",
+            "",
+        ),
+    };
+
+    let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(format!(
+        r#"
+select
+    c.token_pair_id,
+    c.timestamp,
+
+    c.open,
+    c.high,
+    c.low,
+    c.close,
+    c.avg,
+
+    u.open as open_usd,
+    u.high as high_usd,
+    u.low as low_usd,
+    u.close as close_usd,
+    u.avg as avg_usd,
+
+    m.open as market_cap_open,
+    m.high as market_cap_high,
+    m.low as market_cap_low,
+    m.close as market_cap_close,
+    m.avg as market_cap_avg,
+
+    m.open_usd as market_cap_open_usd,
+    m.high_usd as market_cap_high_usd,
+    m.low_usd as market_cap_low_usd,
+    m.close_usd as market_cap_close_usd,
+    m.avg_usd as market_cap_avg_usd,
+
+    {progress_columns},
+
+    c.swap_buy,
+    c.swap_sell,
+    c.volume_buy,
+    c.volume_sell,
+    u.volume_buy as volume_buy_usd,
+    u.volume_sell as volume_sell_usd
+from {schema}.candle_1m c
+left join {schema}.candle_usd_1m u on u.token_pair_id = c.token_pair_id and u.timestamp = c.timestamp
+left join {schema}.candle_market_cap_1m m on m.token_pair_id = c.token_pair_id and m.timestamp = c.timestamp
+{progress_join}
+where c.timestamp >= "#
+    ));
+
+    query_builder.push_bind(from);
+    query_builder.push(" and c.timestamp <= ");
+    query_builder.push_bind(to);
+
+    query_builder.push(" and c.token_pair_id = any(");
+    query_builder.push_bind(token_pairs.iter().map(|id| id.0).collect::<Vec<_>>());
+    query_builder.push(")");
+
+    query_builder.push("\norder by c.token_pair_id, c.timestamp");
+
+    Ok(query_builder
+        .build()
+        .fetch_all(&mut **tx)
+        .await?
+        .iter()
+        .map(to_candle)
+        .collect::<Vec<_>>())
+}
+
+fn to_candle(row: &PgRow) -> Candle {
+    Candle {
+        token_pair: row.get::<TokenPairId, _>("token_pair_id"),
+        timestamp: row.get::<Timestamp, _>("timestamp").to_epoch_seconds(),
+        price: Ohlc {
+            open: row.get("open"),
+            high: row.get("high"),
+            low: row.get("low"),
+            close: row.get("close"),
+            avg: row.get("avg"),
+        },
+        price_usd: to_ohlc(
+            row,
+            "open_usd",
+            "high_usd",
+            "low_usd",
+            "close_usd",
+            "avg_usd",
+        ),
+        market_cap: to_ohlc(
+            row,
+            "market_cap_open",
+            "market_cap_high",
+            "market_cap_low",
+            "market_cap_close",
+            "market_cap_avg",
+        ),
+        market_cap_usd: to_ohlc(
+            row,
+            "market_cap_open_usd",
+            "market_cap_high_usd",
+            "market_cap_low_usd",
+            "market_cap_close_usd",
+            "market_cap_avg_usd",
+        ),
+        progress: to_progress(row),
+        swap_buy: row.get("swap_buy"),
+        swap_sell: row.get("swap_sell"),
+        volume_buy: row.get("volume_buy"),
+        volume_sell: row.get("volume_sell"),
+        volume_buy_usd: row.try_get::<BigDecimal, _>("volume_buy_usd").ok(),
+        volume_sell_usd: row.try_get::<BigDecimal, _>("volume_sell_usd").ok(),
+    }
+}
+
+fn to_ohlc(row: &PgRow, open: &str, high: &str, low: &str, close: &str, avg: &str) -> Option<Ohlc> {
+    Some(Ohlc {
+        open: row.try_get::<BigDecimal, _>(open).ok()?,
+        high: row.try_get::<BigDecimal, _>(high).ok()?,
+        low: row.try_get::<BigDecimal, _>(low).ok()?,
+        close: row.try_get::<BigDecimal, _>(close).ok()?,
+        avg: row.try_get::<BigDecimal, _>(avg).ok()?,
+    })
+}
+
+fn to_progress(row: &PgRow) -> Option<Ohlc> {
+    let get = |column: &str| {
+        row.try_get::<f32, _>(column)
+            .ok()
+            .and_then(BigDecimal::from_f32)
+    };
+
+    Some(Ohlc {
+        open: get("progress_open")?,
+        high: get("progress_high")?,
+        low: get("progress_low")?,
+        close: get("progress_close")?,
+        avg: get("progress_avg")?,
+    })
+}
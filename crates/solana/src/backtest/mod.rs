@@ -0,0 +1,34 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+pub use model::*;
+
+mod candle;
+mod model;
+mod replay;
+mod run;
+
+use sqlx::PgPool;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Replays stored candles through the condition of a sequence to find out how often it would have matched
+#[derive(Clone)]
+pub struct BacktestService(pub Arc<BacktestServiceInner>);
+
+impl Deref for BacktestService {
+    type Target = BacktestServiceInner;
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+pub struct BacktestServiceInner {
+    pool: PgPool,
+}
+
+impl BacktestService {
+    pub fn new(pool: PgPool) -> Self {
+        Self(Arc::new(BacktestServiceInner { pool }))
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{Sequence, TokenPairId, Venue};
+use common::model::{Percent, PriceQuote, Timeframe, Timestamp};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone)]
+pub struct BacktestQuery {
+    pub venue: Venue,
+    pub sequence: Sequence,
+    pub from: Timestamp,
+    pub to: Timestamp,
+    pub token_pairs: Vec<TokenPairId>,
+}
+
+/// Token pair and point in time at which the condition of a sequence would have matched
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BacktestMatch {
+    pub token_pair: TokenPairId,
+    pub timestamp: Timestamp,
+    pub price: PriceQuote,
+    pub price_path: Vec<BacktestPrice>,
+}
+
+/// Price of the token pair a timeframe after the match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BacktestPrice {
+    pub after: Timeframe,
+    pub price: PriceQuote,
+    /// Change compared to the price at the time of the match, None if that price was zero
+    pub percent: Option<Percent>,
+}
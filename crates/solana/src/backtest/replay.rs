@@ -0,0 +1,764 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::backtest::candle::{Candle, Ohlc};
+use crate::backtest::{BacktestMatch, BacktestPrice};
+use crate::pumpfun::fact::add_summary_to_facts;
+use crate::pumpfun::model::summary::{
+    MarketCapWithChange, PriceWithChange, ProgressWithChange, SummaryCurveProgress,
+    SummaryMarketCap, SummaryPrice, SummarySwap, SummaryVolume, SwapWithChange, TimeframeSummary,
+    VolumeWithChange,
+};
+use base::model::{Condition, Fact, Facts, Value, Venue};
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
+use common::model::{
+    Count, MarketCapQuote, MarketCapUsd, Percent, PriceQuote, PriceUsd, Timeframe, Timestamp,
+    VolumeQuote, VolumeUsd,
+};
+use std::collections::VecDeque;
+
+/// Timeframes the facts get summarised for, which are the ones the engine provides as well
+const SUMMARY_TIMEFRAMES: [Timeframe; 6] = [
+    Timeframe::M1,
+    Timeframe::M5,
+    Timeframe::M15,
+    Timeframe::H1,
+    Timeframe::H6,
+    Timeframe::D1,
+];
+
+/// Replays the candles of a single token pair, ordered by timestamp, and tests the condition at each
+/// candle within the time range from..=to. Prices after a match are only reported up to now.
+pub(crate) fn replay(
+    condition: &Condition,
+    venue: Venue,
+    candles: &[Candle],
+    from: i64,
+    to: i64,
+    now: i64,
+) -> Vec<BacktestMatch> {
+    let mut result = vec![];
+    let mut windows = SUMMARY_TIMEFRAMES.map(TimeframeWindows::new);
+
+    for (idx, candle) in candles.iter().enumerate() {
+        if candle.timestamp > to {
+            break;
+        }
+
+        // the windows have to move along every candle, including the ones before the time range
+        for windows in windows.iter_mut() {
+            windows.advance(candles, idx);
+        }
+
+        if candle.timestamp < from {
+            continue;
+        }
+
+        let facts = facts_at(venue, candles, idx, &windows);
+        if !condition.test(&facts) {
+            continue;
+        }
+
+        result.push(BacktestMatch {
+            token_pair: candle.token_pair,
+            timestamp: Timestamp::from_epoch_second(candle.timestamp).unwrap(),
+            price: PriceQuote(candle.price.close.clone()),
+            price_path: price_path(candles, idx, now),
+        })
+    }
+
+    result
+}
+
+fn facts_at(venue: Venue, candles: &[Candle], idx: usize, windows: &[TimeframeWindows]) -> Facts {
+    let mut facts = Facts::new();
+    let candle = &candles[idx];
+
+    facts.set_value(Fact::PriceQuote, Value::quote(candle.price.close.clone()));
+    if let Some(usd) = &candle.price_usd {
+        facts.set_value(Fact::PriceUsd, Value::usd(usd.close.clone()));
+    }
+
+    if let Some(quote) = &candle.market_cap {
+        facts.set_value(Fact::MarketCapQuote, Value::quote(quote.close.clone()));
+    }
+
+    if let Some(usd) = &candle.market_cap_usd {
+        facts.set_value(Fact::MarketCapUsd, Value::usd(usd.close.clone()));
+    }
+
+    if let Some(progress) = candle.progress.as_ref().and_then(|p| p.close.to_f32()) {
+        facts.set_value(Fact::CurveProgressPercent, Value::percent(progress));
+    }
+
+    for windows in windows {
+        add_summary_to_facts(&mut facts, windows.summarise(candles), windows.timeframe);
+    }
+
+    match venue {
+        Venue::PumpFun => facts.set_value(Fact::VenuePumpfun, Value::boolean(true)),
+        Venue::Jupiter => facts.set_value(Fact::VenueJupiter, Value::boolean(true)),
+        Venue::Raydium => {}
+    }
+
+    facts
+}
+
+fn price_path(candles: &[Candle], idx: usize, now: i64) -> Vec<BacktestPrice> {
+    let candle = &candles[idx];
+
+    SUMMARY_TIMEFRAMES
+        .into_iter()
+        .filter_map(|after| {
+            let target = candle.timestamp + after.seconds();
+            if target > now {
+                return None;
+            }
+
+            // the most recent candle at the target time, which might be the matching candle itself
+            let position = candles.partition_point(|c| c.timestamp <= target);
+            let price = candles[position - 1].price.close.clone();
+
+            Some(BacktestPrice {
+                after,
+                percent: percent(&Some(price.clone()), &Some(candle.price.close.clone())),
+                price: PriceQuote(price),
+            })
+        })
+        .collect()
+}
+
+/// Current and previous window of a timeframe, which slide along the candles one candle at a time
+struct TimeframeWindows {
+    timeframe: Timeframe,
+    current: RollingWindow,
+    previous: RollingWindow,
+}
+
+impl TimeframeWindows {
+    fn new(timeframe: Timeframe) -> Self {
+        Self {
+            timeframe,
+            current: RollingWindow::new(),
+            previous: RollingWindow::new(),
+        }
+    }
+
+    /// Moves the windows, so that the current one ends with the candle at idx
+    fn advance(&mut self, candles: &[Candle], idx: usize) {
+        let end = candles[idx].timestamp;
+        let length = self.timeframe.seconds();
+
+        self.current.advance(candles, end - length, end);
+        self.previous
+            .advance(candles, end - 2 * length, end - length);
+    }
+
+    /// Summarises the current window and compares it to the previous one
+    fn summarise(&self, candles: &[Candle]) -> TimeframeSummary {
+        let current = self.current.window(candles);
+        let previous = self.previous.window(candles);
+
+        TimeframeSummary {
+            curve: SummaryCurveProgress {
+                open: progress(&current.progress, &previous.progress, |o| &o.open),
+                high: progress(&current.progress, &previous.progress, |o| &o.high),
+                low: progress(&current.progress, &previous.progress, |o| &o.low),
+                close: progress(&current.progress, &previous.progress, |o| &o.close),
+                avg: progress(&current.progress, &previous.progress, |o| &o.avg),
+            },
+            cap: SummaryMarketCap {
+                open: market_cap(&current, &previous, |o| &o.open),
+                high: market_cap(&current, &previous, |o| &o.high),
+                low: market_cap(&current, &previous, |o| &o.low),
+                close: market_cap(&current, &previous, |o| &o.close),
+                avg: market_cap(&current, &previous, |o| &o.avg),
+            },
+            price: SummaryPrice {
+                open: price(&current, &previous, |o| &o.open),
+                high: price(&current, &previous, |o| &o.high),
+                low: price(&current, &previous, |o| &o.low),
+                close: price(&current, &previous, |o| &o.close),
+                avg: price(&current, &previous, |o| &o.avg),
+            },
+            swap: SummarySwap {
+                all: swap(
+                    current.swap_buy + current.swap_sell,
+                    previous.swap_buy + previous.swap_sell,
+                    previous.is_empty(),
+                ),
+                buy: swap(current.swap_buy, previous.swap_buy, previous.is_empty()),
+                sell: swap(current.swap_sell, previous.swap_sell, previous.is_empty()),
+            },
+            volume: SummaryVolume {
+                all: volume(
+                    &current
+                        .volume_buy
+                        .as_ref()
+                        .zip(current.volume_sell.as_ref())
+                        .map(|(b, s)| b + s),
+                    &current
+                        .volume_buy_usd
+                        .as_ref()
+                        .zip(current.volume_sell_usd.as_ref())
+                        .map(|(b, s)| b + s),
+                    &previous
+                        .volume_buy
+                        .as_ref()
+                        .zip(previous.volume_sell.as_ref())
+                        .map(|(b, s)| b + s),
+                    &previous
+                        .volume_buy_usd
+                        .as_ref()
+                        .zip(previous.volume_sell_usd.as_ref())
+                        .map(|(b, s)| b + s),
+                ),
+                buy: volume(
+                    &current.volume_buy,
+                    &current.volume_buy_usd,
+                    &previous.volume_buy,
+                    &previous.volume_buy_usd,
+                ),
+                sell: volume(
+                    &current.volume_sell,
+                    &current.volume_sell_usd,
+                    &previous.volume_sell,
+                    &previous.volume_sell_usd,
+                ),
+            },
+        }
+    }
+}
+
+/// Aggregation of consecutive candles, None if there are no candles or one of them lacks the value
+struct Window {
+    price: Option<Ohlc>,
+    price_usd: Option<Ohlc>,
+    market_cap: Option<Ohlc>,
+    market_cap_usd: Option<Ohlc>,
+    progress: Option<Ohlc>,
+    swap_buy: i64,
+    swap_sell: i64,
+    volume_buy: Option<BigDecimal>,
+    volume_sell: Option<BigDecimal>,
+    volume_buy_usd: Option<BigDecimal>,
+    volume_sell_usd: Option<BigDecimal>,
+}
+
+impl Window {
+    fn is_empty(&self) -> bool {
+        self.price.is_none()
+    }
+}
+
+/// Candles start..stop with a timestamp within a time range, whose aggregation is kept up to date
+/// while the time range only ever moves forward, so each candle gets added and removed once
+struct RollingWindow {
+    start: usize,
+    stop: usize,
+    price: RollingOhlc,
+    price_usd: RollingOhlc,
+    market_cap: RollingOhlc,
+    market_cap_usd: RollingOhlc,
+    progress: RollingOhlc,
+    swap_buy: i64,
+    swap_sell: i64,
+    volume_buy: RollingSum,
+    volume_sell: RollingSum,
+    volume_buy_usd: RollingSum,
+    volume_sell_usd: RollingSum,
+}
+
+impl RollingWindow {
+    fn new() -> Self {
+        Self {
+            start: 0,
+            stop: 0,
+            price: RollingOhlc::new(|c| Some(&c.price)),
+            price_usd: RollingOhlc::new(|c| c.price_usd.as_ref()),
+            market_cap: RollingOhlc::new(|c| c.market_cap.as_ref()),
+            market_cap_usd: RollingOhlc::new(|c| c.market_cap_usd.as_ref()),
+            progress: RollingOhlc::new(|c| c.progress.as_ref()),
+            swap_buy: 0,
+            swap_sell: 0,
+            volume_buy: RollingSum::new(|c| Some(&c.volume_buy)),
+            volume_sell: RollingSum::new(|c| Some(&c.volume_sell)),
+            volume_buy_usd: RollingSum::new(|c| c.volume_buy_usd.as_ref()),
+            volume_sell_usd: RollingSum::new(|c| c.volume_sell_usd.as_ref()),
+        }
+    }
+
+    /// Moves the window to the candles with a timestamp within from (exclusive) and to (inclusive)
+    fn advance(&mut self, candles: &[Candle], from: i64, to: i64) {
+        while self.stop < candles.len() && candles[self.stop].timestamp <= to {
+            let candle = &candles[self.stop];
+            self.price.add(candles, self.stop);
+            self.price_usd.add(candles, self.stop);
+            self.market_cap.add(candles, self.stop);
+            self.market_cap_usd.add(candles, self.stop);
+            self.progress.add(candles, self.stop);
+            self.swap_buy += candle.swap_buy;
+            self.swap_sell += candle.swap_sell;
+            self.volume_buy.add(candle);
+            self.volume_sell.add(candle);
+            self.volume_buy_usd.add(candle);
+            self.volume_sell_usd.add(candle);
+            self.stop += 1;
+        }
+
+        while self.start < self.stop && candles[self.start].timestamp <= from {
+            let candle = &candles[self.start];
+            self.price.remove(candles, self.start);
+            self.price_usd.remove(candles, self.start);
+            self.market_cap.remove(candles, self.start);
+            self.market_cap_usd.remove(candles, self.start);
+            self.progress.remove(candles, self.start);
+            self.swap_buy -= candle.swap_buy;
+            self.swap_sell -= candle.swap_sell;
+            self.volume_buy.remove(candle);
+            self.volume_sell.remove(candle);
+            self.volume_buy_usd.remove(candle);
+            self.volume_sell_usd.remove(candle);
+            self.start += 1;
+        }
+    }
+
+    fn window(&self, candles: &[Candle]) -> Window {
+        let count = self.stop - self.start;
+        Window {
+            price: self.price.get(candles, self.start, self.stop),
+            price_usd: self.price_usd.get(candles, self.start, self.stop),
+            market_cap: self.market_cap.get(candles, self.start, self.stop),
+            market_cap_usd: self.market_cap_usd.get(candles, self.start, self.stop),
+            progress: self.progress.get(candles, self.start, self.stop),
+            swap_buy: self.swap_buy,
+            swap_sell: self.swap_sell,
+            volume_buy: self.volume_buy.get(count),
+            volume_sell: self.volume_sell.get(count),
+            volume_buy_usd: self.volume_buy_usd.get(count),
+            volume_sell_usd: self.volume_sell_usd.get(count),
+        }
+    }
+}
+
+/// Ohlc of the candles within a rolling window, high and low are kept as monotonic queues of candle indices
+struct RollingOhlc {
+    value: fn(&Candle) -> Option<&Ohlc>,
+    /// Number of candles within the window which lack the value
+    missing: usize,
+    avg: BigDecimal,
+    high: VecDeque<usize>,
+    low: VecDeque<usize>,
+}
+
+impl RollingOhlc {
+    fn new(value: fn(&Candle) -> Option<&Ohlc>) -> Self {
+        Self {
+            value,
+            missing: 0,
+            avg: BigDecimal::zero(),
+            high: VecDeque::new(),
+            low: VecDeque::new(),
+        }
+    }
+
+    fn ohlc<'a>(&self, candles: &'a [Candle], idx: usize) -> &'a Ohlc {
+        (self.value)(&candles[idx]).unwrap()
+    }
+
+    fn add(&mut self, candles: &[Candle], idx: usize) {
+        let Some(ohlc) = (self.value)(&candles[idx]) else {
+            self.missing += 1;
+            return;
+        };
+
+        self.avg += &ohlc.avg;
+
+        while let Some(&last) = self.high.back() {
+            if self.ohlc(candles, last).high > ohlc.high {
+                break;
+            }
+            self.high.pop_back();
+        }
+        self.high.push_back(idx);
+
+        while let Some(&last) = self.low.back() {
+            if self.ohlc(candles, last).low < ohlc.low {
+                break;
+            }
+            self.low.pop_back();
+        }
+        self.low.push_back(idx);
+    }
+
+    fn remove(&mut self, candles: &[Candle], idx: usize) {
+        let Some(ohlc) = (self.value)(&candles[idx]) else {
+            self.missing -= 1;
+            return;
+        };
+
+        self.avg -= &ohlc.avg;
+
+        if self.high.front() == Some(&idx) {
+            self.high.pop_front();
+        }
+        if self.low.front() == Some(&idx) {
+            self.low.pop_front();
+        }
+    }
+
+    fn get(&self, candles: &[Candle], start: usize, stop: usize) -> Option<Ohlc> {
+        if start == stop || self.missing > 0 {
+            return None;
+        }
+
+        Some(Ohlc {
+            open: self.ohlc(candles, start).open.clone(),
+            high: self.ohlc(candles, *self.high.front()?).high.clone(),
+            low: self.ohlc(candles, *self.low.front()?).low.clone(),
+            close: self.ohlc(candles, stop - 1).close.clone(),
+            avg: &self.avg / BigDecimal::from((stop - start) as i64),
+        })
+    }
+}
+
+/// Sum of a value of the candles within a rolling window
+struct RollingSum {
+    value: fn(&Candle) -> Option<&BigDecimal>,
+    /// Number of candles within the window which lack the value
+    missing: usize,
+    sum: BigDecimal,
+}
+
+impl RollingSum {
+    fn new(value: fn(&Candle) -> Option<&BigDecimal>) -> Self {
+        Self {
+            value,
+            missing: 0,
+            sum: BigDecimal::zero(),
+        }
+    }
+
+    fn add(&mut self, candle: &Candle) {
+        match (self.value)(candle) {
+            Some(value) => self.sum += value,
+            None => self.missing += 1,
+        }
+    }
+
+    fn remove(&mut self, candle: &Candle) {
+        match (self.value)(candle) {
+            Some(value) => self.sum -= value,
+            None => self.missing -= 1,
+        }
+    }
+
+    fn get(&self, count: usize) -> Option<BigDecimal> {
+        if count == 0 || self.missing > 0 {
+            return None;
+        }
+        Some(self.sum.clone())
+    }
+}
+
+fn change(current: &Option<BigDecimal>, previous: &Option<BigDecimal>) -> Option<BigDecimal> {
+    Some(current.as_ref()? - previous.as_ref()?)
+}
+
+fn percent(current: &Option<BigDecimal>, previous: &Option<BigDecimal>) -> Option<Percent> {
+    let previous = previous.as_ref()?;
+    if previous.is_zero() {
+        return None;
+    }
+    ((current.as_ref()? - previous) * BigDecimal::from(100) / previous)
+        .to_f32()
+        .map(Percent)
+}
+
+fn pick(ohlc: &Option<Ohlc>, field: fn(&Ohlc) -> &BigDecimal) -> Option<BigDecimal> {
+    ohlc.as_ref().map(|o| field(o).clone())
+}
+
+fn progress(
+    current: &Option<Ohlc>,
+    previous: &Option<Ohlc>,
+    field: fn(&Ohlc) -> &BigDecimal,
+) -> ProgressWithChange {
+    let current = pick(current, field);
+    let previous = pick(previous, field);
+
+    ProgressWithChange {
+        progress: current.as_ref().and_then(|p| p.to_f32()).map(Percent),
+        change: change(&current, &previous)
+            .and_then(|c| c.to_f32())
+            .map(Percent),
+    }
+}
+
+fn market_cap(
+    current: &Window,
+    previous: &Window,
+    field: fn(&Ohlc) -> &BigDecimal,
+) -> MarketCapWithChange {
+    let quote = pick(&current.market_cap, field);
+    let usd = pick(&current.market_cap_usd, field);
+    let previous_quote = pick(&previous.market_cap, field);
+    let previous_usd = pick(&previous.market_cap_usd, field);
+
+    MarketCapWithChange {
+        quote_change: change(&quote, &previous_quote).map(MarketCapQuote),
+        usd_change: change(&usd, &previous_usd).map(MarketCapUsd),
+        percent: percent(&quote, &previous_quote),
+        quote: quote.map(MarketCapQuote),
+        usd: usd.map(MarketCapUsd),
+    }
+}
+
+fn price(current: &Window, previous: &Window, field: fn(&Ohlc) -> &BigDecimal) -> PriceWithChange {
+    let quote = pick(&current.price, field);
+    let usd = pick(&current.price_usd, field);
+    let previous_quote = pick(&previous.price, field);
+    let previous_usd = pick(&previous.price_usd, field);
+
+    PriceWithChange {
+        quote_change: change(&quote, &previous_quote).map(PriceQuote),
+        usd_change: change(&usd, &previous_usd).map(PriceUsd),
+        percent: percent(&quote, &previous_quote),
+        quote: quote.map(PriceQuote),
+        usd: usd.map(PriceUsd),
+    }
+}
+
+fn swap(current: i64, previous: i64, no_previous: bool) -> SwapWithChange {
+    if no_previous {
+        return SwapWithChange {
+            count: Some(Count(current)),
+            change: None,
+            percent: None,
+        };
+    }
+
+    SwapWithChange {
+        count: Some(Count(current)),
+        change: Some(Count(current - previous)),
+        percent: percent(
+            &Some(BigDecimal::from(current)),
+            &Some(BigDecimal::from(previous)),
+        ),
+    }
+}
+
+fn volume(
+    quote: &Option<BigDecimal>,
+    usd: &Option<BigDecimal>,
+    previous_quote: &Option<BigDecimal>,
+    previous_usd: &Option<BigDecimal>,
+) -> VolumeWithChange {
+    VolumeWithChange {
+        quote: quote.clone().map(VolumeQuote),
+        usd: usd.clone().map(VolumeUsd),
+        quote_change: change(quote, previous_quote).map(VolumeQuote),
+        usd_change: change(usd, previous_usd).map(VolumeUsd),
+        percent: percent(quote, previous_quote),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base::model::Field::{Price, SwapBuy};
+    use base::model::Operator::{IncreasedByMoreThan, MoreThan};
+    use base::model::TokenPairId;
+
+    fn ohlc(value: i64) -> Ohlc {
+        Ohlc {
+            open: BigDecimal::from(value),
+            high: BigDecimal::from(value),
+            low: BigDecimal::from(value),
+            close: BigDecimal::from(value),
+            avg: BigDecimal::from(value),
+        }
+    }
+
+    fn candle(minute: i64, price: i64, swap_buy: i64) -> Candle {
+        Candle {
+            token_pair: TokenPairId(1),
+            timestamp: minute * 60,
+            price: ohlc(price),
+            price_usd: None,
+            market_cap: None,
+            market_cap_usd: None,
+            progress: None,
+            swap_buy,
+            swap_sell: 0,
+            volume_buy: BigDecimal::from(swap_buy),
+            volume_sell: BigDecimal::zero(),
+            volume_buy_usd: None,
+            volume_sell_usd: None,
+        }
+    }
+
+    fn summarise(candles: &[Candle], idx: usize, timeframe: Timeframe) -> TimeframeSummary {
+        let mut windows = TimeframeWindows::new(timeframe);
+        for idx in 0..=idx {
+            windows.advance(candles, idx);
+        }
+        windows.summarise(candles)
+    }
+
+    fn price_increased_by_more_than(percent: f32, timeframe: Timeframe) -> Condition {
+        Condition::Compare {
+            field: Price,
+            operator: IncreasedByMoreThan,
+            value: Some(Value::percent(percent)),
+            timeframe: Some(timeframe),
+        }
+    }
+
+    #[test]
+    fn test_summarise() {
+        let candles = vec![
+            candle(0, 10, 1),
+            candle(3, 20, 2),
+            candle(5, 30, 3),
+            candle(9, 40, 4),
+        ];
+
+        let summary = summarise(&candles, 3, Timeframe::M5);
+        assert_eq!(summary.price.open.quote, Some(PriceQuote::from(30i64)));
+        assert_eq!(summary.price.close.quote, Some(PriceQuote::from(40i64)));
+        assert_eq!(
+            summary.price.close.quote_change,
+            Some(PriceQuote::from(20i64))
+        );
+        assert_eq!(summary.price.close.percent, Some(Percent(100.0)));
+        assert_eq!(summary.swap.buy.count, Some(Count(7)));
+        assert_eq!(summary.swap.buy.change, Some(Count(4)));
+
+        let summary = summarise(&candles, 0, Timeframe::M5);
+        assert_eq!(summary.price.close.quote, Some(PriceQuote::from(10i64)));
+        assert_eq!(summary.price.close.percent, None);
+        assert_eq!(summary.swap.buy.change, None);
+    }
+
+    #[test]
+    fn test_summarise_drops_candles_leaving_the_window() {
+        let mut candles = vec![
+            candle(0, 40, 1),
+            candle(1, 5, 1),
+            candle(6, 10, 1),
+            candle(7, 20, 1),
+        ];
+        candles[1].price_usd = Some(ohlc(500));
+        candles[2].price_usd = Some(ohlc(1000));
+        candles[3].price_usd = Some(ohlc(2000));
+
+        let summary = summarise(&candles, 3, Timeframe::M5);
+        assert_eq!(summary.price.high.quote, Some(PriceQuote::from(20i64)));
+        assert_eq!(summary.price.low.quote, Some(PriceQuote::from(10i64)));
+        assert_eq!(summary.price.avg.quote, Some(PriceQuote::from(15i64)));
+        assert_eq!(summary.price.high.usd, Some(PriceUsd::from(2000i64)));
+        assert_eq!(summary.swap.buy.count, Some(Count(2)));
+
+        // the previous window contains a candle without usd price
+        assert_eq!(
+            summary.price.high.quote_change,
+            Some(PriceQuote::from(-20i64))
+        );
+        assert_eq!(summary.price.high.usd_change, None);
+    }
+
+    #[test]
+    fn test_replay() {
+        let candles = vec![
+            candle(0, 10, 1),
+            candle(1, 10, 1),
+            candle(2, 15, 1),
+            candle(3, 15, 1),
+            candle(8, 30, 1),
+        ];
+
+        let result = replay(
+            &price_increased_by_more_than(30.0, Timeframe::M1),
+            Venue::PumpFun,
+            &candles,
+            0,
+            600,
+            100_000,
+        );
+
+        assert_eq!(result.len(), 1);
+
+        let result = result.first().unwrap();
+        assert_eq!(result.token_pair, TokenPairId(1));
+        assert_eq!(result.timestamp.to_epoch_seconds(), 120);
+        assert_eq!(result.price, PriceQuote::from(15i64));
+
+        assert_eq!(result.price_path.len(), 6);
+        assert_eq!(result.price_path[0].after, Timeframe::M1);
+        assert_eq!(result.price_path[0].price, PriceQuote::from(15i64));
+        assert_eq!(result.price_path[0].percent, Some(Percent(0.0)));
+        assert_eq!(result.price_path[1].after, Timeframe::M5);
+        assert_eq!(result.price_path[1].price, PriceQuote::from(15i64));
+        assert_eq!(result.price_path[2].after, Timeframe::M15);
+        assert_eq!(result.price_path[2].price, PriceQuote::from(30i64));
+        assert_eq!(result.price_path[2].percent, Some(Percent(100.0)));
+    }
+
+    #[test]
+    fn test_replay_within_time_range_only() {
+        let candles = vec![candle(0, 10, 1), candle(1, 20, 1), candle(2, 40, 1)];
+
+        let result = replay(
+            &price_increased_by_more_than(30.0, Timeframe::M1),
+            Venue::PumpFun,
+            &candles,
+            100,
+            600,
+            100_000,
+        );
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].timestamp.to_epoch_seconds(), 120);
+    }
+
+    #[test]
+    fn test_price_path_up_to_now() {
+        let candles = vec![candle(0, 10, 1), candle(1, 20, 1)];
+
+        let result = replay(
+            &price_increased_by_more_than(30.0, Timeframe::M1),
+            Venue::PumpFun,
+            &candles,
+            0,
+            600,
+            400,
+        );
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].price_path.len(), 2);
+        assert_eq!(result[0].price_path[1].after, Timeframe::M5);
+    }
+
+    #[test]
+    fn test_replay_swap_count() {
+        let candles = vec![candle(0, 10, 1), candle(1, 10, 5), candle(2, 10, 1)];
+
+        let result = replay(
+            &Condition::Compare {
+                field: SwapBuy,
+                operator: MoreThan,
+                value: Some(Value::count(6)),
+                timeframe: Some(Timeframe::M5),
+            },
+            Venue::Jupiter,
+            &candles,
+            0,
+            600,
+            100_000,
+        );
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].timestamp.to_epoch_seconds(), 120);
+    }
+}
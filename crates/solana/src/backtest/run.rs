@@ -0,0 +1,61 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::backtest::candle::list_candles;
+use crate::backtest::replay::replay;
+use crate::backtest::{BacktestMatch, BacktestQuery, BacktestService};
+use common::model::{Timeframe, Timestamp};
+use common::service::ServiceResult;
+use itertools::Itertools;
+use tokio::task::spawn_blocking;
+
+/// Matches beyond are dropped, as a condition which matches that often is not selective anyway
+const MAX_MATCHES: usize = 1000;
+
+impl BacktestService {
+    /// Every token pair and timestamp within the time range at which the condition of the sequence
+    /// would have matched, ordered by token pair and timestamp and limited to the first 1000 matches.
+    ///
+    /// The facts get derived from the 1 minute candles, so the sequence is tested once per minute with
+    /// swaps and facts which can not be derived from candles, e.g. age or socials, never match.
+    pub async fn backtest(&self, query: BacktestQuery) -> ServiceResult<Vec<BacktestMatch>> {
+        let from = query.from.to_epoch_seconds();
+        let to = query.to.to_epoch_seconds();
+        let now = Timestamp::now().to_epoch_seconds();
+
+        let mut tx = self.pool.begin().await?;
+        // summaries of the largest timeframe look back twice the timeframe,
+        // the price path looks ahead one timeframe
+        let candles = list_candles(
+            &mut tx,
+            query.venue,
+            &query.token_pairs,
+            Timestamp::from_epoch_second(from - 2 * Timeframe::D1.seconds()).unwrap(),
+            Timestamp::from_epoch_second(to + Timeframe::D1.seconds()).unwrap(),
+        )
+        .await?;
+        tx.commit().await?;
+
+        // replaying is cpu bound and must not block the runtime
+        Ok(spawn_blocking(move || {
+            candles
+                .into_iter()
+                .chunk_by(|candle| candle.token_pair)
+                .into_iter()
+                .flat_map(|(_, candles)| {
+                    replay(
+                        &query.sequence.condition,
+                        query.venue,
+                        &candles.collect::<Vec<_>>(),
+                        from,
+                        to,
+                        now,
+                    )
+                })
+                .take(MAX_MATCHES)
+                .collect()
+        })
+        .await
+        .unwrap())
+    }
+}
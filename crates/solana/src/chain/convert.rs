@@ -7,7 +7,7 @@ use base::model::solana::{
     Transaction, TransactionBalance, TransactionSolBalance, TransactionStatus,
     TransactionTokenBalance,
 };
-use base::model::{DecimalAmount, Mint, PublicKey};
+use base::model::{DecimalAmount, Decimals, Mint, PublicKey};
 use bigdecimal::BigDecimal;
 use common::model::{BlockTimestamp, Timestamp};
 use log::warn;
@@ -149,6 +149,7 @@ fn extract_balance(meta: &UiTransactionStatusMeta, keys: &Keys) -> TransactionBa
             token.push(TransactionTokenBalance {
                 mint: Mint::from(pre.mint.clone()),
                 address,
+                decimals: Decimals::from(pre.ui_token_amount.decimals),
                 pre: DecimalAmount::new(pre_balance, pre.ui_token_amount.decimals),
                 post: DecimalAmount::new(post_balance, post.ui_token_amount.decimals),
             })
@@ -166,6 +167,17 @@ fn extract_keys(vtx: &VersionedTransaction, meta: &UiTransactionStatusMeta) -> K
         .map(|key| (*key).into())
         .collect();
 
+    let loaded_account = if let OptionSerializer::Some(loaded) = &meta.loaded_addresses {
+        loaded
+            .writable
+            .iter()
+            .chain(loaded.readonly.iter())
+            .filter_map(|key| PublicKey::from_str(key).ok())
+            .collect()
+    } else {
+        vec![]
+    };
+
     let log_account = if let OptionSerializer::Some(logs) = &meta.log_messages {
         extract_keys_from_logs(logs.as_slice())
     } else {
@@ -174,6 +186,7 @@ fn extract_keys(vtx: &VersionedTransaction, meta: &UiTransactionStatusMeta) -> K
 
     Keys {
         static_account,
+        loaded_account,
         log_account,
     }
 }
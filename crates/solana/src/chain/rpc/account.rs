@@ -9,6 +9,16 @@ use solana_client::rpc_config::RpcAccountInfoConfig;
 use solana_sdk::commitment_config::CommitmentConfig;
 
 impl RpcClient {
+    /// Returns the lamports held by key, 0 if the account does not exist
+    pub async fn sol_balance(&self, key: impl Into<PublicKey>) -> RpcResult<u64> {
+        let key = key.into();
+        Ok(self
+            .delegate
+            .get_balance_with_commitment(&key.into(), CommitmentConfig::confirmed())
+            .await?
+            .value)
+    }
+
     pub async fn get_account(
         &self,
         key: impl Into<PublicKey>,
@@ -5,12 +5,14 @@
 // Original MIT License Copyright (c) 0xcrust 2024.
 
 use crate::rpc::block::GetBlockWithConfigFn;
-use crate::rpc::error::RpcClientError;
 use base::model::solana::Slot;
 use common::model::RpcUrl;
 use log::debug;
 use solana_client::rpc_config::RpcBlockConfig;
 use solana_rpc_client::nonblocking::rpc_client;
+use solana_rpc_client::rpc_client::RpcClientConfig;
+use solana_rpc_client::rpc_sender::RpcSender;
+use solana_sdk::commitment_config::CommitmentConfig;
 use std::ops::Deref;
 use std::sync::Arc;
 use tokio::time::Instant;
@@ -19,6 +21,9 @@ mod account;
 mod block;
 mod error;
 mod slot;
+mod token;
+mod transaction;
+mod transfer;
 
 #[derive(Clone)]
 pub struct RpcClient(pub Arc<RpcClientInner>);
@@ -45,6 +50,26 @@ impl RpcClient {
     }
 }
 
+impl RpcClient {
+    /// Creates a client which routes all requests through the given sender instead of http,
+    /// e.g. a local stand-in of a rpc node for testing
+    pub fn new_with_sender(sender: impl RpcSender + Send + Sync + 'static) -> Self {
+        Self(Arc::new(RpcClientInner {
+            delegate: Arc::new(rpc_client::RpcClient::new_sender(
+                sender,
+                RpcClientConfig::with_commitment(CommitmentConfig::confirmed()),
+            )),
+            get_block_with_config: Arc::new(
+                move |delegate: Arc<rpc_client::RpcClient>, slot: Slot, config: RpcBlockConfig| {
+                    Box::pin(
+                        async move { delegate.get_block_with_config(slot.0 as u64, config).await },
+                    )
+                },
+            ),
+        }))
+    }
+}
+
 impl Default for RpcClient {
     fn default() -> Self {
         Self(Arc::new(RpcClientInner {
@@ -76,4 +101,6 @@ pub struct RpcClientInner {
     pub(crate) get_block_with_config: Arc<GetBlockWithConfigFn>,
 }
 
+pub use error::RpcClientError;
+
 pub type RpcResult<T> = Result<T, RpcClientError>;
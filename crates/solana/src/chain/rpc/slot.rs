@@ -3,9 +3,38 @@
 
 use crate::rpc::{RpcClient, RpcResult};
 use base::model::solana::Slot;
+use solana_sdk::commitment_config::CommitmentConfig;
 
 impl RpcClient {
     pub async fn slot(&self) -> RpcResult<Slot> {
         Ok(self.delegate.get_slot().await?.into())
     }
+
+    /// Returns the highest slot which can not be rolled back anymore
+    pub async fn finalized_slot(&self) -> RpcResult<Slot> {
+        Ok(self
+            .delegate
+            .get_slot_with_commitment(CommitmentConfig::finalized())
+            .await?
+            .into())
+    }
+
+    /// Returns the slots between first and last (inclusive) which have a block on the finalized fork
+    pub async fn finalized_blocks(
+        &self,
+        first: impl Into<Slot>,
+        last: impl Into<Slot>,
+    ) -> RpcResult<Vec<Slot>> {
+        Ok(self
+            .delegate
+            .get_blocks_with_commitment(
+                first.into().0 as u64,
+                Some(last.into().0 as u64),
+                CommitmentConfig::finalized(),
+            )
+            .await?
+            .into_iter()
+            .map(Slot::from)
+            .collect())
+    }
 }
@@ -0,0 +1,29 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::rpc::{RpcClient, RpcResult};
+use base::model::{Mint, PublicKey};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Account;
+
+impl RpcClient {
+    /// Returns the raw amount held by the associated token account of owner, 0 if the account does not exist
+    pub async fn token_balance(
+        &self,
+        owner: impl Into<PublicKey>,
+        mint: impl Into<Mint>,
+    ) -> RpcResult<u64> {
+        let owner: Pubkey = owner.into().into();
+        let mint: Pubkey = mint.into().into();
+
+        let ata = get_associated_token_address(&owner, &mint);
+        match self.get_account(ata).await? {
+            None => Ok(0),
+            Some(account) => Ok(Account::unpack(&account.account.data)
+                .map(|account| account.amount)
+                .unwrap_or(0)),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::rpc::{RpcClient, RpcResult};
+use base::model::solana::Signature;
+use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::hash::Hash;
+use solana_sdk::transaction::VersionedTransaction;
+
+impl RpcClient {
+    pub async fn latest_blockhash(&self) -> RpcResult<Hash> {
+        Ok(self.delegate.get_latest_blockhash().await?)
+    }
+
+    pub async fn send_transaction(&self, tx: &VersionedTransaction) -> RpcResult<Signature> {
+        let signature = self
+            .delegate
+            .send_transaction_with_config(
+                tx,
+                RpcSendTransactionConfig {
+                    skip_preflight: false,
+                    preflight_commitment: Some(CommitmentLevel::Confirmed),
+                    encoding: None,
+                    max_retries: None,
+                    min_context_slot: None,
+                },
+            )
+            .await?;
+
+        Ok(Signature(signature.to_string()))
+    }
+}
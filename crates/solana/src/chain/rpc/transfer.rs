@@ -0,0 +1,156 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::rpc::{RpcClient, RpcClientError, RpcResult};
+use base::model::solana::Signature;
+use base::model::{KeyPair, Mint, PublicKey};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::{Message, VersionedMessage};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::VersionedTransaction;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+/// Base fee of a transaction with a single signature
+const TRANSACTION_FEE: u64 = 5_000;
+
+/// Size of an associated token account, token-2022 accounts carry the immutable owner extension
+const TOKEN_ACCOUNT_LEN: usize = 165;
+const TOKEN_2022_ACCOUNT_LEN: usize = 170;
+
+impl RpcClient {
+    /// Lamports the payer needs on top of the transferred amount to send SOL,
+    /// the transaction fee and the rent exempt minimum the payer account has to keep
+    pub fn transfer_sol_reserve(&self) -> u64 {
+        TRANSACTION_FEE + Rent::default().minimum_balance(0)
+    }
+
+    /// Lamports the payer needs to send a token to recipient, the transaction fee,
+    /// the rent exempt minimum the payer account has to keep and the rent of the
+    /// associated token account of recipient if it does not exist yet
+    pub async fn transfer_token_reserve(
+        &self,
+        recipient: impl Into<PublicKey>,
+        mint: impl Into<Mint>,
+    ) -> RpcResult<u64> {
+        let recipient: Pubkey = recipient.into().into();
+        let mint: Pubkey = mint.into().into();
+
+        let token_program = self.token_program(mint).await?;
+        let destination =
+            get_associated_token_address_with_program_id(&recipient, &mint, &token_program);
+
+        let account_rent = if self.get_account(destination).await?.is_none() {
+            let len = if token_program == spl_token_2022::ID {
+                TOKEN_2022_ACCOUNT_LEN
+            } else {
+                TOKEN_ACCOUNT_LEN
+            };
+            Rent::default().minimum_balance(len)
+        } else {
+            0
+        };
+
+        Ok(self.transfer_sol_reserve() + account_rent)
+    }
+
+    /// Transfers lamports from payer to recipient
+    pub async fn transfer_sol(
+        &self,
+        payer: &KeyPair,
+        recipient: impl Into<PublicKey>,
+        lamports: u64,
+    ) -> RpcResult<Signature> {
+        let keypair: Keypair = payer.clone().into();
+        let recipient: Pubkey = recipient.into().into();
+
+        let instruction = system_instruction::transfer(&keypair.pubkey(), &recipient, lamports);
+        self.sign_and_send(&keypair, &[instruction]).await
+    }
+
+    /// Transfers the raw amount of mint from the associated token account of payer to the one of recipient.
+    /// The associated token account of recipient gets created if it does not exist yet
+    pub async fn transfer_token(
+        &self,
+        payer: &KeyPair,
+        recipient: impl Into<PublicKey>,
+        mint: impl Into<Mint>,
+        amount: u64,
+        decimals: u8,
+    ) -> RpcResult<Signature> {
+        let keypair: Keypair = payer.clone().into();
+        let recipient: Pubkey = recipient.into().into();
+        let mint: Pubkey = mint.into().into();
+
+        let token_program = self.token_program(mint).await?;
+
+        let source =
+            get_associated_token_address_with_program_id(&keypair.pubkey(), &mint, &token_program);
+        let destination =
+            get_associated_token_address_with_program_id(&recipient, &mint, &token_program);
+
+        let mut instructions = Vec::with_capacity(2);
+        if self.get_account(destination).await?.is_none() {
+            instructions.push(create_associated_token_account_idempotent(
+                &keypair.pubkey(),
+                &recipient,
+                &mint,
+                &token_program,
+            ));
+        }
+
+        instructions.push(
+            spl_token_2022::instruction::transfer_checked(
+                &token_program,
+                &source,
+                &mint,
+                &destination,
+                &keypair.pubkey(),
+                &[],
+                amount,
+                decimals,
+            )
+            .map_err(|err| RpcClientError::Transaction(err.to_string()))?,
+        );
+
+        self.sign_and_send(&keypair, &instructions).await
+    }
+
+    async fn token_program(&self, mint: Pubkey) -> RpcResult<Pubkey> {
+        let token_program = match self.get_account(mint).await? {
+            Some(account) => Pubkey::from(account.account.owner),
+            None => {
+                return Err(RpcClientError::Custom(format!(
+                    "mint {mint} does not exist"
+                )))
+            }
+        };
+
+        if token_program != spl_token::ID && token_program != spl_token_2022::ID {
+            return Err(RpcClientError::Custom(format!(
+                "mint {mint} is not owned by a token program"
+            )));
+        }
+
+        Ok(token_program)
+    }
+
+    async fn sign_and_send(
+        &self,
+        payer: &Keypair,
+        instructions: &[Instruction],
+    ) -> RpcResult<Signature> {
+        let blockhash = self.latest_blockhash().await?;
+        let message = VersionedMessage::Legacy(Message::new_with_blockhash(
+            instructions,
+            Some(&payer.pubkey()),
+            &blockhash,
+        ));
+        let transaction = VersionedTransaction::try_new(message, &[payer])
+            .map_err(|err| RpcClientError::Signing(err.to_string()))?;
+        self.send_transaction(&transaction).await
+    }
+}
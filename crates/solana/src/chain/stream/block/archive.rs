@@ -0,0 +1,190 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::stream::BlockStream;
+use async_trait::async_trait;
+use base::model::solana::{Block, Slot};
+use common::Signal;
+use log::error;
+use std::fs::File;
+use std::io::{BufWriter, Error, ErrorKind, Write};
+use std::path::PathBuf;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::task::JoinHandle;
+
+/// Streams blocks from an archive file, which contains one JSON encoded block per line in ascending slot order.
+/// Allows to replay blocks offline, e.g. to reproduce incidents or to regression test parsers and aggregations.
+pub struct ArchiveBlockStream {
+    path: PathBuf,
+    tx: Sender<Block>,
+    rx: Receiver<Block>,
+}
+
+impl ArchiveBlockStream {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let (tx, rx) = channel(1_000);
+        Self {
+            path: path.into(),
+            tx,
+            rx,
+        }
+    }
+}
+
+#[async_trait]
+impl BlockStream for ArchiveBlockStream {
+    async fn stream(self, mut signal: Signal) -> (Receiver<Block>, JoinHandle<()>) {
+        let path = self.path;
+        let tx = self.tx;
+
+        (
+            self.rx,
+            tokio::spawn(async move {
+                let file = match tokio::fs::File::open(&path).await {
+                    Ok(file) => file,
+                    Err(err) => {
+                        error!("failed to open archive {} - {err}", path.to_string_lossy());
+                        signal.terminate("ArchiveBlockStream failed to open archive");
+                        return;
+                    }
+                };
+
+                let mut lines = BufReader::new(file).lines();
+                let mut previous: Option<Slot> = None;
+                let mut idx = 0usize;
+                loop {
+                    if signal.recv_maybe().await.is_some() {
+                        break;
+                    }
+
+                    let line = match lines.next_line().await {
+                        Ok(Some(line)) => line,
+                        Ok(None) => break,
+                        Err(err) => {
+                            error!("failed to read line {} - {err}", idx + 1);
+                            signal.terminate("ArchiveBlockStream failed to read block");
+                            return;
+                        }
+                    };
+                    idx += 1;
+
+                    let block = match serde_json::from_str::<Block>(&line) {
+                        Ok(block) => block,
+                        Err(err) => {
+                            error!("failed to read block in line {idx} - {err}");
+                            signal.terminate("ArchiveBlockStream failed to read block");
+                            return;
+                        }
+                    };
+
+                    if previous.is_some_and(|previous| previous >= block.slot) {
+                        error!("block {} in line {idx} is out of order", block.slot);
+                        signal.terminate("ArchiveBlockStream read block out of order");
+                        return;
+                    }
+                    previous = Some(block.slot);
+
+                    if tx.send(block).await.is_err() {
+                        error!("Failed to send block to channel");
+                        signal.terminate("ArchiveBlockStream failed to send to channel");
+                        return;
+                    }
+                }
+            }),
+        )
+    }
+}
+
+/// Writes blocks into an archive file which can be replayed with [`ArchiveBlockStream`]
+pub struct ArchiveWriter {
+    writer: BufWriter<File>,
+    previous: Option<Slot>,
+}
+
+impl ArchiveWriter {
+    pub fn create(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path.into())?),
+            previous: None,
+        })
+    }
+
+    pub fn append(&mut self, block: &Block) -> std::io::Result<()> {
+        if self.previous.is_some_and(|previous| previous >= block.slot) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("block {} is out of order", block.slot),
+            ));
+        }
+        serde_json::to_writer(&mut self.writer, block)?;
+        self.writer.write_all(b"\n")?;
+        self.previous = Some(block.slot);
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::{ArchiveBlockStream, ArchiveWriter, BlockStream};
+    use base::model::solana::{Block, Slot};
+    use common::model::BlockTimestamp;
+    use common::Signal;
+    use std::env;
+    use std::path::PathBuf;
+
+    fn archive_path(name: &str) -> PathBuf {
+        let mut result = env::temp_dir();
+        result.push(format!("archive_{name}_{}.jsonl", std::process::id()));
+        result
+    }
+
+    fn block(slot: i64) -> Block {
+        Block {
+            slot: Slot(slot),
+            timestamp: BlockTimestamp::now(),
+            transactions: vec![],
+        }
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_replay() {
+        let path = archive_path("replay");
+
+        let mut writer = ArchiveWriter::create(path.clone()).unwrap();
+        writer.append(&block(10)).unwrap();
+        writer.append(&block(12)).unwrap();
+        writer.flush().unwrap();
+
+        let (mut rx, handle) = ArchiveBlockStream::new(path.clone())
+            .stream(Signal::new())
+            .await;
+        handle.await.unwrap();
+
+        assert_eq!(rx.recv().await.unwrap().slot, 10);
+        assert_eq!(rx.recv().await.unwrap().slot, 12);
+        assert!(rx.recv().await.is_none());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_append_out_of_order() {
+        let path = archive_path("out_of_order");
+
+        let mut writer = ArchiveWriter::create(path.clone()).unwrap();
+        writer.append(&block(10)).unwrap();
+
+        let result = writer.append(&block(10));
+        assert!(result.is_err());
+
+        let result = writer.append(&block(9));
+        assert!(result.is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,9 +1,12 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+mod archive;
 mod download;
 mod slot;
 
+pub use archive::{ArchiveBlockStream, ArchiveWriter};
+
 use base::model::solana::{Block, Slot};
 use crate::rpc::RpcClient;
 use crate::stream::block::download::{DownloadResult, Downloader};
@@ -20,6 +23,8 @@ use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tokio::{select, try_join};
 
+/// Streams blocks in ascending slot order, slots without a block are left out.
+/// Consumers rely on this to tell skipped slots apart from missing ones.
 #[async_trait]
 pub trait BlockStream: Send {
     async fn stream(self, signal: Signal) -> (Receiver<Block>, JoinHandle<()>);
@@ -96,7 +101,8 @@ impl<S: SlotStream> BlockStream for RpcBlockStream<S> {
 
         let receive_block_handle = tokio::spawn(async move {
             let mut buffer = BTreeMap::new();
-            let mut expected_slot = Slot(0);
+            // downloads continue right after the previous slot, otherwise they start at whatever arrives first
+            let mut expected_slot = self.previous_slot.map(|slot| slot.next()).unwrap_or(Slot(0));
 
             while let Some(result) = download_rx.recv().await {
                 match result {
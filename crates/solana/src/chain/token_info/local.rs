@@ -6,7 +6,7 @@
 
 use crate::token_info::{rewrite_ipfs, sanitize_value};
 use async_trait::async_trait;
-use base::model::{Decimals, Mint, Uri};
+use base::model::{Decimals, Mint, TwitterHandle, Uri};
 use base::{LoadTokenInfo, TokenInfo};
 use log::warn;
 use serde::{Deserialize, Serialize};
@@ -108,6 +108,13 @@ impl TokenInfoLocalLoader {
                         .clone()
                         .map(|website| rewrite_ipfs(Uri::from(sanitize_value(website))))
                 }),
+                twitter: token.extensions.as_ref().and_then(|e| {
+                    e.twitter
+                        .as_ref()
+                        .and_then(|twitter| TwitterHandle::parse(&sanitize_value(twitter)))
+                }),
+                // not provided in token list json
+                telegram: None,
             };
 
             result.insert(mint, mapped);
@@ -135,6 +142,8 @@ impl TokenInfoLocalLoader {
                 description: None,
                 // not provided in token list json
                 website: None,
+                twitter: None,
+                telegram: None,
             };
 
             result.insert(mint, mapped);
@@ -179,6 +188,8 @@ fn merge_token_info<'a>(
                     image: from_labs.image.clone(),
                     description: from_labs.description.clone(),
                     website: from_labs.website.clone(),
+                    twitter: from_labs.twitter.clone(),
+                    telegram: from_labs.telegram.clone(),
                 };
                 Cow::Owned(merged_meta)
             } else {
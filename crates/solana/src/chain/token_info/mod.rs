@@ -38,9 +38,56 @@ pub(crate) fn rewrite_ipfs(uri: impl Into<Uri>) -> Uri {
         .into()
 }
 
+// public path gateways, in the order they are tried
+const IPFS_GATEWAYS: [&str; 3] = [
+    "https://ipfs.io/ipfs/",
+    "https://dweb.link/ipfs/",
+    "https://nftstorage.link/ipfs/",
+];
+
+/// The uri itself (with a fixed gateway), followed by the same IPFS content on the other public gateways.
+/// Anything which is not served through an IPFS gateway is returned as is
+pub(crate) fn ipfs_gateways(uri: impl Into<Uri>) -> Vec<Uri> {
+    let uri = rewrite_ipfs(uri);
+    let Some(content) = ipfs_content(&uri.0) else {
+        return vec![uri];
+    };
+
+    let mut result = vec![uri];
+    for gateway in IPFS_GATEWAYS {
+        let candidate = Uri(format!("{gateway}{content}"));
+        if !result.contains(&candidate) {
+            result.push(candidate);
+        }
+    }
+    result
+}
+
+// cid and path of a path gateway (https://gateway/ipfs/<cid>/path) or subdomain gateway (https://<cid>.ipfs.gateway/path) uri
+fn ipfs_content(uri: &str) -> Option<String> {
+    let without_scheme = uri
+        .strip_prefix("https://")
+        .or_else(|| uri.strip_prefix("http://"))?;
+    let (host, path) = without_scheme
+        .split_once('/')
+        .unwrap_or((without_scheme, ""));
+
+    if let Some((cid, _)) = host.split_once(".ipfs.") {
+        return match path {
+            "" => Some(cid.to_string()),
+            path => Some(format!("{cid}/{path}")),
+        };
+    }
+
+    path.strip_prefix("ipfs/")
+        .filter(|content| !content.is_empty())
+        .map(ToString::to_string)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::token_info::{rewrite_ipfs, sanitize_value};
+    use crate::token_info::{ipfs_gateways, rewrite_ipfs, sanitize_value};
+    use base::model::Uri;
 
     #[test]
     fn test_ok() {
@@ -104,6 +151,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ipfs_gateways_path() {
+        assert_eq!(
+            ipfs_gateways(
+                "https://cf-ipfs.com/ipfs/QmSqKqHSbJxomL22qqHnRJiQhyUVmVKP1xDAmasXmqT4k4"
+            ),
+            vec![
+                Uri::from("https://ipfs.io/ipfs/QmSqKqHSbJxomL22qqHnRJiQhyUVmVKP1xDAmasXmqT4k4"),
+                Uri::from("https://dweb.link/ipfs/QmSqKqHSbJxomL22qqHnRJiQhyUVmVKP1xDAmasXmqT4k4"),
+                Uri::from(
+                    "https://nftstorage.link/ipfs/QmSqKqHSbJxomL22qqHnRJiQhyUVmVKP1xDAmasXmqT4k4"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ipfs_gateways_subdomain() {
+        let result = ipfs_gateways(
+            "https://bafkreifikbwp45kdcz324uzz4h5ukkziumeqs4dafpdfyn2mulb4xezfte.ipfs.nftstorage.link/",
+        );
+        assert_eq!(result.len(), 4);
+        assert_eq!(
+            result[0],
+            "https://bafkreifikbwp45kdcz324uzz4h5ukkziumeqs4dafpdfyn2mulb4xezfte.ipfs.nftstorage.link/"
+        );
+        assert_eq!(
+            result[1],
+            "https://ipfs.io/ipfs/bafkreifikbwp45kdcz324uzz4h5ukkziumeqs4dafpdfyn2mulb4xezfte"
+        );
+    }
+
+    #[test]
+    fn test_ipfs_gateways_not_ipfs() {
+        assert_eq!(
+            ipfs_gateways("https://arweave.net/abc"),
+            vec![Uri::from("https://arweave.net/abc")]
+        );
+    }
+
     #[test]
     fn sanitize_value_success() {
         assert_eq!(
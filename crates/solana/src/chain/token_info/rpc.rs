@@ -138,6 +138,8 @@ async fn download_and_process(rpc_client: &RpcClient, mint: TokenMint) -> Downlo
                                 metadata: Some(rewrite_ipfs(sanitize_value(metadata.uri)).into()),
                                 image: None,
                                 website: None,
+                                twitter: None,
+                                telegram: None,
                             });
                         };
                     }
@@ -152,6 +154,8 @@ async fn download_and_process(rpc_client: &RpcClient, mint: TokenMint) -> Downlo
                         metadata: None,
                         image: None,
                         website: None,
+                        twitter: None,
+                        telegram: None,
                     })
                 }
                 spl_token_2022::ID => {
@@ -181,6 +185,8 @@ async fn download_and_process(rpc_client: &RpcClient, mint: TokenMint) -> Downlo
                             metadata: None,
                             image: None,
                             website: None,
+                            twitter: None,
+                            telegram: None,
                         });
                     };
 
@@ -194,6 +200,8 @@ async fn download_and_process(rpc_client: &RpcClient, mint: TokenMint) -> Downlo
                         metadata: Some(rewrite_ipfs(sanitize_value(metadata.uri)).into()),
                         image: None,
                         website: None,
+                        twitter: None,
+                        telegram: None,
                     })
                 }
                 _ => {
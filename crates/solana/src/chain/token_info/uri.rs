@@ -4,28 +4,46 @@
 // This file includes portions of code from https://github.com/blockworks-foundation/traffic (AGPL 3.0).
 // Original AGPL 3 License Copyright (c) blockworks-foundation 2024.
 
-use crate::token_info::{rewrite_ipfs, sanitize_value};
-use base::model::Uri;
+use crate::token_info::{ipfs_gateways, rewrite_ipfs, sanitize_value};
+use base::model::{TelegramHandle, TwitterHandle, Uri};
 use base::TokenInfo;
+use common::Limiter;
 use log::error;
 use serde::Deserialize;
-use solana_client::client_error::reqwest::{Client, StatusCode};
+use solana_client::client_error::reqwest::Client;
 use std::time::Duration;
-use tokio::time::sleep;
 
 #[derive(Clone)]
-pub struct TokenInfoUriLoader {}
+pub struct TokenInfoUriLoader {
+    downloader: JsonDownloader,
+}
 
 impl TokenInfoUriLoader {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            downloader: JsonDownloader::new(None),
+        }
+    }
+
+    /// Every request, including the ones to other IPFS gateways, waits for the limiter
+    pub fn new_limited(limiter: Limiter) -> Self {
+        Self {
+            downloader: JsonDownloader::new(Some(limiter)),
+        }
     }
 }
 
 impl TokenInfoUriLoader {
+    /// Fetches the metadata json, IPFS content is requested from other gateways if the one of the uri fails
     pub async fn load(&self, uri: Uri) -> Option<TokenInfo> {
-        let downloader = JsonDownloader::new();
-        let metadata: ExternalMetadata = downloader.fetch(uri.into()).await?;
+        let mut metadata: Option<ExternalMetadata> = None;
+        for candidate in ipfs_gateways(uri) {
+            metadata = self.downloader.fetch(candidate).await;
+            if metadata.is_some() {
+                break;
+            }
+        }
+        let metadata = metadata?;
 
         Some(TokenInfo {
             mint: metadata.mint.map(|mint| sanitize_value(mint).into()),
@@ -43,6 +61,12 @@ impl TokenInfoUriLoader {
             website: metadata
                 .website
                 .map(|web| rewrite_ipfs(Uri::from(sanitize_value(web)))),
+            twitter: metadata
+                .twitter
+                .and_then(|twitter| TwitterHandle::parse(&sanitize_value(twitter))),
+            telegram: metadata
+                .telegram
+                .and_then(|telegram| TelegramHandle::parse(&sanitize_value(telegram))),
         })
     }
 }
@@ -56,71 +80,52 @@ struct ExternalMetadata {
     description: Option<String>,
     image: Option<String>,
     website: Option<String>,
+    twitter: Option<String>,
+    telegram: Option<String>,
 }
 
+/// Requests every uri once, retrying later is up to the caller
+#[derive(Clone)]
 struct JsonDownloader {
     client: Client,
-    max_retries: usize,
-    retry_delay: Duration,
+    limiter: Option<Limiter>,
 }
 
 impl JsonDownloader {
-    pub fn new() -> Self {
+    pub fn new(limiter: Option<Limiter>) -> Self {
         Self {
             client: Client::builder()
                 .connect_timeout(Duration::from_secs(5))
                 .timeout(Duration::from_secs(5))
                 .build()
                 .expect("Failed to build client"),
-            max_retries: 5,
-            retry_delay: Duration::from_millis(250),
+            limiter,
         }
     }
 
     pub async fn fetch<T: for<'de> Deserialize<'de>>(&self, uri: Uri) -> Option<T> {
-        let mut attempts = 0;
-        loop {
-            match self.client.get(&uri.0).send().await {
-                Err(err) => {
-                    attempts += 1;
-                    if attempts >= self.max_retries {
-                        error!("Failed to fetch external data from {uri} after {attempts} attempts: {err}");
-                        return None;
-                    }
-                    sleep(self.retry_delay).await;
-                    continue;
+        if let Some(limiter) = &self.limiter {
+            limiter.limit().await;
+        }
+        match self.client.get(&uri.0).send().await {
+            Err(err) => {
+                error!("Failed to fetch external data from {uri}: {err}");
+                None
+            }
+            Ok(response) => {
+                if !response.status().is_success() {
+                    error!(
+                        "Failed to fetch external data from {uri} - status: {}",
+                        response.status()
+                    );
+                    return None;
                 }
-                Ok(response) => {
-                    if response.status().is_success() {
-                        return match response.json::<T>().await {
-                            Ok(result) => Some(result),
-                            Err(err) => {
-                                error!("Failed to parse JSON from {uri}: {err}");
-                                None
-                            }
-                        };
-                    } else if matches!(
-                        response.status(),
-                        StatusCode::FORBIDDEN
-                            | StatusCode::TOO_MANY_REQUESTS
-                            | StatusCode::BAD_GATEWAY
-                            | StatusCode::GONE
-                            | StatusCode::NOT_FOUND
-                    ) {
-                        error!(
-                            "Giving up fetching data from {uri} - status: {}",
-                            response.status()
-                        );
-                        return None;
-                    }
-                    attempts += 1;
-                    if attempts >= self.max_retries {
-                        error!(
-                            "Failed to fetch external data from {uri} after {attempts} attempts"
-                        );
-                        return None;
+                match response.json::<T>().await {
+                    Ok(result) => Some(result),
+                    Err(err) => {
+                        error!("Failed to parse JSON from {uri}: {err}");
+                        None
                     }
-                    sleep(self.retry_delay).await;
                 }
             }
         }
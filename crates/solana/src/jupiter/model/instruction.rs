@@ -6,11 +6,21 @@ use base::model::{Amount, Mint, PublicKey};
 #[derive(Debug)]
 pub enum Instruction {
     Swap {
-        swaps: Vec<Jupiter6Swap>,
+        route: Jupiter6Route,
         signer: PublicKey,
     },
 }
 
+/// The trade of a user, which Jupiter routes through one or more AMMs
+#[derive(Debug)]
+pub struct Jupiter6Route {
+    pub input_mint: Mint,
+    pub input_amount: Amount,
+    pub output_mint: Mint,
+    pub output_amount: Amount,
+    pub legs: Vec<Jupiter6Swap>,
+}
+
 #[derive(Debug)]
 pub struct Jupiter6Swap {
     pub amm: PublicKey,
@@ -2,7 +2,9 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 pub use instruction::*;
+pub use summary::*;
 pub use swap::*;
 
 mod instruction;
+mod summary;
 mod swap;
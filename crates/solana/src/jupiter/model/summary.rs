@@ -0,0 +1,34 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use common::model::{
+    Count, MarketCapQuote, MarketCapUsd, Percent, PriceQuote, PriceUsd, VolumeQuote,
+};
+
+/// Most recent candle of a token pair within a timeframe, compared to the candle right before it
+#[derive(Clone, Debug)]
+pub struct Summary {
+    pub price: PriceQuote,
+    pub price_usd: Option<PriceUsd>,
+    pub price_avg: PriceQuote,
+    pub price_avg_usd: Option<PriceUsd>,
+
+    pub market_cap: Option<MarketCapQuote>,
+    pub market_cap_usd: Option<MarketCapUsd>,
+
+    pub swap: SummarySwap,
+    pub swap_buy: SummarySwap,
+    pub swap_sell: SummarySwap,
+
+    pub volume: VolumeQuote,
+    pub volume_change: Option<VolumeQuote>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SummarySwap {
+    pub count: Count,
+    /// None if there is no previous candle
+    pub change: Option<Count>,
+    /// None if there is no previous candle or it had no swaps
+    pub percent: Option<Percent>,
+}
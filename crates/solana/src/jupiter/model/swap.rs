@@ -2,7 +2,7 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 use base::model::solana::{Signature, Slot};
-use base::model::{AddressId, DecimalAmount, SwapId, TokenPairId};
+use base::model::{AddressId, DecimalAmount, PublicKey, SwapId, TokenPairId};
 use common::model::{PriceQuote, Timestamp};
 
 #[derive(Debug)]
@@ -18,3 +18,19 @@ pub struct Swap {
     pub timestamp: Timestamp,
     pub signature: Signature,
 }
+
+/// Swap with a single AMM, one of the legs a user level swap got routed through
+#[derive(Debug)]
+pub struct SwapLeg {
+    pub id: SwapId,
+    pub slot: Slot,
+    pub address: AddressId,
+    pub token_pair: TokenPairId,
+    pub amm: Option<PublicKey>,
+    pub amount_base: DecimalAmount,
+    pub amount_quote: DecimalAmount,
+    pub price: PriceQuote,
+    pub is_buy: bool,
+    pub timestamp: Timestamp,
+    pub signature: Signature,
+}
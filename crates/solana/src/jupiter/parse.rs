@@ -1,10 +1,11 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use crate::jupiter::model::{Instruction, Jupiter6Swap};
+use crate::jupiter::model::{Instruction, Jupiter6Route, Jupiter6Swap};
 use crate::parse::{log_and_return_parse_error, ParseError, ParseResult, Parser};
 use base::model::solana::Transaction;
 use base::model::{Amount, Mint, PublicKey};
+use bigdecimal::BigDecimal;
 use common::ByteReader;
 use solana_sdk::pubkey::Pubkey;
 
@@ -58,21 +59,23 @@ impl Default for JupiterParser {
 
 impl Parser<Vec<Instruction>> for JupiterParser {
     fn parse(&self, tx: &Transaction) -> ParseResult<Vec<Instruction>> {
-        let swaps = parse_swaps(tx)?;
-        if swaps.is_empty() {
-            return Ok(vec![]);
-        }
-
-        Ok(vec![Instruction::Swap {
-            swaps,
-            signer: tx.keys.static_account[0].clone(),
-        }])
+        let signer = &tx.keys.static_account[0];
+        Ok(parse_routes(tx)?
+            .into_iter()
+            .map(|route| Instruction::Swap {
+                route,
+                signer: signer.clone(),
+            })
+            .collect())
     }
 }
 
-pub(crate) fn parse_swaps(tx: &Transaction) -> ParseResult<Vec<Jupiter6Swap>> {
+/// One route per instruction which swapped through Jupiter, the legs of a route are the swap events emitted
+/// by its inner instructions
+pub(crate) fn parse_routes(tx: &Transaction) -> ParseResult<Vec<Jupiter6Route>> {
     let mut result = vec![];
     for inner in &tx.inner_instructions {
+        let mut legs = vec![];
         for instruction in &inner.instructions {
             let data = &instruction.instruction.data;
             if data.len() > 16 {
@@ -85,7 +88,7 @@ pub(crate) fn parse_swaps(tx: &Transaction) -> ParseResult<Vec<Jupiter6Swap>> {
                             Err(err) => {
                                 return Err(log_and_return_parse_error(err, &tx.signature, "swap"))
                             }
-                            Ok(swap) => result.push(Jupiter6Swap {
+                            Ok(swap) => legs.push(Jupiter6Swap {
                                 amm: swap.amm,
                                 input_mint: swap.input_mint,
                                 input_amount: swap.input_amount,
@@ -97,11 +100,54 @@ pub(crate) fn parse_swaps(tx: &Transaction) -> ParseResult<Vec<Jupiter6Swap>> {
                 };
             }
         }
+
+        if let Some(route) = route(legs) {
+            result.push(route);
+        }
     }
 
     Ok(result)
 }
 
+// The route starts with the input of the first leg and ends with the output of the last leg. Split routes
+// spend or receive the same mint on several legs, so the amounts of all those legs add up. Only legs before
+// the route receives its input mint again spend the input of the user, and only legs after the route spent
+// its output mint for the last time pay out to the user, otherwise circular routes count amounts twice
+fn route(legs: Vec<Jupiter6Swap>) -> Option<Jupiter6Route> {
+    let input_mint = legs.first()?.input_mint.clone();
+    let output_mint = legs.last()?.output_mint.clone();
+
+    let first_received = legs
+        .iter()
+        .position(|leg| leg.output_mint == input_mint)
+        .unwrap_or(legs.len());
+
+    let input_amount = legs[..first_received]
+        .iter()
+        .filter(|leg| leg.input_mint == input_mint)
+        .map(|leg| leg.input_amount.0.clone())
+        .sum::<BigDecimal>();
+
+    let last_spent = legs
+        .iter()
+        .rposition(|leg| leg.input_mint == output_mint)
+        .map_or(0, |idx| idx + 1);
+
+    let output_amount = legs[last_spent..]
+        .iter()
+        .filter(|leg| leg.output_mint == output_mint)
+        .map(|leg| leg.output_amount.0.clone())
+        .sum::<BigDecimal>();
+
+    Some(Jupiter6Route {
+        input_mint,
+        input_amount: input_amount.into(),
+        output_mint,
+        output_amount: output_amount.into(),
+        legs,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::convert::convert_transaction;
@@ -121,13 +167,25 @@ mod tests {
             let test_instance = JupiterParser::new();
             let tx = transaction("4pp3wY3KcAvzV7sL9y1ENtHgV4A43t2tmAzwxuxHRbJAXxbq7CGjjmhtUpd35y76zfKqp5N2mfR9aLHFRC9AZjg2");
 
-            let Instruction::Swap { swaps, signer } =
-                test_instance.parse(&tx).unwrap().pop().unwrap();
+            let mut result = test_instance.parse(&tx).unwrap();
+            assert_eq!(result.len(), 1);
 
+            let Instruction::Swap { route, signer } = result.pop().unwrap();
             assert_eq!(signer, "ACHPeLHfDUcXQC5pa4NTB2R9TDQtAnwgfszhX2udq7S2");
 
-            assert_eq!(swaps.len(), 1);
-            let swap = swaps.first().unwrap();
+            assert_eq!(
+                route.input_mint,
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+            );
+            assert_eq!(route.input_amount, 17143570);
+            assert_eq!(
+                route.output_mint,
+                "27G8MtK7VtTcCHkpASjSDdkWWYfoqT6ggEuKidVJidD4"
+            );
+            assert_eq!(route.output_amount, 5822752);
+
+            assert_eq!(route.legs.len(), 1);
+            let swap = route.legs.first().unwrap();
             assert_eq!(swap.amm, "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
             assert_eq!(
                 swap.input_mint,
@@ -146,25 +204,108 @@ mod tests {
             let test_instance = JupiterParser::new();
             let tx = transaction("3Qd7xWYqpuUbYNGrfSLgaWSsmp7QF9un8qz4whJt87wmPfkxZDo8YhrqHpGBdQZZAqnWAVEq5DnS8B3MbBzuSVX1");
 
-            let Instruction::Swap { swaps, signer } =
-                test_instance.parse(&tx).unwrap().pop().unwrap();
+            let mut result = test_instance.parse(&tx).unwrap();
+            assert_eq!(result.len(), 1);
+
+            let Instruction::Swap { route, signer } = result.pop().unwrap();
             assert_eq!(signer, "5cTNAQEaDgEsR7mseeDtay7tJqcQoDMm3LMS6Rkj9Cm3");
-            assert_eq!(swaps.len(), 4);
+            // SOL -> USDC, 10% through 3NZ9 and USDT, 90% directly
+            assert_eq!(
+                route.input_mint,
+                "So11111111111111111111111111111111111111112"
+            );
+            assert_eq!(route.input_amount, 800000000);
+            assert_eq!(
+                route.output_mint,
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+            );
+            assert_eq!(route.output_amount, 106927126);
+
+            assert_eq!(route.legs.len(), 4);
+        }
+    }
+
+    mod route {
+        use crate::jupiter::model::Jupiter6Swap;
+        use crate::jupiter::parse::route;
+        use base::model::Mint;
+
+        fn leg(
+            input_mint: Mint,
+            input_amount: u64,
+            output_mint: Mint,
+            output_amount: u64,
+        ) -> Jupiter6Swap {
+            Jupiter6Swap {
+                amm: "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc".into(),
+                input_mint,
+                input_amount: input_amount.into(),
+                output_mint,
+                output_amount: output_amount.into(),
+            }
+        }
+
+        #[test]
+        fn test_split_route() {
+            // WSOL -> USDC, split over two AMMs
+            let result = route(vec![
+                leg(Mint::wsol(), 1_000, Mint::usdc(), 150),
+                leg(Mint::wsol(), 3_000, Mint::usdc(), 450),
+            ])
+            .unwrap();
+            assert_eq!(result.input_mint, Mint::wsol());
+            assert_eq!(result.input_amount, 4_000);
+            assert_eq!(result.output_mint, Mint::usdc());
+            assert_eq!(result.output_amount, 600);
+        }
+
+        #[test]
+        fn test_circular_route() {
+            // WSOL -> USDC -> WSOL
+            let result = route(vec![
+                leg(Mint::wsol(), 2_000, Mint::usdc(), 300),
+                leg(Mint::usdc(), 300, Mint::wsol(), 2_100),
+            ])
+            .unwrap();
+            assert_eq!(result.input_mint, Mint::wsol());
+            assert_eq!(result.input_amount, 2_000);
+            assert_eq!(result.output_mint, Mint::wsol());
+            assert_eq!(result.output_amount, 2_100);
+        }
+
+        #[test]
+        fn test_circular_route_through_input_mint() {
+            // WSOL -> USDC -> WSOL -> USDT -> WSOL, the third leg spends what the second leg received
+            let result = route(vec![
+                leg(Mint::wsol(), 2_000, Mint::usdc(), 300),
+                leg(Mint::usdc(), 300, Mint::wsol(), 2_050),
+                leg(Mint::wsol(), 2_050, Mint::usdt(), 310),
+                leg(Mint::usdt(), 310, Mint::wsol(), 2_100),
+            ])
+            .unwrap();
+            assert_eq!(result.input_mint, Mint::wsol());
+            assert_eq!(result.input_amount, 2_000);
+            assert_eq!(result.output_mint, Mint::wsol());
+            assert_eq!(result.output_amount, 2_100);
+            assert_eq!(result.legs.len(), 4);
         }
     }
 
-    mod parse_swaps {
-        use crate::jupiter::parse::parse_swaps;
+    mod parse_routes {
+        use crate::jupiter::parse::parse_routes;
         use crate::jupiter::parse::tests::transaction;
 
         #[test]
         fn test_single_swap() {
             let tx = transaction("4pp3wY3KcAvzV7sL9y1ENtHgV4A43t2tmAzwxuxHRbJAXxbq7CGjjmhtUpd35y76zfKqp5N2mfR9aLHFRC9AZjg2");
 
-            let mut result = parse_swaps(&tx).unwrap();
+            let mut result = parse_routes(&tx).unwrap();
             assert_eq!(result.len(), 1);
 
-            let swap = result.pop().unwrap();
+            let mut route = result.pop().unwrap();
+            assert_eq!(route.legs.len(), 1);
+
+            let swap = route.legs.pop().unwrap();
             assert_eq!(swap.amm, "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
             assert_eq!(
                 swap.input_mint,
@@ -179,10 +320,26 @@ mod tests {
         }
 
         #[test]
-        fn test_many_swaps() {
+        fn test_multi_hop() {
             let tx = transaction("5zyc7NgDiPKuC3KbgwvLr62sDbCkHqXCU2KsgNtBPGcXudP6tikex5sgiRsWrH3fzs5yKQ5JvqsMoV1fYR3GaBX3");
 
-            let result = parse_swaps(&tx).unwrap();
+            let mut routes = parse_routes(&tx).unwrap();
+            assert_eq!(routes.len(), 1);
+
+            // Av6q -> USDC -> BONK (split over two AMMs) -> SOL
+            let route = routes.pop().unwrap();
+            assert_eq!(
+                route.input_mint,
+                "Av6qVigkb7USQyPXJkUvAEm4f599WTRvd75PUWBA9eNm"
+            );
+            assert_eq!(route.input_amount, 7708640607381);
+            assert_eq!(
+                route.output_mint,
+                "So11111111111111111111111111111111111111112"
+            );
+            assert_eq!(route.output_amount, 1014841680);
+
+            let result = route.legs;
             assert_eq!(result.len(), 4);
             assert_eq!(result[0].amm, "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
             assert_eq!(
@@ -5,11 +5,17 @@
 // Original AGPL 3 License Copyright (c) blockworks-foundation 2024.
 
 use crate::jupiter::repo::candle::CandleRepo;
+use base::model::TokenPairId;
 use common::model::Partition;
 use common::repo::{RepoResult, Tx};
+use sqlx::Row;
 
 impl CandleRepo {
-    pub async fn calculate_1m<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_1m<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         aggregate_candle(
             tx,
             1,
@@ -20,7 +26,11 @@ impl CandleRepo {
         .await
     }
 
-    pub async fn calculate_5m<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_5m<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         aggregate_candle(
             tx,
             5,
@@ -31,7 +41,11 @@ impl CandleRepo {
         .await
     }
 
-    pub async fn calculate_15m<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_15m<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         aggregate_candle(
             tx,
             15,
@@ -42,7 +56,11 @@ impl CandleRepo {
         .await
     }
 
-    pub async fn calculate_1h<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_1h<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         aggregate_candle(
             tx,
             1,
@@ -53,7 +71,11 @@ impl CandleRepo {
         .await
     }
 
-    pub async fn calculate_6h<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_6h<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         aggregate_candle(
             tx,
             6,
@@ -63,7 +85,11 @@ impl CandleRepo {
         )
         .await
     }
-    pub async fn calculate_1d<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_1d<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         aggregate_candle(
             tx,
             1,
@@ -81,7 +107,7 @@ async fn aggregate_candle<'a>(
     time_unit: &str,
     source_table: &str,
     destination_table: &str,
-) -> RepoResult<()> {
+) -> RepoResult<Vec<TokenPairId>> {
     let query_str = format!(
         r#"
 with last_candle_price_ts as (
@@ -193,9 +219,14 @@ where (
        {destination_table}.swap_sell is distinct from excluded.swap_sell or
        {destination_table}.volume_sell is distinct from excluded.volume_sell
     )
+returning token_pair_id
         "#
     );
 
-    let _ = sqlx::query(&query_str).execute(&mut **tx).await?;
-    Ok(())
+    Ok(sqlx::query(&query_str)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| r.get::<TokenPairId, _>("token_pair_id"))
+        .collect())
 }
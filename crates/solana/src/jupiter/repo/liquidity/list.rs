@@ -0,0 +1,53 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::jupiter::repo::LiquidityRepo;
+use base::model::{Amm, PublicKey, TokenPairId};
+use common::repo::{RepoResult, Tx};
+use sqlx::Row;
+use std::collections::HashMap;
+
+impl LiquidityRepo {
+    /// Known AMMs the base token of each token pair got swapped on through Jupiter within the last day,
+    /// no matter which quote token the swapped pool had
+    pub async fn list_by_token_pairs<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        token_pairs: impl IntoIterator<Item = impl Into<TokenPairId>> + Send,
+    ) -> RepoResult<HashMap<TokenPairId, Vec<Amm>>> {
+        let ids = token_pairs
+            .into_iter()
+            .map(|id| id.into().0)
+            .collect::<Vec<_>>();
+
+        let rows = sqlx::query(
+            r#"
+select distinct tp.id as token_pair_id, l.amm
+from solana.token_pair tp
+join solana.token_pair lp on lp.base_id = tp.base_id
+join jupiter.swap_leg l on l.token_pair_id = lp.id
+where
+    tp.id = any($1::int8[])
+    and l.amm is not null
+    and l.timestamp > now() - interval '1 day'
+order by tp.id, l.amm;
+"#,
+        )
+        .bind(&ids)
+        .fetch_all(&mut **tx)
+        .await?;
+
+        let mut result: HashMap<TokenPairId, Vec<Amm>> = HashMap::new();
+        for r in rows {
+            let program = r.get::<PublicKey, _>("amm");
+            if let Some(amm) = Amm::from_program(&program) {
+                result
+                    .entry(r.get::<TokenPairId, _>("token_pair_id"))
+                    .or_default()
+                    .push(amm);
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+mod list;
+
+#[derive(Debug, Clone)]
+pub struct LiquidityRepo(pub Arc<LiquidityRepoInner>);
+
+impl Deref for LiquidityRepo {
+    type Target = LiquidityRepoInner;
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+#[derive(Debug)]
+pub struct LiquidityRepoInner {}
+
+impl Default for LiquidityRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiquidityRepo {
+    pub fn new() -> Self {
+        Self(Arc::new(LiquidityRepoInner {}))
+    }
+}
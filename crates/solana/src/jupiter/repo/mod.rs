@@ -2,9 +2,13 @@
 // This file is licensed under the AGPL-3.0-or-later.
 
 pub use candle::{CandleQuery, CandleRepo};
-pub use swap::{ReadSwapRepo, SlotSwap, SlotSwaps, SwapQueryAll, SwapRepo};
+pub use liquidity::LiquidityRepo;
+pub use summary::{SummaryQuery, SummaryRepo};
+pub use swap::{ReadSwapRepo, SlotSwap, SlotSwapLeg, SlotSwaps, SwapQueryAll, SwapRepo};
 pub use twap::{TwapQuery, TwapRepo};
 
 mod candle;
+mod liquidity;
+mod summary;
 mod swap;
 mod twap;
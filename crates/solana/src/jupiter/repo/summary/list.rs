@@ -0,0 +1,153 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::jupiter::model::{Summary, SummarySwap};
+use crate::jupiter::repo::{SummaryQuery, SummaryRepo};
+use base::model::TokenPairId;
+use common::model::{
+    Count, Limit, MarketCapQuote, MarketCapUsd, Percent, PriceQuote, PriceUsd, Timeframe,
+    VolumeQuote,
+};
+use common::repo::{RepoResult, Tx};
+use sqlx::postgres::PgRow;
+use sqlx::{Postgres, QueryBuilder, Row};
+
+impl SummaryRepo {
+    pub async fn list<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        query: SummaryQuery,
+    ) -> RepoResult<Vec<(TokenPairId, Summary)>> {
+        list_summaries(tx, query.timeframe, None, Some(query.limit)).await
+    }
+
+    pub async fn list_by_token_pairs<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        token_pairs: impl IntoIterator<Item = impl Into<TokenPairId>> + Send,
+        timeframe: Timeframe,
+    ) -> RepoResult<Vec<(TokenPairId, Summary)>> {
+        let ids = token_pairs
+            .into_iter()
+            .map(|id| id.into().0)
+            .collect::<Vec<_>>();
+        list_summaries(tx, timeframe, Some(ids), None).await
+    }
+}
+
+/// Only token pairs with a candle inside the timeframe window ending now are listed, so that dormant token pairs
+/// do not report stale prices and swap counts
+async fn list_summaries<'a>(
+    tx: &mut Tx<'a>,
+    timeframe: Timeframe,
+    token_pairs: Option<Vec<i64>>,
+    limit: Option<Limit>,
+) -> RepoResult<Vec<(TokenPairId, Summary)>> {
+    let table = timeframe.table();
+
+    let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(format!(
+        r#"
+with current as (
+    select distinct on (token_pair_id)
+        token_pair_id,
+        timestamp,
+        close,
+        avg,
+        swap_buy,
+        swap_sell,
+        volume_buy + volume_sell as volume
+    from jupiter.candle_{table}
+    where timestamp > now() - interval '{seconds} seconds'
+"#,
+        seconds = timeframe.seconds()
+    ));
+
+    if let Some(token_pairs) = token_pairs {
+        query_builder.push("    and token_pair_id = any(");
+        query_builder.push_bind(token_pairs);
+        query_builder.push(")\n");
+    }
+
+    query_builder.push(format!(
+        r#"    order by token_pair_id, timestamp desc
+)
+select
+    c.token_pair_id,
+
+    c.close as price,
+    u.close as price_usd,
+    c.avg as price_avg,
+    u.avg as price_avg_usd,
+
+    m.close as market_cap,
+    m.close_usd as market_cap_usd,
+
+    c.swap_buy + c.swap_sell as swap,
+    (c.swap_buy + c.swap_sell) - (p.swap_buy + p.swap_sell) as swap_change,
+    (((c.swap_buy + c.swap_sell) - (p.swap_buy + p.swap_sell)) * 100.0 / nullif(p.swap_buy + p.swap_sell, 0))::float4 as swap_percent,
+    c.swap_buy,
+    c.swap_buy - p.swap_buy as swap_buy_change,
+    ((c.swap_buy - p.swap_buy) * 100.0 / nullif(p.swap_buy, 0))::float4 as swap_buy_percent,
+    c.swap_sell,
+    c.swap_sell - p.swap_sell as swap_sell_change,
+    ((c.swap_sell - p.swap_sell) * 100.0 / nullif(p.swap_sell, 0))::float4 as swap_sell_percent,
+
+    c.volume,
+    c.volume - p.volume as volume_change
+from current c
+left join jupiter.candle_usd_{table} u on u.token_pair_id = c.token_pair_id and u.timestamp = c.timestamp
+left join jupiter.candle_market_cap_{table} m on m.token_pair_id = c.token_pair_id and m.timestamp = c.timestamp
+left join lateral (
+    select
+        swap_buy,
+        swap_sell,
+        volume_buy + volume_sell as volume
+    from jupiter.candle_{table}
+    where token_pair_id = c.token_pair_id and timestamp < c.timestamp
+    order by timestamp desc
+    limit 1
+) p on true
+order by c.token_pair_id"#
+    ));
+
+    if let Some(limit) = limit {
+        query_builder.push("\nlimit ");
+        query_builder.push_bind(limit);
+    }
+
+    Ok(query_builder
+        .build()
+        .fetch_all(&mut **tx)
+        .await?
+        .iter()
+        .map(|row| (row.get::<TokenPairId, _>("token_pair_id"), to_summary(row)))
+        .collect::<Vec<_>>())
+}
+
+fn to_summary(row: &PgRow) -> Summary {
+    Summary {
+        price: row.get::<PriceQuote, _>("price"),
+        price_usd: row.try_get::<PriceUsd, _>("price_usd").ok(),
+        price_avg: row.get::<PriceQuote, _>("price_avg"),
+        price_avg_usd: row.try_get::<PriceUsd, _>("price_avg_usd").ok(),
+        market_cap: row.try_get::<MarketCapQuote, _>("market_cap").ok(),
+        market_cap_usd: row.try_get::<MarketCapUsd, _>("market_cap_usd").ok(),
+        swap: SummarySwap {
+            count: row.get::<Count, _>("swap"),
+            change: row.try_get::<Count, _>("swap_change").ok(),
+            percent: row.try_get::<Percent, _>("swap_percent").ok(),
+        },
+        swap_buy: SummarySwap {
+            count: row.get::<Count, _>("swap_buy"),
+            change: row.try_get::<Count, _>("swap_buy_change").ok(),
+            percent: row.try_get::<Percent, _>("swap_buy_percent").ok(),
+        },
+        swap_sell: SummarySwap {
+            count: row.get::<Count, _>("swap_sell"),
+            change: row.try_get::<Count, _>("swap_sell_change").ok(),
+            percent: row.try_get::<Percent, _>("swap_sell_percent").ok(),
+        },
+        volume: row.get::<VolumeQuote, _>("volume"),
+        volume_change: row.try_get::<VolumeQuote, _>("volume_change").ok(),
+    }
+}
@@ -23,4 +23,11 @@ impl ReadSwapRepo {
             .await?
             .get::<Count, _>("count"))
     }
+
+    pub async fn count_swap_leg<'a>(&self, tx: &mut Tx<'a>) -> RepoResult<Count> {
+        Ok(sqlx::query("select count(*) from jupiter.swap_leg;")
+            .fetch_one(&mut **tx)
+            .await?
+            .get::<Count, _>("count"))
+    }
 }
@@ -8,7 +8,7 @@ use crate::jupiter::model::Swap;
 use crate::jupiter::repo::SwapRepo;
 use base::model::solana::{Signature, Slot};
 use base::model::{
-    determine_mints, AddressId, Amount, DecimalAmount, Mint, PublicKey, SwapId, Token, TokenPair,
+    determine_mints, AddressId, Amount, DecimalAmount, Mint, PublicKey, SwapId, TokenPair,
     TokenPairId, TokenPairMint,
 };
 use common::model::{BlockTimestamp, PriceQuote, Timestamp};
@@ -31,9 +31,22 @@ pub struct SlotSwap {
     pub output_amount: Amount,
     pub wallet: PublicKey,
     pub signature: Signature,
+    pub legs: Vec<SlotSwapLeg>,
+}
+
+/// Swap with a single AMM the trade got routed through
+#[derive(Debug)]
+pub struct SlotSwapLeg {
+    pub amm: PublicKey,
+    pub input_mint: Mint,
+    pub input_amount: Amount,
+    pub output_mint: Mint,
+    pub output_amount: Amount,
 }
 
 impl SwapRepo {
+    /// Inserts the trades of the slot, the legs of each trade are inserted separately. A trade which
+    /// starts and ends with the same mint (e.g. arbitrage) is not a trade of any token pair, only its legs are kept
     pub async fn insert_swaps<'a>(
         &self,
         tx: &mut Tx<'a>,
@@ -62,9 +75,18 @@ impl SwapRepo {
             .collect();
 
         let mut token_pairs = Vec::with_capacity(len);
-        for swap in &slot.swaps {
+        for (input_mint, output_mint) in slot.swaps.iter().flat_map(|swap| {
+            std::iter::once((&swap.input_mint, &swap.output_mint)).chain(
+                swap.legs
+                    .iter()
+                    .map(|leg| (&leg.input_mint, &leg.output_mint)),
+            )
+        }) {
+            if input_mint == output_mint {
+                continue;
+            }
             if let Some((base_mint, quote_mint)) =
-                determine_mints(swap.input_mint.clone(), swap.output_mint.clone())
+                determine_mints(input_mint.clone(), output_mint.clone())
             {
                 let pair = (base_mint, quote_mint);
                 if !token_pairs.contains(&pair) {
@@ -96,27 +118,120 @@ impl SwapRepo {
         let mut timestamps = Vec::with_capacity(len);
         let mut signatures = Vec::with_capacity(len);
 
+        let mut leg_slots = vec![];
+        let mut leg_address_ids = vec![];
+        let mut leg_token_pair_ids = vec![];
+        let mut leg_amms = vec![];
+        let mut leg_amount_bases = vec![];
+        let mut leg_amount_quotes = vec![];
+        let mut leg_prices = vec![];
+        let mut leg_is_buys = vec![];
+        let mut leg_timestamps = vec![];
+        let mut leg_signatures = vec![];
+        let mut leg_indexes = vec![];
+
+        // a transaction can hold several trades, their legs are numbered across the whole transaction
+        let mut next_leg_index: HashMap<Signature, i16> = HashMap::new();
+
         for swap in slot.swaps {
-            if let Some((base_mint, quote_mint)) =
-                determine_mints(swap.input_mint.clone(), swap.output_mint.clone())
-            {
-                let token_pair = token_pairs.get(&(base_mint, quote_mint)).unwrap();
-
-                let (price, amount_base, amount_quote, is_buy) =
-                    calculate_amount_and_side(&swap, &token_pair.base, &token_pair.quote);
-
-                slots.push(slot.slot);
-                address_ids.push(keys.get(&swap.wallet).unwrap());
-                token_pair_ids.push(token_pair.id);
-                amount_bases.push(amount_base);
-                amount_quotes.push(amount_quote);
-                prices.push(price);
-                is_buys.push(is_buy);
-                timestamps.push(slot.timestamp);
-                signatures.push(swap.signature);
+            let address_id = keys.get(&swap.wallet).unwrap();
+
+            if swap.input_mint != swap.output_mint {
+                if let Some((base_mint, quote_mint)) =
+                    determine_mints(swap.input_mint.clone(), swap.output_mint.clone())
+                {
+                    let token_pair = token_pairs.get(&(base_mint, quote_mint)).unwrap();
+
+                    let (price, amount_base, amount_quote, is_buy) = calculate_amount_and_side(
+                        &swap.input_mint,
+                        &swap.input_amount,
+                        &swap.output_mint,
+                        &swap.output_amount,
+                        token_pair,
+                    );
+
+                    slots.push(slot.slot);
+                    address_ids.push(address_id);
+                    token_pair_ids.push(token_pair.id);
+                    amount_bases.push(amount_base);
+                    amount_quotes.push(amount_quote);
+                    prices.push(price);
+                    is_buys.push(is_buy);
+                    timestamps.push(slot.timestamp);
+                    signatures.push(swap.signature.clone());
+                }
+            }
+
+            for leg in swap.legs {
+                let leg_index = next_leg_index.entry(swap.signature.clone()).or_insert(0);
+                let index = *leg_index;
+                *leg_index += 1;
+
+                if leg.input_mint == leg.output_mint {
+                    continue;
+                }
+                if let Some((base_mint, quote_mint)) =
+                    determine_mints(leg.input_mint.clone(), leg.output_mint.clone())
+                {
+                    let token_pair = token_pairs.get(&(base_mint, quote_mint)).unwrap();
+
+                    let (price, amount_base, amount_quote, is_buy) = calculate_amount_and_side(
+                        &leg.input_mint,
+                        &leg.input_amount,
+                        &leg.output_mint,
+                        &leg.output_amount,
+                        token_pair,
+                    );
+
+                    leg_slots.push(slot.slot);
+                    leg_address_ids.push(address_id);
+                    leg_token_pair_ids.push(token_pair.id);
+                    leg_amms.push(leg.amm);
+                    leg_amount_bases.push(amount_base);
+                    leg_amount_quotes.push(amount_quote);
+                    leg_prices.push(price);
+                    leg_is_buys.push(is_buy);
+                    leg_timestamps.push(slot.timestamp);
+                    leg_signatures.push(swap.signature.clone());
+                    leg_indexes.push(index);
+                }
             }
         }
 
+        sqlx::query(
+            r#"
+insert into jupiter.swap_leg (
+    slot, address_id, token_pair_id, amm, amount_base, amount_quote, price, is_buy, timestamp, signature, leg_index
+)
+select
+    unnest($1::int8[]) as slot,
+    unnest($2::int8[]) as address_id,
+    unnest($3::int8[]) as token_pair_id,
+    unnest($4::text[]) as amm,
+    unnest($5::numeric(36, 12)[]) as amount_base,
+    unnest($6::numeric(36, 12)[]) as amount_quote,
+    unnest($7::numeric(36, 12)[]) as price,
+    unnest($8::boolean[]) as is_buy,
+    unnest($9::timestamptz[]) as timestamp,
+    unnest($10::text[]) as signature,
+    unnest($11::int2[]) as leg_index
+on conflict (signature, leg_index) do nothing;
+            "#,
+        )
+        .bind(&leg_slots)
+        .bind(&leg_address_ids)
+        .bind(&leg_token_pair_ids)
+        .bind(&leg_amms)
+        .bind(&leg_amount_bases)
+        .bind(&leg_amount_quotes)
+        .bind(&leg_prices)
+        .bind(&leg_is_buys)
+        .bind(&leg_timestamps)
+        .bind(&leg_signatures)
+        .bind(&leg_indexes)
+        .execute(&mut **tx)
+        .await?;
+
         sqlx::query(
             r#"
 insert into jupiter.micro_swap (
@@ -206,37 +321,42 @@ returning id, slot, address_id, token_pair_id, amount_base, amount_quote, price,
 }
 
 fn calculate_amount_and_side(
-    swap: &SlotSwap,
-    base_token: &Token,
-    quote_token: &Token,
+    input_mint: &Mint,
+    input_amount: &Amount,
+    output_mint: &Mint,
+    output_amount: &Amount,
+    token_pair: &TokenPair,
 ) -> (PriceQuote, DecimalAmount, DecimalAmount, bool) {
-    let input_decimals = if swap.input_mint == base_token.mint {
+    let base_token = &token_pair.base;
+    let quote_token = &token_pair.quote;
+
+    let input_decimals = if *input_mint == base_token.mint {
         &base_token.decimals
     } else {
         &quote_token.decimals
     };
-    let output_decimals = if swap.output_mint == base_token.mint {
+    let output_decimals = if *output_mint == base_token.mint {
         &base_token.decimals
     } else {
         &quote_token.decimals
     };
 
-    let input_amount = DecimalAmount::new(swap.input_amount.clone(), input_decimals.clone());
-    let output_amount = DecimalAmount::new(swap.output_amount.clone(), output_decimals.clone());
+    let input_amount = DecimalAmount::new(input_amount.clone(), *input_decimals);
+    let output_amount = DecimalAmount::new(output_amount.clone(), *output_decimals);
 
-    let amount_base = if swap.input_mint == base_token.mint {
+    let amount_base = if *input_mint == base_token.mint {
         input_amount.clone()
     } else {
         output_amount.clone()
     };
 
-    let amount_quote = if swap.output_mint == base_token.mint {
+    let amount_quote = if *output_mint == base_token.mint {
         input_amount.clone()
     } else {
         output_amount.clone()
     };
 
-    if swap.input_mint == base_token.mint {
+    if *input_mint == base_token.mint {
         (
             PriceQuote(output_amount.0 / input_amount.0),
             amount_base,
@@ -1,10 +1,10 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use crate::jupiter::model::Swap;
+use crate::jupiter::model::{Swap, SwapLeg};
 use crate::jupiter::repo::ReadSwapRepo;
 use base::model::solana::{Signature, Slot};
-use base::model::{AddressId, DecimalAmount, SwapId, TokenPairId};
+use base::model::{AddressId, DecimalAmount, PublicKey, SwapId, TokenPairId};
 use common::model::{PriceQuote, Timestamp};
 use common::repo::{RepoResult, Tx};
 use sqlx::Row;
@@ -104,4 +104,31 @@ impl ReadSwapRepo {
         )
     }
 
+    pub async fn list_legs_with_signature<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        signature: impl Into<Signature>,
+    ) -> RepoResult<Vec<SwapLeg>> {
+        Ok(
+            sqlx::query("select * from jupiter.swap_leg where signature = $1 order by id;")
+                .bind(signature.into())
+                .fetch_all(&mut **tx)
+                .await?
+                .iter()
+                .map(|r| SwapLeg {
+                    id: r.get::<SwapId, _>("id"),
+                    slot: r.get::<Slot, _>("slot"),
+                    address: r.get::<AddressId, _>("address_id"),
+                    token_pair: r.get::<TokenPairId, _>("token_pair_id"),
+                    amm: r.get::<Option<PublicKey>, _>("amm"),
+                    amount_base: r.get::<DecimalAmount, _>("amount_base"),
+                    amount_quote: r.get::<DecimalAmount, _>("amount_quote"),
+                    price: r.get::<PriceQuote, _>("price"),
+                    is_buy: r.get::<bool, _>("is_buy"),
+                    timestamp: r.get::<Timestamp, _>("timestamp"),
+                    signature: r.get::<Signature, _>("signature"),
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
 }
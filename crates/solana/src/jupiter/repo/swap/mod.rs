@@ -4,13 +4,14 @@
 use base::repo::{AddressRepo, TokenPairRepo, TokenRepo};
 use base::LoadTokenInfo;
 use common::model::Limit;
-pub use insert::{SlotSwap, SlotSwaps};
+pub use insert::{SlotSwap, SlotSwapLeg, SlotSwaps};
 use std::ops::Deref;
 use std::sync::Arc;
 
 mod count;
 mod insert;
 mod list;
+mod rollback;
 
 pub struct SwapQueryAll {
     pub limit: Limit,
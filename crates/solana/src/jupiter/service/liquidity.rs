@@ -0,0 +1,49 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::jupiter::repo::LiquidityRepo;
+use base::model::{Amm, TokenPairId};
+use common::service::ServiceResult;
+use sqlx::PgPool;
+use std::ops::Deref;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct LiquidityService(pub Arc<LiquidityServiceInner>);
+
+impl Deref for LiquidityService {
+    type Target = LiquidityServiceInner;
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+pub struct LiquidityServiceInner {
+    pool: PgPool,
+    liquidity: LiquidityRepo,
+}
+
+impl LiquidityService {
+    pub fn new(pool: PgPool, liquidity_repo: LiquidityRepo) -> Self {
+        Self(Arc::new(LiquidityServiceInner {
+            pool,
+            liquidity: liquidity_repo,
+        }))
+    }
+
+    pub fn testing(pool: PgPool) -> Self {
+        Self::new(pool, LiquidityRepo::new())
+    }
+
+    /// Known AMMs the token pair got recently traded on, empty if none are known
+    pub async fn list(&self, token_pair: impl Into<TokenPairId> + Send) -> ServiceResult<Vec<Amm>> {
+        let token_pair = token_pair.into();
+        let mut tx = self.pool.begin().await?;
+        let mut result = self
+            .liquidity
+            .list_by_token_pairs(&mut tx, vec![token_pair])
+            .await?;
+        tx.commit().await?;
+        Ok(result.remove(&token_pair).unwrap_or_default())
+    }
+}
@@ -0,0 +1,6 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+pub use liquidity::LiquidityService;
+
+mod liquidity;
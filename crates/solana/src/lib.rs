@@ -5,6 +5,7 @@
 
 pub use chain::*;
 
+pub mod backtest;
 mod chain;
 pub mod jupiter;
 pub mod parse;
@@ -27,13 +27,13 @@ pub(crate) mod seeds {
 }
 
 pub(crate) mod accounts {
-    // use solana_sdk::{pubkey, pubkey::Pubkey};
+    use solana_sdk::{pubkey, pubkey::Pubkey};
 
-    // pub(crate) const PUMPFUN: Pubkey = pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+    pub(crate) const PUMPFUN: Pubkey = pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
     // pub(crate) const MPL_TOKEN_METADATA: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
-    // pub(crate) const EVENT_AUTHORITY: Pubkey = pubkey!("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1");
-    // pub(crate) const SYSTEM_PROGRAM: Pubkey = pubkey!("11111111111111111111111111111111");
-    // pub(crate) const TOKEN_PROGRAM: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-    // pub(crate) const ASSOCIATED_TOKEN_PROGRAM: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
-    // pub(crate) const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+    pub(crate) const EVENT_AUTHORITY: Pubkey = pubkey!("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1");
+    pub(crate) const SYSTEM_PROGRAM: Pubkey = pubkey!("11111111111111111111111111111111");
+    pub(crate) const TOKEN_PROGRAM: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    pub(crate) const ASSOCIATED_TOKEN_PROGRAM: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+    pub(crate) const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
 }
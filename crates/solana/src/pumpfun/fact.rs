@@ -0,0 +1,407 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::pumpfun::model::summary::TimeframeSummary;
+use base::model::{Fact, Facts, Value};
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
+use common::model::Timeframe;
+use Fact::{
+    CurveProgressAvgPercentAggregate, CurveProgressChangePercentAggregate,
+    CurveProgressClosePercentAggregate, CurveProgressHighPercentAggregate,
+    CurveProgressLowPercentAggregate, CurveProgressOpenPercentAggregate,
+    MarketCapChangePercentAggregate, MarketCapChangeQuoteAggregate, MarketCapChangeUsdAggregate,
+    MarketCapQuoteAggregate, MarketCapSolAggregate, MarketCapUsdAggregate, PriceAvgQuoteAggregate,
+    PriceAvgUsdAggregate, PriceChangePercentAggregate, PriceChangeQuoteAggregate,
+    PriceChangeUsdAggregate, PriceCloseQuoteAggregate, PriceCloseUsdAggregate,
+    PriceHighQuoteAggregate, PriceHighUsdAggregate, PriceLowQuoteAggregate, PriceLowUsdAggregate,
+    PriceOpenQuoteAggregate, PriceOpenUsdAggregate, SwapAllChangeAggregate, SwapAllCountAggregate,
+    SwapAllPercentAggregate, SwapBuyChangeAggregate, SwapBuyCountAggregate,
+    SwapBuyPercentAggregate, SwapSellChangeAggregate, SwapSellCountAggregate,
+    SwapSellPercentAggregate, VolumeBuyChangePercentAggregate, VolumeBuyChangeQuoteAggregate,
+    VolumeBuyChangeUsdAggregate, VolumeBuyQuoteAggregate, VolumeBuyUsdAggregate,
+    VolumeChangePercentAggregate, VolumeChangeQuoteAggregate, VolumeChangeUsdAggregate,
+    VolumeQuoteAggregate, VolumeSellBuyRatioPercentAggregate, VolumeSellChangePercentAggregate,
+    VolumeSellChangeQuoteAggregate, VolumeSellChangeUsdAggregate, VolumeSellQuoteAggregate,
+    VolumeSellUsdAggregate, VolumeUsdAggregate,
+};
+
+/// Maps the summary of a timeframe onto the aggregate facts of that timeframe
+pub fn add_summary_to_facts(facts: &mut Facts, summary: TimeframeSummary, timeframe: Timeframe) {
+    for (progress, fact) in [
+        (&summary.curve.open, CurveProgressOpenPercentAggregate),
+        (&summary.curve.high, CurveProgressHighPercentAggregate),
+        (&summary.curve.low, CurveProgressLowPercentAggregate),
+        (&summary.curve.close, CurveProgressClosePercentAggregate),
+        (&summary.curve.avg, CurveProgressAvgPercentAggregate),
+    ] {
+        if let Some(value) = progress.progress {
+            facts.set_timeframe_value(fact, Value::percent(value.0), timeframe);
+        }
+    }
+
+    if let Some(change) = summary.curve.close.change {
+        facts.set_timeframe_value(
+            CurveProgressChangePercentAggregate,
+            Value::percent(change.0),
+            timeframe,
+        );
+    }
+
+    let cap = summary.cap.close;
+    if let Some(quote) = cap.quote {
+        facts.set_timeframe_value(
+            MarketCapQuoteAggregate,
+            Value::quote(quote.0.clone()),
+            timeframe,
+        );
+
+        facts.set_timeframe_value(MarketCapSolAggregate, Value::sol(quote.0), timeframe);
+    }
+
+    if let Some(usd) = cap.usd {
+        facts.set_timeframe_value(MarketCapUsdAggregate, Value::usd(usd.0), timeframe);
+    }
+
+    if let Some(change) = cap.quote_change {
+        facts.set_timeframe_value(
+            MarketCapChangeQuoteAggregate,
+            Value::quote(change.0),
+            timeframe,
+        );
+    }
+
+    if let Some(change) = cap.usd_change {
+        facts.set_timeframe_value(MarketCapChangeUsdAggregate, Value::usd(change.0), timeframe);
+    }
+
+    if let Some(percent) = cap.percent {
+        facts.set_timeframe_value(
+            MarketCapChangePercentAggregate,
+            Value::percent(percent.0),
+            timeframe,
+        );
+    }
+
+    for (price, quote, usd) in [
+        (
+            &summary.price.open,
+            PriceOpenQuoteAggregate,
+            PriceOpenUsdAggregate,
+        ),
+        (
+            &summary.price.high,
+            PriceHighQuoteAggregate,
+            PriceHighUsdAggregate,
+        ),
+        (
+            &summary.price.low,
+            PriceLowQuoteAggregate,
+            PriceLowUsdAggregate,
+        ),
+        (
+            &summary.price.close,
+            PriceCloseQuoteAggregate,
+            PriceCloseUsdAggregate,
+        ),
+        (
+            &summary.price.avg,
+            PriceAvgQuoteAggregate,
+            PriceAvgUsdAggregate,
+        ),
+    ] {
+        if let Some(value) = &price.quote {
+            facts.set_timeframe_value(quote, Value::quote(value.0.clone()), timeframe);
+        }
+
+        if let Some(value) = &price.usd {
+            facts.set_timeframe_value(usd, Value::usd(value.0.clone()), timeframe);
+        }
+    }
+
+    let price = summary.price.close;
+    if let Some(change) = price.quote_change {
+        facts.set_timeframe_value(PriceChangeQuoteAggregate, Value::quote(change.0), timeframe);
+    }
+
+    if let Some(change) = price.usd_change {
+        facts.set_timeframe_value(PriceChangeUsdAggregate, Value::usd(change.0), timeframe);
+    }
+
+    if let Some(percent) = price.percent {
+        facts.set_timeframe_value(
+            PriceChangePercentAggregate,
+            Value::percent(percent.0),
+            timeframe,
+        );
+    }
+
+    for (swap, count, change, percent) in [
+        (
+            summary.swap.all,
+            SwapAllCountAggregate,
+            SwapAllChangeAggregate,
+            SwapAllPercentAggregate,
+        ),
+        (
+            summary.swap.buy,
+            SwapBuyCountAggregate,
+            SwapBuyChangeAggregate,
+            SwapBuyPercentAggregate,
+        ),
+        (
+            summary.swap.sell,
+            SwapSellCountAggregate,
+            SwapSellChangeAggregate,
+            SwapSellPercentAggregate,
+        ),
+    ] {
+        if let Some(value) = swap.count {
+            facts.set_timeframe_value(count, value, timeframe);
+        }
+
+        if let Some(value) = swap.change {
+            facts.set_timeframe_value(change, value, timeframe);
+        }
+
+        if let Some(value) = swap.percent {
+            facts.set_timeframe_value(percent, Value::percent(value.0), timeframe);
+        }
+    }
+
+    if let (Some(sell), Some(buy)) = (&summary.volume.sell.quote, &summary.volume.buy.quote) {
+        if buy.0 > BigDecimal::zero() {
+            if let Some(ratio) = (&sell.0 * BigDecimal::from(100) / &buy.0).to_f32() {
+                facts.set_timeframe_value(
+                    VolumeSellBuyRatioPercentAggregate,
+                    Value::percent(ratio),
+                    timeframe,
+                );
+            }
+        }
+    }
+
+    for (volume, quote, usd, quote_change, usd_change, percent) in [
+        (
+            summary.volume.all,
+            VolumeQuoteAggregate,
+            VolumeUsdAggregate,
+            VolumeChangeQuoteAggregate,
+            VolumeChangeUsdAggregate,
+            VolumeChangePercentAggregate,
+        ),
+        (
+            summary.volume.buy,
+            VolumeBuyQuoteAggregate,
+            VolumeBuyUsdAggregate,
+            VolumeBuyChangeQuoteAggregate,
+            VolumeBuyChangeUsdAggregate,
+            VolumeBuyChangePercentAggregate,
+        ),
+        (
+            summary.volume.sell,
+            VolumeSellQuoteAggregate,
+            VolumeSellUsdAggregate,
+            VolumeSellChangeQuoteAggregate,
+            VolumeSellChangeUsdAggregate,
+            VolumeSellChangePercentAggregate,
+        ),
+    ] {
+        if let Some(value) = volume.quote {
+            facts.set_timeframe_value(quote, Value::quote(value.0), timeframe);
+        }
+
+        if let Some(value) = volume.usd {
+            facts.set_timeframe_value(usd, Value::usd(value.0), timeframe);
+        }
+
+        if let Some(value) = volume.quote_change {
+            facts.set_timeframe_value(quote_change, Value::quote(value.0), timeframe);
+        }
+
+        if let Some(value) = volume.usd_change {
+            facts.set_timeframe_value(usd_change, Value::usd(value.0), timeframe);
+        }
+
+        if let Some(value) = volume.percent {
+            facts.set_timeframe_value(percent, Value::percent(value.0), timeframe);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pumpfun::model::summary::{
+        MarketCapWithChange, PriceWithChange, ProgressWithChange, SummaryCurveProgress,
+        SummaryMarketCap, SummaryPrice, SummarySwap, SummaryVolume, SwapWithChange,
+        VolumeWithChange,
+    };
+    use common::model::Timeframe::M5;
+    use common::model::{Count, Percent, VolumeQuote};
+
+    fn progress() -> ProgressWithChange {
+        ProgressWithChange {
+            progress: None,
+            change: None,
+        }
+    }
+
+    fn cap() -> MarketCapWithChange {
+        MarketCapWithChange {
+            quote: None,
+            usd: None,
+            quote_change: None,
+            usd_change: None,
+            percent: None,
+        }
+    }
+
+    fn price() -> PriceWithChange {
+        PriceWithChange {
+            quote: None,
+            usd: None,
+            quote_change: None,
+            usd_change: None,
+            percent: None,
+        }
+    }
+
+    fn swap(count: i64, change: i64) -> SwapWithChange {
+        SwapWithChange {
+            count: Some(Count(count)),
+            change: Some(Count(change)),
+            percent: None,
+        }
+    }
+
+    fn volume(quote: i64) -> VolumeWithChange {
+        VolumeWithChange {
+            quote: Some(VolumeQuote::from(quote)),
+            usd: None,
+            quote_change: None,
+            usd_change: None,
+            percent: None,
+        }
+    }
+
+    fn summary() -> TimeframeSummary {
+        TimeframeSummary {
+            curve: SummaryCurveProgress {
+                open: progress(),
+                high: progress(),
+                low: progress(),
+                close: ProgressWithChange {
+                    progress: Some(Percent(42.0)),
+                    change: Some(Percent(12.0)),
+                },
+                avg: progress(),
+            },
+            cap: SummaryMarketCap {
+                open: cap(),
+                high: cap(),
+                low: cap(),
+                close: cap(),
+                avg: cap(),
+            },
+            price: SummaryPrice {
+                open: price(),
+                high: price(),
+                low: price(),
+                close: PriceWithChange {
+                    percent: Some(Percent(30.0)),
+                    ..price()
+                },
+                avg: price(),
+            },
+            swap: SummarySwap {
+                all: swap(15, 1),
+                buy: swap(10, 3),
+                sell: swap(5, -2),
+            },
+            volume: SummaryVolume {
+                all: volume(120),
+                buy: volume(100),
+                sell: volume(20),
+            },
+        }
+    }
+
+    #[test]
+    fn test_swap_change_does_not_overwrite_count() {
+        let mut facts = Facts::new();
+        add_summary_to_facts(&mut facts, summary(), M5);
+
+        assert_eq!(
+            facts.get_with_timeframe(&SwapBuyCountAggregate, &M5),
+            Some(&Value::count(10))
+        );
+        assert_eq!(
+            facts.get_with_timeframe(&SwapBuyChangeAggregate, &M5),
+            Some(&Value::count(3))
+        );
+        assert_eq!(
+            facts.get_with_timeframe(&SwapSellCountAggregate, &M5),
+            Some(&Value::count(5))
+        );
+        assert_eq!(
+            facts.get_with_timeframe(&SwapSellChangeAggregate, &M5),
+            Some(&Value::count(-2))
+        );
+    }
+
+    #[test]
+    fn test_price_and_curve_progress() {
+        let mut facts = Facts::new();
+        add_summary_to_facts(&mut facts, summary(), M5);
+
+        assert_eq!(
+            facts.get_with_timeframe(&PriceChangePercentAggregate, &M5),
+            Some(&Value::percent(30.0))
+        );
+        assert_eq!(
+            facts.get_with_timeframe(&CurveProgressClosePercentAggregate, &M5),
+            Some(&Value::percent(42.0))
+        );
+        assert_eq!(
+            facts.get_with_timeframe(&CurveProgressChangePercentAggregate, &M5),
+            Some(&Value::percent(12.0))
+        );
+        assert_eq!(
+            facts.get_with_timeframe(&CurveProgressOpenPercentAggregate, &M5),
+            None
+        );
+    }
+
+    #[test]
+    fn test_volume() {
+        let mut facts = Facts::new();
+        add_summary_to_facts(&mut facts, summary(), M5);
+
+        assert_eq!(
+            facts.get_with_timeframe(&VolumeBuyQuoteAggregate, &M5),
+            Some(&Value::quote(100))
+        );
+        assert_eq!(
+            facts.get_with_timeframe(&VolumeSellQuoteAggregate, &M5),
+            Some(&Value::quote(20))
+        );
+        assert_eq!(
+            facts.get_with_timeframe(&VolumeSellBuyRatioPercentAggregate, &M5),
+            Some(&Value::percent(20.0))
+        );
+    }
+
+    #[test]
+    fn test_volume_ratio_without_buy_volume() {
+        let mut summary = summary();
+        summary.volume.buy = volume(0);
+
+        let mut facts = Facts::new();
+        add_summary_to_facts(&mut facts, summary, M5);
+
+        assert_eq!(
+            facts.get_with_timeframe(&VolumeSellBuyRatioPercentAggregate, &M5),
+            None
+        );
+    }
+}
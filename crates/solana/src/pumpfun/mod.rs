@@ -5,33 +5,62 @@
 // Original MIT License Copyright (c) nhuxhr 2024.
 
 pub(crate) mod constant;
+pub mod fact;
 pub mod model;
 pub mod parse;
 pub mod repo;
 pub mod rpc;
 pub mod service;
-mod tx;
+pub mod tx;
 pub(crate) mod util;
 
 pub use crate::pumpfun::parse::PumpFunParser;
+use crate::rpc::{RpcClient, RpcClientError};
 pub use rpc::Rpc;
 use std::fmt::{Debug, Display, Formatter};
 
-pub struct Pumpfun {}
+#[derive(Clone)]
+pub struct Pumpfun {
+    rpc: Rpc,
+}
+
+impl Pumpfun {
+    pub fn new(client: RpcClient) -> Self {
+        Self {
+            rpc: Rpc::new(client),
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum PumpfunError {
     CurveCompleted,
+    CurveNotFound,
+    GlobalNotFound,
+    NothingToSell,
+    Rpc(RpcClientError),
+    Transaction(String),
 }
 
 impl Display for PumpfunError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             PumpfunError::CurveCompleted => f.write_str("Curve already completed"),
+            PumpfunError::CurveNotFound => f.write_str("Curve not found"),
+            PumpfunError::GlobalNotFound => f.write_str("Global account not found"),
+            PumpfunError::NothingToSell => f.write_str("Nothing to sell"),
+            PumpfunError::Rpc(err) => f.write_fmt(format_args!("{err}")),
+            PumpfunError::Transaction(msg) => f.write_fmt(format_args!("transaction error: {msg}")),
         }
     }
 }
 
+impl From<RpcClientError> for PumpfunError {
+    fn from(value: RpcClientError) -> Self {
+        PumpfunError::Rpc(value)
+    }
+}
+
 impl std::error::Error for PumpfunError {}
 
 pub type PumpfunResult<T> = std::result::Result<T, PumpfunError>;
@@ -4,7 +4,8 @@
 use base::model::solana::Slot;
 use base::model::{Amount, TokenPairId};
 use common::model::{
-    AgeRelativeToLatestInSeconds, MarketCapQuote, MarketCapUsd, Percent, PriceQuote, PriceUsd,
+    AgeInSeconds, AgeRelativeToLatestInSeconds, MarketCapQuote, MarketCapUsd, Percent, PriceQuote,
+    PriceUsd, Timestamp,
 };
 
 #[derive(Debug, Clone)]
@@ -19,5 +20,14 @@ pub struct Current {
     pub market_cap: Option<MarketCapQuote>,
     pub market_cap_usd: Option<MarketCapUsd>,
     pub complete: bool,
+    /// Time the bonding curve completed and the liquidity got migrated away from pump.fun
+    pub graduated_at: Option<Timestamp>,
     pub age: AgeRelativeToLatestInSeconds,
 }
+
+impl Current {
+    pub fn graduation_age(&self) -> Option<AgeInSeconds> {
+        self.graduated_at
+            .map(|time| AgeInSeconds(Timestamp::now().to_epoch_seconds() - time.to_epoch_seconds()))
+    }
+}
@@ -24,4 +24,15 @@ pub enum Instruction {
         virtual_sol_reserves: Amount,
         virtual_token_reserves: Amount,
     },
+    /// The bonding curve reached 100% and no longer accepts swaps
+    Complete {
+        user: PublicKey,
+        mint: Mint,
+        bonding_curve: PublicKey,
+        timestamp: Timestamp,
+    },
+    /// The liquidity of a completed bonding curve was withdrawn to be migrated to Raydium
+    Withdraw { mint: Mint },
+    /// The liquidity of a completed bonding curve was migrated to the pump.fun AMM
+    Migrate { mint: Mint },
 }
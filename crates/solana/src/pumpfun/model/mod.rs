@@ -4,10 +4,12 @@
 pub use current::Current;
 pub use curve::*;
 pub use instruction::*;
+pub use submitted::*;
 pub use swap::*;
 
 mod current;
 mod curve;
 mod instruction;
+mod submitted;
 pub mod summary;
 mod swap;
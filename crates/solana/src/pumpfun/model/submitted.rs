@@ -0,0 +1,14 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::solana::Signature;
+
+/// Swap transaction which was accepted by the rpc node
+#[derive(Debug, Clone)]
+pub struct SubmittedSwap {
+    pub signature: Signature,
+    /// Expected amount of SOL in lamports, spent for a buy and received for a sell
+    pub sol_amount: u64,
+    /// Expected amount of tokens in base units, received for a buy and spent for a sell
+    pub token_amount: u64,
+}
@@ -6,6 +6,7 @@ use base::model::TokenPair;
 use common::model::{
     Count, MarketCapQuote, MarketCapUsd, Percent, PriceQuote, PriceUsd, VolumeQuote, VolumeUsd,
 };
+use serde::Serialize;
 
 #[derive(Clone, Debug)]
 pub struct PumpfunSummary {
@@ -19,7 +20,7 @@ pub struct PumpfunSummary {
     pub d1: Option<TimeframeSummary>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct TimeframeSummary {
     pub curve: SummaryCurveProgress,
     pub cap: SummaryMarketCap,
@@ -28,7 +29,7 @@ pub struct TimeframeSummary {
     pub volume: SummaryVolume,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SummaryCurveProgress {
     pub open: ProgressWithChange,
     pub high: ProgressWithChange,
@@ -37,7 +38,7 @@ pub struct SummaryCurveProgress {
     pub avg: ProgressWithChange,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SummaryMarketCap {
     pub open: MarketCapWithChange,
     pub high: MarketCapWithChange,
@@ -46,7 +47,7 @@ pub struct SummaryMarketCap {
     pub avg: MarketCapWithChange,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SummaryPrice {
     pub open: PriceWithChange,
     pub high: PriceWithChange,
@@ -55,21 +56,22 @@ pub struct SummaryPrice {
     pub avg: PriceWithChange,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SummarySwap {
     pub all: SwapWithChange,
     pub buy: SwapWithChange,
     pub sell: SwapWithChange,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SummaryVolume {
     pub all: VolumeWithChange,
     pub buy: VolumeWithChange,
     pub sell: VolumeWithChange,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MarketCapWithChange {
     pub quote: Option<MarketCapQuote>,
     pub usd: Option<MarketCapUsd>,
@@ -78,7 +80,8 @@ pub struct MarketCapWithChange {
     pub percent: Option<Percent>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PriceWithChange {
     pub quote: Option<PriceQuote>,
     pub usd: Option<PriceUsd>,
@@ -87,20 +90,21 @@ pub struct PriceWithChange {
     pub percent: Option<Percent>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ProgressWithChange {
     pub progress: Option<Percent>,
     pub change: Option<Percent>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SwapWithChange {
     pub count: Option<Count>,
     pub change: Option<Count>,
     pub percent: Option<Percent>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VolumeWithChange {
     pub quote: Option<VolumeQuote>,
     pub usd: Option<VolumeUsd>,
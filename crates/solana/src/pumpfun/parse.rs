@@ -1,8 +1,9 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
-use base::model::solana::Transaction;
+use base::model::solana::{CompiledInstruction, Transaction};
 use crate::parse::{log_and_return_parse_error, ParseError, ParseResult, Parser};
+use crate::pumpfun::constant::accounts::PUMPFUN;
 use crate::pumpfun::model::Instruction;
 use crate::token_info::sanitize_value;
 use base::model::{Mint, PublicKey};
@@ -54,16 +55,51 @@ impl Parser<Vec<Instruction>> for PumpFunParser {
                             }
                             Ok(instr) => result.push(instr),
                         }
+                    } else if disc == COMPLETE_DISCRIMINANT {
+                        match parse_complete(&reader) {
+                            Err(err) => {
+                                return Err(log_and_return_parse_error(
+                                    err,
+                                    &tx.signature,
+                                    "complete",
+                                ))
+                            }
+                            Ok(instr) => result.push(instr),
+                        }
                     }
                 }
             }
         }
+
+        let instructions = tx
+            .instructions
+            .iter()
+            .chain(tx.inner_instructions.iter().flat_map(|inner| {
+                inner
+                    .instructions
+                    .iter()
+                    .map(|instruction| &instruction.instruction)
+            }));
+
+        for instruction in instructions {
+            if let Some(instr) = parse_migration(tx, instruction) {
+                result.push(instr);
+            }
+        }
+
         Ok(result)
     }
 }
 
 const CREATE_DISCRIMINANT: [u8; 8] = [27, 114, 169, 77, 222, 235, 99, 118];
 const SWAP_DISCRIMINANT: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
+const COMPLETE_DISCRIMINANT: [u8; 8] = [95, 114, 97, 156, 212, 46, 152, 8];
+
+const WITHDRAW_DISCRIMINANT: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+const MIGRATE_DISCRIMINANT: [u8; 8] = [155, 234, 231, 146, 236, 158, 162, 30];
+
+/// Index of the mint within the accounts of the withdraw and migrate instruction
+const MIGRATION_MINT_ACCOUNT: usize = 2;
 
 fn parse_create(reader: &ByteReader) -> ParseResult<Instruction> {
     Ok(Instruction::Create {
@@ -107,11 +143,60 @@ fn parse_swap(reader: &ByteReader) -> ParseResult<Instruction> {
     })
 }
 
+fn parse_complete(reader: &ByteReader) -> ParseResult<Instruction> {
+    Ok(Instruction::Complete {
+        user: Pubkey::try_from(reader.read_range(32)?)
+            .map_err(|_| ParseError::DecodingFailed)?
+            .into(),
+        mint: Pubkey::try_from(reader.read_range(32)?)
+            .map_err(|_| ParseError::DecodingFailed)?
+            .into(),
+        bonding_curve: Pubkey::try_from(reader.read_range(32)?)
+            .map_err(|_| ParseError::DecodingFailed)?
+            .into(),
+        timestamp: Timestamp::from_epoch_second(reader.read_u64()? as i64)
+            .map_err(|_| ParseError::DecodingFailed)?,
+    })
+}
+
+/// Withdraw and migrate are plain instructions of the pump.fun program, which are either
+/// sent directly or invoked by the migration program
+fn parse_migration(tx: &Transaction, instruction: &CompiledInstruction) -> Option<Instruction> {
+    let program = tx.keys.account(instruction.program_id_index)?;
+    if *program != PublicKey::from(PUMPFUN) {
+        return None;
+    }
+
+    let disc = instruction.data.get(0..8)?;
+    if disc != WITHDRAW_DISCRIMINANT && disc != MIGRATE_DISCRIMINANT {
+        return None;
+    }
+
+    let mint = instruction
+        .accounts
+        .get(MIGRATION_MINT_ACCOUNT)
+        .and_then(|idx| tx.keys.account(*idx))
+        .map(|key| Mint::from(key.to_string()))?;
+
+    if disc == WITHDRAW_DISCRIMINANT {
+        Some(Instruction::Withdraw { mint })
+    } else {
+        Some(Instruction::Migrate { mint })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::convert::convert_transaction;
-    use base::model::solana::Transaction;
     use crate::parse::Parser;
+    use crate::pumpfun::constant::accounts::PUMPFUN;
+    use base::model::solana::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Keys, Transaction,
+        TransactionBalance, TransactionStatus,
+    };
+    use base::model::PublicKey;
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
     use crate::pumpfun::model::Instruction;
     use crate::pumpfun::PumpFunParser;
     use lazy_static::lazy_static;
@@ -236,6 +321,130 @@ mod tests {
         assert_eq!(virtual_token_reserves, 333538337869300);
     }
 
+    #[test]
+    fn test_complete() {
+        let test_instance = PumpFunParser::new();
+
+        let mut data = vec![228, 69, 165, 46, 81, 203, 154, 29]; // anchor event instruction
+        data.extend([95, 114, 97, 156, 212, 46, 152, 8]);
+        data.extend(Pubkey::from_str(USER).unwrap().to_bytes());
+        data.extend(Pubkey::from_str(MINT).unwrap().to_bytes());
+        data.extend(Pubkey::from_str(BONDING_CURVE).unwrap().to_bytes());
+        data.extend(1738463019i64.to_le_bytes());
+
+        let tx = migration_transaction(vec![], vec![data]);
+
+        let mut result = test_instance.parse(&tx).unwrap();
+        assert_eq!(result.len(), 1);
+
+        let Instruction::Complete {
+            user,
+            mint,
+            bonding_curve,
+            timestamp,
+        } = result.pop().unwrap()
+        else {
+            panic!()
+        };
+        assert_eq!(user, USER);
+        assert_eq!(mint, MINT);
+        assert_eq!(bonding_curve, BONDING_CURVE);
+        assert_eq!(timestamp.to_string(), "2025-02-02T02:23:39Z");
+    }
+
+    #[test]
+    fn test_withdraw() {
+        let test_instance = PumpFunParser::new();
+        let tx = migration_transaction(vec![183, 18, 70, 156, 148, 109, 161, 34], vec![]);
+
+        let mut result = test_instance.parse(&tx).unwrap();
+        assert_eq!(result.len(), 1);
+
+        let Instruction::Withdraw { mint } = result.pop().unwrap() else {
+            panic!()
+        };
+        assert_eq!(mint, MINT);
+    }
+
+    #[test]
+    fn test_migrate() {
+        let test_instance = PumpFunParser::new();
+        let tx = migration_transaction(vec![155, 234, 231, 146, 236, 158, 162, 30], vec![]);
+
+        let mut result = test_instance.parse(&tx).unwrap();
+        assert_eq!(result.len(), 1);
+
+        let Instruction::Migrate { mint } = result.pop().unwrap() else {
+            panic!()
+        };
+        assert_eq!(mint, MINT);
+    }
+
+    #[test]
+    fn test_migrate_with_lookup_table() {
+        let test_instance = PumpFunParser::new();
+        let mut tx = migration_transaction(vec![155, 234, 231, 146, 236, 158, 162, 30], vec![]);
+
+        // the mint is loaded from an address lookup table
+        let mint = tx.keys.static_account.remove(2);
+        tx.keys.loaded_account = vec![mint];
+        tx.instructions[0].accounts = vec![2, 2, 4, 3];
+
+        let mut result = test_instance.parse(&tx).unwrap();
+        assert_eq!(result.len(), 1);
+
+        let Instruction::Migrate { mint } = result.pop().unwrap() else {
+            panic!()
+        };
+        assert_eq!(mint, MINT);
+    }
+
+    const USER: &str = "39azUYFWPz3VHgKCf3VChUwbpURdCHRxjWVowf5jUJjg";
+    const MINT: &str = "G3TpcmEy28TbzbyjL7TQy5noZbXrFBzJ5Vw5PqhTpump";
+    const BONDING_CURVE: &str = "B8wno3ipF3v1p59SHCZsUfjXwpv98bL7aJHXbo382nA6";
+
+    fn migration_transaction(data: Vec<u8>, events: Vec<Vec<u8>>) -> Transaction {
+        Transaction {
+            signature: "signature1".into(),
+            status: TransactionStatus::Success,
+            balance: TransactionBalance {
+                sol: vec![],
+                token: vec![],
+            },
+            instructions: vec![CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![3, 3, 2, 4],
+                data,
+            }],
+            inner_instructions: vec![InnerInstructions {
+                index: 0,
+                instructions: events
+                    .into_iter()
+                    .map(|data| InnerInstruction {
+                        instruction: CompiledInstruction {
+                            program_id_index: 1,
+                            accounts: vec![],
+                            data,
+                        },
+                        stack_height: Some(2),
+                    })
+                    .collect(),
+            }],
+            log_messages: vec![],
+            keys: Keys {
+                static_account: vec![
+                    PublicKey::from(USER),
+                    PublicKey::from(PUMPFUN),
+                    PublicKey::from(MINT),
+                    PublicKey::from("4wTV1YmiEkRvAtNtsSGPtUrqRYQMe5SKy2uB4Jjaxnjf"),
+                    PublicKey::from(BONDING_CURVE),
+                ],
+                loaded_account: vec![],
+                log_account: vec![],
+            },
+        }
+    }
+
     lazy_static! {
         static ref transactions: HashMap<String, String>  = HashMap::from([
             ("2RqhBZykXDPG6qt5fDRJujKuZL9yqAXxQuMkJ4JC9u9fAJEe774dBVNi4E8UpAbdWB47GpBm1avug1a6VGNN3Ujv".to_string(), r#"{"blockTime":1738395090,"meta":{"computeUnitsConsumed":35888,"err":null,"fee":234049,"innerInstructions":[{"index":2,"instructions":[{"accounts":[4,3,0],"data":"3TJqkRW2LjsV","programIdIndex":11,"stackHeight":2},{"accounts":[12],"data":"2K7nL28PxCW8ejnyCeuMpbWJwVv2AdENBThdFVahYH4gHwS8ffKuawgsQZCcVo7ATBYE6DBXodvTi6JfnuBjoVRmMGgz12aCFPeAvgLHbraRDLCNvwkHvJmEgHoWhu4QrsDRToN4F7Vc4dkaMvtptZEkDkpoTKXs8hog8RyvhjaLGFHpMPhjfvSS3gtw","programIdIndex":6,"stackHeight":2}]}],"loadedAddresses":{"readonly":[],"writable":[]},"logMessages":["Program ComputeBudget111111111111111111111111111111 invoke [1]","Program ComputeBudget111111111111111111111111111111 success","Program ComputeBudget111111111111111111111111111111 invoke [1]","Program ComputeBudget111111111111111111111111111111 success","Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]","Program log: Instruction: Sell","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]","Program log: Instruction: Transfer","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 4645 of 14442 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [2]","Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P consumed 2132 of 6131 compute units","Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P success","Program data: vdt/007mYe5F+cBmWEXkSxdITRvl3MspkqmLFsEA/P1WUE2p8W2nv0SMrQwAAAAAUs0wXeEAAAAAXuL1wVTmRkTr0jSqCE7uhvTbBRbqh6TAeX0ESLdyfH7SzZ1nAAAAAHH2t44TAAAAvkXVTYlcAQBxSpSSDAAAAL6twgH4XQAA","Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P consumed 35588 of 37382 compute units","Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P success"],"postBalances":[871586799,90246585773504,54000031393,2039280,2039280,1,1141440,246642259,1461600,1,731913600,934087680,122100014],"postTokenBalances":[{"accountIndex":3,"mint":"5iA1jhWN6kJRZaASzNZ4K7vvwiR3XykUiJKiAgWSpump","owner":"84jMmWjvPzAo9hrQD7A1VTMCVHyT6oJpN1znKqBB5TLE","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA","uiTokenAmount":{"amount":"310219762808254","decimals":6,"uiAmount":310219762.808254,"uiAmountString":"310219762.808254"}},{"accountIndex":4,"mint":"5iA1jhWN6kJRZaASzNZ4K7vvwiR3XykUiJKiAgWSpump","owner":"7PQ3nyAJHXiFQd5c8HgRBMYLF748MQKgq3uYfTuFioHX","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA","uiTokenAmount":{"amount":"0","decimals":6,"uiAmount":null,"uiAmountString":"0"}}],"preBalances":[661247622,90246583646502,54212731621,2039280,2039280,1,1141440,246642259,1461600,1,731913600,934087680,122100014],"preTokenBalances":[{"accountIndex":3,"mint":"5iA1jhWN6kJRZaASzNZ4K7vvwiR3XykUiJKiAgWSpump","owner":"84jMmWjvPzAo9hrQD7A1VTMCVHyT6oJpN1znKqBB5TLE","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA","uiTokenAmount":{"amount":"309251831687276","decimals":6,"uiAmount":309251831.687276,"uiAmountString":"309251831.687276"}},{"accountIndex":4,"mint":"5iA1jhWN6kJRZaASzNZ4K7vvwiR3XykUiJKiAgWSpump","owner":"7PQ3nyAJHXiFQd5c8HgRBMYLF748MQKgq3uYfTuFioHX","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA","uiTokenAmount":{"amount":"967931120978","decimals":6,"uiAmount":967931.120978,"uiAmountString":"967931.120978"}}],"rewards":[],"status":{"Ok":null}},"slot":317728984,"transaction":["AUdwCvIf2iaf1/ebrZhkK1E/I9oNeLD7j7feQuzpDt7rrs2OfJgBUPlsb/MXMlcQpLRwQgKYyMJyzCBPPTH/dAWAAQAIDV7i9cFU5kZE69I0qghO7ob02wUW6oekwHl9BEi3cnx+rRHmpPwpRKT6glG++BVCbhv7KMa2ZGZ3YHxq2fVmpkZo9nIApxrY/zAHsw3MmRtcQDkFUSXzYCz2PuY7TDyVg8oRvWQG5j8TPzNLh9V4PEG5aY7u1gAE/HrKpjo/eXbNfjCfZPDyiD7i83GMnBloszD3oh8TXxyVLSmwHkkYriADBkZv5SEXMv/srbpyw5vnvIzlu8X3EmssQ5s6QAAAAAFW4PaTZlrPRNsVaL8XW6pRicuX9dL/O2VdK7b9bRiwOoZeae4PVIDKvPZjV+TcLxjVjUXB6nSJ+zcj2Xk8cqZF+cBmWEXkSxdITRvl3MspkqmLFsEA/P1WUE2p8W2nvwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAjJclj04kifG7PRApFI4NgwtaE5na/xCEBI572Nvp+FkG3fbh12Whk9nL4UbO63msHLSF7V9bN5E6jPWFfv8AqazxNusB/BxOiD0jyLWESrWaN/Zq3VfF6aw7U+BZ01xkvqGvo6WfRCJSyZdHO1LdI1/rwIw0QqwWOd4a2/GYabUDBQAFAjKTAAAFAAkD7r9cAAAAAAAGDAcBCAIDBAAJCgsMBhgz5oWkAX+DrVLNMF3hAAAAeJlNDAAAAAAA","base64"],"version":0}"#.to_string()),
@@ -20,7 +20,11 @@ impl CandleRepo {
         sqlx::query(
             format!(
                 r#"
-with last_timestamp as (
+with watermark as (
+    -- swaps of slots past the watermark might still be followed by swaps of earlier slots
+    select coalesce((select watermark from solana.indexer where id = 1), 9223372036854775807) as slot
+),
+last_timestamp as (
     select coalesce(
         (select date_trunc('second', timestamp) from {progress_table} order by timestamp desc limit 1),
         (select timestamp - interval '1 second' from {swap_table} order by timestamp limit 1),
@@ -31,6 +35,7 @@ next_swap_timestamp as (
     select timestamp as ts
     from {swap_table}
     where timestamp > (select ts from last_timestamp)
+      and slot <= (select slot from watermark)
     order by timestamp
     limit 1
 ),
@@ -51,6 +56,7 @@ swaps as (
     where
       timestamp >= (select start_ts from timestamp_range)
       and timestamp < (select end_ts from timestamp_range)
+      and slot <= (select slot from watermark)
 ),
 open as (
     select distinct on (token_pair_id, second)
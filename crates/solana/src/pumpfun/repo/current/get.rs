@@ -7,6 +7,7 @@ use crate::pumpfun::repo::CurrentRepo;
 use base::model::{Amount, TokenPairId};
 use common::model::{
     AgeRelativeToLatestInSeconds, MarketCapQuote, MarketCapUsd, Percent, PriceQuote, PriceUsd,
+    Timestamp,
 };
 use common::repo::{RepoResult, Tx};
 use sqlx::Row;
@@ -44,6 +45,7 @@ impl CurrentRepo {
             virtual_quote_reserves: r.get::<Amount, _>("virtual_quote_reserves"),
             progress: r.get::<Percent, _>("progress"),
             complete: r.get::<bool, _>("complete"),
+            graduated_at: r.try_get::<Timestamp, _>("graduated_at").ok(),
             price: r.get::<PriceQuote, _>("price"),
             price_usd: r.try_get::<PriceUsd, _>("price_usd").ok(),
             market_cap: r.try_get::<MarketCapQuote, _>("market_cap").ok(),
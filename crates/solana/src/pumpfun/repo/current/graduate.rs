@@ -0,0 +1,52 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::pumpfun::repo::CurrentRepo;
+use base::model::solana::Slot;
+use base::model::{Mint, TokenPairId};
+use common::model::Timestamp;
+use common::repo::{RepoResult, Tx};
+use sqlx::Row;
+
+impl CurrentRepo {
+    /// Marks the bonding curves of the mints as complete and records the slot and time of graduation.
+    /// The first graduation is kept, if a curve is reported as graduated more than once
+    pub async fn graduate<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        mints: impl IntoIterator<Item = impl Into<Mint>> + Send,
+        slot: impl Into<Slot> + Send,
+        timestamp: Timestamp,
+    ) -> RepoResult<Vec<TokenPairId>> {
+        let mints = mints
+            .into_iter()
+            .map(|mint| mint.into().to_string())
+            .collect::<Vec<_>>();
+
+        if mints.is_empty() {
+            return Ok(vec![]);
+        }
+
+        Ok(sqlx::query(
+            r#"
+update pumpfun.current c set
+    complete = true,
+    graduated_at = coalesce(c.graduated_at, $2),
+    graduated_slot = coalesce(c.graduated_slot, $3),
+    updated_at = now()
+from solana.token_pair tp
+join solana.token t on t.id = tp.base_id
+where c.id = tp.id and t.mint = any($1::text[])
+returning c.id;
+        "#,
+        )
+        .bind(mints)
+        .bind(timestamp)
+        .bind(slot.into())
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| r.get::<TokenPairId, _>("id"))
+        .collect::<Vec<_>>())
+    }
+}
@@ -8,8 +8,10 @@ use crate::pumpfun::repo::CurrentRepo;
 use base::model::{Amount, TokenPairId};
 use common::model::{
     AgeRelativeToLatestInSeconds, MarketCapQuote, MarketCapUsd, Percent, PriceQuote, PriceUsd,
+    Timestamp,
 };
 use common::repo::{RepoResult, Tx};
+use sqlx::postgres::PgRow;
 use sqlx::Row;
 
 impl CurrentRepo {
@@ -35,19 +37,59 @@ impl CurrentRepo {
         .fetch_all(&mut **tx)
         .await?
         .into_iter()
-        .map(|r| Current {
-            id: r.get::<TokenPairId, _>("id"),
-            slot: r.get::<Slot, _>("slot"),
-            virtual_base_reserves: r.get::<Amount, _>("virtual_base_reserves"),
-            virtual_quote_reserves: r.get::<Amount, _>("virtual_quote_reserves"),
-            progress: r.get::<Percent, _>("progress"),
-            complete: r.get::<bool, _>("complete"),
-            price: r.get::<PriceQuote, _>("price"),
-            price_usd: r.try_get::<PriceUsd, _>("price_usd").ok(),
-            market_cap: r.try_get::<MarketCapQuote, _>("market_cap").ok(),
-            market_cap_usd: r.try_get::<MarketCapUsd, _>("market_cap_usd").ok(),
-            age: r.get::<AgeRelativeToLatestInSeconds, _>("age_seconds"),
-        })
+        .map(to_current)
         .collect::<Vec<_>>())
     }
+
+    pub async fn list_by_token_pairs<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        token_pairs: impl IntoIterator<Item = impl Into<TokenPairId>> + Send,
+    ) -> RepoResult<Vec<Current>> {
+        let ids = token_pairs
+            .into_iter()
+            .map(|id| id.into().0)
+            .collect::<Vec<_>>();
+
+        Ok(sqlx::query(
+            r#"
+        with latest as (
+            select updated_at 
+            from pumpfun.current 
+            order by updated_at desc 
+            limit 1
+        )
+        select 
+            c.*,
+            extract(epoch from (latest.updated_at - c.updated_at))::int8 as age_seconds
+        from 
+            pumpfun.current c,
+            latest
+        where c.id = any($1);
+        "#,
+        )
+        .bind(ids)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(to_current)
+        .collect::<Vec<_>>())
+    }
+}
+
+fn to_current(r: PgRow) -> Current {
+    Current {
+        id: r.get::<TokenPairId, _>("id"),
+        slot: r.get::<Slot, _>("slot"),
+        virtual_base_reserves: r.get::<Amount, _>("virtual_base_reserves"),
+        virtual_quote_reserves: r.get::<Amount, _>("virtual_quote_reserves"),
+        progress: r.get::<Percent, _>("progress"),
+        complete: r.get::<bool, _>("complete"),
+        graduated_at: r.try_get::<Timestamp, _>("graduated_at").ok(),
+        price: r.get::<PriceQuote, _>("price"),
+        price_usd: r.try_get::<PriceUsd, _>("price_usd").ok(),
+        market_cap: r.try_get::<MarketCapQuote, _>("market_cap").ok(),
+        market_cap_usd: r.try_get::<MarketCapUsd, _>("market_cap_usd").ok(),
+        age: r.get::<AgeRelativeToLatestInSeconds, _>("age_seconds"),
+    }
 }
@@ -3,7 +3,9 @@
 
 mod count;
 mod get;
+mod graduate;
 mod list;
+mod rollback;
 mod upsert;
 
 use common::model::Limit;
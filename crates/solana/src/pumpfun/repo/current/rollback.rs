@@ -0,0 +1,77 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::pumpfun::repo::CurrentRepo;
+use base::model::solana::Slot;
+use base::model::TokenPairId;
+use common::repo::{RepoResult, Tx};
+use sqlx::Row;
+
+impl CurrentRepo {
+    /// Restores the curves whose latest swap got dropped with its fork from the latest remaining swap.
+    /// Usd prices and market caps are scaled to the restored price. Curves without any remaining swap are removed.
+    /// Graduations of the dropped slots get undone, returns the token pairs which are no longer graduated.
+    /// Needs to run after the swaps got rolled back.
+    pub async fn rollback<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        slots: &[Slot],
+    ) -> RepoResult<Vec<TokenPairId>> {
+        if slots.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ungraduated = sqlx::query(
+            r#"
+update pumpfun.current set
+    complete = progress >= 100,
+    graduated_at = null,
+    graduated_slot = null,
+    updated_at = now()
+where graduated_slot = any($1::int8[])
+returning id;
+"#,
+        )
+        .bind(slots)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| r.get::<TokenPairId, _>("id"))
+        .collect::<Vec<_>>();
+
+        sqlx::query(
+            r#"
+with latest as (
+    select distinct on (s.token_pair_id)
+        s.token_pair_id, s.slot, s.virtual_base_reserves, s.virtual_quote_reserves, s.progress, s.price
+    from pumpfun.swap s
+    where s.token_pair_id in (select id from pumpfun.current where slot = any($1::int8[]))
+    order by s.token_pair_id, s.slot desc, s.id desc
+)
+update pumpfun.current c set
+    slot = l.slot,
+    virtual_base_reserves = l.virtual_base_reserves,
+    virtual_quote_reserves = l.virtual_quote_reserves,
+    progress = l.progress,
+    complete = l.progress >= 100 or c.graduated_slot is not null,
+    price = l.price,
+    price_usd = c.price_usd * l.price / nullif(c.price, 0),
+    market_cap = c.market_cap * l.price / nullif(c.price, 0),
+    market_cap_usd = c.market_cap_usd * l.price / nullif(c.price, 0),
+    updated_at = now()
+from latest l
+where c.id = l.token_pair_id;
+"#,
+        )
+        .bind(slots)
+        .execute(&mut **tx)
+        .await?;
+
+        sqlx::query("delete from pumpfun.current where slot = any($1::int8[]);")
+            .bind(slots)
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(ungraduated)
+    }
+}
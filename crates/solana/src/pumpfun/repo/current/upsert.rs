@@ -7,6 +7,7 @@ use crate::pumpfun::repo::current::CurrentRepo;
 use base::model::{Amount, TokenPairId};
 use common::model::{
     AgeRelativeToLatestInSeconds, MarketCapQuote, MarketCapUsd, Percent, PriceQuote, PriceUsd,
+    Timestamp,
 };
 use common::repo::{RepoResult, Tx};
 use sqlx::Row;
@@ -49,7 +50,7 @@ on conflict (id) do update set
     market_cap_usd = case when (select usd from latest_sol_price) is null or (select supply from token_supply) is null then null else excluded.market_cap * (select usd from latest_sol_price)                       end,
     updated_at = now()
 returning 
-    id, slot, virtual_base_reserves, virtual_quote_reserves, progress, complete, price, price_usd, market_cap, market_cap_usd, updated_at;
+    id, slot, virtual_base_reserves, virtual_quote_reserves, progress, complete, price, price_usd, market_cap, market_cap_usd, graduated_at, updated_at;
 
 
         "#
@@ -58,7 +59,7 @@ returning
             .bind(swap.slot)
             .bind(swap.virtual_base_reserves)
             .bind(swap.virtual_quote_reserves)
-            .bind(swap.progress.clone())
+            .bind(swap.progress)
             .bind(swap.progress >= 100.0)
             .bind(swap.price)
             .fetch_one(&mut **tx)
@@ -70,6 +71,7 @@ returning
                 virtual_quote_reserves: r.get::<Amount, _>("virtual_quote_reserves"),
                 progress: r.get::<Percent, _>("progress"),
                 complete: r.get::<bool, _>("complete"),
+                graduated_at: r.try_get::<Timestamp, _>("graduated_at").ok(),
                 price: r.get::<PriceQuote, _>("price"),
                 price_usd: r.try_get::<PriceUsd, _>("price_usd").ok(),
                 market_cap: r.try_get::<MarketCapQuote, _>("market_cap").ok(),
@@ -5,11 +5,17 @@
 // original AGPL 3 License Copyright (c) blockworks-foundation 2024.
 
 use crate::pumpfun::repo::SummaryRepo;
+use base::model::TokenPairId;
 use common::model::Partition;
 use common::repo::{RepoResult, Tx};
+use sqlx::Row;
 
 impl SummaryRepo {
-    pub async fn calculate_1m<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_1m<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         calculate_summary(
             tx,
             2,
@@ -23,7 +29,11 @@ impl SummaryRepo {
         .await
     }
 
-    pub async fn calculate_5m<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_5m<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         calculate_summary(
             tx,
             10,
@@ -37,7 +47,11 @@ impl SummaryRepo {
         .await
     }
 
-    pub async fn calculate_15m<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_15m<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         calculate_summary(
             tx,
             30,
@@ -51,7 +65,11 @@ impl SummaryRepo {
         .await
     }
 
-    pub async fn calculate_1h<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_1h<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         calculate_summary(
             tx,
             2,
@@ -65,7 +83,11 @@ impl SummaryRepo {
         .await
     }
 
-    pub async fn calculate_6h<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_6h<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         calculate_summary(
             tx,
             12,
@@ -79,7 +101,11 @@ impl SummaryRepo {
         .await
     }
 
-    pub async fn calculate_1d<'a>(&self, tx: &mut Tx<'a>, partition: Partition) -> RepoResult<()> {
+    pub async fn calculate_1d<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        partition: Partition,
+    ) -> RepoResult<Vec<TokenPairId>> {
         calculate_summary(
             tx,
             2,
@@ -103,7 +129,7 @@ async fn calculate_summary<'a>(
     candle_progress_table: impl AsRef<str>,
     candle_usd_table: impl AsRef<str>,
     destination_table: impl AsRef<str>,
-) -> RepoResult<()> {
+) -> RepoResult<Vec<TokenPairId>> {
     let candle_table = candle_table.as_ref();
     let candle_market_cap_table = candle_market_cap_table.as_ref();
     let candle_progress_table = candle_progress_table.as_ref();
@@ -675,6 +701,7 @@ on conflict (token_pair_id) do update set
 		{destination_table}.volume_sell_change is distinct from excluded.volume_sell_change or
 		{destination_table}.volume_sell_percent is distinct from excluded.volume_sell_percent
     )
+    returning token_pair_id
 "#
     );
 
@@ -682,6 +709,10 @@ on conflict (token_pair_id) do update set
     let _ = sqlx::query("set enable_nestloop = off;")
         .execute(&mut **tx)
         .await?;
-    let _ = sqlx::query(&query_str).execute(&mut **tx).await?;
-    Ok(())
+    Ok(sqlx::query(&query_str)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| r.get::<TokenPairId, _>("token_pair_id"))
+        .collect())
 }
@@ -7,6 +7,7 @@ use crate::pumpfun::repo::summary::row::{
 };
 use crate::pumpfun::repo::{SummaryQuery, SummaryRepo};
 use base::model::TokenPairId;
+use common::model::{Limit, Timeframe};
 use common::repo::{RepoResult, Tx};
 use sqlx::{Postgres, QueryBuilder, Row};
 
@@ -16,10 +17,33 @@ impl SummaryRepo {
         tx: &mut Tx<'a>,
         query: SummaryQuery,
     ) -> RepoResult<Vec<(TokenPairId, TimeframeSummary)>> {
-        let table = format!("pumpfun.summary_{}", query.timeframe.table());
+        list_summaries(tx, query.timeframe, None, Some(query.limit)).await
+    }
+
+    pub async fn list_by_token_pairs<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        token_pairs: impl IntoIterator<Item = impl Into<TokenPairId>> + Send,
+        timeframe: Timeframe,
+    ) -> RepoResult<Vec<(TokenPairId, TimeframeSummary)>> {
+        let ids = token_pairs
+            .into_iter()
+            .map(|id| id.into().0)
+            .collect::<Vec<_>>();
+        list_summaries(tx, timeframe, Some(ids), None).await
+    }
+}
+
+async fn list_summaries<'a>(
+    tx: &mut Tx<'a>,
+    timeframe: Timeframe,
+    token_pairs: Option<Vec<i64>>,
+    limit: Option<Limit>,
+) -> RepoResult<Vec<(TokenPairId, TimeframeSummary)>> {
+    let table = format!("pumpfun.summary_{}", timeframe.table());
 
-        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(format!(
-            r#"
+    let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(format!(
+        r#"
 select
     token_pair_id,
 
@@ -113,28 +137,35 @@ select
     volume_sell_percent
 from {table}
 "#,
-        ));
+    ));
 
-        query_builder.push("\nlimit ");
-        query_builder.push_bind(query.limit);
+    if let Some(token_pairs) = token_pairs {
+        query_builder.push("\nwhere token_pair_id = any(");
+        query_builder.push_bind(token_pairs);
+        query_builder.push(")");
+    }
 
-        Ok(query_builder
-            .build()
-            .fetch_all(&mut **tx)
-            .await?
-            .into_iter()
-            .map(|row| {
-                (
-                    row.get::<TokenPairId, _>("token_pair_id"),
-                    TimeframeSummary {
-                        cap: row_to_market_cap(&row),
-                        curve: row_to_curve_progress(&row),
-                        price: row_to_price(&row),
-                        swap: row_to_swaps(&row),
-                        volume: row_to_volume(&row),
-                    },
-                )
-            })
-            .collect::<Vec<_>>())
+    if let Some(limit) = limit {
+        query_builder.push("\nlimit ");
+        query_builder.push_bind(limit);
     }
+
+    Ok(query_builder
+        .build()
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|row| {
+            (
+                row.get::<TokenPairId, _>("token_pair_id"),
+                TimeframeSummary {
+                    cap: row_to_market_cap(&row),
+                    curve: row_to_curve_progress(&row),
+                    price: row_to_price(&row),
+                    swap: row_to_swaps(&row),
+                    volume: row_to_volume(&row),
+                },
+            )
+        })
+        .collect::<Vec<_>>())
 }
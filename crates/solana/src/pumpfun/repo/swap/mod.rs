@@ -12,6 +12,7 @@ pub use insert::{SlotSwap, SlotSwaps};
 mod count;
 mod insert;
 mod list;
+mod rollback;
 
 pub struct SwapQueryAll {
     pub limit: Limit,
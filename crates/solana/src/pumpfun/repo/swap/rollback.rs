@@ -0,0 +1,80 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::pumpfun::repo::SwapRepo;
+use base::model::solana::Slot;
+use base::model::TokenPairId;
+use common::model::Timestamp;
+use common::repo::{RepoResult, Tx};
+use sqlx::Row;
+
+impl SwapRepo {
+    /// Deletes the swaps of slots which got dropped with their fork and restores the most recent swap
+    /// of the affected token pairs. The block times of the slots bound the search.
+    pub async fn rollback<'a>(
+        &self,
+        tx: &mut Tx<'a>,
+        slots: &[Slot],
+        from: Timestamp,
+        to: Timestamp,
+    ) -> RepoResult<Vec<TokenPairId>> {
+        if slots.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let token_pairs = sqlx::query(
+            r#"
+with deleted as (
+    delete from pumpfun.swap
+    where slot = any($1::int8[]) and timestamp >= $2 and timestamp <= $3
+    returning token_pair_id
+)
+select distinct token_pair_id from deleted;
+"#,
+        )
+        .bind(slots)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|r| r.get::<TokenPairId, _>("token_pair_id"))
+        .collect::<Vec<_>>();
+
+        sqlx::query(
+            r#"
+delete from pumpfun.micro_swap
+where slot = any($1::int8[]) and timestamp >= $2 and timestamp <= $3;
+"#,
+        )
+        .bind(slots)
+        .bind(from)
+        .bind(to)
+        .execute(&mut **tx)
+        .await?;
+
+        if token_pairs.is_empty() {
+            return Ok(token_pairs);
+        }
+
+        sqlx::query("delete from pumpfun.swap_most_recent where token_pair_id = any($1::int8[]);")
+            .bind(&token_pairs)
+            .execute(&mut **tx)
+            .await?;
+
+        sqlx::query(
+            r#"
+insert into pumpfun.swap_most_recent
+select distinct on (token_pair_id) *
+from pumpfun.swap
+where token_pair_id = any($1::int8[])
+order by token_pair_id, slot desc, id desc;
+"#,
+        )
+        .bind(&token_pairs)
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(token_pairs)
+    }
+}
@@ -7,6 +7,7 @@ use crate::pumpfun::{PumpfunError, PumpfunResult, Rpc};
 use base::model::PublicKey;
 use common::ByteReader;
 use log::error;
+use solana_sdk::pubkey::Pubkey;
 
 impl Rpc {
     pub async fn get_curve_info(&self, key: impl Into<PublicKey> + Send) -> Option<CurveInfo> {
@@ -38,8 +39,23 @@ impl Rpc {
     }
 }
 
+impl Rpc {
+    pub(crate) async fn get_curve_account(
+        &self,
+        key: impl Into<Pubkey> + Send,
+    ) -> PumpfunResult<CurveAccount> {
+        let curve_pda = curve_pda(key).ok_or(PumpfunError::CurveNotFound)?;
+        match self.client.get_account(curve_pda).await? {
+            None => Err(PumpfunError::CurveNotFound),
+            Some(account) => Ok(CurveAccount::decode(&ByteReader::new(
+                &account.account.data,
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
-struct CurveAccount {
+pub(crate) struct CurveAccount {
     pub virtual_base_reserves: u64,
     pub virtual_quote_reserves: u64,
     pub real_base_reserves: u64,
@@ -5,7 +5,7 @@
 // Original MIT License Copyright (c) nhuxhr 2024.
 
 use crate::pumpfun::util::global_pda;
-use crate::pumpfun::Rpc;
+use crate::pumpfun::{PumpfunError, PumpfunResult, Rpc};
 use common::ByteReader;
 use solana_sdk::pubkey::Pubkey;
 
@@ -16,6 +16,13 @@ impl Rpc {
         let reader = ByteReader::new(&account.account.data);
         GlobalInfo::decode(&reader)
     }
+
+    pub(crate) async fn get_global_account(&self) -> PumpfunResult<GlobalInfo> {
+        match self.client.get_account(global_pda()).await? {
+            None => Err(PumpfunError::GlobalNotFound),
+            Some(account) => Ok(GlobalInfo::decode(&ByteReader::new(&account.account.data))),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
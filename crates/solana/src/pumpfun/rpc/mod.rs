@@ -8,7 +8,7 @@ mod global;
 
 #[derive(Clone)]
 pub struct Rpc {
-    client: RpcClient,
+    pub(crate) client: RpcClient,
 }
 
 impl Rpc {
@@ -0,0 +1,38 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::pumpfun::model::summary::TimeframeSummary;
+use crate::pumpfun::model::Current;
+use crate::pumpfun::service::token::TokenService;
+use base::model::TokenPairId;
+use common::model::Timeframe;
+use common::service::ServiceResult;
+
+impl TokenService {
+    pub async fn list_current(
+        &self,
+        token_pairs: impl IntoIterator<Item = impl Into<TokenPairId>> + Send,
+    ) -> ServiceResult<Vec<Current>> {
+        let mut tx = self.pool.begin().await?;
+        let result = self
+            .current
+            .list_by_token_pairs(&mut tx, token_pairs)
+            .await?;
+        tx.commit().await?;
+        Ok(result)
+    }
+
+    pub async fn list_summaries(
+        &self,
+        token_pairs: impl IntoIterator<Item = impl Into<TokenPairId>> + Send,
+        timeframe: Timeframe,
+    ) -> ServiceResult<Vec<(TokenPairId, TimeframeSummary)>> {
+        let mut tx = self.pool.begin().await?;
+        let result = self
+            .summary
+            .list_by_token_pairs(&mut tx, token_pairs, timeframe)
+            .await?;
+        tx.commit().await?;
+        Ok(result)
+    }
+}
@@ -12,166 +12,421 @@
 //!
 //! # Instructions
 //!
-//! - `create`: Instruction to create a new token with an associated bonding curve.
 //! - `buy`: Instruction to buy tokens from a bonding curve by providing SOL.
 //! - `sell`: Instruction to sell tokens back to the bonding curve in exchange for SOL.
-// 
-// use crate::pumpfun::constant::accounts::{
-//     ASSOCIATED_TOKEN_PROGRAM, EVENT_AUTHORITY, PUMPFUN, RENT, SYSTEM_PROGRAM, TOKEN_PROGRAM,
-// };
-// use crate::pumpfun::util::{curve_pda, global_pda};
-// use solana_sdk::{
-//     instruction::{AccountMeta, Instruction},
-//     pubkey::Pubkey,
-//     signature::Keypair,
-//     signer::Signer,
-// };
-// use spl_associated_token_account::get_associated_token_address;
-// 
-// mod buy;
-// mod sell;
-// 
-// const BUY: [u8; 8] = [0x66, 0x06, 0x3d, 0x12, 0x01, 0xda, 0xeb, 0xea];
-// const SELL: [u8; 8] = [0x33, 0xe6, 0x85, 0xa4, 0x01, 0x7f, 0x83, 0xad];
-// 
-// pub struct BuyInstructionArgs {
-//     // _amount: u64,
-//     pub amount: u64,
-//     // _max_sol_cost: u64,
-//     pub max_sol_cost: u64,
-// }
-// 
-// /// Creates an instruction to buy tokens from a bonding curve
-// ///
-// /// Buys tokens by providing SOL. The amount of tokens received is calculated based on
-// /// the bonding curve formula. A portion of the SOL is taken as a fee and sent to the
-// /// fee recipient account.
-// ///
-// /// # Arguments
-// ///
-// /// * `payer` - Keypair that will provide the SOL to buy tokens
-// /// * `mint` - Public key of the token mint to buy
-// /// * `fee_recipient` - Public key of the account that will receive the transaction fee
-// /// * `args` - Buy instruction data containing the SOL amount and maximum acceptable token price
-// ///
-// /// # Returns
-// ///
-// /// Returns a Solana instruction that when executed will buy tokens from the bonding curve
-// pub fn create_buy_instruction(
-//     payer: &Keypair,
-//     mint: &Pubkey,
-//     fee_recipient: &Pubkey,
-//     args: BuyInstructionArgs,
-// ) -> Instruction {
-//     let bonding_curve: Pubkey = curve_pda(*mint).unwrap();
-// 
-//     // let expected_size = 1 + 8 + 8;
-//     let mut data = Vec::with_capacity(24);
-//     // data.extend_from_slice(&[9u8]);
-//     data.extend_from_slice(&BUY);
-//     data.extend_from_slice(&args.amount.to_le_bytes());
-//     data.extend_from_slice(&args.max_sol_cost.to_le_bytes());
-//     // assert_eq!(data.len(), expected_size);
-// 
-//     Instruction {
-//         program_id: PUMPFUN,
-//         data,
-//         accounts: vec![
-//             AccountMeta::new_readonly(global_pda(), false),
-//             AccountMeta::new(*fee_recipient, false),
-//             AccountMeta::new_readonly(*mint, false),
-//             AccountMeta::new(bonding_curve, false),
-//             AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
-//             AccountMeta::new(get_associated_token_address(&payer.pubkey(), mint), false),
-//             AccountMeta::new(payer.pubkey(), true),
-//             AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
-//             AccountMeta::new_readonly(TOKEN_PROGRAM, false),
-//             AccountMeta::new_readonly(RENT, false),
-//             AccountMeta::new_readonly(EVENT_AUTHORITY, false),
-//             AccountMeta::new_readonly(PUMPFUN, false),
-//         ],
-//     }
-// }
-// 
-// pub struct SellInstructionArgs {
-//     // _amount,
-//     pub amount: u64,
-//     // _min_sol_output,
-//     pub min_sol_output: u64,
-// }
-// 
-// /// Creates an instruction to sell tokens back to a bonding curve
-// ///
-// /// Sells tokens back to the bonding curve in exchange for SOL. The amount of SOL received
-// /// is calculated based on the bonding curve formula. A portion of the SOL is taken as
-// /// a fee and sent to the fee recipient account.
-// ///
-// /// # Arguments
-// ///
-// /// * `payer` - Keypair that owns the tokens to sell
-// /// * `mint` - Public key of the token mint to sell
-// /// * `fee_recipient` - Public key of the account that will receive the transaction fee
-// /// * `args` - Sell instruction data containing token amount and minimum acceptable SOL output
-// ///
-// /// # Returns
-// ///
-// /// Returns a Solana instruction that when executed will sell tokens to the bonding curve
-// pub fn create_sell_instruction(
-//     payer: &Keypair,
-//     mint: &Pubkey,
-//     fee_recipient: &Pubkey,
-//     args: SellInstructionArgs,
-// ) -> Instruction {
-//     let bonding_curve: Pubkey = curve_pda(*mint).unwrap();
-// 
-//     // let expected_size = 1 + 8 + 8;
-//     let mut data = Vec::with_capacity(24);
-//     // data.extend_from_slice(&[9u8]);
-//     data.extend_from_slice(&SELL);
-//     data.extend_from_slice(&args.amount.to_le_bytes());
-//     data.extend_from_slice(&args.min_sol_output.to_le_bytes());
-//     // assert_eq!(data.len(), expected_size);
-// 
-//     Instruction {
-//         program_id: PUMPFUN,
-//         data,
-//         accounts: vec![
-//             AccountMeta::new_readonly(global_pda(), false),
-//             AccountMeta::new(*fee_recipient, false),
-//             AccountMeta::new_readonly(*mint, false),
-//             AccountMeta::new(bonding_curve, false),
-//             AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
-//             AccountMeta::new(get_associated_token_address(&payer.pubkey(), mint), false),
-//             AccountMeta::new(payer.pubkey(), true),
-//             AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
-//             AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM, false),
-//             AccountMeta::new_readonly(TOKEN_PROGRAM, false),
-//             AccountMeta::new_readonly(EVENT_AUTHORITY, false),
-//             AccountMeta::new_readonly(PUMPFUN, false),
-//         ],
-//     }
-// }
-// 
-// /// Calculates the maximum amount to pay when buying tokens, accounting for slippage tolerance
-// ///
-// /// # Arguments
-// /// * `amount` - The base amount in lamports (1 SOL = 1,000,000,000 lamports)
-// /// * `basis_points` - The slippage tolerance in basis points (1% = 100 basis points)
-// ///
-// /// # Returns
-// /// The maximum amount to pay, including slippage tolerance
-// pub fn calculate_with_slippage_buy(amount: u64, basis_points: u64) -> u64 {
-//     amount + (amount * basis_points) / 10000
-// }
-// 
-// /// Calculates the minimum amount to receive when selling tokens, accounting for slippage tolerance
-// ///
-// /// # Arguments
-// /// * `amount` - The base amount in lamports (1 SOL = 1,000,000,000 lamports)
-// /// * `basis_points` - The slippage tolerance in basis points (1% = 100 basis points)
-// ///
-// /// # Returns
-// /// The minimum amount to receive, accounting for slippage tolerance
-// pub fn calculate_with_slippage_sell(amount: u64, basis_points: u64) -> u64 {
-//     amount - (amount * basis_points) / 10000
-// }
+//! - `create_token_account`: Instruction to create the associated token account of the buyer.
+
+use crate::pumpfun::constant::accounts::{
+    ASSOCIATED_TOKEN_PROGRAM, EVENT_AUTHORITY, PUMPFUN, RENT, SYSTEM_PROGRAM, TOKEN_PROGRAM,
+};
+use crate::pumpfun::util::{curve_pda, global_pda};
+use crate::pumpfun::{Pumpfun, PumpfunError, PumpfunResult};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::hash::Hash;
+use solana_sdk::message::{Message, VersionedMessage};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::VersionedTransaction;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+mod buy;
+mod sell;
+
+const BUY: [u8; 8] = [0x66, 0x06, 0x3d, 0x12, 0x01, 0xda, 0xeb, 0xea];
+const SELL: [u8; 8] = [0x33, 0xe6, 0x85, 0xa4, 0x01, 0x7f, 0x83, 0xad];
+
+const DEFAULT_SLIPPAGE_BASIS_POINTS: u64 = 500;
+const COMPUTE_UNIT_PRICE: u64 = 100_000;
+const COMPUTE_UNIT_LIMIT: u32 = 300_000;
+
+pub struct BuyInstructionArgs {
+    pub amount: u64,
+    pub max_sol_cost: u64,
+}
+
+/// Creates an instruction to buy tokens from a bonding curve
+///
+/// Buys tokens by providing SOL. The amount of tokens received is calculated based on
+/// the bonding curve formula. A portion of the SOL is taken as a fee and sent to the
+/// fee recipient account.
+///
+/// # Arguments
+///
+/// * `payer` - Public key of the account that will provide the SOL to buy tokens
+/// * `mint` - Public key of the token mint to buy
+/// * `fee_recipient` - Public key of the account that will receive the transaction fee
+/// * `args` - Buy instruction data containing the token amount and maximum acceptable SOL cost
+pub fn create_buy_instruction(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    fee_recipient: &Pubkey,
+    args: BuyInstructionArgs,
+) -> Instruction {
+    let bonding_curve: Pubkey = curve_pda(*mint).unwrap();
+
+    let mut data = Vec::with_capacity(24);
+    data.extend_from_slice(&BUY);
+    data.extend_from_slice(&args.amount.to_le_bytes());
+    data.extend_from_slice(&args.max_sol_cost.to_le_bytes());
+
+    Instruction {
+        program_id: PUMPFUN,
+        data,
+        accounts: vec![
+            AccountMeta::new_readonly(global_pda(), false),
+            AccountMeta::new(*fee_recipient, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(bonding_curve, false),
+            AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
+            AccountMeta::new(get_associated_token_address(payer, mint), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM, false),
+            AccountMeta::new_readonly(RENT, false),
+            AccountMeta::new_readonly(EVENT_AUTHORITY, false),
+            AccountMeta::new_readonly(PUMPFUN, false),
+        ],
+    }
+}
+
+pub struct SellInstructionArgs {
+    pub amount: u64,
+    pub min_sol_output: u64,
+}
+
+/// Creates an instruction to sell tokens back to a bonding curve
+///
+/// Sells tokens back to the bonding curve in exchange for SOL. The amount of SOL received
+/// is calculated based on the bonding curve formula. A portion of the SOL is taken as
+/// a fee and sent to the fee recipient account.
+///
+/// # Arguments
+///
+/// * `payer` - Public key of the account that owns the tokens to sell
+/// * `mint` - Public key of the token mint to sell
+/// * `fee_recipient` - Public key of the account that will receive the transaction fee
+/// * `args` - Sell instruction data containing token amount and minimum acceptable SOL output
+pub fn create_sell_instruction(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    fee_recipient: &Pubkey,
+    args: SellInstructionArgs,
+) -> Instruction {
+    let bonding_curve: Pubkey = curve_pda(*mint).unwrap();
+
+    let mut data = Vec::with_capacity(24);
+    data.extend_from_slice(&SELL);
+    data.extend_from_slice(&args.amount.to_le_bytes());
+    data.extend_from_slice(&args.min_sol_output.to_le_bytes());
+
+    Instruction {
+        program_id: PUMPFUN,
+        data,
+        accounts: vec![
+            AccountMeta::new_readonly(global_pda(), false),
+            AccountMeta::new(*fee_recipient, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(bonding_curve, false),
+            AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
+            AccountMeta::new(get_associated_token_address(payer, mint), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM, false),
+            AccountMeta::new_readonly(EVENT_AUTHORITY, false),
+            AccountMeta::new_readonly(PUMPFUN, false),
+        ],
+    }
+}
+
+/// Creates an instruction to create the associated token account of owner for mint
+///
+/// The idempotent variant is used, so that a concurrently created account does not fail the transaction.
+///
+/// # Arguments
+///
+/// * `payer` - Public key of the account that pays the rent and owns the token account
+/// * `mint` - Public key of the token mint
+pub fn create_token_account_instruction(payer: &Pubkey, mint: &Pubkey) -> Instruction {
+    create_associated_token_account_idempotent(payer, payer, mint, &TOKEN_PROGRAM)
+}
+
+/// Calculates the maximum amount to pay when buying tokens, accounting for slippage tolerance
+///
+/// # Arguments
+/// * `amount` - The base amount in lamports (1 SOL = 1,000,000,000 lamports)
+/// * `basis_points` - The slippage tolerance in basis points (1% = 100 basis points)
+///
+/// # Returns
+/// The maximum amount to pay, including slippage tolerance
+pub fn calculate_with_slippage_buy(amount: u64, basis_points: u64) -> u64 {
+    amount.saturating_add(slippage(amount, basis_points))
+}
+
+/// Calculates the minimum amount to receive when selling tokens, accounting for slippage tolerance
+///
+/// # Arguments
+/// * `amount` - The base amount in lamports (1 SOL = 1,000,000,000 lamports)
+/// * `basis_points` - The slippage tolerance in basis points (1% = 100 basis points)
+///
+/// # Returns
+/// The minimum amount to receive, accounting for slippage tolerance
+pub fn calculate_with_slippage_sell(amount: u64, basis_points: u64) -> u64 {
+    amount.saturating_sub(slippage(amount, basis_points))
+}
+
+fn slippage(amount: u64, basis_points: u64) -> u64 {
+    u64::try_from(amount as u128 * basis_points as u128 / 10000).unwrap_or(u64::MAX)
+}
+
+fn compute_budget_instructions() -> [Instruction; 2] {
+    [
+        ComputeBudgetInstruction::set_compute_unit_price(COMPUTE_UNIT_PRICE),
+        ComputeBudgetInstruction::set_compute_unit_limit(COMPUTE_UNIT_LIMIT),
+    ]
+}
+
+impl Pumpfun {
+    /// Determines whether the associated token account of owner for mint does not exist yet
+    pub(crate) async fn token_account_missing(
+        &self,
+        owner: &Pubkey,
+        mint: &Pubkey,
+    ) -> PumpfunResult<bool> {
+        let ata = get_associated_token_address(owner, mint);
+        Ok(self.rpc.client.get_account(ata).await?.is_none())
+    }
+}
+
+fn sign_transaction(
+    payer: &Keypair,
+    instructions: &[Instruction],
+    blockhash: Hash,
+) -> PumpfunResult<VersionedTransaction> {
+    let message = VersionedMessage::Legacy(Message::new_with_blockhash(
+        instructions,
+        Some(&payer.pubkey()),
+        &blockhash,
+    ));
+    VersionedTransaction::try_new(message, &[payer])
+        .map_err(|err| PumpfunError::Transaction(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pumpfun::tx::{
+        calculate_with_slippage_buy, calculate_with_slippage_sell, compute_budget_instructions,
+        create_buy_instruction, create_sell_instruction, create_token_account_instruction,
+        BuyInstructionArgs, SellInstructionArgs,
+    };
+    use solana_sdk::instruction::AccountMeta;
+    use solana_sdk::pubkey;
+    use solana_sdk::pubkey::Pubkey;
+
+    const FEE_RECIPIENT: Pubkey = pubkey!("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM");
+
+    fn hex(data: &[u8]) -> String {
+        data.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn test_buy_instruction() {
+        // szD3dwJV88XBLa2yr7vmXhcQkExQDyF66kB4fHKkMzjWjWqWk9fjReHbLBbdH7M25twvCiqWdzcdjjWYCHYBu9e
+        let payer = pubkey!("4Z4Rrfjiov2jtXJFm3zvETZGR3b15VPPkzKYyL4mtG9e");
+        let mint = pubkey!("WGRXSegEZiEghqbSJJAWTnvL3uZnavUPyf3seZhpump");
+
+        let result = create_buy_instruction(
+            &payer,
+            &mint,
+            &FEE_RECIPIENT,
+            BuyInstructionArgs {
+                amount: 35_866_261_398,
+                max_sol_cost: 17_700_000,
+            },
+        );
+
+        assert_eq!(
+            result.program_id,
+            pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P")
+        );
+        assert_eq!(
+            hex(&result.data),
+            "66063d1201daebea96b7cb5908000000a0140e0100000000"
+        );
+        assert_eq!(
+            result.accounts,
+            vec![
+                AccountMeta::new_readonly(
+                    pubkey!("4wTV1YmiEkRvAtNtsSGPtUrqRYQMe5SKy2uB4Jjaxnjf"),
+                    false
+                ),
+                AccountMeta::new(FEE_RECIPIENT, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(
+                    pubkey!("6oviQ4Sv4BzwcSnpxnLeGS2swDY5y5nH232rFnDJPEVv"),
+                    false
+                ),
+                AccountMeta::new(
+                    pubkey!("ECq12Pvvjphby5xZueDi3L9RdNozZthAVdNkVB9gDaFm"),
+                    false
+                ),
+                AccountMeta::new(
+                    pubkey!("4ZpiTYomS9rB7Cot5pbErfbqGQTSEo4GyrdwwdQFvCRG"),
+                    false
+                ),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(pubkey!("11111111111111111111111111111111"), false),
+                AccountMeta::new_readonly(
+                    pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+                    false
+                ),
+                AccountMeta::new_readonly(
+                    pubkey!("SysvarRent111111111111111111111111111111111"),
+                    false
+                ),
+                AccountMeta::new_readonly(
+                    pubkey!("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
+                    false
+                ),
+                AccountMeta::new_readonly(
+                    pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sell_instruction() {
+        // RvehFKXWFWzW3kicHN1VUcarupH2ZziVQuq8TF35jVehcy941adfcTGkVKtu2aAAHKw5qhXBhcxKBFu8DkbeZB4
+        let payer = pubkey!("BZYX3v8dvHsAmKYFNzky7JVbNUDVC6drjVsarGPYifSM");
+        let mint = pubkey!("2GCTWvAEdtnjMh7tDS9cre7en98QiHYQK16yP6hQpump");
+
+        let result = create_sell_instruction(
+            &payer,
+            &mint,
+            &FEE_RECIPIENT,
+            SellInstructionArgs {
+                amount: 196_735_589_680,
+                min_sol_output: 0,
+            },
+        );
+
+        assert_eq!(
+            result.program_id,
+            pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P")
+        );
+        assert_eq!(
+            hex(&result.data),
+            "33e685a4017f83ad30dd5ace2d0000000000000000000000"
+        );
+        assert_eq!(
+            result.accounts,
+            vec![
+                AccountMeta::new_readonly(
+                    pubkey!("4wTV1YmiEkRvAtNtsSGPtUrqRYQMe5SKy2uB4Jjaxnjf"),
+                    false
+                ),
+                AccountMeta::new(FEE_RECIPIENT, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(
+                    pubkey!("FdZEHC7iewUpJgs2JZ2zTj6PhbNdoiYPZZzrppuvFMYi"),
+                    false
+                ),
+                AccountMeta::new(
+                    pubkey!("AGx9hZpGWbeHW1hNpHn5RQbLEDuT7TYSb7Pvfu4w3VV9"),
+                    false
+                ),
+                AccountMeta::new(
+                    pubkey!("8epnbpcAJG2btmuYwdDGwQFhE9NoCD2b5XAyKQid7Qov"),
+                    false
+                ),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(pubkey!("11111111111111111111111111111111"), false),
+                AccountMeta::new_readonly(
+                    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+                    false
+                ),
+                AccountMeta::new_readonly(
+                    pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+                    false
+                ),
+                AccountMeta::new_readonly(
+                    pubkey!("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
+                    false
+                ),
+                AccountMeta::new_readonly(
+                    pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_token_account_instruction() {
+        // RvehFKXWFWzW3kicHN1VUcarupH2ZziVQuq8TF35jVehcy941adfcTGkVKtu2aAAHKw5qhXBhcxKBFu8DkbeZB4
+        let payer = pubkey!("BZYX3v8dvHsAmKYFNzky7JVbNUDVC6drjVsarGPYifSM");
+        let mint = pubkey!("2GCTWvAEdtnjMh7tDS9cre7en98QiHYQK16yP6hQpump");
+
+        let result = create_token_account_instruction(&payer, &mint);
+
+        assert_eq!(
+            result.program_id,
+            pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+        );
+        assert_eq!(hex(&result.data), "01");
+        assert_eq!(
+            result.accounts,
+            vec![
+                AccountMeta::new(payer, true),
+                AccountMeta::new(
+                    pubkey!("8epnbpcAJG2btmuYwdDGwQFhE9NoCD2b5XAyKQid7Qov"),
+                    false
+                ),
+                AccountMeta::new_readonly(payer, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(pubkey!("11111111111111111111111111111111"), false),
+                AccountMeta::new_readonly(
+                    pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_budget_instructions() {
+        let [price, limit] = compute_budget_instructions();
+        assert_eq!(
+            price.program_id,
+            pubkey!("ComputeBudget111111111111111111111111111111")
+        );
+        assert_eq!(hex(&price.data), "03a086010000000000");
+        assert_eq!(
+            limit.program_id,
+            pubkey!("ComputeBudget111111111111111111111111111111")
+        );
+        assert_eq!(hex(&limit.data), "02e0930400");
+    }
+
+    #[test]
+    fn test_slippage() {
+        assert_eq!(calculate_with_slippage_buy(100_000_000, 500), 105_000_000);
+        assert_eq!(calculate_with_slippage_buy(100_000_000, 0), 100_000_000);
+        assert_eq!(calculate_with_slippage_sell(100_000_000, 500), 95_000_000);
+        assert_eq!(calculate_with_slippage_sell(100_000_000, 10_000), 0);
+    }
+
+    #[test]
+    fn test_slippage_out_of_range() {
+        assert_eq!(calculate_with_slippage_sell(100_000_000, 20_000), 0);
+        assert_eq!(calculate_with_slippage_buy(u64::MAX, 500), u64::MAX);
+        assert_eq!(calculate_with_slippage_buy(u64::MAX, u64::MAX), u64::MAX);
+    }
+}
@@ -4,140 +4,84 @@
 // This file includes portions of code from https://github.com/nhuxhr/pumpfun-rs (MIT License).
 // Original MIT License Copyright (c) nhuxhr 2024.
 
-use crate::model::solana::Signature;
-use crate::pumpfun;
-use crate::pumpfun::Pumpfun;
-use base::model::KeyPair;
-use base::model::TokenMint;
+use crate::pumpfun::model::SubmittedSwap;
+use crate::pumpfun::tx::{
+    calculate_with_slippage_sell, compute_budget_instructions, create_sell_instruction,
+    sign_transaction, SellInstructionArgs, DEFAULT_SLIPPAGE_BASIS_POINTS,
+};
+use crate::pumpfun::{Pumpfun, PumpfunError, PumpfunResult};
+use base::model::{KeyPair, Mint};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
 
 impl Pumpfun {
     /// Sells tokens back to the bonding curve in exchange for SOL
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint to sell
+    /// * `payer` - Key pair of the wallet which holds the tokens and receives the SOL
+    /// * `mint` - Mint of the token to sell
     /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells entire balance
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to 500
-    /// * `priority_fee` - Optional priority fee configuration for compute units
     ///
     /// # Returns
     ///
-    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    /// Returns the submitted swap if the rpc node accepted the transaction
     pub async fn sell(
         &self,
-        _keypair: KeyPair,
-        _mint: TokenMint,
-        _amount: Option<u64>,
-        _slippage_basis_points: Option<u64>,
-        // priority_fee: Option<PriorityFee>,
-        // ) -> Result<Signature, error::ClientError> {
-    ) -> pumpfun::PumpfunResult<Signature> {
-        // // let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), mint);
-        // // let balance = self.rpc.get_token_account_balance(&ata).unwrap();
-        // //
-        // // let balance_u64: u64 = balance.amount.parse::<u64>().unwrap();
-        //
-        // let rpc = Rpc::new(LocalRpcClient::new("https://api.mainnet-beta.solana.com"));
-        //
-        // // let _amount = amount_token.unwrap_or(balance_u64);
-        // let amount = amount.unwrap();
-        // let client = Arc::new(RpcClient::new(
-        //     "https://api.mainnet-beta.solana.com".to_string(),
-        // ));
-        //
-        // let global_account = rpc.get_global_info().await;
-        //
-        // let bonding_curve_account = rpc.get_curve_account(mint.clone()).await.unwrap();
-        //
-        // let min_sol_output = bonding_curve_account
-        //     // .get_sell_price(_amount, global_account.fee_basis_points)
-        //     .get_sell_price(amount, global_account.fee_basis_points)?;
-        // // .unwrap();
-        // // .map_err(error::ClientError::BondingCurveError)?;
-        //
-        // let _min_sol_output =
-        //     calculate_with_slippage_sell(min_sol_output, slippage_basis_points.unwrap_or(500));
-        //
-        // // let mut request = self.program.request();
-        //
-        // // Add priority fee if provided
-        // // if let Some(fee) = priority_fee {
-        // //     if let Some(limit) = fee.limit {
-        // //         let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
-        // //         request = request.instruction(limit_ix);
-        // //     }
-        // //
-        // //     if let Some(price) = fee.price {
-        // //         let price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-        // //         request = request.instruction(price_ix);
-        // //     }
-        // // }
-        //
-        // // Add sell instruction
-        // // request = request.instruction(instruction::sell(
-        // //     self.payer,
-        // //     mint,
-        // //     &global_account.fee_recipient,
-        // //     cpi::instruction::Sell { _amount, _min_sol_output },
-        // // ));
-        // // let kp: Keypair = Keypair::from_base58_string(env!("PRIVATE_KEY"));
-        // let kp: Keypair = keypair.into();
-        // let mint: Pubkey = mint.into();
-        //
-        // let mut instructions: Vec<Instruction> = vec![];
-        //
-        // instructions.push(ComputeBudgetInstruction::set_compute_unit_price(100_000));
-        // instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(300_000));
-        //
-        // // instructions.extend(setup_instructions);
-        // instructions.push(create_sell_instruction(
-        //     &kp,
-        //     &mint,
-        //     &pubkey!("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM"),
-        //     SellInstructionArgs {
-        //         amount,
-        //         min_sol_output: _min_sol_output,
-        //     },
-        // ));
-        // //
-        // // if let Some(cleanup_instruction) = cleanup_instruction {
-        // //     final_instructions.push(cleanup_instruction);
-        // // }
-        //
-        // // let kp: Keypair = payer.clone().into();
-        //
-        // let blockhash = client.get_latest_blockhash().await.unwrap();
-        //
-        // let mut message = VersionedMessage::Legacy(Message::new(&instructions, Some(&kp.pubkey())));
-        // // if let Some(hash) = blockhash {
-        // message.set_recent_blockhash(blockhash);
-        // // }
-        // let mut transaction = VersionedTransaction {
-        //     signatures: vec![solana_sdk::signature::Signature::default()],
-        //     message,
-        // };
-        //
-        // transaction.message.set_recent_blockhash(blockhash);
-        //
-        // let tx = VersionedTransaction::try_new(transaction.message, &[&kp]).unwrap();
-        //
-        // let result = client
-        //     .send_transaction_with_config(
-        //         &tx,
-        //         RpcSendTransactionConfig {
-        //             skip_preflight: false,
-        //             preflight_commitment: None,
-        //             encoding: None,
-        //             max_retries: None,
-        //             min_context_slot: None,
-        //         },
-        //     )
-        //     .await
-        //     .expect("Failed to swap");
-        //
-        // // println!("{result}");
-        // Ok(Signature(result.to_string()))
-        //
-        todo!()
+        payer: KeyPair,
+        mint: impl Into<Mint>,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+    ) -> PumpfunResult<SubmittedSwap> {
+        let mint = mint.into();
+
+        let amount_token = match amount_token {
+            Some(amount) => amount,
+            None => {
+                self.rpc
+                    .client
+                    .token_balance(payer.public.clone(), mint.clone())
+                    .await?
+            }
+        };
+
+        if amount_token == 0 {
+            return Err(PumpfunError::NothingToSell);
+        }
+
+        let global = self.rpc.get_global_account().await?;
+        let curve = self.rpc.get_curve_account(mint.clone()).await?;
+
+        let amount_sol = curve.get_sell_price(amount_token, global.fee_basis_points)?;
+        let min_sol_output = calculate_with_slippage_sell(
+            amount_sol,
+            slippage_basis_points.unwrap_or(DEFAULT_SLIPPAGE_BASIS_POINTS),
+        );
+
+        let keypair: Keypair = payer.clone().into();
+        let owner: Pubkey = payer.public.into();
+        let mint: Pubkey = mint.into();
+
+        let mut instructions = compute_budget_instructions().to_vec();
+        instructions.push(create_sell_instruction(
+            &owner,
+            &mint,
+            &global.fee_recipient,
+            SellInstructionArgs {
+                amount: amount_token,
+                min_sol_output,
+            },
+        ));
+
+        let blockhash = self.rpc.client.latest_blockhash().await?;
+        let transaction = sign_transaction(&keypair, &instructions, blockhash)?;
+        let signature = self.rpc.client.send_transaction(&transaction).await?;
+
+        Ok(SubmittedSwap {
+            signature,
+            sol_amount: amount_sol,
+            token_amount: amount_token,
+        })
     }
 }
@@ -6,6 +6,7 @@ use std::fmt::{Display, Formatter};
 use base::model::PublicKey;
 
 use crate::raydium::http::HttpError;
+use crate::rpc::RpcClientError;
 
 #[derive(Debug)]
 pub enum Error {
@@ -14,6 +15,8 @@ pub enum Error {
     MarketNotFoundError,
     MathError,
     PoolKeysNotFoundError { market: PublicKey },
+    RpcError { message: String },
+    TransactionError { message: String },
 }
 
 impl Display for Error {
@@ -26,6 +29,8 @@ impl Display for Error {
             Error::PoolKeysNotFoundError { market } => {
                 f.write_fmt(format_args!("Failed to get pool keys for {}", market))
             }
+            Error::RpcError { message } => f.write_fmt(format_args!("{message}")),
+            Error::TransactionError { message } => f.write_fmt(format_args!("{message}")),
         }
     }
 }
@@ -39,3 +44,11 @@ impl From<HttpError> for Error {
         }
     }
 }
+
+impl From<RpcClientError> for Error {
+    fn from(value: RpcClientError) -> Self {
+        Self::RpcError {
+            message: value.to_string(),
+        }
+    }
+}
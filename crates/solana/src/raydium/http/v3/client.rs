@@ -88,6 +88,12 @@ impl Default for HttpClient {
 }
 
 impl HttpClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+        }
+    }
+
     pub async fn list_token(&self) -> Result<TokenList, HttpError> {
         let url = format!("{}/mint/list", &self.base_url);
         Ok(Self::execute(reqwest::get(url).await?).await?.data)
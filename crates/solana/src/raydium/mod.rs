@@ -17,6 +17,10 @@ pub use http::v3::client::*;
 use crate::raydium::amm::AmmKeys;
 use crate::rpc::RpcClient;
 
+pub mod model;
+pub mod parse;
+pub mod repo;
+
 mod amm;
 mod error;
 mod http;
@@ -111,6 +115,24 @@ pub struct Raydium {
     config: SwapConfig,
 }
 
+impl Raydium {
+    pub fn new(rpc_client: RpcClient) -> Self {
+        Self {
+            http_client: HttpClient::default(),
+            rpc_client,
+            config: SwapConfig::default(),
+        }
+    }
+
+    pub fn new_with_http_client(rpc_client: RpcClient, http_client: HttpClient) -> Self {
+        Self {
+            http_client,
+            rpc_client,
+            config: SwapConfig::default(),
+        }
+    }
+}
+
 #[derive(Default, Copy, Clone, Debug)]
 pub struct SwapConfig {
     pub priority_fee: Option<PriorityFeeConfig>,
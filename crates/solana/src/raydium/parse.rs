@@ -0,0 +1,467 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::parse::{log_and_return_parse_error, ParseError, ParseResult, Parser};
+use crate::raydium::model::{AmmV4Swap, Instruction};
+use crate::raydium::{RAYDIUM_AUTHORITY, RAYDIUM_LIQUIDITY_POOL_V4_PROGRAM_ID};
+use base::model::solana::{CompiledInstruction, InnerInstruction, Transaction};
+use base::model::{DecimalAmount, Mint, PublicKey};
+use common::ByteReader;
+use log::{trace, warn};
+
+const SWAP_BASE_IN: u8 = 9;
+const SWAP_BASE_OUT: u8 = 11;
+const SWAP_BASE_IN_V2: u8 = 16;
+const SWAP_BASE_OUT_V2: u8 = 17;
+
+const TOKEN_TRANSFER: u8 = 3;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+
+/// Parses swaps of the Raydium AMM v4 program, whether sent to the program directly or
+/// routed through another program like an aggregator
+pub struct RaydiumParser {}
+
+impl RaydiumParser {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for RaydiumParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser<Vec<Instruction>> for RaydiumParser {
+    fn parse(&self, tx: &Transaction) -> ParseResult<Vec<Instruction>> {
+        let swaps = parse_swaps(tx)?;
+        if swaps.is_empty() {
+            return Ok(vec![]);
+        }
+
+        Ok(vec![Instruction::Swap {
+            swaps,
+            signer: tx.keys.static_account[0].clone(),
+        }])
+    }
+}
+
+fn parse_swaps(tx: &Transaction) -> ParseResult<Vec<AmmV4Swap>> {
+    let mut swaps = vec![];
+    for (index, instruction) in tx.instructions.iter().enumerate() {
+        let inner: Vec<&InnerInstruction> = tx
+            .inner_instructions
+            .iter()
+            .filter(|i| i.index as usize == index)
+            .flat_map(|i| i.instructions.iter())
+            .collect();
+
+        if is_swap(tx, instruction) {
+            let transfers = inner
+                .iter()
+                .filter(|i| i.stack_height.is_none_or(|height| height == 2))
+                .map(|i| &i.instruction);
+            let transfers = parse_transfers(tx, transfers)
+                .map_err(|err| log_and_return_parse_error(err, &tx.signature, "swap"))?;
+            swaps.push((instruction, transfers));
+        }
+
+        // swaps routed through another program, their transfers are nested one level deeper
+        for (position, routed) in inner.iter().enumerate() {
+            if !is_swap(tx, &routed.instruction) {
+                continue;
+            }
+
+            let Some(height) = routed.stack_height else {
+                trace!(
+                    "unable to determine transfers of routed raydium swap of {} without stack height",
+                    tx.signature
+                );
+                continue;
+            };
+
+            let transfers = inner[position + 1..]
+                .iter()
+                .take_while(|i| i.stack_height.is_some_and(|h| h > height))
+                .filter(|i| i.stack_height == Some(height + 1))
+                .map(|i| &i.instruction);
+            let transfers = parse_transfers(tx, transfers)
+                .map_err(|err| log_and_return_parse_error(err, &tx.signature, "swap"))?;
+            swaps.push((&routed.instruction, transfers));
+        }
+    }
+
+    let mut result = vec![];
+    for (instruction, transfers) in &swaps {
+        let Some(amm) = instruction
+            .accounts
+            .get(1)
+            .and_then(|idx| tx.keys.account(*idx))
+            .cloned()
+        else {
+            continue;
+        };
+
+        // the mints are derived from the net balance change of the pool vaults,
+        // which can not be attributed to several swaps against the same pool
+        let count = swaps
+            .iter()
+            .filter(|(other, _)| {
+                other.accounts.get(1).and_then(|idx| tx.keys.account(*idx)) == Some(&amm)
+            })
+            .count();
+        if count > 1 {
+            warn!(
+                "skipping {} raydium swaps against the same amm {} in {}",
+                count, amm, tx.signature
+            );
+            continue;
+        }
+
+        // the user pays into the pool first, afterwards the pool pays out to the user
+        let [input_amount, output_amount] = transfers[..] else {
+            trace!(
+                "expected 2 transfers for raydium swap of {} but got {}",
+                tx.signature,
+                transfers.len()
+            );
+            continue;
+        };
+
+        let (Some(input_mint), Some(output_mint)) = (
+            pool_mint(tx, input_amount, true),
+            pool_mint(tx, output_amount, false),
+        ) else {
+            trace!(
+                "unable to determine mints of raydium swap of {}",
+                tx.signature
+            );
+            continue;
+        };
+
+        result.push(AmmV4Swap {
+            amm,
+            input_mint,
+            input_amount: input_amount.into(),
+            output_mint,
+            output_amount: output_amount.into(),
+        });
+    }
+
+    Ok(result)
+}
+
+fn is_swap(tx: &Transaction, instruction: &CompiledInstruction) -> bool {
+    if tx.keys.account(instruction.program_id_index)
+        != Some(&PublicKey::from(RAYDIUM_LIQUIDITY_POOL_V4_PROGRAM_ID))
+    {
+        return false;
+    }
+
+    instruction.data.first().is_some_and(|discriminant| {
+        [
+            SWAP_BASE_IN,
+            SWAP_BASE_OUT,
+            SWAP_BASE_IN_V2,
+            SWAP_BASE_OUT_V2,
+        ]
+        .contains(discriminant)
+    })
+}
+
+/// Amounts of the spl token transfers among the instructions
+fn parse_transfers<'a>(
+    tx: &Transaction,
+    instructions: impl Iterator<Item = &'a CompiledInstruction>,
+) -> ParseResult<Vec<u64>> {
+    let token_program = PublicKey::from(spl_token::ID);
+    let token_2022_program = PublicKey::from(spl_token_2022::ID);
+
+    let mut result = vec![];
+    for instruction in instructions {
+        match tx.keys.account(instruction.program_id_index) {
+            Some(program) if *program == token_program || *program == token_2022_program => {}
+            _ => continue,
+        }
+
+        let reader = ByteReader::new(&instruction.data);
+        let tag = reader.read_u8()?;
+        if tag == TOKEN_TRANSFER || tag == TOKEN_TRANSFER_CHECKED {
+            result.push(reader.read_u64().map_err(|_| ParseError::DecodingFailed)?);
+        }
+    }
+    Ok(result)
+}
+
+/// Mint of the pool vault whose balance changed by the transferred amount.
+/// The vault received the amount if incoming, otherwise it paid it out
+fn pool_mint(tx: &Transaction, amount: u64, incoming: bool) -> Option<Mint> {
+    let authority = PublicKey::from(RAYDIUM_AUTHORITY);
+    tx.balance
+        .token
+        .iter()
+        .filter(|balance| balance.address == authority)
+        .find(|balance| {
+            let change = if incoming {
+                &balance.post.0 - &balance.pre.0
+            } else {
+                &balance.pre.0 - &balance.post.0
+            };
+            change == DecimalAmount::new(amount, balance.decimals).0
+        })
+        .map(|balance| balance.mint.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::convert::convert_transaction;
+    use crate::parse::Parser;
+    use crate::raydium::model::Instruction;
+    use crate::raydium::parse::RaydiumParser;
+    use crate::raydium::{RAYDIUM_AUTHORITY, RAYDIUM_LIQUIDITY_POOL_V4_PROGRAM_ID};
+    use base::model::solana::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Keys, Transaction,
+        TransactionBalance, TransactionStatus, TransactionTokenBalance,
+    };
+    use base::model::{DecimalAmount, Mint, PublicKey};
+    use lazy_static::lazy_static;
+    use solana_sdk::pubkey::Pubkey;
+    use solana_transaction_status::EncodedTransactionWithStatusMeta;
+    use std::collections::HashMap;
+
+    const SIGNER: &str = "ACHPeLHfDUcXQC5pa4NTB2R9TDQtAnwgfszhX2udq7S2";
+    const AMM: &str = "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2";
+    const TOKEN: &str = "27G8MtK7VtTcCHkpASjSDdkWWYfoqT6ggEuKidVJidD4";
+
+    fn swap_data(discriminant: u8) -> Vec<u8> {
+        let mut data = vec![discriminant];
+        data.extend(2_000_000_000u64.to_le_bytes());
+        data.extend(1u64.to_le_bytes());
+        data
+    }
+
+    fn transfer_data(amount: u64) -> Vec<u8> {
+        let mut data = vec![3];
+        data.extend(amount.to_le_bytes());
+        data
+    }
+
+    fn swap_transaction(program: Pubkey, inner: Vec<Vec<u8>>) -> Transaction {
+        Transaction {
+            signature: "signature1".into(),
+            status: TransactionStatus::Success,
+            balance: TransactionBalance {
+                sol: vec![],
+                token: vec![
+                    TransactionTokenBalance {
+                        mint: Mint::wsol(),
+                        address: RAYDIUM_AUTHORITY.into(),
+                        decimals: 9.into(),
+                        pre: DecimalAmount::new(100_000_000_000u64, 9),
+                        post: DecimalAmount::new(102_000_000_000u64, 9),
+                    },
+                    TransactionTokenBalance {
+                        mint: TOKEN.into(),
+                        address: RAYDIUM_AUTHORITY.into(),
+                        decimals: 6.into(),
+                        pre: DecimalAmount::new(900_000_000_000u64, 6),
+                        post: DecimalAmount::new(899_000_000_000u64, 6),
+                    },
+                ],
+            },
+            instructions: vec![CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![3, 2],
+                data: swap_data(9),
+            }],
+            inner_instructions: vec![InnerInstructions {
+                index: 0,
+                instructions: inner
+                    .into_iter()
+                    .map(|data| InnerInstruction {
+                        instruction: CompiledInstruction {
+                            program_id_index: 3,
+                            accounts: vec![],
+                            data,
+                        },
+                        stack_height: Some(2),
+                    })
+                    .collect(),
+            }],
+            log_messages: vec![],
+            keys: Keys {
+                static_account: vec![
+                    PublicKey::from(SIGNER),
+                    PublicKey::from(program),
+                    PublicKey::from(AMM),
+                    PublicKey::from(spl_token::ID),
+                ],
+                loaded_account: vec![],
+                log_account: vec![],
+            },
+        }
+    }
+
+    #[test]
+    fn test_swap_base_in() {
+        let test_instance = RaydiumParser::new();
+        let tx = swap_transaction(
+            RAYDIUM_LIQUIDITY_POOL_V4_PROGRAM_ID,
+            vec![transfer_data(2_000_000_000), transfer_data(1_000_000_000)],
+        );
+
+        let Instruction::Swap { swaps, signer } = test_instance.parse(&tx).unwrap().pop().unwrap();
+        assert_eq!(signer, SIGNER);
+
+        assert_eq!(swaps.len(), 1);
+        let swap = swaps.first().unwrap();
+        assert_eq!(swap.amm, AMM);
+        assert_eq!(swap.input_mint, Mint::wsol());
+        assert_eq!(swap.input_amount, 2_000_000_000);
+        assert_eq!(swap.output_mint, TOKEN);
+        assert_eq!(swap.output_amount, 1_000_000_000);
+    }
+
+    #[test]
+    fn test_other_program() {
+        let test_instance = RaydiumParser::new();
+        let tx = swap_transaction(
+            spl_token::ID,
+            vec![transfer_data(2_000_000_000), transfer_data(1_000_000_000)],
+        );
+        assert!(test_instance.parse(&tx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unmatched_amounts() {
+        let test_instance = RaydiumParser::new();
+        let tx = swap_transaction(
+            RAYDIUM_LIQUIDITY_POOL_V4_PROGRAM_ID,
+            vec![transfer_data(2_000_000_001), transfer_data(1_000_000_000)],
+        );
+        assert!(test_instance.parse(&tx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_missing_transfer() {
+        let test_instance = RaydiumParser::new();
+        let tx = swap_transaction(
+            RAYDIUM_LIQUIDITY_POOL_V4_PROGRAM_ID,
+            vec![transfer_data(2_000_000_000)],
+        );
+        assert!(test_instance.parse(&tx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_several_swaps_against_same_amm() {
+        let test_instance = RaydiumParser::new();
+        let mut tx = swap_transaction(
+            RAYDIUM_LIQUIDITY_POOL_V4_PROGRAM_ID,
+            vec![transfer_data(2_000_000_000), transfer_data(1_000_000_000)],
+        );
+        // each swap alone matches the net balance change of the vaults
+        tx.instructions.push(CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![3, 2],
+            data: swap_data(9),
+        });
+        tx.inner_instructions.push(InnerInstructions {
+            index: 1,
+            instructions: [2_000_000_000, 1_000_000_000]
+                .into_iter()
+                .map(|amount| InnerInstruction {
+                    instruction: CompiledInstruction {
+                        program_id_index: 3,
+                        accounts: vec![],
+                        data: transfer_data(amount),
+                    },
+                    stack_height: Some(2),
+                })
+                .collect(),
+        });
+        assert!(test_instance.parse(&tx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_mainnet_swap() {
+        let test_instance = RaydiumParser::new();
+        let tx = transaction(
+            "5eGREoKmuEDwomMXKMtMd3MfcWoNYcvG9MLwBjhxS1Rt3QPy7NJ2FiFtum7VPm6Crjz94ViopbZgDLsCEQwsioJt",
+        );
+
+        let Instruction::Swap { swaps, signer } = test_instance.parse(&tx).unwrap().pop().unwrap();
+        assert_eq!(signer, "7ACsEkYSvVyCE5AuYC6hP1bNs4SpgCDwsfm3UdnyPERk");
+
+        assert_eq!(swaps.len(), 1);
+        let swap = swaps.first().unwrap();
+        assert_eq!(swap.amm, "7KmVXCjTHmkKwrCJZKdWVRBnHzMmJN1GecPjco8EEdx6");
+        assert_eq!(
+            swap.input_mint,
+            "9hWn4yftfcpsEAga1jj3XutPHqTrJ8WNjELRcbk5pyZA"
+        );
+        assert_eq!(swap.input_amount, 963466221575);
+        assert_eq!(swap.output_mint, Mint::wsol());
+        assert_eq!(swap.output_amount, 313188274);
+    }
+
+    #[test]
+    fn test_mainnet_routed_swap() {
+        let test_instance = RaydiumParser::new();
+        let tx = transaction(
+            "4iW3QjobVANMXoqKvcmzMXiPRGejiXW24SK8tkZuWWwdmEJ4vnKuj6J6CwEgp9Cp4rCmbR8E3nBLaJhmpqPwvDGD",
+        );
+
+        let Instruction::Swap { swaps, signer } = test_instance.parse(&tx).unwrap().pop().unwrap();
+        assert_eq!(signer, "4q9CV44gLwfRDUeUou7HNhr6h7Mr1AkPByTtxqUeT8HL");
+
+        assert_eq!(swaps.len(), 1);
+        let swap = swaps.first().unwrap();
+        assert_eq!(swap.amm, "BAFcYRSGC8bYXhvYJ63Bc9q7P8q2fbzDU7hZu5fbEbsk");
+        assert_eq!(
+            swap.input_mint,
+            "2bW2fdEzuGhGFvcmxGUaHuTB7LPYWNmioacuSrweYGX8"
+        );
+        assert_eq!(swap.input_amount, 1614500056);
+        assert_eq!(swap.output_mint, Mint::wsol());
+        assert_eq!(swap.output_amount, 490578878);
+    }
+
+    #[test]
+    fn test_mainnet_routed_swap_with_lookup_table() {
+        let test_instance = RaydiumParser::new();
+        let tx = transaction(
+            "5WJyGHQvC92CxbVjL2DF7JwE35UPaYZHxZ9YbfG9vb46djxbfuX7HkdKsqKYwu1E95mSLRuK43LJZk8e7ReTjghg",
+        );
+
+        let Instruction::Swap { swaps, signer } = test_instance.parse(&tx).unwrap().pop().unwrap();
+        assert_eq!(signer, "HgLMHM5LrXCNvPhSGpfxxrYhFSZCjLVvFCYZiSAnmYdw");
+
+        assert_eq!(swaps.len(), 1);
+        let swap = swaps.first().unwrap();
+        assert_eq!(swap.amm, "7BfsG2xdeuqmvd7FK5aKkcKzPo8UWoaD9pGaHavbyuPg");
+        assert_eq!(swap.input_mint, Mint::wsol());
+        assert_eq!(swap.input_amount, 19223863);
+        assert_eq!(
+            swap.output_mint,
+            "BZy2VXHdSEtDjy8ku95Gx8ngxLruaDbEohx1ijvX7NhM"
+        );
+        assert_eq!(swap.output_amount, 43985740);
+    }
+
+    lazy_static! {
+        static ref transactions: HashMap<String, String> = HashMap::from([
+            ("5eGREoKmuEDwomMXKMtMd3MfcWoNYcvG9MLwBjhxS1Rt3QPy7NJ2FiFtum7VPm6Crjz94ViopbZgDLsCEQwsioJt".to_string(), r#"{"transaction":["Aeg0YnFI3vulCEutplubPf8cR5o7A9ihukfcQxdX9l6uim8NlWeyZ8VJIjiHXxcVjc/3fP3rPWpQI/fVSJpW4Q2AAQAGEluBxBUGPUeMNfN2FtsA5qx3o2nNW9HlyJMz5Wkpnlb/XfTiEhcn0H8bjBJpKXFSHqfBs/1UVyXalIDvLKdS08Mq2suABL3h64KFDk7J3nEVltA52N04KNAueO4WPQbGM530st/ZhJPLL4b1X8sL8L46ROA1C4fUOgjaNHedk8uSv16vVyOupzm5iSDaTNubY3pRhEjbAfbGsenmDUtEA2zBOVR3uMi1HmvH7EaObLUdEEyzIAP4mJR0K64PkChiiG2Dqgmf3IfFXnrZgbhaiI5xdjVcjfIbDpmzaFAleVrMR99ozuEgxjdkRanf6bkrVQJH3x/alvhCP19YO3OtPU4/KyExDAK/nzCRw6i7a8LfOt/pH9SJLs3XtDz3XOUEjWlCUtlGna5dm7QinMRz82foRJzlS2njNO+B1ec+4+NX/v8JI+/HYTf/VkGphLYD6T/pZ8knYfoYFOIazbCXIZvO1Nt6FjRqh0FZ5riFnee5i9atjjQRibyg6+6BYDd+HAMGRm/lIRcy/+ytunLDm+e8jOW7xfcSayxDmzpAAAAAS9lJxDYCwz8gd5DtFqNSTKG5l1zxIaKpDP/sffi2is0G3fbh12Whk9nL4UbO63msHLSF7V9bN5E6jPWFfv8AqUFXsFgPMcX85EpiWC28+deO51lDoISjk7NQNo0iiZMIDQdRqCgtphMF/imcN7mY5YRx2xE1A3MQ+L4QRaYK9u4ktrYUVbCHmJoVAa8LyD70vs2UvK9GtDv/kd3ErWFei8eBAfYZ1UIiTMseySY0ATvQ1y9mjp5Z4QDZIEuePauuBAwACQP17AEAAAAAAAwABQKZ6QEADRIOAQ8CAwQFEAYHCAkEBREKCwARCQfYD1PgAAAA2W9VCQAAAAAOAwoAAAEJAA==","base64"],"meta":{"err":null,"status":{"Ok":null},"fee":20818,"preBalances":[1480318043,6124800,23357760,16258560,2039280,43074416515,3591360,456770880,456770880,1825162560,2039280,78202390123,1,1141440,934087680,21983851584,1141440,0],"postBalances":[1482336505,6124800,23357760,16258560,2039280,42761228241,3591360,456770880,456770880,1825162560,0,78515578397,1,1141440,934087680,21983851584,1141440,0],"innerInstructions":[{"index":2,"instructions":[{"programIdIndex":14,"accounts":[10,4,0],"data":"3EmfCDHh5hMy","stackHeight":2},{"programIdIndex":14,"accounts":[5,11,15],"data":"3jNrw1jwa9cj","stackHeight":2}]}],"logMessages":["Program ComputeBudget111111111111111111111111111111 invoke [1]","Program ComputeBudget111111111111111111111111111111 success","Program ComputeBudget111111111111111111111111111111 invoke [1]","Program ComputeBudget111111111111111111111111111111 success","Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 invoke [1]","Program log: ray_log: AwfYD1PgAAAA2W9VCQAAAAACAAAAAAAAAAfYD1PgAAAAF4cuKVZ3AACTUVAHCgAAALLfqhIAAAAA","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]","Program log: Instruction: Transfer","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 4645 of 106481 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]","Program log: Instruction: Transfer","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 4736 of 98855 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 consumed 31954 of 125037 compute units","Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 success","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [1]","Program log: Instruction: CloseAccount","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 2916 of 93083 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success"],"preTokenBalances":[{"accountIndex":4,"mint":"9hWn4yftfcpsEAga1jj3XutPHqTrJ8WNjELRcbk5pyZA","uiTokenAmount":{"uiAmount":131211941.807895,"decimals":6,"amount":"131211941807895","uiAmountString":"131211941.807895"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":5,"mint":"So11111111111111111111111111111111111111112","uiTokenAmount":{"uiAmount":43.072377235,"decimals":9,"amount":"43072377235","uiAmountString":"43.072377235"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":10,"mint":"9hWn4yftfcpsEAga1jj3XutPHqTrJ8WNjELRcbk5pyZA","uiTokenAmount":{"uiAmount":963466.221575,"decimals":6,"amount":"963466221575","uiAmountString":"963466.221575"},"owner":"7ACsEkYSvVyCE5AuYC6hP1bNs4SpgCDwsfm3UdnyPERk","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":11,"mint":"So11111111111111111111111111111111111111112","uiTokenAmount":{"uiAmount":78.200350843,"decimals":9,"amount":"78200350843","uiAmountString":"78.200350843"},"owner":"7ACsEkYSvVyCE5AuYC6hP1bNs4SpgCDwsfm3UdnyPERk","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"}],"postTokenBalances":[{"accountIndex":4,"mint":"9hWn4yftfcpsEAga1jj3XutPHqTrJ8WNjELRcbk5pyZA","uiTokenAmount":{"uiAmount":132175408.02947,"decimals":6,"amount":"132175408029470","uiAmountString":"132175408.02947"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":5,"mint":"So11111111111111111111111111111111111111112","uiTokenAmount":{"uiAmount":42.759188961,"decimals":9,"amount":"42759188961","uiAmountString":"42.759188961"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":11,"mint":"So11111111111111111111111111111111111111112","uiTokenAmount":{"uiAmount":78.513539117,"decimals":9,"amount":"78513539117","uiAmountString":"78.513539117"},"owner":"7ACsEkYSvVyCE5AuYC6hP1bNs4SpgCDwsfm3UdnyPERk","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"}],"rewards":[],"loadedAddresses":{"writable":[],"readonly":[]},"computeUnitsConsumed":35170},"version":0}"#.to_string()),
+            ("5WJyGHQvC92CxbVjL2DF7JwE35UPaYZHxZ9YbfG9vb46djxbfuX7HkdKsqKYwu1E95mSLRuK43LJZk8e7ReTjghg".to_string(), r#"{"transaction":["AeFX+zor39hnFxeR24HTUpufFJMJJWkf0foZsIZCk+tlL1xw4q8MjGUxFCofIm9TB4KxeNwArrFPtlVPSp9yFAOAAQAHCvfOhWksskkFUuC6xdSzsMO2ox5qDuvpn2jnx+7kPqnKOZL+mIcHPw0KoIFR2esp7w+59kXrOB/rJ4hKgmDVzbjl/XKxNua7M40iI0vMTSRWWzAXGE8JgiwMWv1ObwX+EwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAwZGb+UhFzL/7K26csOb57yM5bvF9xJrLEObOkAAAAAEedVb8jHAbu50xW7OaBUH/bGy3qP0jlECsc2iVrwTjwbd9uHXZaGT2cvhRs7reawctIXtX1s3kTqM9YV+/wCpjJclj04kifG7PRApFI4NgwtaE5na/xCEBI572Nvp+FmdBs27MEygp6ciIF8pCHAp1h9DLNoA79Dynd2hL66HCLQ/+if11/ZKdMCbHylYed5LCas238ndUUsyGqezjOXo28DYSw/LyLLOIUNT8OLHUw6HdoF/hAaR6I6LaUSoBWEHBAAFAq01AQAEAAkDRTEAAAAAAAAHBgABAA0DBgEBAwIAAQwCAAAAN1UlAQAAAAAGAQEBEQUbBgABAgUIBQkFDwYKDgoMCwoKCgoKCgoKAQIAI+UXy5d6460qAQAAAAdkAAE3VSUBAAAAAGJLoAIAAAAA9AEABgMBAAABCQEch3LDMfjGXtER/RWLnvnH/J3Fza0t0LWC5Ig9SvTZ3wOmpacDCmlo","base64"],"meta":{"err":null,"status":{"Ok":null},"fee":6000,"preBalances":[252784160,0,2039280,1,1,1141440,934087680,731913600,1461600,0,6124800,2093334712789,2039280,997775614907,21983851584,1141440],"postBalances":[233554297,0,2039280,1,1,1141440,934087680,731913600,1461600,0,6124800,2093353936652,2039280,997775614907,21983851584,1141440],"innerInstructions":[{"index":2,"instructions":[{"programIdIndex":6,"accounts":[13],"data":"84eT","stackHeight":2},{"programIdIndex":3,"accounts":[0,1],"data":"11119os1e9qSs2u7TsThXqkBSRVFxhmYaFKFZ1waB2X7armDmvK3p5GmLdUxYdg3h7QSrL","stackHeight":2},{"programIdIndex":6,"accounts":[1],"data":"P","stackHeight":2},{"programIdIndex":6,"accounts":[1,13],"data":"6dp32DYc99NUDBsnxMysJSpz5kL9295mcx7iRD1rc4h4u","stackHeight":2}]},{"index":5,"instructions":[{"programIdIndex":15,"accounts":[6,10,14,10,12,11,10,10,10,10,10,10,10,10,1,2,0],"data":"62LkGMg73HRBvoaDR4v2GVu","stackHeight":2},{"programIdIndex":6,"accounts":[1,11,0],"data":"3NiMjj5fZMu9","stackHeight":3},{"programIdIndex":6,"accounts":[12,2,14],"data":"3SCWbbETCUdM","stackHeight":3},{"programIdIndex":5,"accounts":[9],"data":"QMqFu4fYGGeUEysFnenhAvR83g86EDDNxzUskfkWKYCBPWe1hqgD6jgKAXr6aYoEQaxoqYMTvWgPVk2AHWGHjdbNiNtoaPfZA4znu6cRUSWSeLG2sQrHy2XAAzKaWLfuWJGJYTfjoUGr58LeN7RgEKVVCG9QDQUACWG7PmAy83Loq9H","stackHeight":2}]}],"logMessages":["Program ComputeBudget111111111111111111111111111111 invoke [1]","Program ComputeBudget111111111111111111111111111111 success","Program ComputeBudget111111111111111111111111111111 invoke [1]","Program ComputeBudget111111111111111111111111111111 success","Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL invoke [1]","Program log: CreateIdempotent","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]","Program log: Instruction: GetAccountDataSize","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 1569 of 73572 compute units","Program return: TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA pQAAAAAAAAA=","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program 11111111111111111111111111111111 invoke [2]","Program 11111111111111111111111111111111 success","Program log: Initialize the associated token account","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]","Program log: Instruction: InitializeImmutableOwner","Program log: Please upgrade to SPL Token 2022 for immutable owner support","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 1405 of 66985 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]","Program log: Instruction: InitializeAccount3","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 3158 of 63103 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL consumed 19315 of 78977 compute units","Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL success","Program 11111111111111111111111111111111 invoke [1]","Program 11111111111111111111111111111111 success","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [1]","Program log: Instruction: SyncNative","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 3045 of 59512 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 invoke [1]","Program log: Instruction: Route","Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 invoke [2]","Program log: ray_log: AzdVJQEAAAAAAAAAAAAAAAABAAAAAAAAADdVJQEAAAAACuxI/10EAADls1lk5wEAAEwrnwIAAAAA","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [3]","Program log: Instruction: Transfer","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 4736 of 34684 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [3]","Program log: Instruction: Transfer","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 4645 of 26967 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 consumed 30450 of 51918 compute units","Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 success","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 invoke [2]","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 consumed 184 of 19738 compute units","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 success","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 consumed 38495 of 56467 compute units","Program return: JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 TCufAgAAAAA=","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 success","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [1]","Program log: Instruction: CloseAccount","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 2915 of 17972 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success"],"preTokenBalances":[{"accountIndex":2,"mint":"BZy2VXHdSEtDjy8ku95Gx8ngxLruaDbEohx1ijvX7NhM","uiTokenAmount":{"uiAmount":null,"decimals":6,"amount":"0","uiAmountString":"0"},"owner":"HgLMHM5LrXCNvPhSGpfxxrYhFSZCjLVvFCYZiSAnmYdw","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":11,"mint":"So11111111111111111111111111111111111111112","uiTokenAmount":{"uiAmount":2093.332673509,"decimals":9,"amount":"2093332673509","uiAmountString":"2093.332673509"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":12,"mint":"BZy2VXHdSEtDjy8ku95Gx8ngxLruaDbEohx1ijvX7NhM","uiTokenAmount":{"uiAmount":4801761.43873,"decimals":6,"amount":"4801761438730","uiAmountString":"4801761.43873"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"}],"postTokenBalances":[{"accountIndex":2,"mint":"BZy2VXHdSEtDjy8ku95Gx8ngxLruaDbEohx1ijvX7NhM","uiTokenAmount":{"uiAmount":43.98574,"decimals":6,"amount":"43985740","uiAmountString":"43.98574"},"owner":"HgLMHM5LrXCNvPhSGpfxxrYhFSZCjLVvFCYZiSAnmYdw","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":11,"mint":"So11111111111111111111111111111111111111112","uiTokenAmount":{"uiAmount":2093.351897372,"decimals":9,"amount":"2093351897372","uiAmountString":"2093.351897372"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":12,"mint":"BZy2VXHdSEtDjy8ku95Gx8ngxLruaDbEohx1ijvX7NhM","uiTokenAmount":{"uiAmount":4801717.45299,"decimals":6,"amount":"4801717452990","uiAmountString":"4801717.45299"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"}],"rewards":[],"loadedAddresses":{"writable":["7BfsG2xdeuqmvd7FK5aKkcKzPo8UWoaD9pGaHavbyuPg","9fy9R8fSA7MU8DRUKe6hshhc1TeSr7TFhchBtb42bC8C","FUncFjsL2DqAR9Yn28zx7WjEMzEoz1XtjpvtxJRrEWur"],"readonly":["So11111111111111111111111111111111111111112","5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"]},"computeUnitsConsumed":64220},"version":0}"#.to_string()),
+            ("4iW3QjobVANMXoqKvcmzMXiPRGejiXW24SK8tkZuWWwdmEJ4vnKuj6J6CwEgp9Cp4rCmbR8E3nBLaJhmpqPwvDGD".to_string(), r#"{"transaction":["AbnW8ntHSJXGOwUPFovEp0uFvwI8lwxvR6ptsWjZGmEQ9tdwhcSu7ZvH8HUPXmiY8WObuwvafk4to5xvefsYegqAAQAJDzjoNYimZP6vzoyyV2PsexmcqxFilqTz09OIIVoPGadvJN8reMJeKGh6XwsYcdOuaJHKn3neQ2nvOX334KG4qFQzpo2f2qR64VFJFeOAK5GLtFXWsQr02fl8A0MGdEDIwTZ7IJ5ePL2dlQgzQcB/mdb2AIvxs4mKZljhygmA9vXllvOeiQJje2l8Jor7491bcVmOcdZajB617cTqJ/kgyy/WFjDEj+ItDR2THxAgSe5TO0dD+uXE798JyDM6z6KfbgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAwZGb+UhFzL/7K26csOb57yM5bvF9xJrLEObOkAAAAAEedVb8jHAbu50xW7OaBUH/bGy3qP0jlECsc2iVrwTjwabiFf+q4GE+2h/Y0YYwDXaxDncGus7VZig8AAAAAABBt324ddloZPZy+FGzut5rBy0he1fWzeROoz1hX7/AKlBV7BYDzHF/ORKYlgtvPnXjudZQ6CEo5OzUDaNIomTCEvZScQ2AsM/IHeQ7RajUkyhuZdc8SGiqQz/7H34torNjJclj04kifG7PRApFI4NgwtaE5na/xCEBI572Nvp+Fm0P/on9df2SnTAmx8pWHneSwmrNt/J3VFLMhqns4zl6EnS9fO/q5sNfvz7WxMbTVoDEgqZplLJvsU+HNnzympIBQcABQKCNAEABwAJA3UxAAAAAAAADQYAAwAJBgoBAQgbCgACAwgJCA4IDAoECwQFAQQEBAQEBAQEAgMAI+UXy5d6460qAQAAAAdkAAHYUDtgAAAAAEPy2xwAAAAA9AEACgMDAAABCQA=","base64"],"meta":{"err":null,"status":{"Ok":null},"fee":6000,"preBalances":[111187135231,1749115865772,2039280,0,6124800,2039280,1,1,1141440,997775614907,934087680,21983851584,1141440,731913600,0],"postBalances":[111677708109,1748625286894,2039280,0,6124800,2039280,1,1,1141440,997775614907,934087680,21983851584,1141440,731913600,0],"innerInstructions":[{"index":2,"instructions":[{"programIdIndex":10,"accounts":[9],"data":"84eT","stackHeight":2},{"programIdIndex":6,"accounts":[0,3],"data":"11119os1e9qSs2u7TsThXqkBSRVFxhmYaFKFZ1waB2X7armDmvK3p5GmLdUxYdg3h7QSrL","stackHeight":2},{"programIdIndex":10,"accounts":[3],"data":"P","stackHeight":2},{"programIdIndex":10,"accounts":[3,9],"data":"6QxqsRFbUdnwFUxjzu4JcrgHVBzwfQv25ttdkTRATkGiJ","stackHeight":2}]},{"index":3,"instructions":[{"programIdIndex":12,"accounts":[10,4,11,4,5,1,4,4,4,4,4,4,4,4,2,3,0],"data":"6NDhs847f7KCCH3nWmYJJHd","stackHeight":2},{"programIdIndex":10,"accounts":[2,5,0],"data":"3qe5Ehpa3MNo","stackHeight":3},{"programIdIndex":10,"accounts":[1,3,11],"data":"3mLzC6H2KyeX","stackHeight":3},{"programIdIndex":8,"accounts":[14],"data":"QMqFu4fYGGeUEysFnenhAvR83g86EDDNxzUskfkWKYCBPWe1hqgD6jgKAXr6aYoEQb9cGVgEKpwVq6svdBqkmaoPMTx8rwbdgFo2kYJtxpb8T2TDomhjkeUPJ4q2hgLRxRnmsc4rXF2RJpNjXnzNjmpGd4mZCrqTrfZkXKPQXsizf9Z","stackHeight":2}]}],"logMessages":["Program ComputeBudget111111111111111111111111111111 invoke [1]","Program ComputeBudget111111111111111111111111111111 success","Program ComputeBudget111111111111111111111111111111 invoke [1]","Program ComputeBudget111111111111111111111111111111 success","Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL invoke [1]","Program log: CreateIdempotent","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]","Program log: Instruction: GetAccountDataSize","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 1569 of 70273 compute units","Program return: TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA pQAAAAAAAAA=","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program 11111111111111111111111111111111 invoke [2]","Program 11111111111111111111111111111111 success","Program log: Initialize the associated token account","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]","Program log: Instruction: InitializeImmutableOwner","Program log: Please upgrade to SPL Token 2022 for immutable owner support","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 1405 of 63686 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]","Program log: Instruction: InitializeAccount3","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 3158 of 59804 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL consumed 22315 of 78678 compute units","Program ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL success","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 invoke [1]","Program log: Instruction: Route","Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 invoke [2]","Program log: ray_log: A9hQO2AAAAAAAAAAAAAAAAACAAAAAAAAADmUKOEBAAAArz2FhzgFAAC87E4/lwEAAL6jPR0AAAAA","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [3]","Program log: Instruction: Transfer","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 4645 of 34625 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [3]","Program log: Instruction: Transfer","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 4736 of 26999 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success","Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 consumed 30406 of 51814 compute units","Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 success","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 invoke [2]","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 consumed 184 of 19678 compute units","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 success","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 consumed 38451 of 56363 compute units","Program return: JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 vqM9HQAAAAA=","Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 success","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [1]","Program log: Instruction: CloseAccount","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 2915 of 17912 compute units","Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success"],"preTokenBalances":[{"accountIndex":1,"mint":"So11111111111111111111111111111111111111112","uiTokenAmount":{"uiAmount":1749.113826492,"decimals":9,"amount":"1749113826492","uiAmountString":"1749.113826492"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":2,"mint":"2bW2fdEzuGhGFvcmxGUaHuTB7LPYWNmioacuSrweYGX8","uiTokenAmount":{"uiAmount":8072.500281,"decimals":6,"amount":"8072500281","uiAmountString":"8072.500281"},"owner":"4q9CV44gLwfRDUeUou7HNhr6h7Mr1AkPByTtxqUeT8HL","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":5,"mint":"2bW2fdEzuGhGFvcmxGUaHuTB7LPYWNmioacuSrweYGX8","uiTokenAmount":{"uiAmount":5740349.963695,"decimals":6,"amount":"5740349963695","uiAmountString":"5740349.963695"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"}],"postTokenBalances":[{"accountIndex":1,"mint":"So11111111111111111111111111111111111111112","uiTokenAmount":{"uiAmount":1748.623247614,"decimals":9,"amount":"1748623247614","uiAmountString":"1748.623247614"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":2,"mint":"2bW2fdEzuGhGFvcmxGUaHuTB7LPYWNmioacuSrweYGX8","uiTokenAmount":{"uiAmount":6458.000225,"decimals":6,"amount":"6458000225","uiAmountString":"6458.000225"},"owner":"4q9CV44gLwfRDUeUou7HNhr6h7Mr1AkPByTtxqUeT8HL","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"},{"accountIndex":5,"mint":"2bW2fdEzuGhGFvcmxGUaHuTB7LPYWNmioacuSrweYGX8","uiTokenAmount":{"uiAmount":5741964.463751,"decimals":6,"amount":"5741964463751","uiAmountString":"5741964.463751"},"owner":"5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1","programId":"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"}],"rewards":[],"loadedAddresses":{"writable":[],"readonly":[]},"computeUnitsConsumed":63981},"version":0}"#.to_string()),
+        ]);
+    }
+
+    fn transaction(signature: impl AsRef<str>) -> Transaction {
+        let json = transactions
+            .get(signature.as_ref())
+            .unwrap_or_else(|| panic!("Transaction not found: {}", signature.as_ref()));
+        let tx: EncodedTransactionWithStatusMeta = serde_json::from_str(json).unwrap();
+        convert_transaction(tx)
+    }
+}
@@ -0,0 +1,10 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+pub use candle::{CandleQuery, CandleRepo};
+pub use swap::{ReadSwapRepo, SlotSwap, SlotSwaps, SwapQueryAll, SwapRepo};
+pub use twap::{TwapQuery, TwapRepo};
+
+mod candle;
+mod swap;
+mod twap;
@@ -4,18 +4,14 @@
 // This file includes portions of code from https://github.com/0xcrust/raydium-swap (MIT License).
 // Original MIT License Copyright (c) 0xcrust 2024.
 
-use std::sync::Arc;
-
 use crate::raydium;
 use crate::raydium::amm::AmmKeys;
 use crate::raydium::ix::SwapInstructionsBuilder;
 use crate::raydium::{
-    Raydium, RaydiumQuote, SwapConfigOverrides, RAYDIUM_AUTHORITY,
+    Error, Raydium, RaydiumQuote, SwapConfigOverrides, RAYDIUM_AUTHORITY,
     RAYDIUM_LIQUIDITY_POOL_V4_PROGRAM_ID,
 };
 use base::model::solana::Signature;
-use solana_client::rpc_config::RpcSendTransactionConfig;
-use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signer};
@@ -30,38 +26,23 @@ impl Raydium {
         quote: RaydiumQuote,
         overrides: Option<SwapConfigOverrides>,
     ) -> raydium::Result<Signature> {
-        let client = Arc::new(RpcClient::new(
-            "https://api.mainnet-beta.solana.com".to_string(),
-        ));
-
         let keypair = Keypair::from_base58_string(keypair.as_str());
 
         let mut transaction = self
             .swap_transaction(keypair.pubkey(), quote, overrides)
-            .await
-            .unwrap();
+            .await?;
 
-        let blockhash = client.get_latest_blockhash().await.unwrap();
+        let blockhash = self.rpc_client.latest_blockhash().await?;
         transaction.message.set_recent_blockhash(blockhash);
 
-        let swap_tx = VersionedTransaction::try_new(transaction.message, &[&keypair]).unwrap();
-
-        let result = client
-            .send_transaction_with_config(
-                &swap_tx,
-                RpcSendTransactionConfig {
-                    skip_preflight: false,
-                    preflight_commitment: None,
-                    encoding: None,
-                    max_retries: None,
-                    min_context_slot: None,
-                },
-            )
-            .await
-            .expect("Failed to swap");
-
-        // println!("{result}");
-        Ok(Signature(result.to_string()))
+        let swap_tx =
+            VersionedTransaction::try_new(transaction.message, &[&keypair]).map_err(|err| {
+                Error::TransactionError {
+                    message: err.to_string(),
+                }
+            })?;
+
+        Ok(self.rpc_client.send_transaction(&swap_tx).await?)
     }
 
     pub async fn swap_instructions(
@@ -90,10 +71,6 @@ impl Raydium {
         quote: RaydiumQuote,
         overrides: Option<SwapConfigOverrides>,
     ) -> raydium::Result<SwapInstructionsBuilder> {
-        let client = Arc::new(RpcClient::new(
-            "https://api.mainnet-beta.solana.com".to_string(),
-        ));
-
         let priority_fee = overrides
             .clone()
             .and_then(|o| o.priority_fee)
@@ -133,7 +110,7 @@ impl Raydium {
         builder.swap_instruction = Some(instruction);
 
         let compute_units = builder
-            .handle_compute_units_params(cu_limits, client.as_ref(), input_pubkey)
+            .handle_compute_units_params(cu_limits, self.rpc_client.delegate.as_ref(), input_pubkey)
             .await?;
 
         builder.handle_priority_fee_params(priority_fee, compute_units)?;
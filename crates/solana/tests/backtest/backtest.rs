@@ -0,0 +1,157 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::Field::{Price, SwapBuy};
+use base::model::Operator::{IncreasedByMoreThan, MoreThan};
+use base::model::{Action, Condition, Sequence, TokenPairId, Value, Venue};
+use common::model::{PriceQuote, Timeframe, Timestamp};
+use solana::backtest::{BacktestQuery, BacktestService};
+use sqlx::{Executor, PgPool};
+use testing::run_test_with_pool;
+
+async fn insert_candles(pool: &PgPool) {
+    pool.execute(
+        r#"
+    insert into pumpfun.candle_1m (token_pair_id, timestamp, open, high, low, close, avg, amount_base_buy, amount_quote_buy, amount_base_sell, amount_quote_sell, swap_buy, swap_sell, volume_buy, volume_sell) values
+        (1, '2025-03-20 10:00:00+00', 10, 10, 10, 10, 10, 0, 0, 0, 0, 1, 0, 1, 0),
+        (1, '2025-03-20 10:01:00+00', 10, 10, 10, 10, 10, 0, 0, 0, 0, 1, 0, 1, 0),
+        (1, '2025-03-20 10:02:00+00', 15, 15, 15, 15, 15, 0, 0, 0, 0, 1, 0, 1, 0),
+        (1, '2025-03-20 10:03:00+00', 16, 16, 16, 16, 16, 0, 0, 0, 0, 1, 0, 1, 0),
+        (2, '2025-03-20 10:00:00+00', 10, 10, 10, 10, 10, 0, 0, 0, 0, 1, 0, 1, 0),
+        (2, '2025-03-20 10:01:00+00', 20, 20, 20, 20, 20, 0, 0, 0, 0, 1, 0, 1, 0);
+
+    insert into pumpfun.candle_progress_1m (token_pair_id, timestamp, open, high, low, close, avg) values
+        (1, '2025-03-20 10:02:00+00', 10, 20, 10, 20, 15);
+    "#,
+    )
+    .await
+    .unwrap();
+}
+
+fn price_increased_by_more_than_30_percent() -> Sequence {
+    Sequence {
+        condition: Condition::Compare {
+            field: Price,
+            operator: IncreasedByMoreThan,
+            value: Some(Value::percent(30.0)),
+            timeframe: Some(Timeframe::M1),
+        },
+        action: Action::Buy,
+    }
+}
+
+/// 2025-03-20 10:00:00 UTC plus the given minutes
+fn timestamp(minutes: i64) -> Timestamp {
+    Timestamp::from_epoch_second(1_742_464_800 + minutes * 60).unwrap()
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_backtest() {
+    run_test_with_pool(|pool| async move {
+        insert_candles(&pool).await;
+
+        let test_instance = BacktestService::new(pool);
+        let result = test_instance
+            .backtest(BacktestQuery {
+                venue: Venue::PumpFun,
+                sequence: price_increased_by_more_than_30_percent(),
+                from: timestamp(0),
+                to: timestamp(60),
+                token_pairs: vec![TokenPairId(1), TokenPairId(2)],
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 2);
+
+        assert_eq!(result[0].token_pair, TokenPairId(1));
+        assert_eq!(result[0].timestamp, timestamp(2));
+        assert_eq!(result[0].price, PriceQuote::from(15i64));
+        assert_eq!(result[0].price_path[0].after, Timeframe::M1);
+        assert_eq!(result[0].price_path[0].price, PriceQuote::from(16i64));
+
+        assert_eq!(result[1].token_pair, TokenPairId(2));
+        assert_eq!(result[1].timestamp, timestamp(1));
+        assert_eq!(result[1].price, PriceQuote::from(20i64));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_backtest_token_pairs_only() {
+    run_test_with_pool(|pool| async move {
+        insert_candles(&pool).await;
+
+        let test_instance = BacktestService::new(pool);
+        let result = test_instance
+            .backtest(BacktestQuery {
+                venue: Venue::PumpFun,
+                sequence: price_increased_by_more_than_30_percent(),
+                from: timestamp(0),
+                to: timestamp(60),
+                token_pairs: vec![TokenPairId(2)],
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].token_pair, TokenPairId(2));
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_backtest_without_candles() {
+    run_test_with_pool(|pool| async move {
+        let test_instance = BacktestService::new(pool);
+        let result = test_instance
+            .backtest(BacktestQuery {
+                venue: Venue::Jupiter,
+                sequence: price_increased_by_more_than_30_percent(),
+                from: timestamp(0),
+                to: timestamp(60),
+                token_pairs: vec![TokenPairId(1)],
+            })
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_backtest_limits_matches() {
+    run_test_with_pool(|pool| async move {
+        pool.execute(
+            r#"
+        insert into pumpfun.candle_1m (token_pair_id, timestamp, open, high, low, close, avg, amount_base_buy, amount_quote_buy, amount_base_sell, amount_quote_sell, swap_buy, swap_sell, volume_buy, volume_sell)
+        select 1, '2025-03-20 10:00:00+00'::timestamptz + minute * interval '1 minute', 10, 10, 10, 10, 10, 0, 0, 0, 0, 1, 0, 1, 0
+        from generate_series(0, 1199) as minute;
+        "#,
+        )
+        .await
+        .unwrap();
+
+        let test_instance = BacktestService::new(pool);
+        let result = test_instance
+            .backtest(BacktestQuery {
+                venue: Venue::PumpFun,
+                sequence: Sequence {
+                    condition: Condition::Compare {
+                        field: SwapBuy,
+                        operator: MoreThan,
+                        value: Some(Value::count(0)),
+                        timeframe: Some(Timeframe::M1),
+                    },
+                    action: Action::Buy,
+                },
+                from: timestamp(0),
+                to: timestamp(1200),
+                token_pairs: vec![TokenPairId(1)],
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1000);
+        assert_eq!(result[0].timestamp, timestamp(0));
+        assert_eq!(result[999].timestamp, timestamp(999));
+    })
+    .await
+}
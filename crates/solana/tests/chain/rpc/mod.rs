@@ -3,4 +3,5 @@
 
 mod get_account;
 mod list_accounts;
-mod get_block;
\ No newline at end of file
+mod get_block;
+mod transfer;
@@ -0,0 +1,179 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::KeyPair;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use spl_associated_token_account::get_associated_token_address;
+use testing::rpc::LocalRpc;
+
+const MINT: Pubkey = pubkey!("CpV7zK77DkyVvbCgGWtWrsqvh2VonFrqPg8cecrTpump");
+const RECIPIENT: Pubkey = pubkey!("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM");
+const ASSOCIATED_TOKEN_PROGRAM: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+#[test_log::test(tokio::test)]
+async fn test_transfer_sol() {
+    let rpc = LocalRpc::new();
+    let payer = KeyPair::generate();
+    let test_instance = rpc.client();
+
+    let result = test_instance
+        .transfer_sol(&payer, RECIPIENT, 1_000_000)
+        .await
+        .unwrap();
+
+    let transactions = rpc.sent_transactions();
+    assert_eq!(transactions.len(), 1);
+
+    let transaction = transactions.first().unwrap();
+    assert_eq!(result.0, transaction.signatures[0].to_string());
+
+    let message = &transaction.message;
+    let keys = message.static_account_keys();
+    assert_eq!(keys[0], Pubkey::from(payer.public));
+    assert_eq!(message.instructions().len(), 1);
+
+    let instruction = &message.instructions()[0];
+    assert_eq!(
+        keys[instruction.program_id_index as usize],
+        system_program::ID
+    );
+    assert_eq!(keys[instruction.accounts[1] as usize], RECIPIENT);
+    assert_eq!(instruction.data[..4], 2u32.to_le_bytes());
+    assert_eq!(instruction.data[4..12], 1_000_000u64.to_le_bytes());
+}
+
+#[test_log::test(tokio::test)]
+async fn test_transfer_token_creates_recipient_account() {
+    let rpc = LocalRpc::new();
+    let payer = KeyPair::generate();
+    rpc.add_mint(MINT, 6, 1_000_000_000_000_000);
+    rpc.add_token_account(payer.public.clone().into(), MINT, 5_000_000);
+
+    let test_instance = rpc.client();
+    test_instance
+        .transfer_token(&payer, RECIPIENT, MINT, 2_000_000, 6)
+        .await
+        .unwrap();
+
+    let transactions = rpc.sent_transactions();
+    assert_eq!(transactions.len(), 1);
+
+    let message = &transactions[0].message;
+    let keys = message.static_account_keys();
+    assert_eq!(message.instructions().len(), 2);
+
+    let create_ata = &message.instructions()[0];
+    assert_eq!(
+        keys[create_ata.program_id_index as usize],
+        ASSOCIATED_TOKEN_PROGRAM
+    );
+
+    let transfer = &message.instructions()[1];
+    assert_eq!(keys[transfer.program_id_index as usize], spl_token::ID);
+    assert_eq!(
+        keys[transfer.accounts[0] as usize],
+        get_associated_token_address(&payer.public.clone().into(), &MINT)
+    );
+    assert_eq!(keys[transfer.accounts[1] as usize], MINT);
+    assert_eq!(
+        keys[transfer.accounts[2] as usize],
+        get_associated_token_address(&RECIPIENT, &MINT)
+    );
+    // TransferChecked
+    assert_eq!(transfer.data[0], 12);
+    assert_eq!(transfer.data[1..9], 2_000_000u64.to_le_bytes());
+    assert_eq!(transfer.data[9], 6);
+}
+
+#[test_log::test(tokio::test)]
+async fn test_transfer_token_recipient_account_exists() {
+    let rpc = LocalRpc::new();
+    let payer = KeyPair::generate();
+    rpc.add_mint(MINT, 6, 1_000_000_000_000_000);
+    rpc.add_token_account(payer.public.clone().into(), MINT, 5_000_000);
+    rpc.add_token_account(RECIPIENT, MINT, 0);
+
+    let test_instance = rpc.client();
+    test_instance
+        .transfer_token(&payer, RECIPIENT, MINT, 2_000_000, 6)
+        .await
+        .unwrap();
+
+    let transactions = rpc.sent_transactions();
+    let message = &transactions[0].message;
+    let keys = message.static_account_keys();
+    assert_eq!(message.instructions().len(), 1);
+
+    let transfer = &message.instructions()[0];
+    assert_eq!(keys[transfer.program_id_index as usize], spl_token::ID);
+}
+
+#[test_log::test(tokio::test)]
+async fn test_transfer_token_mint_missing() {
+    let rpc = LocalRpc::new();
+    let payer = KeyPair::generate();
+
+    let test_instance = rpc.client();
+    let result = test_instance
+        .transfer_token(&payer, RECIPIENT, MINT, 2_000_000, 6)
+        .await;
+
+    assert!(result.is_err());
+    assert!(rpc.sent_transactions().is_empty());
+}
+
+#[test_log::test(tokio::test)]
+async fn test_transfer_sol_reserve() {
+    let rpc = LocalRpc::new();
+    let test_instance = rpc.client();
+    // fee + rent exempt minimum of the payer
+    assert_eq!(test_instance.transfer_sol_reserve(), 5_000 + 890_880);
+}
+
+#[test_log::test(tokio::test)]
+async fn test_transfer_token_reserve_creates_recipient_account() {
+    let rpc = LocalRpc::new();
+    rpc.add_mint(MINT, 6, 1_000_000_000_000_000);
+
+    let test_instance = rpc.client();
+    let result = test_instance
+        .transfer_token_reserve(RECIPIENT, MINT)
+        .await
+        .unwrap();
+    assert_eq!(result, 5_000 + 890_880 + 2_039_280);
+}
+
+#[test_log::test(tokio::test)]
+async fn test_transfer_token_reserve_recipient_account_exists() {
+    let rpc = LocalRpc::new();
+    rpc.add_mint(MINT, 6, 1_000_000_000_000_000);
+    rpc.add_token_account(RECIPIENT, MINT, 0);
+
+    let test_instance = rpc.client();
+    let result = test_instance
+        .transfer_token_reserve(RECIPIENT, MINT)
+        .await
+        .unwrap();
+    assert_eq!(result, 5_000 + 890_880);
+}
+
+#[test_log::test(tokio::test)]
+async fn test_sol_balance() {
+    let rpc = LocalRpc::new();
+    rpc.add_sol(RECIPIENT, 1_500_000);
+
+    let test_instance = rpc.client();
+    assert_eq!(
+        test_instance.sol_balance(RECIPIENT).await.unwrap(),
+        1_500_000
+    );
+    assert_eq!(
+        test_instance
+            .sol_balance(Pubkey::from(KeyPair::generate().public))
+            .await
+            .unwrap(),
+        0
+    );
+}
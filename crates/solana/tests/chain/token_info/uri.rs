@@ -1,7 +1,10 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+use common::Limiter;
 use solana::token_info::uri::TokenInfoUriLoader;
+use std::time::Duration;
+use tokio::time::Instant;
 
 #[test_log::test(tokio::test)]
 async fn test_pumpfun_ipfs() {
@@ -23,3 +26,21 @@ async fn test_pumpfun_ipfs() {
         "https://ipfs.io/ipfs/QmVSkzzGYea342mk8PvWz1WHokyGUFs8LQ7AgBFJMYdEaG"
     )
 }
+
+#[test_log::test(tokio::test)]
+async fn test_requests_wait_for_limiter() {
+    // the limiter starts its clock when constructed
+    let start = Instant::now();
+    let test_instance =
+        TokenInfoUriLoader::new_limited(Limiter::new(1, Duration::from_millis(500)));
+
+    for _ in 0..3 {
+        let token_info = test_instance
+            .load("http://127.0.0.1:1/metadata.json".into())
+            .await;
+        assert!(token_info.is_none());
+    }
+
+    // the second and the third request wait for the limiter to reset
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
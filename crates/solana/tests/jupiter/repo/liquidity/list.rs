@@ -0,0 +1,97 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::{Amm, Mint};
+use common::model::BlockTimestamp;
+use solana::jupiter::repo::{LiquidityRepo, SlotSwap, SlotSwapLeg, SlotSwaps};
+use testing::jupiter::insert_swap;
+use testing::run_test_on_empty_db;
+
+#[test_log::test(sqlx::test)]
+async fn test_ok() {
+    run_test_on_empty_db(|mut tx| async move {
+        // WSOL -> USDC -> mint1
+        let mut swaps = insert_swap(
+            &mut tx,
+            SlotSwaps {
+                slot: 12345.into(),
+                timestamp: BlockTimestamp::now(),
+                swaps: vec![SlotSwap {
+                    input_mint: Mint::wsol(),
+                    input_amount: 2_000_000_000.into(),
+                    output_mint: "mint1".into(),
+                    output_amount: 1_000_000.into(),
+                    wallet: Default::default(),
+                    signature: "signature1".into(),
+                    legs: vec![
+                        SlotSwapLeg {
+                            amm: "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc".into(),
+                            input_mint: Mint::wsol(),
+                            input_amount: 2_000_000_000.into(),
+                            output_mint: Mint::usdc(),
+                            output_amount: 300_000_000.into(),
+                        },
+                        SlotSwapLeg {
+                            amm: "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8".into(),
+                            input_mint: Mint::usdc(),
+                            input_amount: 300_000_000.into(),
+                            output_mint: "mint1".into(),
+                            output_amount: 1_000_000.into(),
+                        },
+                    ],
+                }],
+            },
+        )
+        .await;
+        assert_eq!(swaps.len(), 1);
+
+        // mint1/WSOL never got swapped directly, but mint1 has liquidity on Raydium against USDC
+        let pair = swaps.pop().unwrap().token_pair;
+
+        let mut result = LiquidityRepo::new()
+            .list_by_token_pairs(&mut tx, vec![pair])
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.remove(&pair).unwrap(), vec![Amm::Raydium]);
+    })
+    .await;
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_unknown_amm() {
+    run_test_on_empty_db(|mut tx| async move {
+        let mut swaps = insert_swap(
+            &mut tx,
+            SlotSwaps {
+                slot: 12345.into(),
+                timestamp: BlockTimestamp::now(),
+                swaps: vec![SlotSwap {
+                    input_mint: Mint::wsol(),
+                    input_amount: 2_000_000_000.into(),
+                    output_mint: "mint1".into(),
+                    output_amount: 1_000_000.into(),
+                    wallet: Default::default(),
+                    signature: "signature1".into(),
+                    legs: vec![SlotSwapLeg {
+                        amm: "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4".into(),
+                        input_mint: Mint::wsol(),
+                        input_amount: 2_000_000_000.into(),
+                        output_mint: "mint1".into(),
+                        output_amount: 1_000_000.into(),
+                    }],
+                }],
+            },
+        )
+        .await;
+
+        let pair = swaps.pop().unwrap().token_pair;
+
+        let result = LiquidityRepo::new()
+            .list_by_token_pairs(&mut tx, vec![pair])
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+    })
+    .await;
+}
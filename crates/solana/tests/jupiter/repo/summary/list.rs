@@ -0,0 +1,141 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::TokenPairId;
+use common::model::{Limit, Timeframe};
+use common::repo::Tx;
+use solana::jupiter::repo::{SummaryQuery, SummaryRepo};
+use sqlx::Executor;
+use testing::run_test;
+
+async fn insert_candles(tx: &mut Tx<'_>) {
+    tx.execute(
+        r#"
+    insert into jupiter.candle_1m (token_pair_id, timestamp, open, high, low, close, avg, amount_base_buy, amount_quote_buy, amount_base_sell, amount_quote_sell, swap_buy, swap_sell, volume_buy, volume_sell) values
+        (1, now() - interval '90 seconds', 1, 2, 1, 2, 1.5, 0, 0, 0, 0, 4, 6, 10, 20),
+        (1, now() - interval '30 seconds', 2, 3, 2, 3, 2.5, 0, 0, 0, 0, 6, 9, 20, 30),
+        (2, now() - interval '10 seconds', 5, 5, 5, 5, 5, 0, 0, 0, 0, 1, 0, 5, 0);
+
+    insert into jupiter.candle_usd_1m (token_pair_id, timestamp, open, high, low, close, avg, twap, volume_buy, volume_sell) values
+        (1, now() - interval '30 seconds', 200, 300, 200, 300, 250, 250, 2000, 3000);
+
+    insert into jupiter.candle_market_cap_1m (token_pair_id, timestamp, open, open_usd, high, high_usd, low, low_usd, close, close_usd, avg, avg_usd) values
+        (1, now() - interval '30 seconds', 20, 2000, 30, 3000, 20, 2000, 30, 3000, 25, 2500);
+    "#,
+    )
+    .await
+    .unwrap();
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list() {
+    run_test(|mut tx| async move {
+        insert_candles(&mut tx).await;
+
+        let test_instance = SummaryRepo::new();
+        let result = test_instance
+            .list(
+                &mut tx,
+                SummaryQuery {
+                    limit: Limit::unlimited(),
+                    timeframe: Timeframe::M1,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 2);
+
+        let (token_pair, summary) = &result[0];
+        assert_eq!(*token_pair, 1);
+        assert_eq!(summary.price, 3);
+        assert_eq!(summary.price_avg, "2.5");
+        assert_eq!(summary.price_usd.clone().unwrap(), 300);
+        assert_eq!(summary.price_avg_usd.clone().unwrap(), 250);
+        assert_eq!(summary.market_cap.clone().unwrap(), 30);
+        assert_eq!(summary.market_cap_usd.clone().unwrap(), 3000);
+
+        assert_eq!(summary.swap.count, 15);
+        assert_eq!(summary.swap.change.unwrap(), 5);
+        assert_eq!(summary.swap.percent.unwrap(), 50.0);
+        assert_eq!(summary.swap_buy.count, 6);
+        assert_eq!(summary.swap_buy.change.unwrap(), 2);
+        assert_eq!(summary.swap_buy.percent.unwrap(), 50.0);
+        assert_eq!(summary.swap_sell.count, 9);
+        assert_eq!(summary.swap_sell.change.unwrap(), 3);
+        assert_eq!(summary.swap_sell.percent.unwrap(), 50.0);
+
+        assert_eq!(summary.volume, 50);
+        assert_eq!(summary.volume_change.clone().unwrap(), 20);
+
+        let (token_pair, summary) = &result[1];
+        assert_eq!(*token_pair, 2);
+        assert_eq!(summary.price, 5);
+        assert_eq!(summary.price_usd, None);
+        assert_eq!(summary.market_cap, None);
+        assert_eq!(summary.swap.count, 1);
+        assert_eq!(summary.swap.change, None);
+        assert_eq!(summary.swap.percent, None);
+        assert_eq!(summary.volume_change, None);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_by_token_pairs() {
+    run_test(|mut tx| async move {
+        insert_candles(&mut tx).await;
+
+        let test_instance = SummaryRepo::new();
+        let result = test_instance
+            .list_by_token_pairs(&mut tx, [TokenPairId(2), TokenPairId(3)], Timeframe::M1)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0, 2);
+        assert_eq!(result[0].1.price, 5);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_ignores_dormant_token_pairs() {
+    run_test(|mut tx| async move {
+        insert_candles(&mut tx).await;
+        tx.execute(
+            r#"
+        insert into jupiter.candle_1m (token_pair_id, timestamp, open, high, low, close, avg, amount_base_buy, amount_quote_buy, amount_base_sell, amount_quote_sell, swap_buy, swap_sell, volume_buy, volume_sell) values
+            (3, now() - interval '2 days', 7, 7, 7, 7, 7, 0, 0, 0, 0, 20, 20, 5, 0);
+        "#,
+        )
+        .await
+        .unwrap();
+
+        let test_instance = SummaryRepo::new();
+        let result = test_instance
+            .list_by_token_pairs(&mut tx, [TokenPairId(2), TokenPairId(3)], Timeframe::M1)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0, 2);
+    })
+    .await
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_list_without_candles() {
+    run_test(|mut tx| async move {
+        let test_instance = SummaryRepo::new();
+        let result = test_instance
+            .list(
+                &mut tx,
+                SummaryQuery {
+                    limit: Limit::unlimited(),
+                    timeframe: Timeframe::H1,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+    })
+    .await
+}
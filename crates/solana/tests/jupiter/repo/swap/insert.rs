@@ -5,8 +5,8 @@ use base::model::Mint;
 use base::test::{FailingTokenInfoLoader, SuccessfulTokenInfoLoader};
 use common::model::BlockTimestamp;
 use common::repo::error::RepoError;
-use solana::jupiter::repo::{SlotSwap, SlotSwaps, SwapRepo};
-use testing::jupiter::count_swaps;
+use solana::jupiter::repo::{SlotSwap, SlotSwapLeg, SlotSwaps, SwapRepo};
+use testing::jupiter::{count_swap_legs, count_swaps, list_legs_with_signature};
 use testing::run_test_on_empty_db;
 
 fn default_slot_swaps() -> SlotSwaps {
@@ -20,6 +20,7 @@ fn default_slot_swaps() -> SlotSwaps {
             output_amount: 1_000_000.into(),
             wallet: Default::default(),
             signature: "signature1".into(),
+            legs: vec![],
         }],
     }
 }
@@ -67,6 +68,7 @@ async fn test_multiple() {
                     output_amount: 1_000_000.into(),
                     wallet: "7PQ3nyAJHXiFQd5c8HgRBMYLF748MQKgq3uYfTuFioHX".into(),
                     signature: "signature1".into(),
+                    legs: vec![],
                 },
                 SlotSwap {
                     input_mint: "mint2".into(),
@@ -75,6 +77,7 @@ async fn test_multiple() {
                     output_amount: 6_000_000_000i64.into(),
                     wallet: "Bp65Vdx5o5THggj1ZHYsVwaKPhp999mRmAeKyFG9FVnT".into(),
                     signature: "signature2".into(),
+                    legs: vec![],
                 },
             ],
         };
@@ -111,6 +114,187 @@ async fn test_multiple() {
     .await;
 }
 
+#[test_log::test(sqlx::test)]
+async fn test_route() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = SwapRepo::testing(Box::new(SuccessfulTokenInfoLoader::default()));
+
+        // WSOL -> USDC -> mint1
+        let slot_swaps = SlotSwaps {
+            slot: 12345.into(),
+            timestamp: BlockTimestamp::now(),
+            swaps: vec![SlotSwap {
+                input_mint: Mint::wsol(),
+                input_amount: 2_000_000_000.into(),
+                output_mint: "mint1".into(),
+                output_amount: 1_000_000.into(),
+                wallet: Default::default(),
+                signature: "signature1".into(),
+                legs: vec![
+                    SlotSwapLeg {
+                        amm: "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc".into(),
+                        input_mint: Mint::wsol(),
+                        input_amount: 2_000_000_000.into(),
+                        output_mint: Mint::usdc(),
+                        output_amount: 300_000_000.into(),
+                    },
+                    SlotSwapLeg {
+                        amm: "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8".into(),
+                        input_mint: Mint::usdc(),
+                        input_amount: 300_000_000.into(),
+                        output_mint: "mint1".into(),
+                        output_amount: 1_000_000.into(),
+                    },
+                ],
+            }],
+        };
+
+        let mut result = test_instance
+            .insert_swaps(&mut tx, slot_swaps)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+
+        // the trade of the user is counted once
+        let result = result.pop().unwrap();
+        assert_eq!(result.amount_base, "1.0");
+        assert_eq!(result.amount_quote, "2.0");
+        assert_eq!(result.price, "2.0");
+        assert!(result.is_buy);
+
+        let count = count_swaps(&mut tx).await;
+        assert_eq!(count, 1);
+
+        let count = count_swap_legs(&mut tx).await;
+        assert_eq!(count, 2);
+
+        let mut legs = list_legs_with_signature(&mut tx, "signature1").await;
+        assert_eq!(legs.len(), 2);
+
+        let second = legs.pop().unwrap();
+        assert_eq!(second.amount_base, "1.0");
+        assert_eq!(second.amount_quote, "300.0");
+        assert_eq!(second.price, "300.0");
+        assert!(second.is_buy);
+        assert_eq!(
+            second.amm.unwrap(),
+            "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"
+        );
+
+        let first = legs.pop().unwrap();
+        assert_eq!(first.amount_base, "2.0");
+        assert_eq!(first.amount_quote, "300.0");
+        assert_eq!(first.price, "150.0");
+        assert!(!first.is_buy);
+        assert_eq!(
+            first.amm.unwrap(),
+            "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc"
+        );
+    })
+    .await;
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_circular_route() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = SwapRepo::testing(Box::new(SuccessfulTokenInfoLoader::default()));
+
+        // WSOL -> USDC -> WSOL
+        let slot_swaps = SlotSwaps {
+            slot: 12345.into(),
+            timestamp: BlockTimestamp::now(),
+            swaps: vec![SlotSwap {
+                input_mint: Mint::wsol(),
+                input_amount: 2_000_000_000.into(),
+                output_mint: Mint::wsol(),
+                output_amount: 2_100_000_000.into(),
+                wallet: Default::default(),
+                signature: "signature1".into(),
+                legs: vec![
+                    SlotSwapLeg {
+                        amm: "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc".into(),
+                        input_mint: Mint::wsol(),
+                        input_amount: 2_000_000_000.into(),
+                        output_mint: Mint::usdc(),
+                        output_amount: 300_000_000.into(),
+                    },
+                    SlotSwapLeg {
+                        amm: "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8".into(),
+                        input_mint: Mint::usdc(),
+                        input_amount: 300_000_000.into(),
+                        output_mint: Mint::wsol(),
+                        output_amount: 2_100_000_000.into(),
+                    },
+                ],
+            }],
+        };
+
+        let result = test_instance
+            .insert_swaps(&mut tx, slot_swaps)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 0);
+
+        let count = count_swaps(&mut tx).await;
+        assert_eq!(count, 0);
+
+        let count = count_swap_legs(&mut tx).await;
+        assert_eq!(count, 2);
+    })
+    .await;
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_legs_inserted_once() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = SwapRepo::testing(Box::new(SuccessfulTokenInfoLoader::default()));
+
+        let slot_swaps = || SlotSwaps {
+            slot: 12345.into(),
+            timestamp: BlockTimestamp::now(),
+            swaps: vec![SlotSwap {
+                input_mint: Mint::wsol(),
+                input_amount: 2_000_000_000.into(),
+                output_mint: Mint::wsol(),
+                output_amount: 2_100_000_000.into(),
+                wallet: Default::default(),
+                signature: "signature1".into(),
+                legs: vec![
+                    SlotSwapLeg {
+                        amm: "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc".into(),
+                        input_mint: Mint::wsol(),
+                        input_amount: 2_000_000_000.into(),
+                        output_mint: Mint::usdc(),
+                        output_amount: 300_000_000.into(),
+                    },
+                    SlotSwapLeg {
+                        amm: "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8".into(),
+                        input_mint: Mint::usdc(),
+                        input_amount: 300_000_000.into(),
+                        output_mint: Mint::wsol(),
+                        output_amount: 2_100_000_000.into(),
+                    },
+                ],
+            }],
+        };
+
+        test_instance
+            .insert_swaps(&mut tx, slot_swaps())
+            .await
+            .unwrap();
+
+        // the slot gets indexed again
+        test_instance
+            .insert_swaps(&mut tx, slot_swaps())
+            .await
+            .unwrap();
+
+        let count = count_swap_legs(&mut tx).await;
+        assert_eq!(count, 2);
+    })
+    .await;
+}
+
 #[test_log::test(sqlx::test)]
 async fn test_no_swaps() {
     run_test_on_empty_db(|mut tx| async move {
@@ -1,6 +1,8 @@
 // Copyright (c) nyanbot.com 2025.
 // This file is licensed under the AGPL-3.0-or-later.
 
+mod backtest;
 mod chain;
 mod jupiter;
 mod pumpfun;
+mod raydium;
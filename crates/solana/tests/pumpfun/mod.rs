@@ -3,3 +3,4 @@
 
 mod repo;
 mod rpc;
+mod tx;
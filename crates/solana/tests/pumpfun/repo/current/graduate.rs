@@ -0,0 +1,107 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::pumpfun::repo::current::inserting_slot_swaps;
+use base::model::Mint;
+use common::model::Timestamp;
+use solana::pumpfun::repo::CurrentRepo;
+use testing::pumpfun::insert_swap;
+use testing::run_test_on_empty_db;
+
+#[test_log::test(sqlx::test)]
+async fn test_graduate() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = CurrentRepo::testing();
+
+        let test_swap = insert_swap(&mut tx, inserting_slot_swaps())
+            .await
+            .pop()
+            .unwrap();
+        let _ = test_instance.upsert(&mut tx, test_swap).await.unwrap();
+
+        let graduated_at = Timestamp::from_epoch_second(1738463019).unwrap();
+        let result = test_instance
+            .graduate(&mut tx, vec![Mint::from("mint1")], 12345, graduated_at)
+            .await
+            .unwrap();
+        assert_eq!(result, vec![1000]);
+
+        let result = test_instance.get(&mut tx, 1000).await.unwrap();
+        assert!(result.complete);
+        assert_eq!(result.graduated_at.unwrap().to_epoch_seconds(), 1738463019);
+        assert!(result.graduation_age().unwrap().0 > 0);
+    })
+    .await;
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_graduate_keeps_first_graduation() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = CurrentRepo::testing();
+
+        let test_swap = insert_swap(&mut tx, inserting_slot_swaps())
+            .await
+            .pop()
+            .unwrap();
+        let _ = test_instance.upsert(&mut tx, test_swap).await.unwrap();
+
+        let _ = test_instance
+            .graduate(
+                &mut tx,
+                vec![Mint::from("mint1")],
+                12345,
+                Timestamp::from_epoch_second(1738463019).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let _ = test_instance
+            .graduate(
+                &mut tx,
+                vec![Mint::from("mint1")],
+                12345,
+                Timestamp::from_epoch_second(1738463999).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let result = test_instance.get(&mut tx, 1000).await.unwrap();
+        assert_eq!(result.graduated_at.unwrap().to_epoch_seconds(), 1738463019);
+    })
+    .await;
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_graduate_unknown_mint() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = CurrentRepo::testing();
+
+        let result = test_instance
+            .graduate(
+                &mut tx,
+                vec![Mint::from("mint1")],
+                12345,
+                Timestamp::from_epoch_second(1738463019).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+    })
+    .await;
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_not_graduated() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = CurrentRepo::testing();
+
+        let test_swap = insert_swap(&mut tx, inserting_slot_swaps())
+            .await
+            .pop()
+            .unwrap();
+        let result = test_instance.upsert(&mut tx, test_swap).await.unwrap();
+        assert_eq!(result.graduated_at, None);
+        assert!(result.graduation_age().is_none());
+    })
+    .await;
+}
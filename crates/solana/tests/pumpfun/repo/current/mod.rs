@@ -4,6 +4,8 @@
 use common::model::BlockTimestamp;
 use solana::pumpfun::repo::{SlotSwap, SlotSwaps};
 
+mod graduate;
+mod rollback;
 mod upsert;
 
 pub(crate) fn inserting_slot_swaps() -> SlotSwaps {
@@ -0,0 +1,148 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use crate::pumpfun::repo::current::{inserting_slot_swaps, updating_slot_swaps};
+use base::model::solana::Slot;
+use base::model::Mint;
+use base::test::SuccessfulTokenInfoLoader;
+use common::model::Timestamp;
+use solana::pumpfun::repo::{CurrentRepo, SwapRepo};
+use testing::pumpfun::{count_swaps, insert_swap};
+use testing::run_test_on_empty_db;
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = CurrentRepo::testing();
+        let swap_repo = SwapRepo::testing(Box::new(SuccessfulTokenInfoLoader::default()));
+
+        let test_swap = insert_swap(&mut tx, inserting_slot_swaps())
+            .await
+            .pop()
+            .unwrap();
+        let _ = test_instance.upsert(&mut tx, test_swap).await.unwrap();
+
+        let dropped = updating_slot_swaps();
+        let timestamp = dropped.timestamp.0;
+        let test_swap = insert_swap(&mut tx, dropped).await.pop().unwrap();
+        let _ = test_instance.upsert(&mut tx, test_swap).await.unwrap();
+
+        let result = swap_repo
+            .rollback(&mut tx, &[Slot(23456)], timestamp, timestamp)
+            .await
+            .unwrap();
+        assert_eq!(result, vec![1000]);
+        assert_eq!(count_swaps(&mut tx).await, 1);
+
+        let result = test_instance
+            .rollback(&mut tx, &[Slot(23456)])
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+
+        let result = test_instance.get(&mut tx, 1000).await.unwrap();
+        assert_eq!(result.slot, 12345);
+        assert_eq!(result.virtual_base_reserves, 512561011366544);
+        assert_eq!(result.virtual_quote_reserves, 62802280169);
+        assert_eq!(result.progress, 70.66435);
+        assert_eq!(result.price, 2);
+    })
+    .await;
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback_only_swap() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = CurrentRepo::testing();
+        let swap_repo = SwapRepo::testing(Box::new(SuccessfulTokenInfoLoader::default()));
+
+        let dropped = inserting_slot_swaps();
+        let timestamp = dropped.timestamp.0;
+        let test_swap = insert_swap(&mut tx, dropped).await.pop().unwrap();
+        let _ = test_instance.upsert(&mut tx, test_swap).await.unwrap();
+
+        let _ = swap_repo
+            .rollback(&mut tx, &[Slot(12345)], timestamp, timestamp)
+            .await
+            .unwrap();
+        assert_eq!(count_swaps(&mut tx).await, 0);
+
+        let _ = test_instance
+            .rollback(&mut tx, &[Slot(12345)])
+            .await
+            .unwrap();
+
+        let result = test_instance.get(&mut tx, 1000).await;
+        assert!(result.is_err());
+    })
+    .await;
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback_graduation() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = CurrentRepo::testing();
+
+        let test_swap = insert_swap(&mut tx, inserting_slot_swaps())
+            .await
+            .pop()
+            .unwrap();
+        let _ = test_instance.upsert(&mut tx, test_swap).await.unwrap();
+
+        let _ = test_instance
+            .graduate(
+                &mut tx,
+                vec![Mint::from("mint1")],
+                23456,
+                Timestamp::from_epoch_second(1738463019).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .rollback(&mut tx, &[Slot(23456)])
+            .await
+            .unwrap();
+        assert_eq!(result, vec![1000]);
+
+        let result = test_instance.get(&mut tx, 1000).await.unwrap();
+        assert_eq!(result.slot, 12345);
+        assert!(!result.complete);
+        assert_eq!(result.graduated_at, None);
+    })
+    .await;
+}
+
+#[test_log::test(sqlx::test)]
+async fn test_rollback_keeps_graduation_of_other_slot() {
+    run_test_on_empty_db(|mut tx| async move {
+        let test_instance = CurrentRepo::testing();
+
+        let test_swap = insert_swap(&mut tx, inserting_slot_swaps())
+            .await
+            .pop()
+            .unwrap();
+        let _ = test_instance.upsert(&mut tx, test_swap).await.unwrap();
+
+        let _ = test_instance
+            .graduate(
+                &mut tx,
+                vec![Mint::from("mint1")],
+                12345,
+                Timestamp::from_epoch_second(1738463019).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let result = test_instance
+            .rollback(&mut tx, &[Slot(23456)])
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+
+        let result = test_instance.get(&mut tx, 1000).await.unwrap();
+        assert!(result.complete);
+        assert!(result.graduated_at.is_some());
+    })
+    .await;
+}
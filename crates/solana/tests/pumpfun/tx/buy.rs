@@ -0,0 +1,128 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::KeyPair;
+use solana::pumpfun::{Pumpfun, PumpfunError};
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use testing::rpc::LocalRpc;
+
+const MINT: Pubkey = pubkey!("CpV7zK77DkyVvbCgGWtWrsqvh2VonFrqPg8cecrTpump");
+const FEE_RECIPIENT: Pubkey = pubkey!("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM");
+const PUMPFUN: Pubkey = pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+const ASSOCIATED_TOKEN_PROGRAM: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+#[test_log::test(tokio::test)]
+async fn test_buy() {
+    let rpc = LocalRpc::new();
+    rpc.add_pumpfun_global(FEE_RECIPIENT, 100);
+    rpc.add_pumpfun_curve(
+        MINT,
+        1_073_000_000_000_000,
+        30_000_000_000,
+        793_100_000_000_000,
+        0,
+        false,
+    );
+
+    let payer = KeyPair::generate();
+    let test_instance = Pumpfun::new(rpc.client());
+
+    let result = test_instance
+        .buy(payer.clone(), MINT, 100_000_000, Some(500))
+        .await
+        .unwrap();
+
+    assert_eq!(result.sol_amount, 100_000_000);
+    assert_eq!(result.token_amount, 3_564_784_053_156);
+
+    let transactions = rpc.sent_transactions();
+    assert_eq!(transactions.len(), 1);
+
+    let transaction = transactions.first().unwrap();
+    assert_eq!(result.signature.0, transaction.signatures[0].to_string());
+
+    let message = &transaction.message;
+    let keys = message.static_account_keys();
+    assert_eq!(keys[0], Pubkey::from(payer.public));
+    assert_eq!(message.instructions().len(), 4);
+
+    let create_ata = &message.instructions()[2];
+    assert_eq!(
+        keys[create_ata.program_id_index as usize],
+        ASSOCIATED_TOKEN_PROGRAM
+    );
+
+    let instruction = message.instructions().last().unwrap();
+    assert_eq!(keys[instruction.program_id_index as usize], PUMPFUN);
+    assert_eq!(
+        instruction.data[..8],
+        [0x66, 0x06, 0x3d, 0x12, 0x01, 0xda, 0xeb, 0xea]
+    );
+    assert_eq!(instruction.data[8..16], 3_564_784_053_156u64.to_le_bytes());
+    assert_eq!(instruction.data[16..24], 105_000_000u64.to_le_bytes());
+    assert_eq!(keys[instruction.accounts[1] as usize], FEE_RECIPIENT);
+    assert_eq!(keys[instruction.accounts[2] as usize], MINT);
+}
+
+#[test_log::test(tokio::test)]
+async fn test_buy_token_account_exists() {
+    let rpc = LocalRpc::new();
+    rpc.add_pumpfun_global(FEE_RECIPIENT, 100);
+    rpc.add_pumpfun_curve(
+        MINT,
+        1_073_000_000_000_000,
+        30_000_000_000,
+        793_100_000_000_000,
+        0,
+        false,
+    );
+
+    let payer = KeyPair::generate();
+    rpc.add_token_account(payer.public.clone().into(), MINT, 0);
+
+    let test_instance = Pumpfun::new(rpc.client());
+    test_instance
+        .buy(payer.clone(), MINT, 100_000_000, Some(500))
+        .await
+        .unwrap();
+
+    let transactions = rpc.sent_transactions();
+    let message = &transactions.first().unwrap().message;
+    let keys = message.static_account_keys();
+
+    assert_eq!(message.instructions().len(), 3);
+    assert!(message
+        .instructions()
+        .iter()
+        .all(|ix| keys[ix.program_id_index as usize] != ASSOCIATED_TOKEN_PROGRAM));
+}
+
+#[test_log::test(tokio::test)]
+async fn test_curve_completed() {
+    let rpc = LocalRpc::new();
+    rpc.add_pumpfun_global(FEE_RECIPIENT, 100);
+    rpc.add_pumpfun_curve(MINT, 0, 0, 0, 0, true);
+
+    let test_instance = Pumpfun::new(rpc.client());
+    let result = test_instance
+        .buy(KeyPair::generate(), MINT, 100_000_000, None)
+        .await;
+
+    assert!(matches!(result, Err(PumpfunError::CurveCompleted)));
+    assert!(rpc.sent_transactions().is_empty());
+}
+
+#[test_log::test(tokio::test)]
+async fn test_curve_not_found() {
+    let rpc = LocalRpc::new();
+    rpc.add_pumpfun_global(FEE_RECIPIENT, 100);
+
+    let test_instance = Pumpfun::new(rpc.client());
+    let result = test_instance
+        .buy(KeyPair::generate(), MINT, 100_000_000, None)
+        .await;
+
+    assert!(matches!(result, Err(PumpfunError::CurveNotFound)));
+    assert!(rpc.sent_transactions().is_empty());
+}
@@ -0,0 +1,77 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::model::KeyPair;
+use solana::pumpfun::{Pumpfun, PumpfunError};
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use testing::rpc::LocalRpc;
+
+const MINT: Pubkey = pubkey!("CpV7zK77DkyVvbCgGWtWrsqvh2VonFrqPg8cecrTpump");
+const FEE_RECIPIENT: Pubkey = pubkey!("CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM");
+const PUMPFUN: Pubkey = pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+
+#[test_log::test(tokio::test)]
+async fn test_sell_entire_balance() {
+    let payer = KeyPair::generate();
+
+    let rpc = LocalRpc::new();
+    rpc.add_pumpfun_global(FEE_RECIPIENT, 100);
+    rpc.add_pumpfun_curve(
+        MINT,
+        1_073_000_000_000_000,
+        30_000_000_000,
+        793_100_000_000_000,
+        0,
+        false,
+    );
+    rpc.add_token_account(payer.public.clone().into(), MINT, 1_000_000_000_000);
+
+    let test_instance = Pumpfun::new(rpc.client());
+
+    let result = test_instance
+        .sell(payer.clone(), MINT, None, Some(500))
+        .await
+        .unwrap();
+
+    assert_eq!(result.token_amount, 1_000_000_000_000);
+    assert_eq!(result.sol_amount, 27_653_631);
+
+    let transactions = rpc.sent_transactions();
+    assert_eq!(transactions.len(), 1);
+
+    let message = &transactions[0].message;
+    let keys = message.static_account_keys();
+
+    let instruction = message.instructions().last().unwrap();
+    assert_eq!(keys[instruction.program_id_index as usize], PUMPFUN);
+    assert_eq!(
+        instruction.data[..8],
+        [0x33, 0xe6, 0x85, 0xa4, 0x01, 0x7f, 0x83, 0xad]
+    );
+    assert_eq!(instruction.data[8..16], 1_000_000_000_000u64.to_le_bytes());
+    assert_eq!(instruction.data[16..24], 26_270_950u64.to_le_bytes());
+    assert_eq!(keys[instruction.accounts[1] as usize], FEE_RECIPIENT);
+}
+
+#[test_log::test(tokio::test)]
+async fn test_nothing_to_sell() {
+    let rpc = LocalRpc::new();
+    rpc.add_pumpfun_global(FEE_RECIPIENT, 100);
+    rpc.add_pumpfun_curve(
+        MINT,
+        1_073_000_000_000_000,
+        30_000_000_000,
+        793_100_000_000_000,
+        0,
+        false,
+    );
+
+    let test_instance = Pumpfun::new(rpc.client());
+    let result = test_instance
+        .sell(KeyPair::generate(), MINT, None, None)
+        .await;
+
+    assert!(matches!(result, Err(PumpfunError::NothingToSell)));
+    assert!(rpc.sent_transactions().is_empty());
+}
@@ -0,0 +1,273 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use common::model::Partition;
+use solana::raydium::repo::CandleRepo;
+use sqlx::Executor;
+use testing::{assert_sql, run_test_with_pool_on_empty_db};
+
+#[test_log::test(sqlx::test)]
+async fn test_candles_for_all_trades() {
+    run_test_with_pool_on_empty_db(|pool| async move {
+		let mut tx = pool.begin().await.unwrap();
+		tx.execute(r#"
+		insert into solana.address (id, address, created_at, updated_at) values
+			(409453, '2kGnvtybBSWvAXaWS1GqxvM4QTFKnb65QAcJVVyjA9Hm', '2025-03-17 00:01:18.362459 +00:00', '2025-03-17 00:01:18.362459 +00:00'),
+			(1023840, 'EcdVnxHHGXSDRpiLvZUswhheabPaR2EsqytSYiyK9Wfr', '2025-03-18 15:46:10.846393 +00:00', '2025-03-18 15:46:10.846393 +00:00'),
+			(172078, 'CrJZsespDs8qmTxJMgSayYV5P9bEMGuQj2dQ7DtP1d1o', '2025-03-15 11:19:15.450070 +00:00', '2025-03-15 11:19:15.450070 +00:00'),
+			(288297, 'Bpn2h56aHsHLhEEY4StxoPA1tumjoBfEAHdzTWka8888', '2025-03-15 16:55:06.393151 +00:00', '2025-03-15 16:55:06.393151 +00:00'),
+			(30326, '7arLg7M1FvjNbyCcwfhxu4T2jSGgZFE6qgKUC1CUaYTR', '2025-03-15 04:37:54.510984 +00:00', '2025-03-15 04:37:54.510984 +00:00'),
+			(62831, 'HeYupSzxTKVETwSUwKAY5wqrhm6GGn4RPfy63xJzyC3q', '2025-03-15 05:32:50.563312 +00:00', '2025-03-15 05:32:50.563312 +00:00'),
+			(29920, 'BguSaSF1f1ffr5h2ny9Jm1iMVtz7HqRapt8Ahx8ssWCE', '2025-03-15 04:37:24.158264 +00:00', '2025-03-15 04:37:24.158264 +00:00'),
+			(775031, 'HpuqkppgAqDSW91ShfzKBaNaDqG67DfF133DfzfiUCL7', '2025-03-18 00:56:12.182613 +00:00', '2025-03-18 00:56:12.182613 +00:00'),
+			(639913, 'HVcFveGHYGJ7D7KCzvEbQaV5sYC6T9LcJPCpa85A2m13', '2025-03-17 15:09:03.527470 +00:00', '2025-03-17 15:09:03.527470 +00:00'),
+			(214472, '9juxrt7aXBnKvhUmobv1uVHBYMENwn3HyBQ2ygELTH59', '2025-03-15 13:45:57.322426 +00:00', '2025-03-15 13:45:57.322426 +00:00'),
+			(553965, '7gPoHw86dMV2NdYqSezV8EgHzUgmRSso2qJt9xqFrnLf', '2025-03-17 09:51:51.550957 +00:00', '2025-03-17 09:51:51.550957 +00:00'),
+			(775231, '5iT5Xqio2qHhGzGLWNyKJkrr8dAtye7gzSX5BuD8ZPFc', '2025-03-18 00:57:29.337050 +00:00', '2025-03-18 00:57:29.337050 +00:00'),
+			(783624, 'HZCLTmVrtekAfc5ib9piTtH4hdKZzj21phmL7vNLUUE5', '2025-03-18 01:50:22.620500 +00:00', '2025-03-18 01:50:22.620500 +00:00'),
+			(613367, '3DgFjtoPi9178DQvtBTAJJyrzyVbbCbvtk5c7F5GfDeY', '2025-03-17 13:55:37.561681 +00:00', '2025-03-17 13:55:37.561681 +00:00'),
+			(11346, 'GB5vcWrmoJGdK6Ub8xxadtdhj77WFMnm89DJQYepMZEv', '2025-03-15 04:17:50.266420 +00:00', '2025-03-15 04:17:50.266420 +00:00'),
+			(241910, '9kwqAJd7mJb8Dtxynie47b3sF7L6bcutCB6oZoJatmJX', '2025-03-15 15:19:25.974836 +00:00', '2025-03-15 15:19:25.974836 +00:00'),
+			(217508, 'CnfGo2sLStkmWnDeoEKbuPJAfKERKnLNWNRqqJSB9J42', '2025-03-15 13:55:42.890465 +00:00', '2025-03-15 13:55:42.890465 +00:00'),
+			(479289, 'FuHtHatthAM83nKqQxEjb5bpckuiez8H26FEBZx4quKo', '2025-03-17 04:00:01.338920 +00:00', '2025-03-17 04:00:01.338920 +00:00'),
+			(11347, 'FuxV1qLLSJVTVwBc47LaC6XggwDmBrjdRM1fz5tB9NQF', '2025-03-15 04:17:50.266420 +00:00', '2025-03-15 04:17:50.266420 +00:00'),
+			(249108, 'FCxv9u6HGXviZidPW2yNyURGRc54QRMJF16YR2MZ3zpo', '2025-03-15 15:32:29.816793 +00:00', '2025-03-15 15:32:29.816793 +00:00'),
+			(2960, 'ZG98FUCjb8mJ824Gbs6RsgVmr1FhXb2oNiJHa2dwmPd', '2025-03-15 04:11:48.516424 +00:00', '2025-03-15 04:11:48.516424 +00:00'),
+			(185716, '5BmGNUYjDdo6xiKyXDVZc7jG681a55dAWM2zoErYLwNm', '2025-03-15 12:09:45.989789 +00:00', '2025-03-15 12:09:45.989789 +00:00'),
+			(390276, '8Kww74XnDTz2EUK2PxbxkHfUiK6JJsCHdcSxayy1LCgJ', '2025-03-15 23:00:10.133321 +00:00', '2025-03-15 23:00:10.133321 +00:00'),
+			(195153, 'BGvw7VFC1ANfKbAWHXvXdAAjsmrUpmaCxX4u9j3oZkmd', '2025-03-15 12:45:20.940432 +00:00', '2025-03-15 12:45:20.940432 +00:00'),
+			(862, 'GnxivPdS6JVnLkUqLYYYGGDfFiqo4f7ESaiViyNP27Rq', '2025-03-15 04:10:47.476590 +00:00', '2025-03-15 04:10:47.476590 +00:00'),
+			(1177922, 'BHuNVUwPaD7dwafzE2Hgpykz8C6hsSNYJ12gd1jDjFy3', '2025-03-18 20:51:12.051722 +00:00', '2025-03-18 20:51:12.051722 +00:00'),
+			(768839, 'CEt1ThiPJ9LQWJsLzmXpFPP25ev9RjQZygkTaf3WCpZ3', '2025-03-18 00:18:48.948917 +00:00', '2025-03-18 00:18:48.948917 +00:00'),
+			(6563, 'Ff7NWbib89C3SiW519FCitGvhHiFGb5TRwEXbqFrbVqu', '2025-03-15 04:14:01.207744 +00:00', '2025-03-15 04:14:01.207744 +00:00'),
+			(195458, 'BcCWCRbujCX9qeYKEMVHK6x5YuusRArLSmNiAGUefmDp', '2025-03-15 12:46:28.386445 +00:00', '2025-03-15 12:46:28.386445 +00:00'),
+			(999160, '5Wy56hBUTPAYLCaHDAS2BXZTRVgpJNyZ8gEVhdQyUzA1', '2025-03-18 14:47:15.562495 +00:00', '2025-03-18 14:47:15.562495 +00:00'),
+			(390196, 'GJXpJku4M8wPpNGUto7bETfwJQMHVkBoXt715coVJVnY', '2025-03-15 22:59:51.955798 +00:00', '2025-03-15 22:59:51.955798 +00:00'),
+			(998868, '5fY5pqxv5jbNKMuXVQ29kKZ1Le5D7SQ2ZeXC8ELoxnp1', '2025-03-18 14:46:37.850316 +00:00', '2025-03-18 14:46:37.850316 +00:00'),
+			(105779, 'H14zzBuxHkwmupR5NGku8UAjHg2JXvoRyACRUfaxBooB', '2025-03-15 07:43:16.921214 +00:00', '2025-03-15 07:43:16.921214 +00:00'),
+			(77193, 'CffVqgKxLTxiGbYLCAbkGxHzDesHo6bvYW5GhJKW9EHQ', '2025-03-15 06:10:05.357308 +00:00', '2025-03-15 06:10:05.357308 +00:00'),
+			(113790, 'FmHvqvsnZLxr7reHnjsL2NPNP9zZ6F5CQGGTaBczBv51', '2025-03-15 08:15:00.528216 +00:00', '2025-03-15 08:15:00.528216 +00:00'),
+			(190326, 'BSnQSo3Z5E643EXBmvadKKNxt3GBSo9NYgdwzXpr9uDB', '2025-03-15 12:27:29.848642 +00:00', '2025-03-15 12:27:29.848642 +00:00'),
+			(88173, '87mpsFWyzmTk5xKim4Ts26hFip2m9MJe9KrRusJrFpDi', '2025-03-15 06:43:32.886313 +00:00', '2025-03-15 06:43:32.886313 +00:00'),
+			(1114510, '6p7tpJposHfGc7LeQ9ZNECe5Xcct92mSpdjrAYPQtCae', '2025-03-18 18:55:30.742377 +00:00', '2025-03-18 18:55:30.742377 +00:00'),
+			(1901, 'a8fZdgPDXoKSs7BCKH6kfFkayE3gW2rRKtbW3Ec6Bv3', '2025-03-15 04:11:16.905988 +00:00', '2025-03-15 04:11:16.905988 +00:00'),
+			(1583, 'Dm2Ds8sBzaCgbEq4ndyxgF43ggjrg42bXbVia56uy8qS', '2025-03-15 04:11:08.113457 +00:00', '2025-03-15 04:11:08.113457 +00:00'),
+			(620001, '59tF7SCvxFWSdyKKB7Rwzdide8YJmtv55A9K3itdxx22', '2025-03-17 14:08:34.727828 +00:00', '2025-03-17 14:08:34.727828 +00:00'),
+			(243, 'nya666pQkP3PzWxi7JngU3rRMHuc7zbLK8c8wxQ4qpT', '2025-03-15 04:10:31.503334 +00:00', '2025-03-15 04:10:31.503334 +00:00'),
+			(193318, '96QLcVkMrsdK5y4UyeMMHsbuF8fehhEdFf8GobgC4vtm', '2025-03-15 12:38:09.645192 +00:00', '2025-03-15 12:38:09.645192 +00:00'),
+			(111169, '9Z1YUifC4yb3GhKBBGcYuC2wj2iZHbbGfPaDVJdPwxtQ', '2025-03-15 08:05:06.229724 +00:00', '2025-03-15 08:05:06.229724 +00:00'),
+			(442339, 'D19ULWFxXPMtr43ebonkyV7JmfseHSTEvybAeYno1BJK', '2025-03-17 01:48:09.360990 +00:00', '2025-03-17 01:48:09.360990 +00:00'),
+			(894023, '2zm8rGcrbV1VXXEFHbEir3mcir8gzkZMEB8x3QXp7ERn', '2025-03-18 11:19:59.820602 +00:00', '2025-03-18 11:19:59.820602 +00:00'),
+			(1147703, '9Qq6TQnF9HFBDyHMEmWji1Tbw8VFxbqcLbaMZgYm9gsK', '2025-03-18 19:44:45.615329 +00:00', '2025-03-18 19:44:45.615329 +00:00'),
+			(77195, '51saZ1fduCdFZ354MD4wH6AR7CX9BkxXEhP8AYuL2UWh', '2025-03-15 06:10:05.674205 +00:00', '2025-03-15 06:10:05.674205 +00:00'),
+			(865569, 'DMTDqZqA5YUfwWFN3pCxyrTnfWBpU6hbJNA3NwX4utVL', '2025-03-18 10:34:09.406574 +00:00', '2025-03-18 10:34:09.406574 +00:00'),
+			(674847, '7CiKqgADJDTyoFVS7h2jZe6VAu2BmbTSHEYfGTWefCmG', '2025-03-17 17:18:11.526156 +00:00', '2025-03-17 17:18:11.526156 +00:00'),
+			(986048, '3juvut5xo6hB7qK6tTJvmPKz21AmtmbLKkT3DDExNVJV', '2025-03-18 14:16:13.056547 +00:00', '2025-03-18 14:16:13.056547 +00:00'),
+			(1180795, 'CyuL4vYqioQVr2FwFEqsQXhMshkXQ6rWqmWqLvuXA4iZ', '2025-03-18 20:57:54.302707 +00:00', '2025-03-18 20:57:54.302707 +00:00'),
+			(30348, '5QdkRTks2zLJa789jogRBcW1hRgwFCWC2NE2rd63hSNH', '2025-03-15 04:37:55.869760 +00:00', '2025-03-15 04:37:55.869760 +00:00'),
+			(652883, '7qTiu8k9J5ydad9WZVSbQvU3Y9QwoN1bM6znfaC8iVdR', '2025-03-17 15:57:30.093356 +00:00', '2025-03-17 15:57:30.093356 +00:00'),
+			(774725, 'GEQg7FNXPGefHEs3NefBiGZnUMo5U1tXpErbpZe5g1N', '2025-03-18 00:54:03.105626 +00:00', '2025-03-18 00:54:03.105626 +00:00'),
+			(1201226, '3cTZNVZAFNTGkqQwvp7aLESdD9McxaGSQKDJmb7CSyMj', '2025-03-18 21:46:44.791577 +00:00', '2025-03-18 21:46:44.791577 +00:00'),
+			(1186828, '86GdceWQdKcBkrzV1wXRcUUmtdeEFdXVwtzrw3c6wx8D', '2025-03-18 21:11:40.011899 +00:00', '2025-03-18 21:11:40.011899 +00:00'),
+			(687, 'o7RY6P2vQMuGSu1TrLM81weuzgDjaCRTXYRaXJwWcvc', '2025-03-15 04:10:43.272679 +00:00', '2025-03-15 04:10:43.272679 +00:00'),
+			(108324, '5EzS4svfdHhbrFsRxLVdHuxzNQA7fFeeZNmwz6ptSWuB', '2025-03-15 07:53:06.725124 +00:00', '2025-03-15 07:53:06.725124 +00:00'),
+			(227107, '6uWZVncn51p1QS2yBALmxiawBTj1JMqVsHiJKZ68iuUd', '2025-03-15 14:28:47.350113 +00:00', '2025-03-15 14:28:47.350113 +00:00'),
+			(7068, 'Hv3tUE6RTx1phwH7skKt6Q1QMe3bPpYvtqP6MuzG18kL', '2025-03-15 04:14:26.955218 +00:00', '2025-03-15 04:14:26.955218 +00:00'),
+			(484082, '6kDwhGgEzKZ3sodCAoV1YtzCRbJ9ptxbQNqv9K7XUfQC', '2025-03-17 04:21:12.829351 +00:00', '2025-03-17 04:21:12.829351 +00:00'),
+			(1210681, 'HBzP1cKoKUWRKVJwaZbG9SfNN5YRem1jWy8egvjq2VJo', '2025-03-18 22:09:27.985356 +00:00', '2025-03-18 22:09:27.985356 +00:00'),
+			(636531, 'Ce4LQfcrXuwZVoGk6aBmJYc4VQ7sXG3KQgfd2QK4XZYd', '2025-03-17 14:56:03.803438 +00:00', '2025-03-17 14:56:03.803438 +00:00'),
+			(5821, '2tSTcq1r59k6EngMLucNX93skaL3BY6TrBFEmfqHsu3m', '2025-03-15 04:13:36.195638 +00:00', '2025-03-15 04:13:36.195638 +00:00'),
+			(462161, 'GkGju1ZFR41QzVhNqaB5Ts27DP7AsSaRoK67sUvKL5D3', '2025-03-17 02:40:59.628864 +00:00', '2025-03-17 02:40:59.628864 +00:00'),
+			(998055, 'BBzbZfJYPXdXsypZcJJLAgk5o2q9J2oLqoaoYoMfr59A', '2025-03-18 14:45:25.525813 +00:00', '2025-03-18 14:45:25.525813 +00:00'),
+			(932376, '24SEtQVPUhRqNfUZ7deFnYSQDV2dvSc821DPUVtGvbwD', '2025-03-18 11:57:59.981938 +00:00', '2025-03-18 11:57:59.981938 +00:00'),
+			(1208781, 'HRjKNeR2ebRgAY68PpJepVVk7boSM19FZi59e9j4tzkF', '2025-03-18 22:04:47.190663 +00:00', '2025-03-18 22:04:47.190663 +00:00'),
+			(1113363, 'BHrPrw2Jte9SNcKts97jKNqACuZHN4kaDn3Mr2TbbXQp', '2025-03-18 18:53:19.975801 +00:00', '2025-03-18 18:53:19.975801 +00:00'),
+			(408904, '3HLXBgyap1JXQTGzzUf7B8HqH86SkGa3nD34G6dFb2cY', '2025-03-16 23:59:51.001916 +00:00', '2025-03-16 23:59:51.001916 +00:00'),
+			(613368, 'DqyD6tBdM2xbZafKdg5wnyKncyJsVF9mqGhRS1Htv6Ey', '2025-03-17 13:55:37.561681 +00:00', '2025-03-17 13:55:37.561681 +00:00'),
+			(1063962, 'xUQXPMDnoSRe7AXsLDF6MEndDz8yXKWSjxoN3eQCgSg', '2025-03-18 17:18:43.940111 +00:00', '2025-03-18 17:18:43.940111 +00:00'),
+			(1214015, '6o2vLWRJMaAPxT7ctY2qb2jFYPxjsvByogZFXeXbE24B', '2025-03-18 22:16:43.996749 +00:00', '2025-03-18 22:16:43.996749 +00:00'),
+			(1247, '5wLXMpos53nkeubipmbBzQYG28TKnTBjnS97CDwfeBKJ', '2025-03-15 04:10:58.921383 +00:00', '2025-03-15 04:10:58.921383 +00:00'),
+			(667801, '6gsByRSrK4y86CNZytkfSha48L2NX77DV2FDLaLjhpmc', '2025-03-17 16:53:02.494754 +00:00', '2025-03-17 16:53:02.494754 +00:00'),
+			(257578, '2jsiKMVYqqSZ24gj2t5UiVMe7jN6d2GEqLrsB1t5pMrv', '2025-03-15 15:46:17.913462 +00:00', '2025-03-15 15:46:17.913462 +00:00'),
+			(214164, '9MtLYj3LshhLQvk3oQppTwMzEo2KVxgPe6Hb7PQhZBT5', '2025-03-15 13:44:53.681727 +00:00', '2025-03-15 13:44:53.681727 +00:00');
+
+		insert into solana.token (id, version, mint, name, symbol, decimals, supply, metadata, description, image, website, creator_id, block_id, block_time, created_at, updated_at) values
+		(100042, 0, '5a5F5HrTv12QoFwQmf1qiLmoMDQZ64WXjFvh9pZBpump', 'We Just Got 8 Free Pizzas', 'Brick', 6, 1000000000.000000000000, 'https://ipfs.io/ipfs/QmcB2H3uX9zreJqrHy5NWTEf4qzbaBqFDXsYz1jsd3CVRt', null, null, null, 652883, 327383755, '2025-03-17 15:57:14.000000 +00:00', '2025-03-17 15:57:30.096817 +00:00', '2025-03-17 15:57:30.096817 +00:00');
+
+		insert into solana.token_pair (id, base_id, quote_id) values (100763, 100042, 1);
+
+insert into raydium.swap (id, slot, address_id, token_pair_id, amount_base, amount_quote, price, is_buy, timestamp, signature)
+values  (4628606, 327383755, 652883, 100763, 34612903.225806000000, 1.000000000000, 0.000000028891, true, '2025-03-17 15:57:14.000000 +00:00', '5NcqgNaSaFiqrza3Xk8hvLYUGQVNGERvfnzR1cg7oRksYLCppLtR994mZMsWBSRYwqi8EkcMYJpURafsoX8Rc1vn'),
+        (4628619, 327383755, 687, 100763, 22128217.531188000000, 0.675000000000, 0.000000030504, true, '2025-03-17 15:57:14.000000 +00:00', '3UeVvLddtMnj4fVi8o95z6y1QSzGakymyn1DFmM8Dgb33YHUKPYLVMz6sJuMhUZNftDmJSsCeLo9fdn7jaU8wdm4'),
+        (4628808, 327383770, 479289, 100763, 32082.929638000000, 0.001000000000, 0.000000031169, true, '2025-03-17 15:57:20.000000 +00:00', '47eH3ivqKbyrz77bJV4euiUECs3Vm9gJpT6A3du48y8DQ5D86QaigUZK8S9KQNYy11m3QDUfRbCy4t84BPJrpMWX'),
+        (4629605, 327383831, 1901, 100763, 0.000001000000, 0.000000001000, 0.001000000000, true, '2025-03-17 15:57:45.000000 +00:00', '5LfEnrdP1R1wjr4YDjThmHibb385hxezS3u5euQsa2VXEX6ZMNRDtVUe1iDW9XWmdoxrUQ5VFjCA1VtnXh9rAY6Q'),
+        (4629874, 327383856, 390276, 100763, 23481944.691692000000, 0.749250000000, 0.000000031907, true, '2025-03-17 15:57:56.000000 +00:00', '4ncxsEqVHYVR6m3JYb42F3xP8xeMbafrZiQ1c9apyuyKRipWCQQfSeq6apDtahgSpxMauc8Y9wjMz4kKaT1zVrhV'),
+        (4634271, 327384065, 687, 100763, 22128217.530097000000, 0.706997758000, 0.000000031950, false, '2025-03-17 15:59:20.000000 +00:00', '2ua6xubrEing5sCoFc7yVhaL4ovUmuMT8Y1TsEpFWvKGmV4xGwbbW1hXzuRH2kVfXFAdVBsKzfror5Xrpd4RQpkt'),
+        (4669012, 327386311, 241910, 100763, 1567938.090429000000, 0.049079250000, 0.000000031302, true, '2025-03-17 16:14:21.000000 +00:00', 'MY6nKz7gaJkeTDbGLMQov4fgyP9fNsfyf3Z1sREfAhDwKhyECEggkpM2Q9VXeZ4B69VCogjziW58QsJb6Gpk5he'),
+        (4765676, 327392169, 667801, 100763, 10841363.528276000000, 0.343554750000, 0.000000031689, true, '2025-03-17 16:53:17.000000 +00:00', '5wcKmyF3QZy8NBj5nuksUmufVhpsxVZy8JAJee6FhRECE1Yd9VSuRXioSBBmTCF4Z8s2HfMKojsFRSpkjJTKCFHN'),
+        (4835202, 327395824, 674847, 100763, 1306506.903182000000, 0.041904600000, 0.000000032074, true, '2025-03-17 17:17:51.000000 +00:00', 'dQk4oMgiqhBixuHAt8q77XhKv1mYwL1RN5zWve7fpuRPa3UXinsYYXmaFabg7MKykvwZQvwhAWqBdTNGZYJ4cWT'),
+        (5704685, 327442489, 390276, 100763, 23481944.691692000000, 0.736854545000, 0.000000031380, false, '2025-03-17 22:28:23.000000 +00:00', '4UrQAiRhieFCu4VrAHZJp1zt7B6tKGgsnwQqLqryzRkFULar5Yu7H8uNk1LkWNutCiF4TAHpp8NhGECQMxXAciHV'),
+        (5767877, 327445917, 113790, 100763, 3186998.505897000000, 0.098019801000, 0.000000030756, true, '2025-03-17 22:51:32.000000 +00:00', '4MHdxHYMw3GiL9innnLeDpkmEt7bJWK6D7ctXEJcpxUTiJCAoJupHqo5egcjQ2fUfAWwaEmV1hog5ZkgPoPtZwm'),
+        (6040073, 327464258, 774725, 100763, 6441657.443349000000, 0.200000001000, 0.000000031048, true, '2025-03-18 00:53:47.000000 +00:00', '3mEgeYf1dFYACPVxmyLnAvvn84eCgQSQf1u9dB4B7jDp1YzUqbqbsJwt68VDrHX4gDdVggahzjNTNimjmiibvgnG'),
+        (6050085, 327465019, 775031, 100763, 10143247.091889000000, 0.320124351000, 0.000000031560, true, '2025-03-18 00:58:52.000000 +00:00', '7keY9cUvR23CH5MqTj3yaZZvQkCGXCtyZeEPZnVr86J3ddCdm4SiLjLBKqfvrUJQSaByMBipCLTPb9sCYMP2Dqj'),
+        (6209293, 327477859, 774725, 100763, 6441657.443349000000, 0.204045035000, 0.000000031676, false, '2025-03-18 02:24:51.000000 +00:00', '4H2Q4ZP7DjjT6L5x4u8Wm2g8ancg8BRtsWkeRNS7a5nCNk4m5L5ojzmkPRfAp5a24UD5CNP9Sibo86d89LpJQTRk'),
+        (6210265, 327477948, 774725, 100763, 6314753.927613000000, 0.200000001000, 0.000000031672, true, '2025-03-18 02:25:26.000000 +00:00', '4G97DmAjioB8fmV8P7Uha9sf552hfACc2sQacJFUDA48kXvoDRtpMCpv1ii15up6qv5pB43FJNcYa5qZhF6Vrrcs'),
+        (6221612, 327478752, 217508, 100763, 30426676.602318000000, 1.000000000000, 0.000000032866, true, '2025-03-18 02:30:48.000000 +00:00', '3UxMbaKEKsh7pQ7S6TjtomqvbdvFik1fSbWr2RUVELsnJoBZr3eM2GNrpsEo4vZkd6F8bNQvDkQgX9Tb7C3phZwa'),
+        (6221680, 327478754, 11346, 100763, 12170670.640927000000, 0.417704691000, 0.000000034321, true, '2025-03-18 02:30:49.000000 +00:00', '22Hsd8uX54vAcs8ScKWx6GqvKaQhxUu1nFTktJQUyfxYJQJpXiemdeg36pbVBYyEbqo7mTynZCfGC4vFfcyuthZj'),
+        (6221691, 327478754, 11347, 100763, 12170670.640927000000, 0.428404767000, 0.000000035200, true, '2025-03-18 02:30:49.000000 +00:00', '4BcM7aB1Wt38r5k4tCE2NGj9mYC4KvyLiPyJGvKMwPzSmCWBef7f6eun7PssXiTeXq5g61C2u4mqaoSfxGHbFcog'),
+        (6221802, 327478761, 613367, 100763, 30350110.353896000000, 1.117699178000, 0.000000036827, true, '2025-03-18 02:30:52.000000 +00:00', '5e8uD2wd4jo8XeFoh93hJTbMcYVBwKW7LvATnNnHGVWnG7M3hKnXZYbytWcTFeYsachiYizczKNTHhq2Bbs8dhtJ'),
+        (6221809, 327478762, 613368, 100763, 30181810.296480000000, 1.187124164000, 0.000000039332, true, '2025-03-18 02:30:52.000000 +00:00', '3SKSwGQJFycD6iHSqDfErPFkkd8JJirurhGRZqGe29pCiuRRK9nNkowxBwGEdVJdu4XoYyexyPnoUJuERxCPN37b'),
+        (6222852, 327478836, 613368, 100763, 30181810.296480000000, 1.187124163000, 0.000000039332, false, '2025-03-18 02:31:22.000000 +00:00', 'QpE3Tg5ZVkqYxnMeA7nTZ63JTtfSFKVKNCc561pTV8rGU58z9VSrd99yNqgpnFdixQEcBrMnvocf4PJ1dqiW7PN'),
+        (6222857, 327478837, 613367, 100763, 30350110.353896000000, 1.117699177000, 0.000000036827, false, '2025-03-18 02:31:22.000000 +00:00', 'af8vrwQHHnnYcgMEX7DMVRGmsTFjUcS8xMcgPyS89tDddRcyNcTBUDhibGRhLpBr8kevNR12o25wDA2wvqwM7Bt'),
+        (6233290, 327479541, 11347, 100763, 12170670.640927000000, 0.428404766000, 0.000000035200, false, '2025-03-18 02:36:03.000000 +00:00', 'pt5YxGri74BxVP4j1QwkeNxCqPohvZ9mDTqXdNaoYigCc6mmyEC71LXQzypJKc2AmsFpuG8a3F18to9ARo33w4D'),
+        (6233291, 327479541, 11346, 100763, 12170670.640927000000, 0.417704690000, 0.000000034321, false, '2025-03-18 02:36:03.000000 +00:00', '5GDoV3M2uEu1Udb6ySyhUFiQ5FMjHm2pPMjS7YdoZZW48NMNre2jdxQ9cFkXuZddrAMJ5t4imeHNk3CXtqJp6XXm'),
+        (6318441, 327486535, 214164, 100763, 28638451.801094000000, 1.000000000000, 0.000000034918, true, '2025-03-18 03:22:42.000000 +00:00', '3yAsRNUgbKSMA3dFm5oRgpKbE49B6bo6XtNATerbpZkDC8kgY5eRMzucwh5Y4xK3tCAMKcCFiZc1vVNvAyVqkc77'),
+        (6883337, 327549413, 442339, 100763, 5526895.112778000000, 0.200000000000, 0.000000036187, true, '2025-03-18 10:21:10.000000 +00:00', '4iYFsKDPA8NMBKKcaZNYbAKCRZyczpfEnu3s9rDSWWBy2mkSLv7bHLKz9reob7MygZkdWK22GLviqXZqX51MuT18'),
+        (6948734, 327558240, 894023, 100763, 13403357.485409000000, 0.494927706000, 0.000000036926, true, '2025-03-18 11:19:44.000000 +00:00', '65sDdFNkguiQBr2LMTzxqi5S83ouKDNtSF1V8Cn97mJfABS1ayXVm4hKvpNnzK6QH16VN9xuD6RgWc6Y9v4J7x6G'),
+        (7258894, 327584906, 77195, 100763, 7940041.119530000000, 0.300000001000, 0.000000037783, true, '2025-03-18 14:17:16.000000 +00:00', '64fcKcJ5hww69P2UZNLPGCsfrrK4yQ4raUTEtB6EnKHbumNcbsdWpp2jWnE4Adbr9EaZqZWS2Xjj2BNTYjpzQfvn'),
+        (7278414, 327586309, 77195, 100763, 7940041.119530000000, 0.300000000000, 0.000000037783, false, '2025-03-18 14:26:36.000000 +00:00', '5eTP36vF5W6RXSjCGRUx1GrvAua62Rx9nCKv2gSsKYHgoq8i9yu6MAbKYXx4gkVersbV3gwhCsBgonkRhFvbesZw'),
+        (7307231, 327588019, 77193, 100763, 13158265.367463000000, 0.500000001000, 0.000000037999, true, '2025-03-18 14:38:05.000000 +00:00', '2gZR1BhHKenfRC8xGQ8rZoqA8RC7erReKeUyt5vTfgm3yiA93DuaFHhhjmGdoHz4nmzGmGMj5kHPsN63knaF3Rv'),
+        (7313603, 327588385, 77193, 100763, 13158265.367463000000, 0.500000000000, 0.000000037999, false, '2025-03-18 14:40:30.000000 +00:00', '4FeBrZNHmCwcx6nD11Yoxn31wRYMP3q264u21767mgZhzqjCk7jxL6FfcFvpAF2mFwi9UaQU83gA8EZv7aLV2jYR'),
+        (7319876, 327588829, 241910, 100763, 1567938.090429000000, 0.058635086000, 0.000000037396, false, '2025-03-18 14:43:28.000000 +00:00', '3PKFwCLT8mVLAmMv46QWcxVC6cLfSA4RfDG1r8595VYY4UhLCSA1nU7xWChbuPtkSzxsMfgoiCLxkwwruVBJsoZs'),
+        (7379622, 327592882, 77195, 100763, 5326438.515349000000, 0.200000001000, 0.000000037549, true, '2025-03-18 15:10:20.000000 +00:00', '4tYqKkZAPc9hDFSVPqD2ifpiRM9WxBwyAScL2qGmB5ZqWwPpoHdHu63R5Rj74FKocj1ovV6DCvfwK2LjztQxzqnU'),
+        (7489686, 327599885, 77195, 100763, 5326438.515349000000, 0.200000000000, 0.000000037549, false, '2025-03-18 15:56:59.000000 +00:00', '61PtJLBaGMr68CUjnwDeF1CGkzqwXsXmTkutDiReyXQhCrRYnBj7my6AkW2iy1Vo4adNVyHFDibW277RtUFGZJav'),
+        (7727747, 327616026, 185716, 100763, 26034829.314066000000, 1.000000001000, 0.000000038410, true, '2025-03-18 17:44:03.000000 +00:00', '2bQUHvqedLtA1sUvoasAbLMBgxTRrdCtnLEo53B2THpUGgxZpUm11hYjaeK13HJf6hPYFKkgxcK1ZNXGTbRzVdec'),
+        (7727774, 327616028, 865569, 100763, 11194976.593853000000, 0.447960454000, 0.000000040014, true, '2025-03-18 17:44:04.000000 +00:00', 'EzMMBCpD8k9MAfvUmrW3uCJH4L6ENFvY4TfvZq7eKZz6uTPYVw6nKUFLGCjVQRzCLXh3JQaq49Am8XFYr2D5t5x'),
+        (7727775, 327616028, 105779, 100763, 3905224.393205000000, 0.158924226000, 0.000000040695, true, '2025-03-18 17:44:04.000000 +00:00', '4oCg1AxVbGXhHzBw8BSPmVYXSFjqKXiQrs4rKeXn7EGsB5cuAw9wW6runTtM6f7VjoNgmyXGhh3e2kaKKESr5tdY'),
+        (7727777, 327616028, 7068, 100763, 10413931.715212000000, 0.430717325000, 0.000000041360, true, '2025-03-18 17:44:04.000000 +00:00', '4y4dFZTLYNDA2xaDBvCbhyyNBQiCcmp6cjr9sE58faMDPPqPHxq5QLyrbmoRNM71ooAJbYPqJfhLFxtkuJcS32kb'),
+        (7728347, 327616052, 77195, 100763, 11786628.451484000000, 0.500000001000, 0.000000042421, true, '2025-03-18 17:44:13.000000 +00:00', '48ZZSbLw3pBXxGRYKcFygRMoLRtG7ikWopZFtPt1hsRYAUaoX9ZVzUePLw4eFcFUekGa3bTnQcxemWowSJsD1JDm'),
+        (7728363, 327616053, 29920, 100763, 9429302.742329000000, 0.409916281000, 0.000000043473, true, '2025-03-18 17:44:13.000000 +00:00', '3cjGxgN7vk8WjiME99dMCBGkYdr5co2NRd946zr5QQDs4BEN8yjSu6uMjAiNCLNUaUWTwd57Yf62LTidcm1BRwxW'),
+        (7730983, 327616174, 986048, 100763, 3640369.504872000000, 0.160683508000, 0.000000044139, true, '2025-03-18 17:45:01.000000 +00:00', '26uosaFb98nsvgft9g31d5qHZcb2HZZod9wz4p2VqjqfN1zapE4jLX7QT5m3Z3NxTdCo42e9EpjT4MmhocHje3ML'),
+        (7733016, 327616292, 6563, 100763, 32220877.828653000000, 1.484410552000, 0.000000046070, true, '2025-03-18 17:45:48.000000 +00:00', '34dv3EoSkxKsyEnMgkmkvA4GEnTp5XUTraEbusYNRAKo6UGop61rUgjyZ677Rg7o2N73oaZT1CqRGH9Em5xLBxF6'),
+        (7737297, 327616586, 998055, 100763, 10311399.326255000000, 0.500000001000, 0.000000048490, true, '2025-03-18 17:47:44.000000 +00:00', 'hmsqctn8Hb2D9ecEBK2WyNyNPvs5m7PRxPshLqyYusSEs3vHgyv1b1gAXZd4Xtmiiw3afAtUgnDQE721x5z2xLC'),
+        (7737780, 327616621, 1063962, 100763, 19863843.740995000000, 1.000000001000, 0.000000050343, true, '2025-03-18 17:47:58.000000 +00:00', 'R36bFv2nsf6ynu2gfWBYRYkP7HgmKZHpE1B2aJrnPijfQMqnE1ABeFbGPrwmJQGMNeWj8xbMq2d81AcsFENneNX'),
+        (7738481, 327616665, 998868, 100763, 2895817.045130000000, 0.150000001000, 0.000000051799, true, '2025-03-18 17:48:15.000000 +00:00', '2NFbLwSn9bXzdML4mvj2v7AKJoy5zKzSPAxx74d5c3kRjYU7AovxxXTHzZeUr2PnWPTMkDnX78oSq52DbMZHsHXm'),
+        (7740509, 327616787, 7068, 100763, 10413931.715212000000, 0.534343072000, 0.000000051310, false, '2025-03-18 17:49:04.000000 +00:00', '2AafjqRJi5cy23Yx8UXyA2hs4CEt6kDUAcNRHEcNGKFQnDEtXPgQastPP9ezn7zBjrUK2ahDPpG2Q9ags9J8en4u'),
+        (7742360, 327616885, 768839, 100763, 17384909.631812000000, 0.900000001000, 0.000000051769, true, '2025-03-18 17:49:43.000000 +00:00', '5Cuskewe6SzesmK45cyJpbHejqVFDLmaQeP1ziGL2PRTBRhVpVAdAYTsBDm9yDzUXok6Xq7LzZLbMRZ6Tz8R3CES'),
+        (7743149, 327616936, 29920, 100763, 9429302.742329000000, 0.493065911000, 0.000000052291, false, '2025-03-18 17:50:04.000000 +00:00', '35Y2uigFo5rpDCZPmhfMBp2rf7Ti1Q4fQBw6aTa1tAffrPMD4c3RJuUKFwieSHKbT5CKkm3pKaPt5MELY56kcKMh'),
+        (7744354, 327617012, 77193, 100763, 9560302.876823000000, 0.500000001000, 0.000000052300, true, '2025-03-18 17:50:34.000000 +00:00', '5xxmNe3AqiZcNq7LjfZZreLxEwz88meGiBigzCFc4dAJRZsstAxi43vN9BNZdLiWSqcvqNNjexkfcra4N6YM4nkZ'),
+        (7749699, 327617355, 77195, 100763, 2102734.515744000000, 0.111021097000, 0.000000052798, false, '2025-03-18 17:52:51.000000 +00:00', '4orBAenuPBH3LZQ2A82jMMdaSpsPWk3BqhR5v5AR2vKn1VRvd9xhCSCuUoZQhvm78AUcqToHbPvcDTJkxdLac4xx'),
+        (7750630, 327617407, 30348, 100763, 22051981.199500000000, 1.194879453000, 0.000000054185, true, '2025-03-18 17:53:12.000000 +00:00', '2bL4CHARP7cmVCMfYgy1XcCnQupduPrMzy72rUGBQquPznDUDXzUrDg8CugR5MKqxjR6uee3z2ZzZdR2q4arrkXS'),
+        (7756614, 327617717, 77193, 100763, 9560302.876823000000, 0.526432547000, 0.000000055064, false, '2025-03-18 17:55:16.000000 +00:00', '2ScPtqASMiivBsTXWuXmVfgJDdoHGzGaYpihiTvF4neAqhMd6YFdCtRThxujEQNXUHnAAq4WPneB39VefHD5Y9LA'),
+        (7771574, 327618593, 77195, 100763, 4841946.967870000000, 0.261659293000, 0.000000054040, false, '2025-03-18 18:01:05.000000 +00:00', '5BTzHf9PWQ8FbrbJcA3L9183Kj3BLV5YNARAGVpdzeevZ7hmGL3zNUuku5UFwRziLwDZ6N5WuXGhyAihfkmZ6oLy'),
+        (7773593, 327618731, 865569, 100763, 5597488.296927000000, 0.298439399000, 0.000000053317, false, '2025-03-18 18:01:59.000000 +00:00', '3B9teTb8DRmJrT93ob33h5DtEN4LBxZjCHQ29iH8YqyvUt6eFgmyVUzpyiuz3dTXuHB3LpFQqNV76GWxBjo3k9DM'),
+        (7774860, 327618806, 77195, 100763, 4841946.967870000000, 0.254721838000, 0.000000052607, false, '2025-03-18 18:02:30.000000 +00:00', 'QYGeG5TfFf5Pdz4tmhZtjR2wCXhPw8TisSxtj9G2usuneCWZqS76LTy9yiAZePwFHbe7DmjMF4t4XSzNtCEkd6v'),
+        (7775414, 327618849, 77195, 100763, 3806799.052929000000, 0.200000001000, 0.000000052538, true, '2025-03-18 18:02:48.000000 +00:00', '58qYkNppHPJt5qkgpkTMCkvfgDcU5vKLYYvbX8uQZvvYhijdXR1kFHot7Y85vd5hxVAqeqPLRsk6DixmBUqws1cp'),
+        (7775481, 327618853, 30348, 100763, 12910364.627278000000, 0.670500189000, 0.000000051935, false, '2025-03-18 18:02:49.000000 +00:00', '4CpoH2k5nR46VqEQ9KG61om5bB9CzDT9JRG3y7GhhssNA2A6zdc1VUaK8su7LZwpFBoQf4fsPmcfv2G3hdyK5Dot'),
+        (7779088, 327619089, 1063962, 100763, 19863843.740995000000, 0.990073671000, 0.000000049843, false, '2025-03-18 18:04:26.000000 +00:00', '5GQX4gF5fw9KujmsPzmvjD55PoPkwpjGbhXUo9cZAQBT9Q66RnEe6wx7J9mqwboKJPM9CvyteKyQTJqP3mZatKRE'),
+        (7779958, 327619152, 865569, 100763, 5597488.296926000000, 0.270324515000, 0.000000048294, false, '2025-03-18 18:04:51.000000 +00:00', '4bR4D4rBsKTAWYx1PiZYotWRWsiwLeucQ9DYWK4bc1yt4tjb7DTBCvJKFsrWWHFLDR6SW2MddceLCuEjE6uBJBzi'),
+        (7780619, 327619208, 77195, 100763, 3806799.052929000000, 0.181744468000, 0.000000047742, false, '2025-03-18 18:05:13.000000 +00:00', '3suXi5AcXZvkJQst7NMrujbHZWtLtmxnBjuje2oRjbupkqgsUsN7TrFTXN4z6Bpes5rxe9xkQNegXJ1sYxJqFvVq'),
+        (7782499, 327619320, 195153, 100763, 24476388.802979000000, 1.198800000000, 0.000000048978, true, '2025-03-18 18:05:59.000000 +00:00', '26AyqiGd5c1GZHdyxFU1TMbS5z9m2hz8Y5xBTSLrcfCaGezEYJ5V5fUp7DESxVeLVqdGgQp7FBQ9khKEw3SK4QEW'),
+        (7784270, 327619480, 77195, 100763, 1976118.593471000000, 0.100000001000, 0.000000050604, true, '2025-03-18 18:07:03.000000 +00:00', '3zvBsC17YzkCH4ZbRqNHezAcBMQVV87HXSGZ8K1aVYbA78eBwBJAGgNLE2CvrKPVX5o1df4SHFM2EwoZvat4BCmi'),
+        (7805190, 327620850, 30348, 100763, 9141616.572222000000, 0.458490582000, 0.000000050154, false, '2025-03-18 18:16:12.000000 +00:00', '3gHQgWzCEzxqUKns4q8LxGHZRjHM4LbGVZbvMJSS9atzNBXok3h1SSpFPWa3k6Gptf5f3YbTBUqRKrUyewPXm8Gd'),
+        (7822197, 327621900, 77195, 100763, 1976118.593471000000, 0.097746440000, 0.000000049464, false, '2025-03-18 18:23:13.000000 +00:00', '5JdNv3WXaYhLDFUAKSACft1MbMzJHL8VkdLmZ3SBXybjXZe8qEKSWPDrUEtSjPLgWcyu4ctM3eCRAr54RzfjjGpJ'),
+        (7874405, 327625370, 999160, 100763, 3358132.869154000000, 0.166391595000, 0.000000049549, true, '2025-03-18 18:46:19.000000 +00:00', 'upo6AkAEwEac4ceW7mBqtXQD6LMT1NH1P2wdsjF4nH9ChneUFecoGLjCr4dHe81jpkcDp1eUSqQL13NL2Fw7vtv'),
+        (7890623, 327626371, 1113363, 100763, 1523498.492651000000, 0.075946599000, 0.000000049850, true, '2025-03-18 18:53:04.000000 +00:00', '4mQWZeyT3auaW7vrjFbZLzXx1uni2znx4qVp5CYSQQbqmcoZEKsZQAbCozUpi7qhXYUaTJ9Mycgd2aReQNqCrZR5'),
+        (7897255, 327626700, 1114510, 100763, 19157333.258504000000, 0.980198019000, 0.000000051166, true, '2025-03-18 18:55:14.000000 +00:00', 'pMm9h2j8ibLCmBd1BE82aSTCjQY9DNtQyPDf2y5czhrnZ2H6kTeqT2WKKAbvRNkjVTMfPePsLuWdKLKKfNmxjEy'),
+        (7934256, 327629436, 774725, 100763, 6314753.927613000000, 0.328351302000, 0.000000051997, false, '2025-03-18 19:13:29.000000 +00:00', '2aCUv8z82x8VtPNoKEvv7o31D66VH3x4zWy4VaWNoK845W3mDFRDL4nQAyFdos2AtiCLxHrFjFqVjBecM4QSZK4f'),
+        (7985112, 327631997, 2960, 100763, 456242.353140000000, 0.023520232000, 0.000000051552, false, '2025-03-18 19:30:37.000000 +00:00', '2Wsn4MZJsKT2BUys88Znz3NDzHKWWwxqe441dP1vUUFGyyELRFpT9i1vKEG92D6LthWJWRKXky85Ku2eCvMYky6Q'),
+        (8022562, 327634041, 227107, 100763, 28079367.386591000000, 1.500000000000, 0.000000053420, true, '2025-03-18 19:44:18.000000 +00:00', '5Zg4CiZW9eJrNLKEjUB79H3GBDNCizmyPJ6hTHfv9yQUscMhNvKKQnjweNrXEET2JYR53ojE2Vmnj3hmPJsCYUKT'),
+        (8023133, 327634071, 1147703, 100763, 13291544.305040000000, 0.749250000000, 0.000000056370, true, '2025-03-18 19:44:30.000000 +00:00', '3vU12ooPKA9y9tF3LCEMxm3ufGNX3tdL1mSkcthvW2NSSozJLd9r2WuPXLV75JVZcFctYStVBrzJL59xPSf4Y1qb'),
+        (8023142, 327634072, 5821, 100763, 23632802.252034000000, 1.400000000000, 0.000000059240, true, '2025-03-18 19:44:30.000000 +00:00', '4fdYEW44XPUSvbuP1S8tFQYc5aXje8fxhqdag56kwd753bUgPbS2Vos2JjpfjExJ8QZsh2vVkZKjbDRx55VWxM2U'),
+        (8023188, 327634075, 986048, 100763, 182018.475243000000, 0.011131203000, 0.000000061154, false, '2025-03-18 19:44:32.000000 +00:00', '35X76P7oCfR6cZsNDfxP1sbf7aKximrtYFQZd5v8jNqArhcSbMuM6AG5ZsGaYKrxffBMGYXV82ZVFKiuHJnHyw6s'),
+        (8075283, 327636910, 30326, 100763, 8014973.500756000000, 0.495500000000, 0.000000061822, true, '2025-03-18 20:03:20.000000 +00:00', '1VKbN93XasHgyW8v9qE6KonHx48cGz1iptj8D2a3S2ULxsjoYWXcw3rJebgFhyUvWGdY9L8VsH3qG5RKETr9YuD'),
+        (8076822, 327636985, 30326, 100763, 15510233.608501000000, 0.991000000000, 0.000000063893, true, '2025-03-18 20:03:50.000000 +00:00', '28zBk4TpaWpNWBgVbjCHvBzHSVmPt5c1Pkx4UbwtEPXsPzFSbFbCcZCojTxwSJwr1UASBGGAZLxLf3ph8mqG73sG'),
+        (8119308, 327639626, 88173, 100763, 29317407.804033000000, 1.998000000000, 0.000000068151, true, '2025-03-18 20:21:18.000000 +00:00', '4hm3r9hYRL52orHHwCaptYFzerhmEd1CkGDbmBAhPEPWRdCH4acPhwz4s7LZdVUv8Aoj4z51oRasQcdDBG3uJ7nH'),
+        (8119386, 327639632, 1583, 100763, 8850831.001538000000, 0.637866993000, 0.000000072069, true, '2025-03-18 20:21:20.000000 +00:00', '5qX6akkFoipaPzqwJRNhJxS1DbtFiZu2GfEVcTGZGqaGgi2LyjRqWT3CuZDVp2GK4dJg82hiTkMv5aEBcnJCjcC6'),
+        (8119393, 327639632, 1023840, 100763, 10276693.094590000000, 0.762300001000, 0.000000074178, true, '2025-03-18 20:21:20.000000 +00:00', 'WHdJAdFr9KCqYoT3hEhs732eGQkJLRy2aJje8PxfsnQ64uj3gfb9RzQkWPtapNoqUi5r6zSAHW9yDK16NLRSirT'),
+        (8119437, 327639633, 288297, 100763, 11100534.874722000000, 0.850805487000, 0.000000076645, true, '2025-03-18 20:21:21.000000 +00:00', '2yygajtSd9B5GHWEHm19uSLYdDK1KmPygk5x14UjUdBT5pdcR3vFp6udVLaxPR7HzMb343uKtxnGuiMUG1X8kfB6'),
+        (8119438, 327639633, 243, 100763, 2512081.700404000000, 0.196634738000, 0.000000078276, true, '2025-03-18 20:21:21.000000 +00:00', '21ZszWRAkncwQrqC9M9j3C9XBeTWmoKGJc464YvZtR9i5B1HnqBFrA6qSNfpEkvMwJobsTKpbsW3h1YyoQeXuf8s'),
+        (8119673, 327639650, 243, 100763, 2512081.700404000000, 0.196634737000, 0.000000078276, false, '2025-03-18 20:21:28.000000 +00:00', '3yA2o4BFqBRzww41QV4n1jAEWVwgqnsxYrXHiw2nZFk9TuUWW9ZiRKRP9wq7Xxz2DWGXGmpCRNNLAdiPucBBYLT7'),
+        (8124184, 327639889, 775231, 100763, 18319665.220713000000, 1.470297029000, 0.000000080258, true, '2025-03-18 20:23:03.000000 +00:00', '3TqLEDbtjwiKPhTaVXWAfGvsmSnJd5iGPDQ4bpJVpzKVXsAhemjhbzk6KNRAGJ3ZCXdF7WF3NqX4dHCQSwbhL9n4'),
+        (8127397, 327640027, 775231, 100763, 18319665.220713000000, 1.470297028000, 0.000000080258, false, '2025-03-18 20:24:00.000000 +00:00', 'P9RiV8ZdZr74X7DoaLd9TyzNdWCxrDxeuV2Xudro9LddFQ7g3FoREsZoX7zRe2YQa3qANePYnV92MpqMNCD1RHg'),
+        (8130269, 327640188, 288297, 100763, 11100534.874722000000, 0.850805486000, 0.000000076645, false, '2025-03-18 20:25:03.000000 +00:00', '4W1uJkoCDw3MafZQkkQBZtgCLrVSNfFfge3g7o7j6WhY4UUQrDdkGFZzeKiyRetaacuozD8reWr5RGF2jRMt9G9p'),
+        (8131716, 327640264, 30326, 100763, 11762603.554628000000, 0.870572774000, 0.000000074012, false, '2025-03-18 20:25:34.000000 +00:00', '4CvSXLT88m2ufxPBneBDqMd3X6wBURB64ybjZxJ7HXJqfE1rBdKr4Bn9AwdDCnRsVWhobY8dPRRzTTAH5ExUoMHQ'),
+        (8146269, 327641065, 1583, 100763, 8850831.001538000000, 0.635039976000, 0.000000071749, false, '2025-03-18 20:30:54.000000 +00:00', '5qBRHCaKoQyzTNF9YhB4CMd2KHj18nZPjGVMMX2hift6chFN9jVBWkiiyypdE9kuoZD67ncKB2PdKnkiVyTPnv9b'),
+        (8195911, 327644070, 1177922, 100763, 6988226.571783000000, 0.500000001000, 0.000000071549, true, '2025-03-18 20:50:56.000000 +00:00', '3y546oxK2KLMiuCBY3JqEDMWjwqM61PKHEYAcgLE2Drv7vx9xa79q3bksKoTZfTpv91ZNT2mx4qe7mfd9iziL41r'),
+        (8196273, 327644094, 783624, 100763, 683278.896266000000, 0.049450500000, 0.000000072372, true, '2025-03-18 20:51:05.000000 +00:00', '5imyftzoVThiRYNHNzTPSeEYCuvt43qPmz33zwHyno6Bq7z6sgKmmK4SmRrtUiNESa1AHKfFqnrugqq6mG6geVzL'),
+        (8200690, 327644325, 62831, 100763, 1377475.812182000000, 0.100000000000, 0.000000072597, true, '2025-03-18 20:52:40.000000 +00:00', '36nhvQdpGEefpmNTB9TG2rydVXkmu45x2EuxZ8zJHpEyV37BQzKmuC6oqQ7244n3XV2xjHyMncua9ihKbDJ4Bv6N'),
+        (8209930, 327644892, 62831, 100763, 3418910.377365000000, 0.250000000000, 0.000000073123, true, '2025-03-18 20:56:30.000000 +00:00', '3XvjvjYEGG57W6JLcP75sDnQ4g9JZ77HJJwpVFGhD2bmejbZQu2E4FtM33ycDpdh4in6DKCAMud7FKZayrG3hrhQ'),
+        (8210081, 327644905, 62831, 100763, 4796386.189547000000, 0.349999999000, 0.000000072972, false, '2025-03-18 20:56:35.000000 +00:00', '4jDa6dMhbwK5evWUqKgby6emG2KN8s5S4Wm72ue9vrpig6vY5DM7kH6V5PJzFLJWttV3C1hC8SgmbcTZyd5JweNS'),
+        (8212318, 327645065, 1180795, 100763, 270489.190471000000, 0.019603960000, 0.000000072476, true, '2025-03-18 20:57:38.000000 +00:00', '2hpyTps17EAmgbdikzi4VcuD614HUY86iJ7oxjQZKdpH7wjYmY6ViNVxEUVTKs3qNQpnwirNFT6Qg2MuiachYrcz'),
+        (8246048, 327647124, 1186828, 100763, 38920418.082170000000, 2.997000000000, 0.000000077003, true, '2025-03-18 21:11:24.000000 +00:00', '31t1daQ6UkyxaUTxhDBdMLMTwF1miFDP8GNDVzLcvSRoGwf3EUruVRcSckUnZ5uxnYwWcCTgwfRXYcVcMuiktyNV'),
+        (8249521, 327647326, 390196, 100763, 11761071.531044000000, 0.980198019000, 0.000000083343, true, '2025-03-18 21:12:43.000000 +00:00', 'HfP8uFXNTFeCs4drwQ8Qf4ko7xTVbdfd53biqDGx7Zy6d463e8otSDfC6ffqbQhL64dae86KgpBDgmwW9gw86Nw'),
+        (8251716, 327647448, 30326, 100763, 1176260.355462000000, 0.099714883000, 0.000000084773, false, '2025-03-18 21:13:32.000000 +00:00', 'WPYhkDwrVmuGbHMRnVUyZZfTkNhe8wxHTV5Pw6knCRTM8aFYqzoB2myPgSQXwDco4WUa366ermY8YyB3VtSNjnq'),
+        (8254473, 327647614, 390196, 100763, 11761071.531044000000, 0.976497965000, 0.000000083028, false, '2025-03-18 21:14:39.000000 +00:00', '61iPdJuJJxwzKb55THARCGcdP4bGeYDe74w5M6r9ZU7QSHtbEFDQnwXymWUiEDboMh7Jv96LUqr4c6QtvcFTio3F'),
+        (8329862, 327652080, 620001, 100763, 600840.725637000000, 0.049000000000, 0.000000081552, true, '2025-03-18 21:44:15.000000 +00:00', '2o2yo9e6a9cBdVwEegc9ADLM8Q6LyJo4LCyvMQ64Q8tEwYsC4jwXtax3XWoJzFJJh4ZaEsxbvW976nY9skb6A3v4'),
+        (8334963, 327652369, 1201226, 100763, 10726716.527606000000, 0.890843593000, 0.000000083049, true, '2025-03-18 21:46:09.000000 +00:00', 'eCenhnd4RucAmrffmms9Q4asxMAqwyXnPM72ioMRSwXeKycuG95nidZySWSUKnyVUXdg9jApUGQf8Z5MgAVxmEp'),
+        (8338921, 327652565, 1201226, 100763, 5309724.681165000000, 0.444804588000, 0.000000083772, false, '2025-03-18 21:47:27.000000 +00:00', 'JmRWn7BpZxS52kZN6xfXn9xqSHVjopMqC7br9tnX8JLsJxiR5oaEgMXY4cXwjVnDp7oiVVxUW7QvAywAU1hZmJw'),
+        (8372565, 327654310, 108324, 100763, 11811465.468971000000, 1.000000000000, 0.000000084663, true, '2025-03-18 21:59:04.000000 +00:00', '62sX3e2JdfDy1BRtGEJUbjmFgyzqHBR4VK5qTc5wbp3R5ZUFgGZtv6KAa2kkPK9TQmXK1SfviMejyuZyaMSN7LzF'),
+        (8374738, 327654444, 214472, 100763, 11150547.690458000000, 0.980198019000, 0.000000087906, true, '2025-03-18 21:59:57.000000 +00:00', '5vNVpFUTiAJrUR7Jg6bHXrB95GDFN2K8ENsZarMs4vRUpHMb6HzrXA9SnuGbDuc62Lkuhdk89LNjhgd1uFxh58vG'),
+        (8376184, 327654534, 214472, 100763, 5423968.531550000000, 0.490099009000, 0.000000090358, true, '2025-03-18 22:00:34.000000 +00:00', '3CYAGdwoQCqHTxvHikYZr8VjjZNeCem5cVnFbNcbEm9xAdBvHMNZgQfBQ3a3FXw95atMHx6hRNdZ7Q64gMpz7Zmu'),
+        (8376185, 327654534, 214472, 100763, 5326715.576462000000, 0.490099009000, 0.000000092008, true, '2025-03-18 22:00:34.000000 +00:00', '5D8FDEV6DMbfuFjKtcvZY5f3EG77cLH9D8JPvKSnFn7NbwBbHhkXpBqeuL9dcaEM7zGMKAoe8cyVKpbDAaZU2NeP'),
+        (8376281, 327654539, 986048, 100763, 182018.475244000000, 0.016893382000, 0.000000092811, false, '2025-03-18 22:00:36.000000 +00:00', '5HJdzpjtYtxhGFnW6n2dcmsvwcA4ftHejQJizeVAfwZtSHJtQtEMEPNGXSYwVN6nzkfiE9Cghq7MpB9yqbgGqVWv'),
+        (8377984, 327654656, 111169, 100763, 1065080.070386000000, 0.099000000000, 0.000000092951, true, '2025-03-18 22:01:22.000000 +00:00', '54TVawxAy9WExxnFmEmVtoZbxpXkqrghAfjcgn4eHeG49D3ySVCyoTatnG2aEEyCEAYifxnXMYifTdAQBKZaEZuf'),
+        (8381836, 327654901, 30326, 100763, 1058634.319916000000, 0.098401939000, 0.000000092952, false, '2025-03-18 22:02:59.000000 +00:00', '4MSuX4YAth7hyXWH8Saanx7T1mvoHbtdgbhpDhHzw7wqS6n1CWLtkjZLTjefuJQxt5DnwPgBj8ngQP161URMKFdY'),
+        (8383419, 327654993, 390196, 100763, 10378082.236289000000, 0.980198019000, 0.000000094449, true, '2025-03-18 22:03:36.000000 +00:00', '5ttqZELZUhVijLxoGYdfN8akRzwGDxPndysJD4UFb2MFzxZw8auTrE5qktQ91qJNNyJguSf8yebzeqtkPBgUw4aY'),
+        (8383548, 327655004, 932376, 100763, 10442793.998338000000, 1.022452615000, 0.000000097910, true, '2025-03-18 22:03:40.000000 +00:00', 'o5AYE1wXLnFKnGDvz1vZLzzD8k93jr4ZsPDSMaYgntKL2pEv4LZRa4CskjBHvkpNw28BQM2GZeKmF9ASxnMHj9h'),
+        (8385014, 327655109, 30326, 100763, 4763854.439625000000, 0.471051477000, 0.000000098880, false, '2025-03-18 22:04:21.000000 +00:00', '5aaN6QCoD9xdodJz7SNQ6FHP7TbDFnDJ8xnjoSjRwaKUeMEtdvGmuiv1GsGTnb6PF6thLeRFVmT8crheBW2QQvjo'),
+        (8385399, 327655134, 1208781, 100763, 5265204.689293000000, 0.521084942000, 0.000000098968, true, '2025-03-18 22:04:32.000000 +00:00', 'xoK7MVbdfLyth544mjt4aLPa4q9zks7k5ZuA77N6AS3mdqeha5QVfnAFdbSZitzrgYPEUaU43SZgmx8JSMX8VNY'),
+        (8385640, 327655149, 195458, 100763, 9828292.011462000000, 0.999000000000, 0.000000101645, true, '2025-03-18 22:04:38.000000 +00:00', '2NHH6Uq54Sc8XCF9pZJTthyHMicF55zAtcuARoH9ZkcUtojAh5DUipdtweQRc4B9aGCtQkenXynqgsGZxuauRQxv'),
+        (8386661, 327655215, 30326, 100763, 4763854.439626000000, 0.488582292000, 0.000000102560, false, '2025-03-18 22:05:03.000000 +00:00', '31P8scoErLbfQDQpQRFXseJqFrTDm3awTQFcD97HF9PSN41zGa3KLQ9grTkFortQpxSVqWLGT5rKVnvzcBWj54Yq'),
+        (8387372, 327655269, 190326, 100763, 4821108.139250000000, 0.494505000000, 0.000000102571, true, '2025-03-18 22:05:25.000000 +00:00', '5sTLnXRMVhgj7CBczDh5xfLUzT8pMrCWc5RYRjgdvXit7TKLgvedCfXNRbhUVJgHcEdcXtfvnTSvQULPuwRWT41v'),
+        (8387748, 327655286, 462161, 100763, 9501179.217410000000, 1.000000000000, 0.000000105250, true, '2025-03-18 22:05:32.000000 +00:00', '4jwGo39HsVFTpwaT4VbBedHDtGCbWpSgX4uzc7wF4h5QKei7V9bY7fbh5FgE3oEiTBgcB54deF7XkBmUF5HJxB5N'),
+        (8387758, 327655287, 408904, 100763, 3800471.686964000000, 0.409771644000, 0.000000107821, true, '2025-03-18 22:05:32.000000 +00:00', '2G2QLYQV4yThzkMNPPYa8AG7a5ZHUQ8VGWLFidKhcNWWAV2xXsYyTXuvqHs71QA4b4HvaGTyR6Lg4S6m8i5rSCB8'),
+        (8388603, 327655347, 193318, 100763, 7551026.153507000000, 0.831373116000, 0.000000110101, true, '2025-03-18 22:05:57.000000 +00:00', '47ujTFnwzbu1Zkfh6FXvD5mPeYMpHRc9mpD9Qe8EmYJZp1KV2V9YWXp2qfyZ1pM8oyfacmGxWiZ9CU9rTGDzsbCg'),
+        (8388750, 327655354, 1247, 100763, 1071579.226375000000, 0.119880000000, 0.000000111872, true, '2025-03-18 22:06:00.000000 +00:00', '2CQ6Et5b9ZdrRq4vdGKuXMnpCbHZkcZAWaMVnXLUFEdin7mJ6Yz1kV8cGaZUfQbVAUatGJSGFqCfPbed5GFJpc6K'),
+        (8388974, 327655372, 639913, 100763, 89209.267229000000, 0.010001666000, 0.000000112115, true, '2025-03-18 22:06:07.000000 +00:00', '2AqpFCVsvZqAEsKooPqnj4FzMaLV8UmSTyd4gUZL5VnLK82mN4DbeEmqFpSyGNB1JFNG8X2SVkocxXpFafifAPsv'),
+        (8390250, 327655458, 932376, 100763, 10442793.998338000000, 1.148598259000, 0.000000109990, false, '2025-03-18 22:06:41.000000 +00:00', '4CnwX7xcsYV7KnW1sykw3JWoitD7vWX1GHpLCscJugW6Hsw8WHjGj3c8uV9SKePm2rCec58mSmAkfYMq84hMRQjA'),
+        (8394425, 327655704, 553965, 100763, 8961070.809208000000, 0.982905958000, 0.000000109686, true, '2025-03-18 22:08:20.000000 +00:00', '5HRDchMnwY8q9vseNVBUdQoVowMvziZvwUCB4ChWhrh5nMqXae1EgQPNNApGupHucZdzWkDUAV6GYD1SHtyv1QyG'),
+        (8395341, 327655769, 1210681, 100763, 167410.036433000000, 0.018674660000, 0.000000111550, true, '2025-03-18 22:08:46.000000 +00:00', '3KoBqYvBBSiLqXnnY4MddG1ikWMwLBUZe7RoGZ9m9hnTE8vs5LNhNA16htDiSqXL5D2ZvmUpfugxqbYjwJejVFxv'),
+        (8396726, 327655860, 1210681, 100763, 144839.700046000000, 0.016166325000, 0.000000111615, true, '2025-03-18 22:09:22.000000 +00:00', '63VwBqL9urXAecQBB1KaPmMD8XjLZ2cjDKm7dyMP9qPfjpFKFS16wtCg9edxgajTCKoQQbduFpFya8T6cdweByqZ'),
+        (8399047, 327655998, 88173, 100763, 19056315.072621000000, 2.054632156000, 0.000000107819, false, '2025-03-18 22:10:17.000000 +00:00', '2HJWmuW2x1gNpijtuT3L81saFcDcSnARdrGv388BqJ1GWrrYi7zsMB6cLjhRd3eiYUL7aoGQVC7bpyBw3jrxC9tF'),
+        (8400918, 327656106, 408904, 100763, 3800471.686964000000, 0.393032622000, 0.000000103417, false, '2025-03-18 22:11:04.000000 +00:00', 'bRbceSPwEgwWEiHgcHrxEnK4KLBkKjGbZikQfLG9LjABUP9vyXeFjtCKc3AWx7RNtPReLRe2xha3v2HV9PsDsdV'),
+        (8405663, 327656360, 172078, 100763, 9569283.662995000000, 1.000000001000, 0.000000104501, true, '2025-03-18 22:12:45.000000 +00:00', 'VG7xhPruWhshJ7DZJGYJpoYRsuTYrJKMwoXg57hCtt36Qne8DLJ4akPM6y7WzHvXZG9E2jkN1VWPhDZvnDwYFRa'),
+        (8412689, 327656758, 484082, 100763, 2861111.888007000000, 0.305778802000, 0.000000106874, true, '2025-03-18 22:15:25.000000 +00:00', '5FeMMq7ER7Kb9TdjGR7dFQm4TGb3XsRbAWJdp9E8vsx1UyQVhc6ma4WW8S9KF8zXPHpkjT3vUoQcjNMvn3tSHzYJ'),
+        (8415376, 327656915, 1214015, 100763, 2002728.548149000000, 0.215948700000, 0.000000107827, true, '2025-03-18 22:16:28.000000 +00:00', '2xbqMrntfY9AbgprdZhw9V3JKnm4FzyA7fdBq1scm1ukVavv1wL298vB5XLaJQhzThqE52TgemUrWFzHKbaXecnX'),
+        (8418164, 327657060, 172078, 100763, 9569283.662995000000, 1.017760856000, 0.000000106357, false, '2025-03-18 22:17:26.000000 +00:00', '2caj4Loj7sDcPXY32v6qve4JmM8Z4p9omYTPBdkYw7ujiwyXUkMHrKcE4ASyqzNcxdbNLCrHg8vexiCZ66LyrA9e'),
+        (8437383, 327658329, 409453, 100763, 1913452.780331000000, 0.200691980000, 0.000000104885, true, '2025-03-18 22:25:51.000000 +00:00', '2r1QEZ4yPs413dwiSGSk2HA6BZ8kUtypnWVwJs5UnGrmHk4AdvE4n97Xs6q7kAipPur4mjwhvesR5y2tBC5hLuJf'),
+        (8438241, 327658397, 214472, 100763, 21901231.798470000000, 2.217245775000, 0.000000101238, false, '2025-03-18 22:26:19.000000 +00:00', 'f9mwEGpcQp47LPGBHg6jNrQmGcz6RGrAQquuqti6JsFKMBdq9YeDra3tM5SV3FYz9dRFExHD6KHYhjzt3FoWBeU'),
+        (8440199, 327658532, 999160, 100763, 3358132.869154000000, 0.325122407000, 0.000000096816, false, '2025-03-18 22:27:12.000000 +00:00', 'QW7TtLh2uQaThbVC3CkmZF1tV1vqWtP34VUVuJeEb2JD5yNFS34k8tG7CmLJ111a8EhBAPhiSF4Y2XBjgDuq81G'),
+        (8448456, 327659095, 667801, 100763, 10830000.000000000000, 1.023269942000, 0.000000094485, false, '2025-03-18 22:30:55.000000 +00:00', '4y9vjCErWoWe29FAFcczoqhVYnQiqJJjsVn7zaCP4UPizXFQdT6DzxEeejRDnd9gE5Prr8BHTToKFNurp9wAeFeM'),
+        (8450307, 327659210, 667801, 100763, 11363.000000000000, 0.001053873000, 0.000000092746, false, '2025-03-18 22:31:41.000000 +00:00', 'kCjQh4nDC1AQJVgvRC1y6e7CmU7kDvnHuqsnZMw5ohJojM95C42FrRpvMqBc95Kri1oV5YfJQf4jdWCM4d53oN2'),
+        (8456021, 327659501, 249108, 100763, 12536834.856963000000, 1.188000000000, 0.000000094761, true, '2025-03-18 22:33:36.000000 +00:00', '3HHpkRwga8aFVicvP1DdDwTiYXe9jhRYjLHwLhzoFvPfz32xF7d7U7Nz55NbdYdFSYPCGowg4rMG6KDHyrMyVNo5'),
+        (8456994, 327659553, 636531, 100763, 1217397.281501000000, 0.118119145000, 0.000000097026, true, '2025-03-18 22:33:58.000000 +00:00', '557WnEHM7F5wY3AsHknSTgH573thefWn1khAzUzCjW5ibTb1QsAd5QDCNkCdELfpZxADbMT8aAepsEYyTvbbSuvu'),
+        (8457007, 327659554, 862, 100763, 5933514.856805000000, 0.582934458000, 0.000000098244, true, '2025-03-18 22:33:59.000000 +00:00', '3ziDJxmRRNWWfJpE9Q71orBN3Pq5UhSyg2huUaFNFSMbnaBx1K28fHUA7rbuQBu6Rmg4fSYb1AeozYYd6RnvYcwQ'),
+        (8461774, 327659905, 2960, 100763, 107267.165276000000, 0.010646197000, 0.000000099249, false, '2025-03-18 22:36:17.000000 +00:00', 'HGotQtjLVBcgGXL8bE8yuzX6szWnuo7mC5SoEYCKhb4L9Ztv6LShQofB5sNLYyfNJTStZbkYpRSieWVTExVSG8F'),
+        (8465064, 327660154, 862, 100763, 5933514.856805000000, 0.582716036000, 0.000000098208, false, '2025-03-18 22:37:58.000000 +00:00', 'UA9vUUMaraSiwyntjkoaGafuB11MwM8NmqCiiRWN38U3zpykhAsyN2H3QU2ycYMRrVRSnxZnVAishobnqg9u1CC'),
+        (8473823, 327660762, 636531, 100763, 1217397.281501000000, 0.118075162000, 0.000000096990, false, '2025-03-18 22:42:00.000000 +00:00', 'iKMNVCydDRKmxEdRUDyvtLTx8HZrJ7PRgezMtmJRffzYWEh8CeFcadnLBjbTR5931qgCenAcAPuVhGj4J7r4AHb'),
+        (8477979, 327661011, 249108, 100763, 12536834.856963000000, 1.187562808000, 0.000000094726, false, '2025-03-18 22:43:39.000000 +00:00', '3KQHkzwwMT32unfJTpfyXEhy6FQZ4G4B73s6FmtQAMKiqipDNoyiCz8z86L2URLnd1p4EkH3crdWZY1JCrNdP3rJ'),
+        (8480440, 327661162, 1247, 100763, 1071579.226375000000, 0.099166295000, 0.000000092542, false, '2025-03-18 22:44:39.000000 +00:00', '2K9eCW5kGEvm9eLFkxah59Jgb9afXrt49xrZZnTgHxFq1EdDck3fuMV8x9EQpDzM8PPocw5yHWeVrxpTBRGgKrC4'),
+        (8484684, 327661412, 30326, 100763, 10536615.871319000000, 0.991000000000, 0.000000094053, true, '2025-03-18 22:46:19.000000 +00:00', '5KRk99fx7G74CFT3NGnHdB96fFP4XKL8VXjF3T3fEdXeJYv2ZywLAfWV27aW9CAnhd4gcV9LPRKRXCUBndDLoXik'),
+        (8486456, 327661531, 257578, 100763, 5174663.905146000000, 0.500000000000, 0.000000096625, true, '2025-03-18 22:47:07.000000 +00:00', '5BNxqau4nFxYFRtwe5HeZwLoj3LCDmaiLNWHKJYU4RJXmNB2csQ3cAWagxekmvf3C56CGBRSMXXV8yzSWvxnAHia'),
+        (8486464, 327661532, 862, 100763, 5917475.659797000000, 0.582926243000, 0.000000098509, true, '2025-03-18 22:47:07.000000 +00:00', '5y6qr6SYKNF1n8eEnmiYfy831nsiAN6QnAFs14D7fmVc8rLSibxCKvemevaA2sM4sJQYEUADnpW75RDbMCogYbRD'),
+        (8497104, 327662132, 862, 100763, 5917475.659797000000, 0.582926242000, 0.000000098509, false, '2025-03-18 22:51:07.000000 +00:00', '67UUPKWKqZ2NgbHwdWXGfmVh7Mod9y7W61TMvmF3UsKdPjAT2Pf97DxcGroh3Nz99aQzkBANYD3z1NbJob7Q2i7Q'),
+        (8509356, 327662844, 257578, 100763, 5174663.905146000000, 0.500000000000, 0.000000096625, false, '2025-03-18 22:55:52.000000 +00:00', '2kT6sMPo5Y4ykuss4fA2aFNwRQhojq3oYKnzFAPfWWK9WaUzcmvvvoF8z1mnPeShAvtCAfDUQg9nkyAtVUPj8F4b');
+	"#).await.unwrap();
+		tx.commit().await.unwrap();
+
+		let test_instance = CandleRepo::new();
+
+		let mut tx = pool.begin().await.unwrap();
+		for _ in 0..100 {
+			test_instance.calculate_1s(&mut tx, Partition::SEVEN).await.unwrap();
+		}
+
+
+		assert_sql!(&mut tx, "(select count(*) from raydium.candle_1s) = 132");
+		assert_sql!(&mut tx, "(select sum(swap_buy + swap_sell) from raydium.candle_1s) = 146");
+		assert_sql!(&mut tx, "(select sum(swap_buy) from raydium.candle_1s) = 91");
+		assert_sql!(&mut tx, "(select sum(swap_sell) from raydium.candle_1s) = 55");
+
+		// most recent candle has no duration
+		assert_sql!(&mut tx, "(select duration from raydium.candle_1s order by timestamp desc limit 1) is null");
+
+		// 2025-03-18 22:33:58 - only exists for 1 second
+		assert_sql!(&mut tx, "(select duration from raydium.candle_1s where timestamp = '2025-03-18 22:33:58') = 1");
+
+		// 2025-03-18 22:33:59 - exists for 138 seconds
+		assert_sql!(&mut tx, "(select duration from raydium.candle_1s where timestamp = '2025-03-18 22:33:59') = 138");
+
+		tx.commit().await.unwrap();
+	}).await;
+}
@@ -5,7 +5,7 @@ use base::model::solana::Signature;
 use base::test::SuccessfulTokenInfoLoader;
 use common::model::Count;
 use common::repo::Tx;
-use solana::jupiter::model::Swap;
+use solana::jupiter::model::{Swap, SwapLeg};
 use solana::jupiter::repo::{ReadSwapRepo, SlotSwaps, SwapRepo};
 
 pub async fn list_all_swaps<'a>(tx: &mut Tx<'a>) -> Vec<Swap> {
@@ -32,6 +32,16 @@ pub async fn list_micro_with_signature<'a>(
         .unwrap()
 }
 
+pub async fn list_legs_with_signature<'a>(
+    tx: &mut Tx<'a>,
+    signature: impl Into<Signature>,
+) -> Vec<SwapLeg> {
+    ReadSwapRepo::new()
+        .list_legs_with_signature(tx, signature)
+        .await
+        .unwrap()
+}
+
 pub async fn count_swaps<'a>(tx: &mut Tx<'a>) -> Count {
     ReadSwapRepo::new().count_swap(tx).await.unwrap()
 }
@@ -40,6 +50,10 @@ pub async fn count_micro_swaps<'a>(tx: &mut Tx<'a>) -> Count {
     ReadSwapRepo::new().count_micro_swap(tx).await.unwrap()
 }
 
+pub async fn count_swap_legs<'a>(tx: &mut Tx<'a>) -> Count {
+    ReadSwapRepo::new().count_swap_leg(tx).await.unwrap()
+}
+
 pub async fn insert_swap<'a>(tx: &mut Tx<'a>, slot_swaps: SlotSwaps) -> Vec<Swap> {
     SwapRepo::testing(Box::new(SuccessfulTokenInfoLoader::default()))
         .insert_swaps(tx, slot_swaps)
@@ -25,9 +25,13 @@ pub mod invocation;
 pub mod jupiter;
 pub mod notification;
 pub mod pumpfun;
+pub mod raydium;
+pub mod rpc;
 pub mod rule;
 pub mod token;
 pub mod token_pair;
+pub mod trade;
+pub mod transfer;
 pub mod user;
 pub mod wallet;
 
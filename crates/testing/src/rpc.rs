@@ -0,0 +1,262 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use async_trait::async_trait;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana::rpc::RpcClient;
+use solana_client::client_error::{ClientError, Result};
+use solana_client::rpc_request::{RpcError, RpcRequest};
+use solana_client::rpc_sender::{RpcSender, RpcTransportStats};
+use solana_sdk::account::Account;
+use solana_sdk::hash::Hash;
+use solana_sdk::program_option::COption;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::{EncodedTransaction, TransactionBinaryEncoding};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+
+const PUMPFUN: Pubkey = pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+
+/// In memory stand-in for a rpc node, serves accounts from a map and records every transaction sent to it
+#[derive(Clone, Default)]
+pub struct LocalRpc(Arc<LocalRpcInner>);
+
+#[derive(Default)]
+struct LocalRpcInner {
+    accounts: RwLock<HashMap<Pubkey, Account>>,
+    transactions: RwLock<Vec<VersionedTransaction>>,
+}
+
+impl LocalRpc {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn client(&self) -> RpcClient {
+        RpcClient::new_with_sender(self.clone())
+    }
+
+    pub fn add_account(&self, key: impl Into<Pubkey>, account: Account) {
+        self.0.accounts.write().unwrap().insert(key.into(), account);
+    }
+
+    /// Adds the pump.fun global account
+    pub fn add_pumpfun_global(&self, fee_recipient: Pubkey, fee_basis_points: u64) {
+        let mut data = Vec::with_capacity(113);
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(Pubkey::default().as_ref());
+        data.extend_from_slice(fee_recipient.as_ref());
+        data.extend_from_slice(&1_073_000_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&30_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&793_100_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&1_000_000_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&fee_basis_points.to_le_bytes());
+
+        let (global, _) = Pubkey::find_program_address(&[b"global"], &PUMPFUN);
+        self.add_account(global, program_account(data));
+    }
+
+    /// Adds the pump.fun bonding curve account of the given mint
+    pub fn add_pumpfun_curve(
+        &self,
+        mint: Pubkey,
+        virtual_base_reserves: u64,
+        virtual_quote_reserves: u64,
+        real_base_reserves: u64,
+        real_quote_reserves: u64,
+        complete: bool,
+    ) {
+        let mut data = Vec::with_capacity(49);
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&virtual_base_reserves.to_le_bytes());
+        data.extend_from_slice(&virtual_quote_reserves.to_le_bytes());
+        data.extend_from_slice(&real_base_reserves.to_le_bytes());
+        data.extend_from_slice(&real_quote_reserves.to_le_bytes());
+        data.extend_from_slice(&1_000_000_000_000_000u64.to_le_bytes());
+        data.push(complete as u8);
+
+        let (curve, _) = Pubkey::find_program_address(&[b"bonding-curve", mint.as_ref()], &PUMPFUN);
+        self.add_account(curve, program_account(data));
+    }
+
+    /// Adds a system account of owner holding lamports
+    pub fn add_sol(&self, owner: Pubkey, lamports: u64) {
+        self.add_account(
+            owner,
+            Account {
+                lamports,
+                data: vec![],
+                owner: solana_sdk::system_program::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    /// Adds a spl token mint account
+    pub fn add_mint(&self, mint: Pubkey, decimals: u8, supply: u64) {
+        let mut data = vec![0u8; Mint::LEN];
+        Mint::pack(
+            Mint {
+                mint_authority: COption::None,
+                supply,
+                decimals,
+                is_initialized: true,
+                freeze_authority: COption::None,
+            },
+            &mut data,
+        )
+        .unwrap();
+
+        self.add_account(
+            mint,
+            Account {
+                lamports: 1_461_600,
+                data,
+                owner: spl_token::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    /// Adds the associated token account of owner holding amount of mint
+    pub fn add_token_account(&self, owner: Pubkey, mint: Pubkey, amount: u64) {
+        let mut data = vec![0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                mint,
+                owner,
+                amount,
+                state: AccountState::Initialized,
+                ..TokenAccount::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+
+        self.add_account(
+            get_associated_token_address(&owner, &mint),
+            Account {
+                lamports: 2_039_280,
+                data,
+                owner: spl_token::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    pub fn sent_transactions(&self) -> Vec<VersionedTransaction> {
+        self.0.transactions.read().unwrap().clone()
+    }
+
+    fn account_value(&self, key: &str) -> Value {
+        let Ok(key) = Pubkey::from_str(key) else {
+            return Value::Null;
+        };
+
+        match self.0.accounts.read().unwrap().get(&key) {
+            None => Value::Null,
+            Some(account) => json!({
+                "lamports": account.lamports,
+                "data": [BASE64_STANDARD.encode(&account.data), "base64"],
+                "owner": account.owner.to_string(),
+                "executable": account.executable,
+                "rentEpoch": account.rent_epoch,
+                "space": account.data.len(),
+            }),
+        }
+    }
+}
+
+fn program_account(data: Vec<u8>) -> Account {
+    Account {
+        lamports: 1_000_000,
+        data,
+        owner: PUMPFUN,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn with_context(value: Value) -> Value {
+    json!({
+        "context": { "slot": 1 },
+        "value": value
+    })
+}
+
+#[async_trait]
+impl RpcSender for LocalRpc {
+    async fn send(&self, request: RpcRequest, params: Value) -> Result<Value> {
+        match request {
+            RpcRequest::GetAccountInfo => Ok(with_context(
+                self.account_value(params[0].as_str().unwrap_or_default()),
+            )),
+            RpcRequest::GetMultipleAccounts => Ok(with_context(Value::Array(
+                params[0]
+                    .as_array()
+                    .cloned()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|key| self.account_value(key.as_str().unwrap_or_default()))
+                    .collect(),
+            ))),
+            RpcRequest::GetBalance => {
+                let key = Pubkey::from_str(params[0].as_str().unwrap_or_default()).ok();
+                let lamports = key
+                    .and_then(|key| {
+                        self.0
+                            .accounts
+                            .read()
+                            .unwrap()
+                            .get(&key)
+                            .map(|a| a.lamports)
+                    })
+                    .unwrap_or(0);
+                Ok(with_context(json!(lamports)))
+            }
+            RpcRequest::GetLatestBlockhash => Ok(with_context(json!({
+                "blockhash": Hash::default().to_string(),
+                "lastValidBlockHeight": 1234
+            }))),
+            RpcRequest::GetSlot => Ok(json!(1)),
+            RpcRequest::SendTransaction => {
+                let encoded = params[0].as_str().unwrap_or_default().to_string();
+                let transaction =
+                    EncodedTransaction::Binary(encoded, TransactionBinaryEncoding::Base64)
+                        .decode()
+                        .ok_or_else(|| {
+                            ClientError::from(RpcError::RpcRequestError(
+                                "unable to decode transaction".to_string(),
+                            ))
+                        })?;
+
+                let signature = transaction.signatures[0].to_string();
+                self.0.transactions.write().unwrap().push(transaction);
+                Ok(Value::String(signature))
+            }
+            request => Err(ClientError::from(RpcError::RpcRequestError(format!(
+                "{request} not supported by local rpc"
+            )))),
+        }
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        RpcTransportStats::default()
+    }
+
+    fn url(&self) -> String {
+        "local".to_string()
+    }
+}
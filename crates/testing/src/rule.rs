@@ -5,7 +5,7 @@ use crate::user::{get_or_create_another_user, get_or_create_test_user};
 use base::model::Action::NotifyTelegram;
 use base::model::Condition::Compare;
 use base::model::Operator::MoreThan;
-use base::model::{Field, Rule, RuleId, RuleName, RuleStatus, Sequence, Value};
+use base::model::{Field, Rule, RuleId, RuleLimit, RuleName, RuleRearm, RuleStatus, Sequence, Value};
 use base::repo::{RuleCreateCmd, RuleRepo, RuleUpdateCmd};
 use common::model::{Count, Timeframe};
 use common::repo::Tx;
@@ -50,6 +50,32 @@ pub async fn create_active_rule_for_test_user<'a>(
                 name: created.name,
                 sequence: created.sequence,
                 status: RuleStatus::Active,
+                limit: created.limit,
+            },
+        )
+        .await
+        .unwrap()
+}
+
+/// Creates an inactive rule which gets invoked again whenever its condition matches
+pub async fn create_rearming_rule_for_test_user<'a>(
+    tx: &mut Tx<'a>,
+    name: impl Into<RuleName>,
+) -> Rule {
+    let created = create_inactive_rule_for_test_user(tx, name).await;
+    RuleRepo::new()
+        .update(
+            tx,
+            RuleUpdateCmd {
+                id: created.id,
+                user: created.user,
+                name: created.name,
+                sequence: created.sequence,
+                status: created.status,
+                limit: RuleLimit {
+                    rearm: RuleRearm::Always,
+                    ..created.limit
+                },
             },
         )
         .await
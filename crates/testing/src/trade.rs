@@ -0,0 +1,10 @@
+// Copyright (c) nyanbot.com 2025.
+// This file is licensed under the AGPL-3.0-or-later.
+
+use base::repo::TradeRepo;
+use common::model::Count;
+use common::repo::Tx;
+
+pub async fn count_all<'a>(tx: &mut Tx<'a>) -> Count {
+    TradeRepo::new().count_all(tx).await.unwrap()
+}
@@ -0,0 +1,46 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+alter table solana.invocation add constraint invocation_id_unique unique (id);
+
+create table solana.trade
+(
+    id            bigserial       primary key,
+    invocation_id int8            not null,
+    user_id       int8            not null,
+    wallet_id     int8            not null,
+    token_pair_id int8            not null,
+    venue         int2            not null,
+    side          int2            not null,
+    status        int2            not null,
+    amount_base   numeric(36, 12),
+    amount_quote  numeric(36, 12),
+    price         numeric(36, 12),
+    signature     text,
+    error         text,
+
+    created_at    timestamptz default (timezone('utc', now())),
+    updated_at    timestamptz default (timezone('utc', now())),
+
+    constraint fk_invocation
+        foreign key (invocation_id)
+            references solana.invocation (id)
+            on delete cascade,
+
+    constraint fk_user
+        foreign key (user_id)
+            references nyanbot.user (id)
+            on delete cascade,
+
+    constraint fk_wallet
+        foreign key (wallet_id)
+            references solana.wallet (id)
+            on delete cascade,
+
+    constraint fk_token_pair
+        foreign key (token_pair_id)
+            references solana.token_pair (id)
+            on delete cascade
+);
+
+create index trade_invocation_id_idx on solana.trade (invocation_id);
@@ -0,0 +1,11 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop index solana.invocation_pending_idx;
+
+delete from solana.invocation where stage > 0;
+
+alter table solana.invocation drop constraint invocation_pkey;
+alter table solana.invocation add primary key (rule_id, token_pair_id);
+
+alter table solana.invocation drop column stage;
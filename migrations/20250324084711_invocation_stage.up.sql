@@ -0,0 +1,9 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+alter table solana.invocation add column stage int2 not null default 0;
+
+alter table solana.invocation drop constraint invocation_pkey;
+alter table solana.invocation add primary key (rule_id, token_pair_id, stage);
+
+create index invocation_pending_idx on solana.invocation (rule_id) where next is not null;
@@ -0,0 +1,32 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+create table solana.transfer
+(
+    id         bigserial       primary key,
+    user_id    int8            not null,
+    wallet_id  int8            not null,
+    token_id   int8,
+    recipient  text            not null,
+    amount     numeric(36, 12) not null,
+    signature  text            not null,
+
+    created_at timestamptz default (timezone('utc', now())),
+    updated_at timestamptz default (timezone('utc', now())),
+
+    constraint fk_user
+        foreign key (user_id)
+            references nyanbot.user (id)
+            on delete cascade,
+
+    constraint fk_wallet
+        foreign key (wallet_id)
+            references solana.wallet (id)
+            on delete cascade,
+
+    constraint fk_token
+        foreign key (token_id)
+            references solana.token (id)
+);
+
+create index transfer_wallet_id_idx on solana.transfer (wallet_id);
@@ -0,0 +1,4 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop table solana.token_pair_change;
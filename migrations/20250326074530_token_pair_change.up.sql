@@ -0,0 +1,19 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+create table solana.token_pair_change
+(
+    token_pair_id int8 not null,
+    kind          int2 not null,
+
+    created_at    timestamptz default (timezone('utc', now())),
+
+    primary key (token_pair_id, kind),
+
+    constraint fk_token_pair
+        foreign key (token_pair_id)
+            references solana.token_pair (id)
+            on delete cascade
+);
+
+create index token_pair_change_created_at_idx on solana.token_pair_change (created_at);
@@ -0,0 +1,9 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop index solana.invocation_rule_id_idx;
+
+alter table solana.rule drop column end_at;
+alter table solana.rule drop column start_at;
+alter table solana.rule drop column cooldown_seconds;
+alter table solana.rule drop column max_invocations;
@@ -0,0 +1,9 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+alter table solana.rule add column max_invocations int4;
+alter table solana.rule add column cooldown_seconds int8;
+alter table solana.rule add column start_at timestamptz;
+alter table solana.rule add column end_at timestamptz;
+
+create index invocation_rule_id_idx on solana.invocation (rule_id) where stage = 0;
@@ -0,0 +1,4 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop schema raydium cascade;
\ No newline at end of file
@@ -0,0 +1,99 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+create schema raydium;
+
+create table raydium.swap
+(
+    id            bigserial       not null,
+    slot          int8            not null,
+    address_id    int8            not null,
+    token_pair_id int8            not null,
+    amount_base   numeric(36, 12) not null,
+    amount_quote  numeric(36, 12) not null,
+    price         numeric(36, 12) not null,
+    is_buy        boolean         not null,
+    timestamp     timestamptz     not null,
+    signature     text            not null,
+    created_at    timestamptz not null default (timezone('utc', now())),
+    updated_at    timestamptz not null default (timezone('utc', now())),
+
+    constraint fk_wallet foreign key (address_id) references solana.address (id),
+
+    constraint fk_token_pair foreign key (token_pair_id) references solana.token_pair (id)
+) partition by hash (token_pair_id);
+
+create index swap_token_pair_idx on raydium.swap (token_pair_id);
+
+create table raydium.swap_1 partition of raydium.swap for values with (modulus 8, remainder 0);
+
+create table raydium.swap_2 partition of raydium.swap for values with (modulus 8, remainder 1);
+
+create table raydium.swap_3 partition of raydium.swap for values with (modulus 8, remainder 2);
+
+create table raydium.swap_4 partition of raydium.swap for values with (modulus 8, remainder 3);
+
+create table raydium.swap_5 partition of raydium.swap for values with (modulus 8, remainder 4);
+
+create table raydium.swap_6 partition of raydium.swap for values with (modulus 8, remainder 5);
+
+create table raydium.swap_7 partition of raydium.swap for values with (modulus 8, remainder 6);
+
+create table raydium.swap_8 partition of raydium.swap for values with (modulus 8, remainder 7);
+
+
+create index swap_1_timestamp ON raydium.swap_1 (timestamp desc);
+create index swap_2_timestamp ON raydium.swap_2 (timestamp desc);
+create index swap_3_timestamp ON raydium.swap_3 (timestamp desc);
+create index swap_4_timestamp ON raydium.swap_4 (timestamp desc);
+create index swap_5_timestamp ON raydium.swap_5 (timestamp desc);
+create index swap_6_timestamp ON raydium.swap_6 (timestamp desc);
+create index swap_7_timestamp ON raydium.swap_7 (timestamp desc);
+create index swap_8_timestamp ON raydium.swap_8 (timestamp desc);
+
+
+create table raydium.micro_swap
+(
+    id            bigserial       not null,
+    slot          int8            not null,
+    address_id    int8            not null,
+    token_pair_id int8            not null,
+    amount_base   numeric(36, 12) not null,
+    amount_quote  numeric(36, 12) not null,
+    price         numeric(36, 12) not null,
+    is_buy        boolean         not null,
+    timestamp     timestamptz     not null,
+    signature     text            not null,
+    created_at    timestamptz     not null default (timezone('utc', now())),
+    updated_at    timestamptz     not null default (timezone('utc', now())),
+
+    constraint fk_wallet foreign key (address_id) references solana.address (id),
+    constraint fk_token_pair foreign key (token_pair_id) references solana.token_pair (id)
+);
+
+create table raydium.swap_most_recent as
+select distinct on
+(
+    token_pair_id
+) *
+    from raydium.swap
+    order by token_pair_id, slot desc;
+
+create unique index swap_most_recent_idx on raydium.swap_most_recent (token_pair_id);
+
+create function raydium.update_most_recent_swap() returns trigger as $$
+begin
+delete
+from raydium.swap_most_recent
+where token_pair_id = new.token_pair_id;
+insert into raydium.swap_most_recent
+values (new.*);
+return new;
+end;
+$$
+language plpgsql;
+
+create trigger update_most_recent
+    after insert
+    on raydium.swap
+    for each row execute function raydium.update_most_recent_swap();
\ No newline at end of file
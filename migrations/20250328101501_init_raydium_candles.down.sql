@@ -0,0 +1,14 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+
+drop table raydium.candle_1s_most_recent cascade;
+drop function raydium.candle_1s_update_most_recent cascade;
+drop table raydium.candle_1s cascade;
+
+drop table raydium.candle_1m cascade;
+drop table raydium.candle_5m cascade;
+drop table raydium.candle_15m cascade;
+drop table raydium.candle_1h cascade;
+drop table raydium.candle_6h cascade;
+drop table raydium.candle_1d cascade;
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop table raydium.twap_1m cascade;
+drop table raydium.twap_5m cascade;
+drop table raydium.twap_15m cascade;
+drop table raydium.twap_1h cascade;
+drop table raydium.twap_6h cascade;
+drop table raydium.twap_1d cascade;
\ No newline at end of file
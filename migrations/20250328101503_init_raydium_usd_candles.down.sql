@@ -0,0 +1,9 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop table raydium.candle_usd_1m cascade;
+drop table raydium.candle_usd_5m cascade;
+drop table raydium.candle_usd_15m cascade;
+drop table raydium.candle_usd_1h cascade;
+drop table raydium.candle_usd_6h cascade;
+drop table raydium.candle_usd_1d cascade;
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop table raydium.candle_market_cap_1m cascade;
+drop table raydium.candle_market_cap_5m cascade;
+drop table raydium.candle_market_cap_15m cascade;
+drop table raydium.candle_market_cap_1h cascade;
+drop table raydium.candle_market_cap_6h cascade;
+drop table raydium.candle_market_cap_1d cascade;
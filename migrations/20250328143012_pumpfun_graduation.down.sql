@@ -0,0 +1,4 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+alter table pumpfun.current drop column graduated_at;
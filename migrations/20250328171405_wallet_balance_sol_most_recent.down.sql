@@ -0,0 +1,21 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop trigger trigger_update_most_recent_sol_balance on solana.wallet_balance_sol;
+
+create or replace function solana.update_wallet_balance_sol_most_recent() returns trigger as $$
+begin
+insert into solana.wallet_balance_sol_most_recent (wallet_id, balance, delta, slot, created_at, updated_at)
+values (new.wallet_id, new.balance, new.delta, new.slot, new.created_at, now()) on conflict (wallet_id) do
+update set balance = excluded.balance, delta = excluded.delta, slot = excluded.slot, updated_at = now();
+return null;
+end;
+$$
+language plpgsql;
+
+create trigger trigger_update_most_recent_sol_balance
+    after insert
+    on solana.wallet_balance_sol
+    for each row execute function solana.update_wallet_balance_sol_most_recent();
+
+alter table solana.wallet_balance_sol_most_recent drop column timestamp;
@@ -0,0 +1,27 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+alter table solana.wallet_balance_sol_most_recent add column timestamp timestamptz;
+
+update solana.wallet_balance_sol_most_recent mr
+set timestamp = b.timestamp
+from solana.wallet_balance_sol b
+where b.wallet_id = mr.wallet_id and b.slot = mr.slot;
+
+create or replace function solana.update_wallet_balance_sol_most_recent() returns trigger as $$
+begin
+insert into solana.wallet_balance_sol_most_recent (wallet_id, balance, delta, slot, timestamp, created_at, updated_at)
+values (new.wallet_id, new.balance, new.delta, new.slot, new.timestamp, new.created_at, now()) on conflict (wallet_id) do
+update set balance = excluded.balance, delta = excluded.delta, slot = excluded.slot, timestamp = excluded.timestamp, updated_at = now()
+where solana.wallet_balance_sol_most_recent.slot <= excluded.slot;
+return null;
+end;
+$$
+language plpgsql;
+
+drop trigger trigger_update_most_recent_sol_balance on solana.wallet_balance_sol;
+
+create trigger trigger_update_most_recent_sol_balance
+    after insert or update
+    on solana.wallet_balance_sol
+    for each row execute function solana.update_wallet_balance_sol_most_recent();
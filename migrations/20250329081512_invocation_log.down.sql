@@ -0,0 +1,26 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop index solana.invocation_rule_token_pair_idx;
+
+-- only the first invocation of each stage survives
+delete
+from solana.invocation i
+    using solana.invocation o
+where i.rule_id = o.rule_id
+  and i.token_pair_id = o.token_pair_id
+  and i.stage = o.stage
+  and i.id > o.id;
+
+alter table solana.trade drop constraint fk_invocation;
+alter table solana.invocation drop constraint invocation_pkey;
+alter table solana.invocation add primary key (rule_id, token_pair_id, stage);
+alter table solana.invocation add constraint invocation_id_unique unique (id);
+
+alter table solana.trade add constraint fk_invocation
+    foreign key (invocation_id)
+        references solana.invocation (id)
+        on delete cascade;
+
+alter table solana.rule drop column max_invocations_per_token_pair;
+alter table solana.rule drop column rearm;
@@ -0,0 +1,17 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+alter table solana.rule add column rearm int2 not null default 0;
+alter table solana.rule add column max_invocations_per_token_pair int4;
+
+alter table solana.trade drop constraint fk_invocation;
+alter table solana.invocation drop constraint invocation_id_unique;
+alter table solana.invocation drop constraint invocation_pkey;
+alter table solana.invocation add primary key (id);
+
+alter table solana.trade add constraint fk_invocation
+    foreign key (invocation_id)
+        references solana.invocation (id)
+        on delete cascade;
+
+create index invocation_rule_token_pair_idx on solana.invocation (rule_id, token_pair_id, stage);
@@ -0,0 +1,6 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+alter table solana.indexer drop column watermark;
+
+drop table if exists solana.indexed_slot;
@@ -0,0 +1,12 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+create table solana.indexed_slot
+(
+    slot       int8 primary key,
+    -- 1 = indexed, 2 = skipped by the leader
+    status     int2        not null,
+    created_at timestamptz not null default (timezone('utc', now()))
+);
+
+alter table solana.indexer add column watermark int8;
@@ -0,0 +1,7 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop index if exists solana.indexed_slot_unfinalized_idx;
+
+alter table solana.indexed_slot drop column timestamp;
+alter table solana.indexed_slot drop column commitment;
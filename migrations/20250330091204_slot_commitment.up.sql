@@ -0,0 +1,9 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+-- slots recorded so far are old enough to be finalized
+alter table solana.indexed_slot add column commitment int2 not null default 3;
+alter table solana.indexed_slot alter column commitment drop default;
+alter table solana.indexed_slot add column timestamp timestamptz;
+
+create index indexed_slot_unfinalized_idx on solana.indexed_slot (slot) where commitment < 3;
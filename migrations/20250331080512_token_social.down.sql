@@ -0,0 +1,5 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+alter table solana.token drop column telegram;
+alter table solana.token drop column twitter;
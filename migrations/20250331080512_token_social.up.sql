@@ -0,0 +1,6 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+-- handles as parsed from the token metadata, without the leading @
+alter table solana.token add column twitter text;
+alter table solana.token add column telegram text;
@@ -0,0 +1,8 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop index if exists solana.token_metadata_unresolved_idx;
+
+alter table solana.token drop column metadata_resolved_at;
+alter table solana.token drop column metadata_attempted_at;
+alter table solana.token drop column metadata_attempts;
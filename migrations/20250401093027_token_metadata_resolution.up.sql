@@ -0,0 +1,10 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+-- progress of fetching the off-chain metadata json the token's metadata uri points to
+alter table solana.token add column metadata_attempts int2 not null default 0;
+alter table solana.token add column metadata_attempted_at timestamptz;
+alter table solana.token add column metadata_resolved_at timestamptz;
+
+create index token_metadata_unresolved_idx on solana.token (metadata_attempts, id)
+    where metadata is not null and metadata_resolved_at is null;
@@ -0,0 +1,29 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+-- jupiter.swap holds the trade of the user, these are the swaps with the single AMMs the trade got routed through
+create table jupiter.swap_leg
+(
+    id            bigserial       not null,
+    slot          int8            not null,
+    address_id    int8            not null,
+    token_pair_id int8            not null,
+    amount_base   numeric(36, 12) not null,
+    amount_quote  numeric(36, 12) not null,
+    price         numeric(36, 12) not null,
+    is_buy        boolean         not null,
+    timestamp     timestamptz     not null,
+    signature     text            not null,
+    -- position of the leg among the legs of the transaction
+    leg_index     int2            not null,
+    created_at    timestamptz     not null default (timezone('utc', now())),
+    updated_at    timestamptz     not null default (timezone('utc', now())),
+
+    primary key (id),
+    constraint fk_wallet foreign key (address_id) references solana.address (id),
+    constraint fk_token_pair foreign key (token_pair_id) references solana.token_pair (id),
+    constraint unique_leg unique (signature, leg_index)
+);
+
+create index swap_leg_token_pair_idx on jupiter.swap_leg (token_pair_id, timestamp desc);
+create index swap_leg_slot_idx on jupiter.swap_leg (slot);
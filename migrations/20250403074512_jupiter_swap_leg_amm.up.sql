@@ -0,0 +1,5 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+-- program id of the AMM the leg got routed through
+alter table jupiter.swap_leg add column amm text;
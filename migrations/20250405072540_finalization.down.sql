@@ -0,0 +1,8 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop table solana.token_balance_previous;
+
+alter table pumpfun.current drop column graduated_slot;
+
+alter table solana.token_pair_change drop column slot;
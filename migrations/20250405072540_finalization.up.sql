@@ -0,0 +1,22 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+-- changes of unfinalized slots are held back until the watermark passed their slot
+alter table solana.token_pair_change add column slot int8;
+
+-- the slot which graduated the curve, so that a dropped fork can undo it
+alter table pumpfun.current add column graduated_slot int8;
+
+-- the balance before each unfinalized update, so that a dropped fork can restore it
+create table solana.token_balance_previous
+(
+    address_id         int8 not null,
+    token_id           int8 not null,
+    slot               int8 not null,
+    previous_balance   numeric(36, 12),
+    previous_slot      int8,
+    previous_timestamp timestamptz,
+    primary key (token_id, address_id, slot)
+);
+
+create index token_balance_previous_slot_idx on solana.token_balance_previous (slot);
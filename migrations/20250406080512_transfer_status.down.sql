@@ -0,0 +1,9 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+drop index solana.transfer_wallet_pending_idx;
+
+delete from solana.transfer where signature is null;
+alter table solana.transfer alter column signature set not null;
+alter table solana.transfer drop column error;
+alter table solana.transfer drop column status;
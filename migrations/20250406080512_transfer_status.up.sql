@@ -0,0 +1,11 @@
+-- Copyright (c) nyanbot.com 2025.
+-- This file is licensed under the AGPL-3.0-or-later.
+
+-- transfers are recorded as pending before they get sent and receive their signature afterwards
+alter table solana.transfer add column status int2 not null default 1;
+alter table solana.transfer alter column status drop default;
+alter table solana.transfer add column error text;
+alter table solana.transfer alter column signature drop not null;
+
+-- a wallet sends one transfer at a time, so that concurrent requests can not spend the same balance
+create unique index transfer_wallet_pending_idx on solana.transfer (wallet_id) where status = 3;